@@ -215,9 +215,9 @@ impl Valgrind {
                     .args([
                         "-g", // Debug info
                         "-O0", // No optimization for better analysis
-                        "-o", executable_path.to_str().unwrap(),
-                        file_path.to_str().unwrap(),
-                    ]);
+                    ])
+                    .arg("-o").arg(&executable_path)
+                    .arg(file_path);
                     
                 let output = cmd.output().context("Failed to compile C code")?;
                 
@@ -233,9 +233,9 @@ impl Valgrind {
                     .args([
                         "-g", // Debug info
                         "-O0", // No optimization for better analysis
-                        "-o", executable_path.to_str().unwrap(),
-                        file_path.to_str().unwrap(),
-                    ]);
+                    ])
+                    .arg("-o").arg(&executable_path)
+                    .arg(file_path);
                     
                 let output = cmd.output().context("Failed to compile C++ code")?;
                 
@@ -736,9 +736,9 @@ impl AddressSanitizer {
                         "-g",                 // Debug info
                         "-O1",                // Optimize slightly for better ASan results
                         "-fno-omit-frame-pointer", // Better stack traces
-                        "-o", executable_path.to_str().unwrap(),
-                        file_path.to_str().unwrap(),
-                    ]);
+                    ])
+                    .arg("-o").arg(&executable_path)
+                    .arg(file_path);
                     
                 let output = cmd.output().context("Failed to compile C code with ASan")?;
                 
@@ -763,9 +763,9 @@ impl AddressSanitizer {
                         "-g",                 // Debug info
                         "-O1",                // Optimize slightly for better ASan results
                         "-fno-omit-frame-pointer", // Better stack traces
-                        "-o", executable_path.to_str().unwrap(),
-                        file_path.to_str().unwrap(),
-                    ]);
+                    ])
+                    .arg("-o").arg(&executable_path)
+                    .arg(file_path);
                     
                 let output = cmd.output().context("Failed to compile C++ code with ASan")?;
                 
@@ -1150,7 +1150,9 @@ impl Analyzer for RustMemoryLeakDetector {
         
         // Create a wrapper main.rs if the file isn't already main.rs
         if file_path.file_name().unwrap() != "main.rs" {
-            let mod_name = file_path.file_stem().unwrap().to_str().unwrap();
+            let mod_name = file_path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or_else(|| anyhow!("Rust file name {} is not valid UTF-8", file_path.display()))?;
             let main_rs_path = src_dir.join("main.rs");
             let main_rs_content = format!(r#"
 mod {};
@@ -1717,7 +1719,9 @@ impl Analyzer for PythonMemoryAnalyzer {
         };
         
         // Copy the Python file to the temp directory
-        let file_name = file_path.file_name().unwrap().to_str().unwrap();
+        let file_name = file_path.file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow!("Python file name {} is not valid UTF-8", file_path.display()))?;
         let dest_path = temp_dir.path().join(file_name);
         fs::copy(file_path, &dest_path).context("Failed to copy Python file")?;
         
@@ -205,9 +205,9 @@ impl PerfCpuProfiler {
                     .args([
                         "-g", // Debug info
                         "-O0", // No optimization for better profiling
-                        "-o", executable_path.to_str().unwrap(),
-                        file_path.to_str().unwrap(),
-                    ]);
+                    ])
+                    .arg("-o").arg(&executable_path)
+                    .arg(file_path);
                     
                 let output = cmd.output().context("Failed to compile C code")?;
                 
@@ -223,9 +223,9 @@ impl PerfCpuProfiler {
                     .args([
                         "-g", // Debug info
                         "-O0", // No optimization for better profiling
-                        "-o", executable_path.to_str().unwrap(),
-                        file_path.to_str().unwrap(),
-                    ]);
+                    ])
+                    .arg("-o").arg(&executable_path)
+                    .arg(file_path);
                     
                 let output = cmd.output().context("Failed to compile C++ code")?;
                 
@@ -2,12 +2,22 @@ use std::path::{Path, PathBuf};
 use std::fs;
 use std::collections::HashMap;
 use std::env;
+use std::fmt;
+use std::time::SystemTime;
 use serde::{Serialize, Deserialize};
 use anyhow::{Result, anyhow, Context};
 use log::{debug, info, warn};
 use dirs;
 
+pub mod wizard;
+
 // Main configuration struct that includes all settings
+/// Default cap on how much validator output is kept per stream
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 256 * 1024;
+
+/// Default cap on the issues reported for a single file
+pub const DEFAULT_MAX_ISSUES_PER_FILE: usize = 100;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     // General settings
@@ -16,15 +26,74 @@ pub struct Config {
     pub watch: bool,
     pub watch_interval: u64,
     pub timeout: u64,
+    pub summary_only: bool,
+    // Stop at the first file that fails or errors (set by --fail-fast)
+    pub fail_fast: bool,
+    // Start watching without validating every file first (set by --no-initial-scan)
+    pub skip_initial_scan: bool,
+    // Validate files that look binary instead of skipping them
+    pub allow_binary: bool,
+    // Validate fenced code blocks in Markdown files (set by --check-code-blocks)
+    pub check_code_blocks: bool,
+    // Keep validator tools off the network (set by --offline)
+    pub offline: bool,
+    // Only validate files modified at or after this time (set by --since)
+    pub modified_since: Option<SystemTime>,
+    // Most bytes of a validator's stdout/stderr kept; the rest is dropped
+    pub max_output_bytes: usize,
+    // Most issues reported for one file; the rest are summarized
+    pub max_issues_per_file: usize,
+    
+    // Languages to validate (empty means all)
+    pub languages: Vec<String>,
     
     // Paths to config files that were loaded
     pub loaded_config_paths: Vec<PathBuf>,
     
-    // Custom file extensions mappings
+    // Custom file type mappings, by exact file name or glob pattern
     pub file_mappings: HashMap<String, String>,
     
     // Language-specific settings
     pub validators: ValidatorConfigs,
+    
+    // Source file encoding checks
+    pub encoding: EncodingConfig,
+    
+    // Explicit paths for validator tools (e.g. "gcc" -> "/usr/bin/gcc-12")
+    pub tools: HashMap<String, String>,
+    
+    // Per-function complexity budget
+    pub complexity: ComplexityConfig,
+    
+    // Whitespace and line-length rules, on top of .editorconfig
+    pub hygiene: HygieneConfig,
+    
+    // Patterns for credentials committed in files
+    pub secrets: SecretsConfig,
+    
+    // Most copies of a tool (e.g. "dotnet" -> 2) a scan runs at once
+    pub concurrency: HashMap<String, usize>,
+    
+    // How scans treat generated files (protobuf output, minified bundles, ...)
+    pub generated: GeneratedConfig,
+    
+    // Strict mode for new files only, while a codebase catches up
+    pub adoption: AdoptionConfig,
+    
+    // Quality budgets enforced by `synx gate`
+    pub gate: GateConfig,
+    
+    // Project defaults for `synx scan`; flags override them
+    pub scan: ScanConfig,
+    
+    // Terminal output settings
+    pub ui: UiConfig,
+    
+    // Extra tree-sitter grammars for the TUI's syntax tree view
+    pub treesitter: TreeSitterConfig,
+    
+    // Validate every file as this type, whatever its name (set by --lang)
+    pub language: Option<crate::detectors::FileType>,
 }
 
 // Container for all language-specific configurations
@@ -49,101 +118,394 @@ pub struct ValidatorConfigs {
     pub custom: HashMap<String, CustomValidatorConfig>,
 }
 
+impl ValidatorConfigs {
+    /// Per-file-type timeout override for a validator section name (e.g. "rust")
+    pub fn timeout_for(&self, language: &str) -> Option<u64> {
+        match language {
+            "rust" => self.rust.timeout_secs,
+            "cpp" => self.cpp.timeout_secs,
+            "c" => self.c.timeout_secs,
+            "csharp" => self.csharp.timeout_secs,
+            "python" => self.python.timeout_secs,
+            "javascript" => self.javascript.timeout_secs,
+            "typescript" => self.typescript.timeout_secs,
+            "go" => self.go.timeout_secs,
+            "java" => self.java.timeout_secs,
+            "html" => self.html.timeout_secs,
+            "css" => self.css.timeout_secs,
+            "yaml" => self.yaml.timeout_secs,
+            "json" => self.json.timeout_secs,
+            "shell" => self.shell.timeout_secs,
+            "dockerfile" => self.dockerfile.timeout_secs,
+            _ => None,
+        }
+    }
+    
+    /// Per-file-type strictness for a validator section name, overriding --strict
+    pub fn strict_for(&self, language: &str) -> Option<bool> {
+        match language {
+            "rust" => self.rust.strict,
+            "cpp" => self.cpp.strict,
+            "c" => self.c.strict,
+            "csharp" => self.csharp.strict,
+            "python" => self.python.strict,
+            "javascript" => self.javascript.strict,
+            "typescript" => self.typescript.strict,
+            "go" => self.go.strict,
+            "java" => self.java.strict,
+            "html" => self.html.strict,
+            "css" => self.css.strict,
+            "yaml" => self.yaml.strict,
+            "json" => self.json.strict,
+            "shell" => self.shell.strict,
+            "dockerfile" => self.dockerfile.strict,
+            _ => None,
+        }
+    }
+    
+    /// A validator section's settings, as they'd be written to a config file
+    pub fn settings_for(&self, language: &str) -> serde_json::Value {
+        let settings = match language {
+            "rust" => serde_json::to_value(&self.rust),
+            "cpp" => serde_json::to_value(&self.cpp),
+            "c" => serde_json::to_value(&self.c),
+            "csharp" => serde_json::to_value(&self.csharp),
+            "python" => serde_json::to_value(&self.python),
+            "javascript" => serde_json::to_value(&self.javascript),
+            "typescript" => serde_json::to_value(&self.typescript),
+            "go" => serde_json::to_value(&self.go),
+            "java" => serde_json::to_value(&self.java),
+            "html" => serde_json::to_value(&self.html),
+            "css" => serde_json::to_value(&self.css),
+            "yaml" => serde_json::to_value(&self.yaml),
+            "json" => serde_json::to_value(&self.json),
+            "shell" => serde_json::to_value(&self.shell),
+            "dockerfile" => serde_json::to_value(&self.dockerfile),
+            _ => return serde_json::Value::Null,
+        };
+        settings.unwrap_or_default()
+    }
+    
+    /// Whether `[validators.<language>] enabled = false` (or --disable) turned
+    /// a validator section off
+    pub fn is_disabled(&self, language: &str) -> bool {
+        let enabled = match language {
+            "rust" => self.rust.enabled,
+            "cpp" => self.cpp.enabled,
+            "c" => self.c.enabled,
+            "csharp" => self.csharp.enabled,
+            "python" => self.python.enabled,
+            "javascript" => self.javascript.enabled,
+            "typescript" => self.typescript.enabled,
+            "go" => self.go.enabled,
+            "java" => self.java.enabled,
+            "html" => self.html.enabled,
+            "css" => self.css.enabled,
+            "yaml" => self.yaml.enabled,
+            "json" => self.json.enabled,
+            "shell" => self.shell.enabled,
+            "dockerfile" => self.dockerfile.enabled,
+            _ => None,
+        };
+        enabled == Some(false)
+    }
+    
+    /// Turn off the validator for a language, named any way `FileType::from_name`
+    /// understands (e.g. "yml" or "yaml")
+    pub fn disable(&mut self, name: &str) -> Result<()> {
+        let unknown = || anyhow!("No validator to disable for '{}'", name);
+        let section = crate::detectors::FileType::from_name(name)
+            .as_ref()
+            .and_then(validator_section)
+            .ok_or_else(unknown)?;
+        let enabled = match section {
+            "rust" => &mut self.rust.enabled,
+            "cpp" => &mut self.cpp.enabled,
+            "c" => &mut self.c.enabled,
+            "csharp" => &mut self.csharp.enabled,
+            "python" => &mut self.python.enabled,
+            "javascript" => &mut self.javascript.enabled,
+            "typescript" => &mut self.typescript.enabled,
+            "go" => &mut self.go.enabled,
+            "java" => &mut self.java.enabled,
+            "html" => &mut self.html.enabled,
+            "css" => &mut self.css.enabled,
+            "yaml" => &mut self.yaml.enabled,
+            "json" => &mut self.json.enabled,
+            "shell" => &mut self.shell.enabled,
+            "dockerfile" => &mut self.dockerfile.enabled,
+            _ => return Err(unknown()),
+        };
+        *enabled = Some(false);
+        Ok(())
+    }
+}
+
+/// The `[validators.<section>]` that configures files of a type, if any
+pub fn validator_section(file_type: &crate::detectors::FileType) -> Option<&'static str> {
+    use crate::detectors::FileType;
+    let section = match file_type {
+        FileType::Rust => "rust",
+        FileType::Cpp => "cpp",
+        FileType::C => "c",
+        FileType::CSharp => "csharp",
+        FileType::Python => "python",
+        FileType::JavaScript | FileType::Jsx => "javascript",
+        FileType::TypeScript | FileType::Tsx => "typescript",
+        FileType::Go => "go",
+        FileType::Java => "java",
+        FileType::Html => "html",
+        FileType::Css => "css",
+        FileType::Yaml => "yaml",
+        FileType::Json => "json",
+        FileType::Shell => "shell",
+        FileType::Dockerfile => "dockerfile",
+        _ => return None,
+    };
+    Some(section)
+}
+
 // Language-specific configuration structs
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct RustConfig {
     pub edition: Option<String>,       // Rust edition to use (e.g., "2021")
     pub clippy: Option<bool>,          // Whether to run clippy
     pub clippy_flags: Option<Vec<String>>, // Additional clippy flags
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct CppConfig {
     pub standard: Option<String>,      // C++ standard to use (e.g., "c++17")
     pub include_paths: Option<Vec<String>>, // Additional include paths
+    pub defines: Option<Vec<String>>,  // Preprocessor defines (e.g., "DEBUG" or "VERSION=2")
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct CConfig {
     pub standard: Option<String>,      // C standard to use (e.g., "c11")
     pub check_memory: Option<bool>,    // Whether to check for memory leaks
     pub include_paths: Option<Vec<String>>, // Additional include paths
+    pub defines: Option<Vec<String>>,  // Preprocessor defines (e.g., "DEBUG" or "VERSION=2")
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct CSharpConfig {
     pub use_dotnet: Option<bool>,      // Whether to prefer dotnet CLI over Mono
     pub framework: Option<String>,     // Target framework (e.g., "net6.0")
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct PythonConfig {
     pub mypy_strict: Option<bool>,     // Whether to use strict type checking
     pub pylint_threshold: Option<f64>, // Pylint score threshold
     pub ignore_rules: Option<Vec<String>>, // Rules to ignore
+    pub interpreters: Option<Vec<String>>, // Compile under each of these (e.g. python3.8); defaults to python3
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct JavaScriptConfig {
     pub eslint_config: Option<String>, // Path to custom ESLint config
     pub node_version: Option<String>,  // Target Node.js version
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct TypeScriptConfig {
     pub eslint_config: Option<String>, // Path to custom ESLint config
     pub tsconfig: Option<String>,      // Path to tsconfig.json
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct GoConfig {
     pub test: Option<bool>,            // Whether to run tests
     pub lint_flags: Option<Vec<String>>, // Additional golangci-lint flags
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct JavaConfig {
     pub checkstyle_config: Option<String>, // Path to checkstyle config
     pub version: Option<String>,       // Java version to target
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct HtmlConfig {
     pub tidy_flags: Option<Vec<String>>, // Additional tidy flags
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct CssConfig {
     pub csslint_flags: Option<Vec<String>>, // Additional csslint flags
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct YamlConfig {
     pub custom_config: Option<String>, // Path to custom yamllint config
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct JsonConfig {
     pub allow_comments: Option<bool>,  // Whether to allow comments in JSON
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct ShellConfig {
     pub shell_type: Option<String>,    // Shell type (bash, sh, zsh)
     pub ignore_rules: Option<Vec<String>>, // Shellcheck rules to ignore
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct DockerfileConfig {
     pub ignore_rules: Option<Vec<String>>, // Hadolint rules to ignore
+    pub timeout_secs: Option<u64>,     // Overrides the global timeout for this file type
+    pub enabled: Option<bool>,         // false skips these files instead of validating them
+    pub strict: Option<bool>,          // Overrides --strict for these files
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EncodingConfig {
+    pub require_utf8: Option<bool>,    // Fail files that aren't valid UTF-8
+    pub forbid_bom: Option<bool>,      // Fail files that start with a byte order mark
+    pub fix: Option<bool>,             // Strip BOMs and normalize line endings before validating
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComplexityConfig {
+    pub enabled: Option<bool>,         // Fail files with functions over budget
+    pub max_cyclomatic: Option<usize>, // Highest allowed cyclomatic complexity per function
+    pub max_cognitive: Option<usize>,  // Highest allowed cognitive complexity per function
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HygieneConfig {
+    pub enabled: Option<bool>,         // Fail files that break the whitespace rules
+    pub editorconfig: Option<bool>,    // Read rules from the nearest .editorconfig (default: true)
+    pub indent_style: Option<String>,  // "space" or "tab"; overrides .editorconfig
+    pub indent_size: Option<usize>,    // Spaces per indentation level; overrides .editorconfig
+    pub max_line_length: Option<usize>, // Longest allowed line in characters; overrides .editorconfig
+    pub insert_final_newline: Option<bool>, // Require a newline at the end of the file
+    pub trim_trailing_whitespace: Option<bool>, // Forbid whitespace at the end of lines
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecretsConfig {
+    pub enabled: Option<bool>,         // Fail files that contain likely secrets
+    pub builtin: Option<bool>,         // Use the built-in patterns (AWS keys, private keys, tokens; default: true)
+    pub patterns: Option<HashMap<String, String>>, // Extra patterns by name; the first capture group, if any, is the secret
+    pub min_entropy: Option<f64>,      // Bits per character for a long token to count as a key (default: 4.2, 0 disables)
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GeneratedConfig {
+    pub action: Option<String>,        // "skip" (default), "warn" or "validate"
+    pub markers: Option<Vec<String>>,  // Header text that marks a file as generated
+    pub suffixes: Option<Vec<String>>, // File name endings of generated files (e.g. ".min.js")
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdoptionConfig {
+    pub strict_after: Option<String>,  // Files created on or after this date (YYYY-MM-DD) are validated strictly
+    pub source: Option<String>,        // Where creation dates come from: "git" (default) or "mtime"
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GateConfig {
+    pub min_quality: Option<f64>,      // Lowest overall quality score (0-100) allowed; default 60
+    pub max_debt_ratio: Option<f64>,   // Most technical debt hours per 1000 lines
+    pub max_critical_complexity_files: Option<usize>, // Most files with cyclomatic complexity over 20
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScanConfig {
+    pub exclude: Option<Vec<String>>,  // Glob patterns always excluded, on top of --exclude
+    pub default_parallel: Option<usize>, // Workers when --parallel isn't given
+    pub default_format: Option<String>, // Output format when --format isn't given
+    pub empty_files: Option<String>,    // "pass" (default), "skip" or "fail" for empty or whitespace-only files
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TreeSitterConfig {
+    pub grammars: Option<HashMap<String, String>>, // File extension (e.g. "zig") -> grammar library exporting tree_sitter_<extension>
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct UiConfig {
+    pub glyphs: Option<String>,        // "auto" (default), "unicode" or "ascii" status marks
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct CustomValidatorConfig {
     pub command: String,               // Command to run
     pub args: Option<Vec<String>>,     // Arguments for the command
     pub strict_args: Option<Vec<String>>, // Additional args for strict mode
     pub success_pattern: Option<String>, // Regex pattern for success
+    pub working_dir: Option<String>,   // Directory to run in; defaults to the file's project root
 }
 
 // Implement Default for each config struct
@@ -153,6 +515,9 @@ impl Default for RustConfig {
             edition: Some("2021".to_string()),
             clippy: Some(false),
             clippy_flags: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -162,6 +527,10 @@ impl Default for CppConfig {
         Self {
             standard: Some("c++17".to_string()),
             include_paths: None,
+            defines: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -172,6 +541,10 @@ impl Default for CConfig {
             standard: Some("c11".to_string()),
             check_memory: Some(false),
             include_paths: None,
+            defines: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -181,6 +554,9 @@ impl Default for CSharpConfig {
         Self {
             use_dotnet: Some(true),
             framework: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -191,6 +567,10 @@ impl Default for PythonConfig {
             mypy_strict: Some(false),
             pylint_threshold: Some(7.0),
             ignore_rules: None,
+            interpreters: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -200,6 +580,9 @@ impl Default for JavaScriptConfig {
         Self {
             eslint_config: None,
             node_version: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -209,6 +592,9 @@ impl Default for TypeScriptConfig {
         Self {
             eslint_config: None,
             tsconfig: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -218,6 +604,9 @@ impl Default for GoConfig {
         Self {
             test: Some(false),
             lint_flags: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -227,6 +616,9 @@ impl Default for JavaConfig {
         Self {
             checkstyle_config: None,
             version: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -235,6 +627,9 @@ impl Default for HtmlConfig {
     fn default() -> Self {
         Self {
             tidy_flags: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -243,6 +638,9 @@ impl Default for CssConfig {
     fn default() -> Self {
         Self {
             csslint_flags: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -251,6 +649,9 @@ impl Default for YamlConfig {
     fn default() -> Self {
         Self {
             custom_config: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -259,6 +660,9 @@ impl Default for JsonConfig {
     fn default() -> Self {
         Self {
             allow_comments: Some(false),
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -268,6 +672,9 @@ impl Default for ShellConfig {
         Self {
             shell_type: None,
             ignore_rules: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
         }
     }
 }
@@ -276,28 +683,71 @@ impl Default for DockerfileConfig {
     fn default() -> Self {
         Self {
             ignore_rules: None,
+            timeout_secs: None,
+            enabled: None,
+            strict: None,
+        }
+    }
+}
+
+impl Default for EncodingConfig {
+    fn default() -> Self {
+        Self {
+            require_utf8: Some(false),
+            forbid_bom: Some(false),
+            fix: Some(false),
+        }
+    }
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
+            max_cyclomatic: Some(10),
+            max_cognitive: Some(15),
         }
     }
 }
 
 // TOML config file structure
 #[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 struct ConfigFile {
     general: Option<GeneralConfig>,
     validators: Option<ValidatorsConfig>,
     file_mappings: Option<HashMap<String, String>>,
+    encoding: Option<EncodingConfig>,
+    tools: Option<HashMap<String, String>>,
+    complexity: Option<ComplexityConfig>,
+    hygiene: Option<HygieneConfig>,
+    secrets: Option<SecretsConfig>,
+    concurrency: Option<HashMap<String, usize>>,
+    generated: Option<GeneratedConfig>,
+    adoption: Option<AdoptionConfig>,
+    gate: Option<GateConfig>,
+    scan: Option<ScanConfig>,
+    ui: Option<UiConfig>,
+    treesitter: Option<TreeSitterConfig>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 struct GeneralConfig {
     strict: Option<bool>,
     verbose: Option<bool>,
     watch: Option<bool>,
+    #[serde(alias = "interval")]
     watch_interval: Option<u64>,
     timeout: Option<u64>,
+    summary_only: Option<bool>,
+    languages: Option<Vec<String>>,
+    max_output_bytes: Option<usize>,
+    max_issues_per_file: Option<usize>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 struct ValidatorsConfig {
     rust: Option<RustConfig>,
     cpp: Option<CppConfig>,
@@ -331,9 +781,32 @@ impl Default for Config {
             watch: false,
             watch_interval: 2,
             timeout: 30,
+            summary_only: false,
+            fail_fast: false,
+            skip_initial_scan: false,
+            allow_binary: false,
+            check_code_blocks: false,
+            offline: false,
+            modified_since: None,
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
+            max_issues_per_file: DEFAULT_MAX_ISSUES_PER_FILE,
+            languages: Vec::new(),
             loaded_config_paths: Vec::new(),
             file_mappings,
             validators: ValidatorConfigs::default(),
+            encoding: EncodingConfig::default(),
+            tools: HashMap::new(),
+            complexity: ComplexityConfig::default(),
+            hygiene: HygieneConfig::default(),
+            secrets: SecretsConfig::default(),
+            concurrency: HashMap::new(),
+            generated: GeneratedConfig::default(),
+            adoption: AdoptionConfig::default(),
+            gate: GateConfig::default(),
+            scan: ScanConfig::default(),
+            ui: UiConfig::default(),
+            treesitter: TreeSitterConfig::default(),
+            language: None,
         }
     }
 }
@@ -397,6 +870,14 @@ impl Config {
         Ok(config)
     }
     
+    /// Layer the settings of the config file at `path` on top of this config
+    pub fn merge_file(&mut self, path: &Path) -> Result<()> {
+        let config_file = load_config_file(path)?;
+        self.merge_from_config_file(&config_file)?;
+        self.loaded_config_paths.push(path.to_path_buf());
+        Ok(())
+    }
+    
     /// Merges settings from a config file into this config
     fn merge_from_config_file(&mut self, config_file: &ConfigFile) -> Result<()> {
         // Merge general settings
@@ -416,6 +897,18 @@ impl Config {
             if let Some(timeout) = general.timeout {
                 self.timeout = timeout;
             }
+            if let Some(summary_only) = general.summary_only {
+                self.summary_only = summary_only;
+            }
+            if let Some(languages) = &general.languages {
+                self.languages = languages.clone();
+            }
+            if let Some(max_output_bytes) = general.max_output_bytes {
+                self.max_output_bytes = max_output_bytes;
+            }
+            if let Some(max_issues) = general.max_issues_per_file {
+                self.max_issues_per_file = max_issues;
+            }
         }
 
         // Merge file mappings
@@ -430,6 +923,89 @@ impl Config {
             self.merge_validator_configs(validators)?;
         }
         
+        // Merge encoding settings
+        if let Some(encoding) = &config_file.encoding {
+            merge_into(&mut self.encoding, encoding);
+        }
+        
+        // Merge complexity budget
+        if let Some(complexity) = &config_file.complexity {
+            merge_into(&mut self.complexity, complexity);
+        }
+        
+        // Merge whitespace rules
+        if let Some(hygiene) = &config_file.hygiene {
+            merge_into(&mut self.hygiene, hygiene);
+        }
+        
+        // Merge secret patterns, catching a bad regex before any file needs it
+        if let Some(secrets) = &config_file.secrets {
+            crate::validators::secrets::check_config(secrets)?;
+            merge_into(&mut self.secrets, secrets);
+        }
+        
+        // Merge tool paths
+        if let Some(tools) = &config_file.tools {
+            for (name, path) in tools {
+                self.tools.insert(name.clone(), path.clone());
+            }
+        }
+        
+        // Merge generated-file policy
+        if let Some(generated) = &config_file.generated {
+            merge_into(&mut self.generated, generated);
+        }
+        
+        // Merge strictness adoption policy, catching a bad date before any file needs it
+        if let Some(adoption) = &config_file.adoption {
+            if let Some(date) = &adoption.strict_after {
+                crate::validators::adoption::parse_cutoff(date)?;
+            }
+            crate::validators::adoption::age_source(adoption)?;
+            merge_into(&mut self.adoption, adoption);
+        }
+        
+        // Merge quality gate budgets
+        if let Some(gate) = &config_file.gate {
+            merge_into(&mut self.gate, gate);
+        }
+        
+        // Merge scan defaults, rejecting ones the scan command would
+        if let Some(scan) = &config_file.scan {
+            if let Some(format) = &scan.default_format {
+                if format != "ndjson" && !crate::validators::REPORT_FORMATS.contains(&format.as_str()) {
+                    return Err(anyhow!("Invalid [scan] default_format '{}' (expected one of: ndjson, {})",
+                        format, crate::validators::REPORT_FORMATS.join(", ")));
+                }
+            }
+            if scan.default_parallel == Some(0) {
+                return Err(anyhow!("Invalid [scan] default_parallel 0 (expected at least 1)"));
+            }
+            crate::validators::empty::policy(scan.empty_files.as_deref())?;
+            merge_into(&mut self.scan, scan);
+        }
+        
+        // Merge terminal output settings
+        if let Some(ui) = &config_file.ui {
+            crate::glyphs::GlyphSet::from_name(ui.glyphs.as_deref())?;
+            merge_into(&mut self.ui, ui);
+        }
+        
+        // Merge extra tree-sitter grammars
+        if let Some(treesitter) = &config_file.treesitter {
+            merge_into(&mut self.treesitter, treesitter);
+        }
+        
+        // Merge per-tool concurrency limits
+        if let Some(concurrency) = &config_file.concurrency {
+            for (tool, &limit) in concurrency {
+                if limit == 0 {
+                    return Err(anyhow!("Concurrency limit for '{}' must be at least 1", tool));
+                }
+                self.concurrency.insert(tool.clone(), limit);
+            }
+        }
+        
         Ok(())
     }
     
@@ -508,19 +1084,72 @@ impl Config {
         Ok(())
     }
     
-    /// Generate a default configuration file at the default path
-    pub fn generate_default_config() -> Result<PathBuf> {
+    /// Write a config built from `template` to `path`. An existing file is
+    /// only replaced when `force` is set.
+    pub fn init_config_file(path: &Path, template: ConfigTemplate, force: bool) -> Result<()> {
+        if path.exists() && !force {
+            return Err(anyhow!("A configuration already exists at {} (use --force to overwrite it)", path.display()));
+        }
+        template.config().save_to_file(path)
+    }
+    
+    /// Generate a configuration file from a template at the default path
+    pub fn generate_default_config(template: ConfigTemplate, force: bool) -> Result<PathBuf> {
         let config_path = get_default_config_path()
             .context("Failed to determine default config path")?;
         
-        let config = Config::default();
-        config.save_to_file(&config_path)?;
-        
-        println!("Created default config at {:?}", config_path);
+        Self::init_config_file(&config_path, template, force)?;
         Ok(config_path)
     }
 }
 
+/// Starting points for `synx config init --template`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigTemplate {
+    /// The built-in defaults
+    #[default]
+    Minimal,
+    /// Strict mode, with clippy and strict mypy
+    Strict,
+    /// Summary output for CI logs and longer timeouts for shared runners
+    Ci,
+}
+
+impl ConfigTemplate {
+    pub const NAMES: [&'static str; 3] = ["minimal", "strict", "ci"];
+    
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "minimal" => Ok(ConfigTemplate::Minimal),
+            "strict" => Ok(ConfigTemplate::Strict),
+            "ci" => Ok(ConfigTemplate::Ci),
+            other => Err(anyhow!("Unknown config template '{}' (expected one of: {})", other, Self::NAMES.join(", "))),
+        }
+    }
+    
+    /// The configuration this template starts from
+    pub fn config(&self) -> Config {
+        let mut config = Config::default();
+        match self {
+            ConfigTemplate::Minimal => {}
+            ConfigTemplate::Strict => {
+                config.strict = true;
+                config.validators.rust.clippy = Some(true);
+                config.validators.python.mypy_strict = Some(true);
+            }
+            ConfigTemplate::Ci => {
+                // A one-line summary plus failing files reads well in CI logs,
+                // and stays plain when NO_COLOR is set
+                config.summary_only = true;
+                config.verbose = false;
+                config.watch = false;
+                config.timeout = 120;
+            }
+        }
+        config
+    }
+}
+
 /// Get the default configuration file path
 pub fn get_default_config_path() -> Result<PathBuf> {
     let home_dir = dirs::home_dir()
@@ -604,12 +1233,307 @@ fn load_config_file(path: &Path) -> Result<ConfigFile> {
     let content = fs::read_to_string(path)
         .context(format!("Failed to read configuration file: {}", path.display()))?;
     
-    let config: ConfigFile = toml::from_str(&content)
+    let (config, unknown_keys) = parse_config_lenient(&content)
         .context(format!("Failed to parse TOML in file: {}", path.display()))?;
+    for key in unknown_keys {
+        warn!("Ignoring unknown key `{}` in {} (run `synx config validate` for details)", key, path.display());
+    }
     
     Ok(config)
 }
 
+/// Parse a config file, skipping keys the schema doesn't know instead of
+/// failing on them, so configs written for newer versions still load. The
+/// skipped keys are returned; any other error (bad syntax, wrong type) fails.
+/// `validate_config_str` is the strict check.
+fn parse_config_lenient(content: &str) -> Result<(ConfigFile, Vec<String>)> {
+    let mut table: toml::Table = toml::from_str(content)?;
+    let mut unknown_keys = Vec::new();
+
+    loop {
+        // Deserialize the table written back out, where every table has its own
+        // header, so the error location is enough to tell which key it is about
+        let source = toml::to_string(&table)?;
+        let error = match toml::from_str::<ConfigFile>(&source) {
+            Ok(config) => return Ok((config, unknown_keys)),
+            Err(e) => e,
+        };
+
+        let lines: Vec<String> = source.lines().map(String::from).collect();
+        let line = error.span().map(|span| line_of_offset(&source, span.start));
+        match parse_unknown_field(error.message()).map(|(field, _)| qualify_key(&lines, line, &field)) {
+            Some(key) if remove_key(&mut table, &key) => unknown_keys.push(key),
+            _ => return Err(error.into()),
+        }
+    }
+}
+
+/// Keys that are still accepted but have been superseded: (old, replacement)
+const DEPRECATED_KEYS: &[(&str, &str)] = &[
+    ("general.interval", "general.watch_interval"),
+];
+
+/// How serious a problem found while validating a config file is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigIssueLevel {
+    /// The file will not load (syntax error, unknown key, wrong type)
+    Error,
+    /// The file loads but uses something that should be changed (deprecated key)
+    Warning,
+}
+
+/// A single problem found while validating a config file
+#[derive(Debug, Clone)]
+pub struct ConfigIssue {
+    pub level: ConfigIssueLevel,
+    pub line: Option<usize>,           // 1-based line number in the file
+    pub key: Option<String>,           // Dotted key path, e.g. "general.strict"
+    pub message: String,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Validate a configuration file against the config schema
+pub fn validate_config_file(path: &Path) -> Result<Vec<ConfigIssue>> {
+    let content = fs::read_to_string(path)
+        .context(format!("Failed to read configuration file: {}", path.display()))?;
+
+    Ok(validate_config_str(&content))
+}
+
+/// Validate configuration TOML against the config schema.
+///
+/// Every unknown or misspelled key is reported, not just the first one:
+/// after each failure the offending line is commented out and the
+/// document is checked again.
+pub fn validate_config_str(content: &str) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+
+    // Deprecated keys still deserialize (via serde aliases), so look for them separately
+    if let Ok(table) = toml::from_str::<toml::Table>(content) {
+        for (old, new) in DEPRECATED_KEYS {
+            if lookup_key(&table, old) {
+                issues.push(ConfigIssue {
+                    level: ConfigIssueLevel::Warning,
+                    line: locate_key(content, old),
+                    key: Some(old.to_string()),
+                    message: format!("`{}` is deprecated, use `{}` instead", old, new),
+                });
+            }
+        }
+    }
+
+    let mut source = content.to_string();
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+
+    loop {
+        if let Err(e) = toml::from_str::<toml::Table>(&source) {
+            // Only report syntax errors in the original text, not ones introduced
+            // by commenting out a multi-line value below
+            if source == content {
+                issues.push(ConfigIssue {
+                    level: ConfigIssueLevel::Error,
+                    line: e.span().map(|span| line_of_offset(&source, span.start)),
+                    key: None,
+                    message: format!("invalid TOML: {}", e.message()),
+                });
+            }
+            break;
+        }
+
+        let error = match toml::from_str::<ConfigFile>(&source) {
+            Ok(_) => break,
+            Err(e) => e,
+        };
+
+        let line = error.span().map(|span| line_of_offset(&source, span.start));
+        let (key, message) = match parse_unknown_field(error.message()) {
+            Some((field, expected)) => {
+                let key = qualify_key(&lines, line, &field);
+                let mut message = format!("unknown key `{}`", key);
+                if let Some(suggestion) = closest_match(&field, &expected) {
+                    message.push_str(&format!(" (did you mean `{}`?)", suggestion));
+                }
+                (Some(key), message)
+            }
+            None => (None, error.message().to_string()),
+        };
+
+        issues.push(ConfigIssue {
+            level: ConfigIssueLevel::Error,
+            line,
+            key,
+            message,
+        });
+
+        // Without a location there is nothing to comment out, so stop here
+        match line {
+            Some(line) => comment_out(&mut lines, line),
+            None => break,
+        }
+        source = lines.join("\n");
+    }
+
+    issues
+}
+
+// Extract the field name and expected alternatives from a serde unknown-field message
+fn parse_unknown_field(message: &str) -> Option<(String, Vec<String>)> {
+    let rest = message.strip_prefix("unknown field `")?;
+    let end = rest.find('`')?;
+    let field = rest[..end].to_string();
+
+    let expected = rest[end + 1..]
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .map(String::from)
+        .collect();
+
+    Some((field, expected))
+}
+
+// Pick the expected key closest to the misspelled one, if any is close enough
+fn closest_match<'a>(field: &str, expected: &'a [String]) -> Option<&'a str> {
+    expected.iter()
+        .map(|candidate| (edit_distance(field, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.as_str())
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+fn line_of_offset(source: &str, offset: usize) -> usize {
+    source[..offset.min(source.len())].matches('\n').count() + 1
+}
+
+// Table header (e.g. "validators.rust") in effect at the given 1-based line
+fn table_header_at(lines: &[String], line: usize) -> Option<String> {
+    lines.iter()
+        .take(line)
+        .rev()
+        .map(|l| l.trim())
+        .find(|l| l.starts_with('['))
+        .map(|l| l.trim_matches(|c| c == '[' || c == ']').trim().to_string())
+}
+
+// Build the dotted path of a key from the table header it appears under
+fn qualify_key(lines: &[String], line: Option<usize>, field: &str) -> String {
+    let Some(line) = line else {
+        return field.to_string();
+    };
+
+    // An unknown table is reported on its own header line; qualify it by its parent
+    let current = lines.get(line - 1).map(|l| l.trim()).unwrap_or_default();
+    if current.starts_with('[') {
+        let header = current.trim_matches(|c| c == '[' || c == ']').trim();
+        return match header.rsplit_once('.') {
+            Some((parent, _)) => format!("{}.{}", parent, field),
+            None => field.to_string(),
+        };
+    }
+
+    match table_header_at(lines, line) {
+        Some(header) => format!("{}.{}", header, field),
+        None => field.to_string(),
+    }
+}
+
+// Comment out a key, or a whole table if the line is a table header
+fn comment_out(lines: &mut [String], line: usize) {
+    let Some(index) = line.checked_sub(1).filter(|i| *i < lines.len()) else {
+        return;
+    };
+
+    let end = if lines[index].trim().starts_with('[') {
+        lines.iter()
+            .skip(index + 1)
+            .position(|l| l.trim().starts_with('['))
+            .map_or(lines.len(), |offset| index + 1 + offset)
+    } else {
+        index + 1
+    };
+
+    for l in &mut lines[index..end] {
+        l.insert(0, '#');
+    }
+}
+
+fn lookup_key(table: &toml::Table, dotted: &str) -> bool {
+    let mut parts = dotted.split('.').peekable();
+    let mut current = table;
+
+    while let Some(part) = parts.next() {
+        match current.get(part) {
+            Some(_) if parts.peek().is_none() => return true,
+            Some(toml::Value::Table(next)) => current = next,
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+// Remove a dotted key from a table, from every entry of an array of tables on the way
+fn remove_key(table: &mut toml::Table, dotted: &str) -> bool {
+    match dotted.split_once('.') {
+        None => table.remove(dotted).is_some(),
+        Some((head, rest)) => match table.get_mut(head) {
+            Some(toml::Value::Table(next)) => remove_key(next, rest),
+            // Counted rather than `any`, which would stop after the first entry
+            Some(toml::Value::Array(items)) => items.iter_mut()
+                .filter_map(toml::Value::as_table_mut)
+                .map(|next| remove_key(next, rest))
+                .filter(|removed| *removed)
+                .count() > 0,
+            _ => false,
+        },
+    }
+}
+
+// Find the 1-based line defining a dotted key, assuming `[table]` headers
+fn locate_key(content: &str, dotted: &str) -> Option<usize> {
+    let (header, key) = dotted.rsplit_once('.').unwrap_or(("", dotted));
+    let mut current_header = String::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            current_header = trimmed.trim_matches(|c| c == '[' || c == ']').trim().to_string();
+        } else if current_header == header {
+            if let Some(rest) = trimmed.strip_prefix(key) {
+                if rest.trim_start().starts_with('=') {
+                    return Some(index + 1);
+                }
+            }
+        }
+    }
+
+    None
+}
+
 // Helper function to merge configs
 fn merge_into<T: Clone>(target: &mut T, source: &T) {
     *target = source.clone();
@@ -624,6 +1548,14 @@ fn convert_to_config_file(config: &Config) -> ConfigFile {
             watch: Some(config.watch),
             watch_interval: Some(config.watch_interval),
             timeout: Some(config.timeout),
+            summary_only: Some(config.summary_only),
+            languages: if config.languages.is_empty() {
+                None
+            } else {
+                Some(config.languages.clone())
+            },
+            max_output_bytes: Some(config.max_output_bytes),
+            max_issues_per_file: Some(config.max_issues_per_file),
         }),
         validators: Some(ValidatorsConfig {
             rust: Some(config.validators.rust.clone()),
@@ -652,5 +1584,25 @@ fn convert_to_config_file(config: &Config) -> ConfigFile {
         } else {
             Some(config.file_mappings.clone())
         },
+        encoding: Some(config.encoding.clone()),
+        tools: if config.tools.is_empty() {
+            None
+        } else {
+            Some(config.tools.clone())
+        },
+        complexity: Some(config.complexity.clone()),
+        hygiene: Some(config.hygiene.clone()),
+        secrets: Some(config.secrets.clone()),
+        concurrency: if config.concurrency.is_empty() {
+            None
+        } else {
+            Some(config.concurrency.clone())
+        },
+        generated: Some(config.generated.clone()),
+        adoption: Some(config.adoption.clone()),
+        gate: Some(config.gate.clone()),
+        scan: Some(config.scan.clone()),
+        ui: Some(config.ui.clone()),
+        treesitter: Some(config.treesitter.clone()),
     }
 }
@@ -0,0 +1,179 @@
+//! Interactive `synx config wizard`
+//!
+//! Detects which validator tools are installed, asks a handful of questions
+//! (pre-filled from what was detected) and writes a tailored config file.
+//! The same answers can be given up front for non-interactive use.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use anyhow::{Result, Context};
+use dialoguer::{Confirm, Input, MultiSelect};
+
+use super::Config;
+
+/// Languages offered by the wizard and the tool each one is validated with
+pub const WIZARD_LANGUAGES: &[(&str, &str)] = &[
+    ("rust", "rustc"),
+    ("c", "gcc"),
+    ("cpp", "g++"),
+    ("python", "python3"),
+    ("javascript", "node"),
+    ("typescript", "tsc"),
+    ("vue", "node"),
+    ("svelte", "node"),
+    ("html", "tidy"),
+    ("css", "stylelint"),
+    ("json", "jq"),
+    ("yaml", "yamllint"),
+    ("shell", "shellcheck"),
+    ("dockerfile", "hadolint"),
+];
+
+/// A validator tool and where it was found on PATH, if anywhere
+#[derive(Debug, Clone)]
+pub struct DetectedTool {
+    pub language: &'static str,
+    pub tool: &'static str,
+    pub path: Option<PathBuf>,
+}
+
+/// The choices that make up a wizard-generated config
+#[derive(Debug, Clone, PartialEq)]
+pub struct WizardAnswers {
+    pub strict: bool,
+    pub languages: Vec<String>,
+    pub timeout: u64,
+    /// Tool name -> explicit path, only for tools that need one
+    pub tools: HashMap<String, String>,
+}
+
+/// Look up the tool for every wizard language on PATH
+pub fn detect_tools() -> Vec<DetectedTool> {
+    WIZARD_LANGUAGES.iter()
+        .map(|(language, tool)| DetectedTool {
+            language,
+            tool,
+            path: which::which(tool).ok(),
+        })
+        .collect()
+}
+
+/// Answers to start from: every language whose tool is installed, default timeout
+pub fn default_answers(detected: &[DetectedTool]) -> WizardAnswers {
+    let defaults = Config::default();
+    WizardAnswers {
+        strict: defaults.strict,
+        languages: detected.iter()
+            .filter(|d| d.path.is_some())
+            .map(|d| d.language.to_string())
+            .collect(),
+        timeout: defaults.timeout,
+        tools: HashMap::new(),
+    }
+}
+
+/// Ask the user for each answer, starting from `defaults`
+pub fn prompt_answers(detected: &[DetectedTool], defaults: &WizardAnswers) -> Result<WizardAnswers> {
+    let strict = Confirm::new()
+        .with_prompt("Enable strict mode (treat warnings as errors)?")
+        .default(defaults.strict)
+        .interact()?;
+
+    let labels: Vec<String> = detected.iter()
+        .map(|d| match &d.path {
+            Some(path) => format!("{} ({})", d.language, path.display()),
+            None => format!("{} ({} not found)", d.language, d.tool),
+        })
+        .collect();
+    let preselected: Vec<bool> = detected.iter()
+        .map(|d| defaults.languages.iter().any(|l| l == d.language))
+        .collect();
+    let chosen = MultiSelect::new()
+        .with_prompt("Languages to validate (space to toggle, enter to confirm)")
+        .items(&labels)
+        .defaults(&preselected)
+        .interact()?;
+    let languages: Vec<String> = chosen.iter()
+        .map(|&i| detected[i].language.to_string())
+        .collect();
+
+    let timeout: u64 = Input::new()
+        .with_prompt("Validation timeout in seconds")
+        .default(defaults.timeout)
+        .interact_text()?;
+
+    // Ask for a path only for tools the chosen languages need but PATH doesn't have
+    let mut tools = defaults.tools.clone();
+    for tool in detected.iter().filter(|d| languages.iter().any(|l| l == d.language)) {
+        if tool.path.is_some() || tools.contains_key(tool.tool) {
+            continue;
+        }
+        let path: String = Input::new()
+            .with_prompt(format!("Path to {} (leave empty to skip)", tool.tool))
+            .allow_empty(true)
+            .interact_text()?;
+        if !path.trim().is_empty() {
+            tools.insert(tool.tool.to_string(), path.trim().to_string());
+        }
+    }
+
+    Ok(WizardAnswers { strict, languages, timeout, tools })
+}
+
+/// Turn wizard answers into a full configuration
+pub fn build_config(answers: &WizardAnswers) -> Config {
+    Config {
+        strict: answers.strict,
+        languages: answers.languages.clone(),
+        timeout: answers.timeout,
+        tools: answers.tools.clone(),
+        ..Default::default()
+    }
+}
+
+/// Write the config built from `answers` to `path`
+pub fn write_config(answers: &WizardAnswers, path: &Path) -> Result<()> {
+    build_config(answers).save_to_file(path)
+        .context(format!("Failed to write wizard config to {}", path.display()))
+}
+
+/// Parse a `--tool name=path` argument
+pub fn parse_tool_arg(arg: &str) -> Result<(String, String)> {
+    let (name, path) = arg.split_once('=')
+        .filter(|(name, path)| !name.trim().is_empty() && !path.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Invalid tool path '{}', expected NAME=PATH", arg))?;
+    Ok((name.trim().to_string(), path.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detected(installed: &[&str]) -> Vec<DetectedTool> {
+        WIZARD_LANGUAGES.iter()
+            .map(|(language, tool)| DetectedTool {
+                language,
+                tool,
+                path: installed.contains(tool).then(|| PathBuf::from("/usr/bin").join(tool)),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_defaults_follow_installed_tools() {
+        let answers = default_answers(&detected(&["gcc", "python3"]));
+        assert_eq!(answers.languages, vec!["c", "python"]);
+        assert_eq!(answers.timeout, 30);
+        assert!(!answers.strict);
+    }
+
+    #[test]
+    fn test_parse_tool_arg() {
+        assert_eq!(
+            parse_tool_arg("gcc=/opt/gcc/bin/gcc").unwrap(),
+            ("gcc".to_string(), "/opt/gcc/bin/gcc".to_string())
+        );
+        assert!(parse_tool_arg("gcc").is_err());
+        assert!(parse_tool_arg("=/usr/bin/gcc").is_err());
+    }
+}
@@ -2,10 +2,11 @@ use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::fs;
-use log::{info, warn};
+use log::info;
 
 /// Configuration specific to daemon operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct DaemonConfig {
     /// Directories and files to watch for changes
     pub watch_paths: Vec<PathBuf>,
@@ -54,6 +55,56 @@ pub struct DaemonConfig {
     
     /// Enable system notifications for validation results
     pub enable_notifications: bool,
+    
+    /// Webhook to tell about validation failures
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+    
+    /// Skip saves that didn't change a file's content, using the scan cache,
+    /// and record the daemon's verdicts there for later scans
+    #[serde(default = "default_use_cache")]
+    pub use_cache: bool,
+    
+    /// Address (e.g. "127.0.0.1:7979") to serve live status at, as JSON
+    /// under /status; None to not serve it
+    #[serde(default)]
+    pub status_addr: Option<String>,
+}
+
+fn default_use_cache() -> bool {
+    true
+}
+
+/// Where and how often the daemon reports validation failures
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationConfig {
+    /// URL to POST a JSON payload to when a watched file fails validation
+    pub webhook_url: Option<String>,
+    
+    /// Least severe failure worth a notification: low, medium, high or critical
+    pub min_severity: String,
+    
+    /// Seconds before another failure of the same file is reported again
+    pub debounce_secs: u64,
+    
+    /// Most notifications sent in any one minute
+    pub max_per_minute: usize,
+    
+    /// Seconds to wait for the webhook to answer
+    pub timeout_secs: u64,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            webhook_url: None,
+            min_severity: "low".to_string(),
+            debounce_secs: 60,
+            max_per_minute: 10,
+            timeout_secs: 10,
+        }
+    }
 }
 
 impl Default for DaemonConfig {
@@ -87,6 +138,9 @@ impl Default for DaemonConfig {
             include_patterns: vec![],
             max_concurrent_validations: 4,
             enable_notifications: false,
+            notifications: NotificationConfig::default(),
+            use_cache: true,
+            status_addr: None,
         }
     }
 }
@@ -106,8 +160,9 @@ impl DaemonConfig {
         let config: DaemonConfig = toml::from_str(&content)
             .map_err(|e| anyhow!("Failed to parse config file {}: {}", path.display(), e))?;
             
+        config.validate()
+            .map_err(|e| anyhow!("{}: {}", path.display(), e))?;
         info!("Loaded daemon configuration from {}", path.display());
-        config.validate()?;
         
         Ok(config)
     }
@@ -170,54 +225,77 @@ impl DaemonConfig {
         }
     }
     
-    /// Validate the configuration
+    /// Validate the configuration, naming the key behind every problem
     pub fn validate(&self) -> Result<()> {
-        // Check watch paths exist
+        let problems = self.problems();
+        if problems.is_empty() {
+            return Ok(());
+        }
+        Err(anyhow!("Invalid daemon configuration:{}", problems.iter()
+            .map(|problem| format!("\n  {}", problem))
+            .collect::<String>()))
+    }
+    
+    /// Everything wrong with the configuration, as "key: what's wrong"
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        
         for path in &self.watch_paths {
             if !path.exists() {
-                warn!("Watch path does not exist: {}", path.display());
+                problems.push(format!("watch_paths: {} does not exist", path.display()));
             }
         }
-        
-        // Validate numeric values
-        if self.debounce_ms == 0 {
-            return Err(anyhow!("Debounce time must be greater than 0"));
-        }
-        
-        if self.validation_timeout == 0 {
-            return Err(anyhow!("Validation timeout must be greater than 0"));
+        if self.watch_paths.is_empty() {
+            problems.push("watch_paths: at least one path is needed".to_string());
         }
         
-        if self.health_check_interval == 0 {
-            return Err(anyhow!("Health check interval must be greater than 0"));
-        }
-        
-        if self.max_concurrent_validations == 0 {
-            return Err(anyhow!("Max concurrent validations must be greater than 0"));
+        // Zero would mean validating on every event, never timing out or
+        // never checking health
+        for (key, value) in [
+            ("debounce_ms", self.debounce_ms),
+            ("validation_timeout", self.validation_timeout),
+            ("health_check_interval", self.health_check_interval),
+            ("max_log_size_mb", self.max_log_size_mb),
+            ("max_concurrent_validations", self.max_concurrent_validations as u64),
+        ] {
+            if value == 0 {
+                problems.push(format!("{}: must be greater than 0", key));
+            }
         }
         
-        if self.max_log_size_mb == 0 {
-            return Err(anyhow!("Max log size must be greater than 0"));
+        if let Some(ref addr) = self.status_addr {
+            let port = addr.rsplit_once(':').and_then(|(_, port)| port.parse::<u16>().ok());
+            if !matches!(port, Some(port) if port > 0) {
+                problems.push(format!("status_addr: '{}' needs a port between 1 and 65535 (e.g. \"127.0.0.1:7979\")", addr));
+            }
         }
         
-        // Validate paths are accessible if specified
-        if let Some(ref pid_file) = self.pid_file {
-            if let Some(parent) = pid_file.parent() {
-                if !parent.exists() {
-                    return Err(anyhow!("PID file directory does not exist: {}", parent.display()));
-                }
+        if let Some(ref url) = self.notifications.webhook_url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                problems.push(format!("notifications.webhook_url: must be http:// or https://: {}", url));
+            }
+            if let Err(e) = super::webhook::parse_severity(&self.notifications.min_severity) {
+                problems.push(format!("notifications.min_severity: {}", e));
+            }
+            if self.notifications.max_per_minute == 0 {
+                problems.push("notifications.max_per_minute: must be greater than 0".to_string());
+            }
+            if self.notifications.timeout_secs == 0 {
+                problems.push("notifications.timeout_secs: must be greater than 0".to_string());
             }
         }
         
-        if let Some(ref log_file) = self.log_file {
-            if let Some(parent) = log_file.parent() {
-                if !parent.exists() {
-                    return Err(anyhow!("Log file directory does not exist: {}", parent.display()));
+        // Validate paths are accessible if specified
+        for (key, file) in [("pid_file", &self.pid_file), ("log_file", &self.log_file)] {
+            let parent = file.as_ref().and_then(|file| file.parent());
+            if let Some(parent) = parent {
+                if !parent.as_os_str().is_empty() && !parent.exists() {
+                    problems.push(format!("{}: directory {} does not exist", key, parent.display()));
                 }
             }
         }
         
-        Ok(())
+        problems
     }
     
     /// Get default daemon configuration paths
@@ -311,6 +389,49 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_notification_validation() {
+        let mut config = DaemonConfig::default();
+        config.notifications.webhook_url = Some("https://hooks.example.com/synx".to_string());
+        assert!(config.validate().is_ok());
+        
+        config.notifications.min_severity = "severe".to_string();
+        assert!(config.validate().is_err());
+        
+        config.notifications.min_severity = "high".to_string();
+        config.notifications.webhook_url = Some("hooks.example.com/synx".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_problems_name_their_key() {
+        let mut config = DaemonConfig::default();
+        config.watch_paths = vec![PathBuf::from("/nonexistent/synx/project")];
+        config.health_check_interval = 0;
+        config.status_addr = Some("127.0.0.1:70000".to_string());
+        
+        let problems = config.problems();
+        assert_eq!(problems.len(), 3);
+        assert!(problems[0].starts_with("watch_paths: /nonexistent/synx/project"));
+        assert!(problems[1].starts_with("health_check_interval:"));
+        assert!(problems[2].starts_with("status_addr:"));
+        
+        config.status_addr = Some("localhost:7979".to_string());
+        assert_eq!(config.problems().len(), 2);
+    }
+
+    #[test]
+    fn test_unknown_keys_are_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("daemon.toml");
+        let mut content = toml::to_string_pretty(&DaemonConfig::default()).unwrap();
+        content.insert_str(0, "debounce = 100\n");
+        fs::write(&config_path, content).unwrap();
+        
+        let error = DaemonConfig::from_file(&config_path).unwrap_err().to_string();
+        assert!(error.contains("unknown field `debounce`"), "{}", error);
+    }
+
     #[test]
     fn test_exclude_patterns() {
         let config = DaemonConfig::default();
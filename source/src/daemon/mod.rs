@@ -1,20 +1,33 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, watch};
 use notify::{RecommendedWatcher, RecursiveMode, Watcher, EventKind};
 use log::{info, warn, error, debug};
 use chrono::{DateTime, Utc};
 
 use crate::config::Config as SynxConfig;
-use crate::validators::{validate_file, ValidationOptions, FileValidationConfig};
+use crate::detectors::FileType;
+use crate::validators::{
+    validate_file_detailed, validator_fingerprint, config_fingerprint, has_builtin_validator, ValidationOptions, FileValidationConfig,
+};
+use crate::validators::scan::ValidationCache;
 
 pub mod config;
 pub mod service;
+pub mod webhook;
+pub mod status;
 
-pub use config::DaemonConfig;
-pub use service::{install_service, uninstall_service, ServiceManager};
+pub use config::{DaemonConfig, NotificationConfig};
+pub use status::{DaemonStatus, RecentValidation, ValidationOutcome};
+pub use service::{install_service, uninstall_service, ServiceFile, ServiceManager};
+pub use webhook::{FailureNotification, WebhookNotifier};
+
+/// How many recently validated files the status keeps
+pub const RECENT_LIMIT: usize = 10;
 
 /// Events that the daemon can handle
 #[derive(Debug, Clone)]
@@ -32,9 +45,23 @@ pub struct DaemonStats {
     pub files_validated: u64,
     pub validation_errors: u64,
     pub validation_successes: u64,
+    /// Saves skipped because the file's content matched the cache
+    pub cache_hits: u64,
     pub last_validation: Option<DateTime<Utc>>,
     pub watched_directories: Vec<PathBuf>,
     pub watched_files: u64,
+    /// The last `RECENT_LIMIT` files validated, oldest first
+    pub recent: VecDeque<RecentValidation>,
+}
+
+impl DaemonStats {
+    /// Remember how validating `path` went, forgetting the oldest entry when full
+    fn record(&mut self, path: &Path, outcome: ValidationOutcome) {
+        if self.recent.len() == RECENT_LIMIT {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(RecentValidation { path: path.to_path_buf(), outcome, at: Utc::now() });
+    }
 }
 
 impl Default for DaemonStats {
@@ -44,9 +71,11 @@ impl Default for DaemonStats {
             files_validated: 0,
             validation_errors: 0,
             validation_successes: 0,
+            cache_hits: 0,
             last_validation: None,
             watched_directories: Vec::new(),
             watched_files: 0,
+            recent: VecDeque::new(),
         }
     }
 }
@@ -58,6 +87,10 @@ pub struct SynxDaemon {
     stats: DaemonStats,
     watcher: Option<RecommendedWatcher>,
     debounce_map: HashMap<PathBuf, Instant>,
+    notifier: Option<WebhookNotifier>,
+    cache: Option<ValidationCache>,
+    // Latest stats, for the status server
+    status_tx: watch::Sender<DaemonStats>,
 }
 
 impl SynxDaemon {
@@ -68,6 +101,9 @@ impl SynxDaemon {
             watched_directories: daemon_config.watch_paths.clone(),
             ..Default::default()
         };
+        let notifier = WebhookNotifier::from_config(&daemon_config.notifications)?;
+        let cache = daemon_config.use_cache.then(ValidationCache::new);
+        let (status_tx, _) = watch::channel(stats.clone());
 
         Ok(Self {
             config: daemon_config,
@@ -75,6 +111,9 @@ impl SynxDaemon {
             stats,
             watcher: None,
             debounce_map: HashMap::new(),
+            notifier,
+            cache,
+            status_tx,
         })
     }
 
@@ -88,6 +127,7 @@ impl SynxDaemon {
         
         // Setup file watcher
         self.setup_watcher(tx.clone()).await?;
+        self.serve_status().await?;
         
         // Setup signal handlers for graceful shutdown
         self.setup_signal_handlers(tx.clone()).await?;
@@ -170,10 +210,29 @@ impl SynxDaemon {
 
         // Count initial files
         self.stats.watched_files = self.count_watched_files();
+        self.publish_stats();
         
         Ok(())
     }
 
+    /// Serve the status at `status_addr`, if set, returning the address bound
+    pub async fn serve_status(&self) -> Result<Option<SocketAddr>> {
+        let Some(addr) = &self.config.status_addr else {
+            return Ok(None);
+        };
+        let listener = TcpListener::bind(addr).await
+            .with_context(|| format!("Failed to serve status at {}", addr))?;
+        let local = listener.local_addr()?;
+        info!("Serving status at http://{}{}", local, status::STATUS_PATH);
+        tokio::spawn(status::serve(listener, self.status_tx.subscribe()));
+        Ok(Some(local))
+    }
+
+    /// Make the current stats visible to status requests
+    fn publish_stats(&self) {
+        self.status_tx.send_replace(self.stats.clone());
+    }
+
     /// Restart the watcher (used when config is reloaded)
     async fn restart_watcher(&mut self, tx: mpsc::Sender<DaemonEvent>) -> Result<()> {
         info!("Restarting file watcher");
@@ -201,39 +260,81 @@ impl SynxDaemon {
 
     /// Async file validation
     async fn validate_file_async(&mut self, path: &Path) -> Result<()> {
-        info!("Validating file: {}", path.display());
-        
         let validation_options = ValidationOptions {
             strict: self.synx_config.strict,
             verbose: self.config.verbose_logging,
             timeout: self.config.validation_timeout,
             config: Some(FileValidationConfig::default()),
+            analyze_memory: false,
+            max_file_size: None,
+            allow_binary: self.synx_config.allow_binary,
+            modified_since: None,
+            max_depth: None,
+            profiler: None,
+            incremental: false,
+            follow_symlinks: false,
+            offline: self.synx_config.offline,
+            registry: Default::default(),
         };
 
+        // Some editors rewrite a file on save without changing it
+        let fingerprint = FileType::from_path(path).ok()
+            .and_then(|file_type| validator_fingerprint(&file_type, validation_options.config.as_ref()));
+        let config_hash = config_fingerprint(path, &validation_options);
+        if let Some(cache) = &self.cache {
+            if cache.is_valid_cached(path, fingerprint.as_deref(), &config_hash).is_some() {
+                self.stats.cache_hits += 1;
+                self.publish_stats();
+                debug!("Content unchanged since last validation: {}", path.display());
+                return Ok(());
+            }
+        }
+
+        info!("Validating file: {}", path.display());
+
         // Run validation in a blocking task to avoid blocking the async runtime
         let path_clone = path.to_path_buf();
         let validation_result = tokio::task::spawn_blocking(move || {
-            validate_file(&path_clone, &validation_options)
+            validate_file_detailed(&path_clone, &validation_options)
         }).await?;
 
         // Update statistics
         self.stats.files_validated += 1;
         self.stats.last_validation = Some(Utc::now());
 
-        match validation_result {
-            Ok(true) => {
+        // Later scans and saves can reuse the verdict
+        if let (Some(cache), Ok(result)) = (&self.cache, &validation_result) {
+            cache.cache_result(path, result.success, &result.errors, fingerprint.as_deref(), &config_hash);
+            cache.save();
+        }
+
+        let failure = match validation_result {
+            Ok(result) if result.success => {
                 self.stats.validation_successes += 1;
+                self.stats.record(path, ValidationOutcome::Passed);
                 if self.config.verbose_logging {
                     info!("✅ Validation passed: {}", path.display());
                 }
+                None
             }
-            Ok(false) => {
+            Ok(result) => {
                 self.stats.validation_errors += 1;
+                self.stats.record(path, ValidationOutcome::Failed);
                 warn!("❌ Validation failed: {}", path.display());
+                Some(FailureNotification::from_result(path, &result))
             }
             Err(e) => {
                 self.stats.validation_errors += 1;
+                self.stats.record(path, ValidationOutcome::Error);
                 error!("❌ Validation error for {}: {}", path.display(), e);
+                Some(FailureNotification::from_error(path, &e))
+            }
+        };
+        self.publish_stats();
+
+        if let (Some(failure), Some(notifier)) = (failure, self.notifier.as_mut()) {
+            if notifier.notify(&failure).is_none() {
+                debug!("Webhook notification held back for: {}", path.display());
             }
         }
 
@@ -342,6 +443,7 @@ impl SynxDaemon {
         info!("Files validated: {}", self.stats.files_validated);
         info!("Validation successes: {}", self.stats.validation_successes);
         info!("Validation errors: {}", self.stats.validation_errors);
+        info!("Unchanged saves skipped: {}", self.stats.cache_hits);
         info!("Watched directories: {}", self.stats.watched_directories.len());
         info!("Watched files: {}", self.stats.watched_files);
         
@@ -357,23 +459,12 @@ impl SynxDaemon {
     }
 }
 
-/// Check if a file should be validated based on its extension
+/// Check if a file should be validated: its name or extension must be one a
+/// built-in validator handles
 fn should_validate_file(path: &Path) -> bool {
-    if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
-        matches!(extension.to_lowercase().as_str(),
-            "rs" | "py" | "js" | "ts" | "tsx" | "jsx" | "java" | "go" | "c" | "cpp" | "cxx" | "cc" |
-            "cs" | "html" | "htm" | "css" | "json" | "yaml" | "yml" | "sh" | "bash" | "dockerfile"
-        )
-    } else {
-        // Check for files without extensions that might be relevant
-        if let Some(file_name) = path.file_name().and_then(|name| name.to_str()) {
-            matches!(file_name.to_lowercase().as_str(),
-                "dockerfile" | "makefile" | "jenkinsfile"
-            )
-        } else {
-            false
-        }
-    }
+    FileType::from_path(path)
+        .map(|file_type| has_builtin_validator(&file_type))
+        .unwrap_or(false)
 }
 
 /// Count files in a directory recursively
@@ -404,6 +495,11 @@ mod tests {
         assert!(should_validate_file(Path::new("test.py")));
         assert!(should_validate_file(Path::new("test.js")));
         assert!(should_validate_file(Path::new("Dockerfile")));
+        assert!(should_validate_file(Path::new("Main.java")));
+        assert!(should_validate_file(Path::new("Program.CS")));
+        // Detected, but there's no validator for them
+        assert!(!should_validate_file(Path::new("App.jsx")));
+        assert!(!should_validate_file(Path::new("Makefile")));
         assert!(!should_validate_file(Path::new("test.txt")));
         assert!(!should_validate_file(Path::new("README.md")));
     }
@@ -421,4 +517,89 @@ mod tests {
         let count = count_files_in_directory(temp_path);
         assert_eq!(count, 2); // Only .rs and .py files should be counted
     }
+
+    #[tokio::test]
+    async fn test_unchanged_save_is_served_from_cache() {
+        if which::which("python3").is_err() {
+            eprintln!("Skipping test: python3 not installed");
+            return;
+        }
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("app.py");
+        fs::write(&file, "x = 1\n").unwrap();
+
+        let mut daemon = SynxDaemon::new(DaemonConfig::default(), SynxConfig::default()).unwrap();
+        let cache_file = temp_dir.path().join("validation_cache.json");
+        daemon.cache = Some(ValidationCache::with_file(cache_file.clone()));
+
+        daemon.validate_file_async(&file).await.unwrap();
+        assert_eq!(daemon.stats.files_validated, 1);
+
+        // A save that only bumps the modification time
+        fs::File::options().write(true).open(&file).unwrap()
+            .set_modified(std::time::SystemTime::now() + Duration::from_secs(60)).unwrap();
+        daemon.validate_file_async(&file).await.unwrap();
+        assert_eq!(daemon.stats.files_validated, 1);
+        assert_eq!(daemon.stats.cache_hits, 1);
+
+        // The verdict was saved where scans with the same settings look for it
+        let fingerprint = validator_fingerprint(&FileType::Python, Some(&FileValidationConfig::default()));
+        let options = ValidationOptions {
+            timeout: daemon.config.validation_timeout,
+            config: Some(FileValidationConfig::default()),
+            ..Default::default()
+        };
+        let cache = ValidationCache::with_file(cache_file);
+        assert!(cache.is_valid_cached(&file, fingerprint.as_deref(), &config_fingerprint(&file, &options)).is_some());
+
+        fs::write(&file, "x = 2\n").unwrap();
+        daemon.validate_file_async(&file).await.unwrap();
+        assert_eq!(daemon.stats.files_validated, 2);
+    }
+
+    #[tokio::test]
+    async fn test_status_endpoint_reports_stats() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("data.json");
+        fs::write(&file, "{}\n").unwrap();
+
+        let config = DaemonConfig {
+            watch_paths: vec![temp_dir.path().to_path_buf()],
+            use_cache: false,
+            status_addr: Some("127.0.0.1:0".to_string()),
+            ..Default::default()
+        };
+        let mut daemon = SynxDaemon::new(config, SynxConfig::default()).unwrap();
+        daemon.validate_file_async(&file).await.unwrap();
+        let addr = daemon.serve_status().await.unwrap().unwrap();
+
+        let body: serde_json::Value = reqwest::get(format!("http://{}/status", addr)).await.unwrap()
+            .json().await.unwrap();
+        for field in [
+            "uptime_secs", "watched_directories", "watched_files", "files_validated",
+            "validation_successes", "validation_errors", "recent",
+        ] {
+            assert!(body.get(field).is_some(), "missing {}", field);
+        }
+        assert_eq!(body["files_validated"], 1);
+        assert_eq!(body["watched_directories"][0], temp_dir.path().to_string_lossy().as_ref());
+        assert_eq!(body["recent"][0]["path"], file.to_string_lossy().as_ref());
+
+        // Later validations show up without restarting the server
+        daemon.validate_file_async(&file).await.unwrap();
+        let status = status::fetch(&addr.to_string()).await.unwrap();
+        assert_eq!(status.files_validated, 2);
+        assert_eq!(status.recent.len(), 2);
+        assert_eq!(status.validation_successes + status.validation_errors, 2);
+    }
+
+    #[test]
+    fn test_recent_activity_is_capped() {
+        let mut stats = DaemonStats::default();
+        for i in 0..RECENT_LIMIT + 3 {
+            stats.record(Path::new(&format!("file{}.py", i)), ValidationOutcome::Passed);
+        }
+        assert_eq!(stats.recent.len(), RECENT_LIMIT);
+        assert_eq!(stats.recent[0].path, PathBuf::from("file3.py"));
+    }
 }
@@ -9,6 +9,18 @@ pub struct ServiceManager {
     service_name: String,
     binary_path: PathBuf,
     config_path: Option<PathBuf>,
+    service_dir: Option<PathBuf>,
+    dry_run: bool,
+}
+
+/// The service definition an install writes or an uninstall removes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceFile {
+    /// The unit or plist file; on Windows, the service's name in the service database
+    pub target: PathBuf,
+    /// The rendered unit or plist (the `sc` command on Windows). For an
+    /// uninstall, what the file holds now, if anything.
+    pub content: String,
 }
 
 impl ServiceManager {
@@ -17,6 +29,8 @@ impl ServiceManager {
             service_name,
             binary_path,
             config_path: None,
+            service_dir: None,
+            dry_run: false,
         }
     }
 
@@ -25,8 +39,20 @@ impl ServiceManager {
         self
     }
 
+    /// Keep the unit or plist somewhere other than the system's service directory
+    pub fn with_service_dir(mut self, service_dir: PathBuf) -> Self {
+        self.service_dir = Some(service_dir);
+        self
+    }
+
+    /// Render the service definition without writing, removing or registering anything
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     /// Install the service based on the current platform
-    pub fn install(&self) -> Result<()> {
+    pub fn install(&self) -> Result<ServiceFile> {
         #[cfg(target_os = "linux")]
         {
             self.install_systemd_service()
@@ -49,7 +75,7 @@ impl ServiceManager {
     }
 
     /// Uninstall the service
-    pub fn uninstall(&self) -> Result<()> {
+    pub fn uninstall(&self) -> Result<ServiceFile> {
         #[cfg(target_os = "linux")]
         {
             self.uninstall_systemd_service()
@@ -194,41 +220,59 @@ impl ServiceManager {
 // Linux systemd implementation
 #[cfg(target_os = "linux")]
 impl ServiceManager {
-    fn install_systemd_service(&self) -> Result<()> {
-        let service_content = self.generate_systemd_service()?;
-        let service_file = format!("/etc/systemd/system/{}.service", self.service_name);
+    fn systemd_service_file(&self) -> PathBuf {
+        self.service_dir.clone()
+            .unwrap_or_else(|| PathBuf::from("/etc/systemd/system"))
+            .join(format!("{}.service", self.service_name))
+    }
+
+    fn install_systemd_service(&self) -> Result<ServiceFile> {
+        let service = ServiceFile {
+            target: self.systemd_service_file(),
+            content: self.generate_systemd_service()?,
+        };
+        if self.dry_run {
+            return Ok(service);
+        }
         
         // Write service file
-        fs::write(&service_file, service_content)
+        fs::write(&service.target, &service.content)
             .map_err(|e| anyhow!("Failed to write systemd service file: {}", e))?;
         
-        info!("Created systemd service file: {}", service_file);
+        info!("Created systemd service file: {}", service.target.display());
         
         // Reload systemd
         self.systemctl_command("daemon-reload")?;
         
-        Ok(())
+        Ok(service)
     }
 
-    fn uninstall_systemd_service(&self) -> Result<()> {
-        let service_file = format!("/etc/systemd/system/{}.service", self.service_name);
+    fn uninstall_systemd_service(&self) -> Result<ServiceFile> {
+        let service_file = self.systemd_service_file();
+        let service = ServiceFile {
+            content: fs::read_to_string(&service_file).unwrap_or_default(),
+            target: service_file,
+        };
+        if self.dry_run {
+            return Ok(service);
+        }
         
         // Stop and disable first
         let _ = self.stop();
         let _ = self.disable();
         
         // Remove service file
-        if Path::new(&service_file).exists() {
-            fs::remove_file(&service_file)
+        if service.target.exists() {
+            fs::remove_file(&service.target)
                 .map_err(|e| anyhow!("Failed to remove systemd service file: {}", e))?;
             
-            info!("Removed systemd service file: {}", service_file);
+            info!("Removed systemd service file: {}", service.target.display());
         }
         
         // Reload systemd
         self.systemctl_command("daemon-reload")?;
         
-        Ok(())
+        Ok(service)
     }
 
     fn systemctl_command(&self, command: &str) -> Result<()> {
@@ -319,12 +363,24 @@ WantedBy=multi-user.target
 // macOS launchd implementation
 #[cfg(target_os = "macos")]
 impl ServiceManager {
-    fn install_launchd_service(&self) -> Result<()> {
-        let plist_content = self.generate_launchd_plist()?;
-        let plist_file = format!("/Library/LaunchDaemons/com.synx.{}.plist", self.service_name);
+    fn launchd_plist_file(&self) -> PathBuf {
+        self.service_dir.clone()
+            .unwrap_or_else(|| PathBuf::from("/Library/LaunchDaemons"))
+            .join(format!("com.synx.{}.plist", self.service_name))
+    }
+
+    fn install_launchd_service(&self) -> Result<ServiceFile> {
+        let service = ServiceFile {
+            target: self.launchd_plist_file(),
+            content: self.generate_launchd_plist()?,
+        };
+        if self.dry_run {
+            return Ok(service);
+        }
+        let plist_file = service.target.to_string_lossy().to_string();
         
         // Write plist file
-        fs::write(&plist_file, plist_content)
+        fs::write(&plist_file, &service.content)
             .map_err(|e| anyhow!("Failed to write launchd plist file: {}", e))?;
         
         info!("Created launchd plist file: {}", plist_file);
@@ -340,28 +396,35 @@ impl ServiceManager {
             .output()
             .map_err(|e| anyhow!("Failed to set plist permissions: {}", e))?;
         
-        Ok(())
+        Ok(service)
     }
 
-    fn uninstall_launchd_service(&self) -> Result<()> {
-        let plist_file = format!("/Library/LaunchDaemons/com.synx.{}.plist", self.service_name);
+    fn uninstall_launchd_service(&self) -> Result<ServiceFile> {
+        let plist_file = self.launchd_plist_file();
+        let service = ServiceFile {
+            content: fs::read_to_string(&plist_file).unwrap_or_default(),
+            target: plist_file,
+        };
+        if self.dry_run {
+            return Ok(service);
+        }
         
         // Unload first
         let _ = self.stop();
         
         // Remove plist file
-        if Path::new(&plist_file).exists() {
-            fs::remove_file(&plist_file)
+        if service.target.exists() {
+            fs::remove_file(&service.target)
                 .map_err(|e| anyhow!("Failed to remove launchd plist file: {}", e))?;
             
-            info!("Removed launchd plist file: {}", plist_file);
+            info!("Removed launchd plist file: {}", service.target.display());
         }
         
-        Ok(())
+        Ok(service)
     }
 
     fn launchctl_command(&self, command: &str) -> Result<()> {
-        let plist_file = format!("/Library/LaunchDaemons/com.synx.{}.plist", self.service_name);
+        let plist_file = self.launchd_plist_file();
         
         let output = Command::new("launchctl")
             .arg(command)
@@ -374,7 +437,7 @@ impl ServiceManager {
             warn!("launchctl {} warning: {}", command, stderr);
         }
 
-        info!("Executed: launchctl {} {}", command, plist_file);
+        info!("Executed: launchctl {} {}", command, plist_file.display());
         Ok(())
     }
 
@@ -458,24 +521,32 @@ r#"<?xml version="1.0" encoding="UTF-8"?>
 // Windows service implementation
 #[cfg(target_os = "windows")]
 impl ServiceManager {
-    fn install_windows_service(&self) -> Result<()> {
+    fn install_windows_service(&self) -> Result<ServiceFile> {
         let config_arg = if let Some(ref config_path) = self.config_path {
             format!(" --config \"{}\"", config_path.display())
         } else {
             String::new()
         };
+        let args = [
+            "create".to_string(),
+            self.service_name.clone(),
+            "binPath=".to_string(),
+            format!("\"{}\" daemon{}", self.binary_path.display(), config_arg),
+            "start=".to_string(),
+            "auto".to_string(),
+            "DisplayName=".to_string(),
+            "Synx Code Validation Daemon".to_string(),
+        ];
+        let service = ServiceFile {
+            target: PathBuf::from(&self.service_name),
+            content: format!("sc {}\n", args.join(" ")),
+        };
+        if self.dry_run {
+            return Ok(service);
+        }
 
         let output = Command::new("sc")
-            .args(&[
-                "create",
-                &self.service_name,
-                "binPath=",
-                &format!("\"{}\" daemon{}", self.binary_path.display(), config_arg),
-                "start=",
-                "auto",
-                "DisplayName=",
-                "Synx Code Validation Daemon",
-            ])
+            .args(&args)
             .output()
             .map_err(|e| anyhow!("Failed to create Windows service: {}", e))?;
 
@@ -485,10 +556,18 @@ impl ServiceManager {
         }
 
         info!("Created Windows service: {}", self.service_name);
-        Ok(())
+        Ok(service)
     }
 
-    fn uninstall_windows_service(&self) -> Result<()> {
+    fn uninstall_windows_service(&self) -> Result<ServiceFile> {
+        let service = ServiceFile {
+            target: PathBuf::from(&self.service_name),
+            content: format!("sc delete {}\n", self.service_name),
+        };
+        if self.dry_run {
+            return Ok(service);
+        }
+
         // Stop first
         let _ = self.stop();
 
@@ -503,7 +582,7 @@ impl ServiceManager {
         }
 
         info!("Deleted Windows service: {}", self.service_name);
-        Ok(())
+        Ok(service)
     }
 
     fn sc_command(&self, command: &str) -> Result<()> {
@@ -564,34 +643,44 @@ impl std::fmt::Display for ServiceStatus {
     }
 }
 
-/// Install the Synx daemon as a system service
-pub fn install_service(service_name: &str, binary_path: &Path, config_path: Option<&Path>) -> Result<()> {
-    let mut manager = ServiceManager::new(service_name.to_string(), binary_path.to_path_buf());
+/// Install the Synx daemon as a system service, returning the service file.
+/// With `dry_run` the file is only rendered, for review.
+pub fn install_service(service_name: &str, binary_path: &Path, config_path: Option<&Path>, dry_run: bool) -> Result<ServiceFile> {
+    let mut manager = ServiceManager::new(service_name.to_string(), binary_path.to_path_buf())
+        .with_dry_run(dry_run);
     
     if let Some(config) = config_path {
         manager = manager.with_config_path(config.to_path_buf());
     }
     
-    manager.install()?;
+    let service = manager.install()?;
+    if dry_run {
+        return Ok(service);
+    }
     manager.enable()?;
     
     info!("Synx daemon service installed successfully");
-    println!("✅ Service '{}' installed successfully", service_name);
+    println!("{} Service '{}' installed successfully", crate::glyphs::OK, service_name);
     println!("   To start: sudo systemctl start {} (Linux) or sudo launchctl load /Library/LaunchDaemons/com.synx.{}.plist (macOS)", service_name, service_name);
     
-    Ok(())
+    Ok(service)
 }
 
-/// Uninstall the Synx daemon service
-pub fn uninstall_service(service_name: &str) -> Result<()> {
-    let manager = ServiceManager::new(service_name.to_string(), PathBuf::new());
+/// Uninstall the Synx daemon service, returning the service file it removed.
+/// With `dry_run` nothing is stopped or removed.
+pub fn uninstall_service(service_name: &str, dry_run: bool) -> Result<ServiceFile> {
+    let manager = ServiceManager::new(service_name.to_string(), PathBuf::new())
+        .with_dry_run(dry_run);
     
-    manager.uninstall()?;
+    let service = manager.uninstall()?;
+    if dry_run {
+        return Ok(service);
+    }
     
     info!("Synx daemon service uninstalled successfully");
-    println!("✅ Service '{}' uninstalled successfully", service_name);
+    println!("{} Service '{}' uninstalled successfully", crate::glyphs::OK, service_name);
     
-    Ok(())
+    Ok(service)
 }
 
 #[cfg(test)]
@@ -626,6 +715,27 @@ mod tests {
         assert!(service_content.contains("ExecStart=/usr/bin/synx daemon"));
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_systemd_dry_run_writes_nothing() {
+        let dir = TempDir::new().unwrap();
+        let manager = ServiceManager::new("test-service".to_string(), PathBuf::from("/usr/bin/synx"))
+            .with_config_path(PathBuf::from("/etc/synx/daemon.toml"))
+            .with_service_dir(dir.path().to_path_buf())
+            .with_dry_run(true);
+
+        let service = manager.install().unwrap();
+        assert_eq!(service.target, dir.path().join("test-service.service"));
+        assert!(service.content.contains("ExecStart=/usr/bin/synx daemon --config /etc/synx/daemon.toml"));
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+
+        // An uninstall shows the file it would remove and leaves it in place
+        fs::write(&service.target, &service.content).unwrap();
+        let removed = manager.uninstall().unwrap();
+        assert_eq!(removed, service);
+        assert!(service.target.exists());
+    }
+
     #[cfg(target_os = "macos")]
     #[test]
     fn test_launchd_plist_generation() {
@@ -637,4 +747,18 @@ mod tests {
         assert!(plist_content.contains("<key>ProgramArguments</key>"));
         assert!(plist_content.contains("/usr/bin/synx"));
     }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn test_launchd_dry_run_writes_nothing() {
+        let dir = TempDir::new().unwrap();
+        let manager = ServiceManager::new("test-service".to_string(), PathBuf::from("/usr/bin/synx"))
+            .with_service_dir(dir.path().to_path_buf())
+            .with_dry_run(true);
+
+        let service = manager.install().unwrap();
+        assert_eq!(service.target, dir.path().join("com.synx.test-service.plist"));
+        assert!(service.content.contains("<string>/usr/bin/synx</string>"));
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
 }
@@ -0,0 +1,209 @@
+//! Live daemon status over HTTP
+//!
+//! With `status_addr` set, the daemon answers `GET /status` with a JSON
+//! snapshot of its statistics: uptime, what it watches, how validations
+//! went and the most recent files it checked. `synx daemon status` fetches
+//! and renders it.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+
+use super::DaemonStats;
+
+/// Path the status is served at
+pub const STATUS_PATH: &str = "/status";
+
+/// How a recently validated file fared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationOutcome {
+    Passed,
+    Failed,
+    /// The validator couldn't run (missing tool, unreadable file, ...)
+    Error,
+}
+
+impl std::fmt::Display for ValidationOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ValidationOutcome::Passed => "passed",
+            ValidationOutcome::Failed => "failed",
+            ValidationOutcome::Error => "error",
+        })
+    }
+}
+
+/// One entry in the daemon's recent activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentValidation {
+    pub path: PathBuf,
+    pub outcome: ValidationOutcome,
+    pub at: DateTime<Utc>,
+}
+
+/// The JSON served at `/status`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    pub version: String,
+    pub started_at: DateTime<Utc>,
+    pub uptime_secs: i64,
+    pub watched_directories: Vec<PathBuf>,
+    pub watched_files: u64,
+    pub files_validated: u64,
+    pub validation_successes: u64,
+    pub validation_errors: u64,
+    pub cache_hits: u64,
+    pub last_validation: Option<DateTime<Utc>>,
+    /// Newest first
+    pub recent: Vec<RecentValidation>,
+}
+
+impl DaemonStatus {
+    pub fn from_stats(stats: &DaemonStats) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            started_at: stats.start_time,
+            uptime_secs: Utc::now().signed_duration_since(stats.start_time).num_seconds(),
+            watched_directories: stats.watched_directories.clone(),
+            watched_files: stats.watched_files,
+            files_validated: stats.files_validated,
+            validation_successes: stats.validation_successes,
+            validation_errors: stats.validation_errors,
+            cache_hits: stats.cache_hits,
+            last_validation: stats.last_validation,
+            recent: stats.recent.iter().rev().cloned().collect(),
+        }
+    }
+}
+
+/// Answer status requests on `listener` until the daemon exits
+pub async fn serve(listener: TcpListener, stats: watch::Receiver<DaemonStats>) {
+    loop {
+        let mut stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!("Failed to accept status connection: {}", e);
+                continue;
+            }
+        };
+        let stats = stats.clone();
+        tokio::spawn(async move {
+            if let Err(e) = respond(&mut stream, &stats).await {
+                debug!("Status request failed: {}", e);
+            }
+        });
+    }
+}
+
+async fn respond(stream: &mut TcpStream, stats: &watch::Receiver<DaemonStats>) -> Result<()> {
+    let mut buffer = [0u8; 1024];
+    let read = stream.read(&mut buffer).await?;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let mut request_line = request.lines().next().unwrap_or("").split_whitespace();
+
+    let (status, body) = match (request_line.next(), request_line.next()) {
+        (Some("GET"), Some(STATUS_PATH)) => {
+            let snapshot = DaemonStatus::from_stats(&stats.borrow());
+            ("200 OK", serde_json::to_string(&snapshot)?)
+        }
+        _ => ("404 Not Found", "{\"error\":\"not found\"}".to_string()),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, body.len(), body,
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Fetch the status of the daemon serving at `addr` (host:port)
+pub async fn fetch(addr: &str) -> Result<DaemonStatus> {
+    let url = format!("http://{}{}", addr, STATUS_PATH);
+    let response = reqwest::get(&url).await
+        .map_err(|e| anyhow!("No daemon answering at {}: {}", addr, e))?;
+    Ok(response.error_for_status()?.json().await?)
+}
+
+/// The status as a table for the terminal
+pub fn render(status: &DaemonStatus) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Version:          {}", status.version);
+    let _ = writeln!(out, "Uptime:           {}", format_uptime(status.uptime_secs));
+    let _ = writeln!(out, "Watched files:    {}", status.watched_files);
+    let _ = writeln!(out, "Files validated:  {} ({} passed, {} failed)",
+        status.files_validated, status.validation_successes, status.validation_errors);
+    let _ = writeln!(out, "Unchanged saves:  {}", status.cache_hits);
+    let last = status.last_validation
+        .map(|at| at.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "never".to_string());
+    let _ = writeln!(out, "Last validation:  {}", last);
+
+    let _ = writeln!(out, "\nWatched directories:");
+    for dir in &status.watched_directories {
+        let _ = writeln!(out, "  {}", dir.display());
+    }
+
+    let _ = writeln!(out, "\nRecent activity:");
+    if status.recent.is_empty() {
+        let _ = writeln!(out, "  (none yet)");
+    } else {
+        let _ = writeln!(out, "  {:<10} {:<8} FILE", "TIME", "RESULT");
+        for entry in &status.recent {
+            let _ = writeln!(out, "  {:<10} {:<8} {}",
+                entry.at.format("%H:%M:%S").to_string(), entry.outcome.to_string(), entry.path.display());
+        }
+    }
+    out
+}
+
+fn format_uptime(secs: i64) -> String {
+    let secs = secs.max(0);
+    let (days, hours, minutes) = (secs / 86400, secs % 86400 / 3600, secs % 3600 / 60);
+    if days > 0 {
+        format!("{}d {:02}h {:02}m", days, hours, minutes)
+    } else {
+        format!("{}h {:02}m {:02}s", hours, minutes, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_uptime() {
+        assert_eq!(format_uptime(0), "0h 00m 00s");
+        assert_eq!(format_uptime(3725), "1h 02m 05s");
+        assert_eq!(format_uptime(2 * 86400 + 3600 + 60), "2d 01h 01m");
+    }
+
+    #[test]
+    fn test_render_lists_recent_activity() {
+        let mut stats = DaemonStats {
+            watched_directories: vec![PathBuf::from("/srv/app")],
+            files_validated: 2,
+            validation_successes: 1,
+            validation_errors: 1,
+            ..Default::default()
+        };
+        let at = Utc::now();
+        stats.recent.push_back(RecentValidation { path: PathBuf::from("a.py"), outcome: ValidationOutcome::Passed, at });
+        stats.recent.push_back(RecentValidation { path: PathBuf::from("b.py"), outcome: ValidationOutcome::Failed, at });
+
+        let table = render(&DaemonStatus::from_stats(&stats));
+        assert!(table.contains("Files validated:  2 (1 passed, 1 failed)"));
+        assert!(table.contains("  /srv/app\n"));
+        // Newest first
+        let b = table.find("failed   b.py").unwrap();
+        let a = table.find("passed   a.py").unwrap();
+        assert!(b < a);
+    }
+}
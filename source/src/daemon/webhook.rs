@@ -0,0 +1,216 @@
+//! Webhook notifications for validation failures
+//!
+//! With `[notifications] webhook_url` set, the daemon POSTs a JSON payload
+//! for every watched file that fails validation. Repeat failures of one file
+//! are debounced and the total is rate-limited, so a mass edit doesn't flood
+//! the endpoint.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use log::warn;
+use tokio::task::JoinHandle;
+
+use crate::analysis::IssueSeverity;
+use crate::validators::ValidationResult;
+use super::config::NotificationConfig;
+
+/// A validation failure worth telling someone about
+#[derive(Debug, Clone)]
+pub struct FailureNotification {
+    pub file: PathBuf,
+    pub summary: String,
+    pub severity: IssueSeverity,
+    pub issues: usize,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl FailureNotification {
+    /// Summarize a failed validation, rated by its most severe issue
+    pub fn from_result(file: &Path, result: &ValidationResult) -> Self {
+        let summary = match result.errors.first() {
+            Some(first) if result.errors.len() > 1 => {
+                format!("{} issues, first: {}", result.errors.len(), first.message)
+            }
+            Some(first) => first.message.clone(),
+            None => "File failed validation".to_string(),
+        };
+        Self {
+            file: file.to_path_buf(),
+            summary,
+            // A failure without details still failed
+            severity: result.errors.iter().map(|error| error.severity).max().unwrap_or(IssueSeverity::High),
+            issues: result.errors.len(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Summarize a validator that couldn't run at all
+    pub fn from_error(file: &Path, error: &anyhow::Error) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            summary: error.to_string(),
+            severity: IssueSeverity::High,
+            issues: 0,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "file": self.file.to_string_lossy(),
+            "summary": self.summary,
+            "severity": self.severity.to_string().to_lowercase(),
+            "issues": self.issues,
+            "timestamp": self.timestamp.to_rfc3339(),
+        })
+    }
+}
+
+/// Parse a configured severity name, ignoring case
+pub fn parse_severity(name: &str) -> Result<IssueSeverity> {
+    match name.to_lowercase().as_str() {
+        "low" => Ok(IssueSeverity::Low),
+        "medium" => Ok(IssueSeverity::Medium),
+        "high" => Ok(IssueSeverity::High),
+        "critical" => Ok(IssueSeverity::Critical),
+        _ => Err(anyhow!("Invalid severity '{}' (expected low, medium, high or critical)", name)),
+    }
+}
+
+/// Sends failure notifications to the configured webhook
+pub struct WebhookNotifier {
+    url: String,
+    min_severity: IssueSeverity,
+    debounce: Duration,
+    max_per_minute: usize,
+    client: reqwest::Client,
+    // When each file was last reported, and every send in the last minute
+    last_sent: HashMap<PathBuf, Instant>,
+    recent: VecDeque<Instant>,
+}
+
+impl WebhookNotifier {
+    /// A notifier for the config, or `None` when no webhook is configured
+    pub fn from_config(config: &NotificationConfig) -> Result<Option<Self>> {
+        let url = match &config.webhook_url {
+            Some(url) => url.clone(),
+            None => return Ok(None),
+        };
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()?;
+        Ok(Some(Self {
+            url,
+            min_severity: parse_severity(&config.min_severity)?,
+            debounce: Duration::from_secs(config.debounce_secs),
+            max_per_minute: config.max_per_minute,
+            client,
+            last_sent: HashMap::new(),
+            recent: VecDeque::new(),
+        }))
+    }
+
+    /// Whether a notification passes the severity gate, the per-file debounce
+    /// and the rate limit. A `true` counts as sent.
+    pub fn should_send(&mut self, notification: &FailureNotification, now: Instant) -> bool {
+        if notification.severity < self.min_severity {
+            return false;
+        }
+        if let Some(&last) = self.last_sent.get(&notification.file) {
+            if now.duration_since(last) < self.debounce {
+                return false;
+            }
+        }
+        while self.recent.front().is_some_and(|&sent| now.duration_since(sent) >= Duration::from_secs(60)) {
+            self.recent.pop_front();
+        }
+        if self.recent.len() >= self.max_per_minute {
+            return false;
+        }
+
+        self.recent.push_back(now);
+        self.last_sent.insert(notification.file.clone(), now);
+        true
+    }
+
+    /// POST the notification in the background, unless it's gated. Failures
+    /// are logged; the handle is there for anyone who wants the outcome.
+    pub fn notify(&mut self, notification: &FailureNotification) -> Option<JoinHandle<Result<()>>> {
+        if !self.should_send(notification, Instant::now()) {
+            return None;
+        }
+        let request = self.client.post(&self.url).json(&notification.payload());
+        let file = notification.file.clone();
+        Some(tokio::spawn(async move {
+            let result = match request.send().await {
+                Ok(response) => response.error_for_status().map(|_| ()).map_err(anyhow::Error::from),
+                Err(e) => Err(e.into()),
+            };
+            if let Err(ref e) = result {
+                warn!("Failed to send webhook notification for {}: {}", file.display(), e);
+            }
+            result
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notifier(config: NotificationConfig) -> WebhookNotifier {
+        let config = NotificationConfig {
+            webhook_url: Some("http://127.0.0.1:9/hook".to_string()),
+            ..config
+        };
+        WebhookNotifier::from_config(&config).unwrap().unwrap()
+    }
+
+    fn failure(file: &str, severity: IssueSeverity) -> FailureNotification {
+        FailureNotification {
+            file: PathBuf::from(file),
+            summary: "invalid syntax".to_string(),
+            severity,
+            issues: 1,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_severity_gate() {
+        let mut notifier = notifier(NotificationConfig {
+            min_severity: "high".to_string(),
+            ..Default::default()
+        });
+        let now = Instant::now();
+        assert!(!notifier.should_send(&failure("a.py", IssueSeverity::Medium), now));
+        assert!(notifier.should_send(&failure("a.py", IssueSeverity::Critical), now));
+    }
+
+    #[test]
+    fn test_debounce_and_rate_limit() {
+        let mut notifier = notifier(NotificationConfig {
+            debounce_secs: 30,
+            max_per_minute: 2,
+            ..Default::default()
+        });
+        let start = Instant::now();
+        assert!(notifier.should_send(&failure("a.py", IssueSeverity::High), start));
+        // The same file again too soon
+        assert!(!notifier.should_send(&failure("a.py", IssueSeverity::High), start + Duration::from_secs(10)));
+        assert!(notifier.should_send(&failure("b.py", IssueSeverity::High), start));
+        // Over the limit for this minute
+        assert!(!notifier.should_send(&failure("c.py", IssueSeverity::High), start + Duration::from_secs(1)));
+        assert!(notifier.should_send(&failure("c.py", IssueSeverity::High), start + Duration::from_secs(61)));
+    }
+
+    #[test]
+    fn test_no_webhook_means_no_notifier() {
+        assert!(WebhookNotifier::from_config(&NotificationConfig::default()).unwrap().is_none());
+        assert!(parse_severity("Critical").is_ok());
+        assert!(parse_severity("urgent").is_err());
+    }
+}
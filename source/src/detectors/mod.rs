@@ -1,8 +1,12 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::fs::File;
 use std::io::Read;
 use anyhow::{Result, Context};
 use tree_magic_mini as magic;
+use globset::Glob;
+use once_cell::sync::Lazy;
+use regex::Regex;
 
 /// FileType enum representing the detected file types
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -21,12 +25,16 @@ pub enum FileType {
     Yaml,
     Toml,
     Dockerfile,
+    DotEnv,          // .env files of KEY=VALUE settings
     Shell,
     Markdown,
     GraphQL,         // GraphQL schema and query files
     C,
     Cpp,
     Rust,
+    Java,
+    Go,
+    CSharp,
     Unknown(String),
 }
 
@@ -47,17 +55,148 @@ impl std::fmt::Display for FileType {
             FileType::Yaml => write!(f, "YAML"),
             FileType::Toml => write!(f, "TOML"),
             FileType::Dockerfile => write!(f, "Dockerfile"),
+            FileType::DotEnv => write!(f, ".env"),
             FileType::Shell => write!(f, "Shell"),
             FileType::Markdown => write!(f, "Markdown"),
             FileType::GraphQL => write!(f, "GraphQL"),
             FileType::C => write!(f, "C"),
             FileType::Cpp => write!(f, "C++"),
             FileType::Rust => write!(f, "Rust"),
+            FileType::Java => write!(f, "Java"),
+            FileType::Go => write!(f, "Go"),
+            FileType::CSharp => write!(f, "C#"),
             FileType::Unknown(ext) => write!(f, "Unknown ({})", ext),
         }
     }
 }
 
+impl FileType {
+    /// Look up a file type by language name, as used in config mappings and
+    /// CLI filters (e.g. "python", "rust", "yaml"). Common short forms such as
+    /// "py", "rs" and "yml" are accepted too.
+    pub fn from_name(name: &str) -> Option<FileType> {
+        match name.trim().to_lowercase().as_str() {
+            "python" | "py" => Some(FileType::Python),
+            "javascript" | "js" => Some(FileType::JavaScript),
+            "jsx" => Some(FileType::Jsx),
+            "typescript" | "ts" => Some(FileType::TypeScript),
+            "tsx" => Some(FileType::Tsx),
+            "vue" => Some(FileType::Vue),
+            "svelte" => Some(FileType::Svelte),
+            "html" => Some(FileType::Html),
+            "css" => Some(FileType::Css),
+            "scss" => Some(FileType::Scss),
+            "json" => Some(FileType::Json),
+            "yaml" | "yml" => Some(FileType::Yaml),
+            "toml" => Some(FileType::Toml),
+            "dockerfile" => Some(FileType::Dockerfile),
+            "dotenv" | "env" => Some(FileType::DotEnv),
+            "shell" | "sh" => Some(FileType::Shell),
+            "markdown" | "md" => Some(FileType::Markdown),
+            "graphql" => Some(FileType::GraphQL),
+            "c" => Some(FileType::C),
+            "cpp" | "c++" => Some(FileType::Cpp),
+            "rust" | "rs" => Some(FileType::Rust),
+            "java" => Some(FileType::Java),
+            "go" | "golang" => Some(FileType::Go),
+            "csharp" | "cs" | "c#" => Some(FileType::CSharp),
+            _ => None,
+        }
+    }
+
+    /// Look up a file type by file extension (without the dot, any case).
+    /// This is the one table of extensions synx recognizes.
+    pub fn from_extension(extension: &str) -> Option<FileType> {
+        match extension.to_lowercase().as_str() {
+            "py" => Some(FileType::Python),
+            "js" => Some(FileType::JavaScript),
+            "jsx" => Some(FileType::Jsx),
+            "ts" => Some(FileType::TypeScript),
+            "tsx" => Some(FileType::Tsx),
+            "vue" => Some(FileType::Vue),
+            "svelte" => Some(FileType::Svelte),
+            "html" | "htm" => Some(FileType::Html),
+            "css" => Some(FileType::Css),
+            "scss" => Some(FileType::Scss),
+            "json" | "jsonc" | "json5" => Some(FileType::Json),
+            "yaml" | "yml" => Some(FileType::Yaml),
+            "toml" => Some(FileType::Toml),
+            "md" | "markdown" => Some(FileType::Markdown),
+            "graphql" | "gql" => Some(FileType::GraphQL),
+            "c" => Some(FileType::C),
+            // `.h` could be either; `is_cpp_header` looks at the content when that matters
+            "h" => Some(FileType::C),
+            "cpp" | "cc" | "cxx" | "hpp" | "hxx" | "hh" => Some(FileType::Cpp),
+            "rs" => Some(FileType::Rust),
+            "java" => Some(FileType::Java),
+            "go" => Some(FileType::Go),
+            "cs" => Some(FileType::CSharp),
+            "sh" | "bash" | "zsh" => Some(FileType::Shell),
+            "dockerfile" => Some(FileType::Dockerfile),
+            "env" => Some(FileType::DotEnv),
+            _ => None,
+        }
+    }
+
+    /// Look up a file type for files known by their whole name, like `Dockerfile`
+    /// or `.env.local`
+    pub fn from_file_name(name: &str) -> Option<FileType> {
+        match name {
+            "Dockerfile" | "dockerfile" | "Containerfile" => Some(FileType::Dockerfile),
+            ".env" => Some(FileType::DotEnv),
+            _ if name.starts_with(".env.") => Some(FileType::DotEnv),
+            _ => None,
+        }
+    }
+
+    /// The file type a path's extension or name stands for, without looking
+    /// at the contents or any config mappings
+    pub fn from_path(path: &Path) -> Result<FileType> {
+        let by_extension = path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(FileType::from_extension);
+        let by_name = || path.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(FileType::from_file_name);
+
+        by_extension.or_else(by_name)
+            .ok_or_else(|| anyhow::anyhow!("Unrecognized file type: {}", path.display()))
+    }
+
+    /// A file name that detection and the validators recognize as this type,
+    /// for when source has to be written somewhere before it can be checked
+    pub fn file_name(&self) -> String {
+        let extension = match self {
+            FileType::Python => "py",
+            FileType::JavaScript => "js",
+            FileType::TypeScript => "ts",
+            FileType::Jsx => "jsx",
+            FileType::Tsx => "tsx",
+            FileType::Vue => "vue",
+            FileType::Svelte => "svelte",
+            FileType::Html => "html",
+            FileType::Css => "css",
+            FileType::Scss => "scss",
+            FileType::Json => "json",
+            FileType::Yaml => "yaml",
+            FileType::Toml => "toml",
+            FileType::Dockerfile => return "Dockerfile".to_string(),
+            FileType::DotEnv => return ".env".to_string(),
+            FileType::Shell => "sh",
+            FileType::Markdown => "md",
+            FileType::GraphQL => "graphql",
+            FileType::C => "c",
+            FileType::Cpp => "cpp",
+            FileType::Rust => "rs",
+            FileType::Java => "java",
+            FileType::Go => "go",
+            FileType::CSharp => "cs",
+            FileType::Unknown(ext) => ext,
+        };
+        format!("source.{}", extension)
+    }
+}
+
 /// Map a MIME type to a FileType with improved detection
 fn mime_to_file_type(mime: &str) -> Option<FileType> {
     // First check for exact matches
@@ -80,6 +219,9 @@ fn mime_to_file_type(mime: &str) -> Option<FileType> {
         "text/x-c" | "text/x-csrc" => Some(FileType::C),
         "text/x-c++" | "text/x-c++src" => Some(FileType::Cpp),
         "text/x-rust" | "text/rust" => Some(FileType::Rust),
+        "text/x-java" | "text/x-java-source" => Some(FileType::Java),
+        "text/x-go" => Some(FileType::Go),
+        "text/x-csharp" => Some(FileType::CSharp),
         "application/x-shellscript" | "text/x-shellscript" | "text/x-sh" => Some(FileType::Shell),
         _ => {
             // Check for partial matches if exact match fails
@@ -126,30 +268,30 @@ fn check_for_shebang(path: &Path) -> Result<Option<FileType>> {
     let mut buffer = [0; 1024];
     let n = file.read(&mut buffer).context("Failed to read file")?;
     let content = String::from_utf8_lossy(&buffer[..n]);
-    
+
     if content.starts_with("#!/bin/bash") || 
        content.starts_with("#!/bin/sh") || 
        content.starts_with("#!/usr/bin/env bash") || 
        content.starts_with("#!/usr/bin/env sh") {
         return Ok(Some(FileType::Shell));
     }
-    
+
     if content.starts_with("#!/usr/bin/env python") || 
        content.starts_with("#!/usr/bin/python") {
         return Ok(Some(FileType::Python));
     }
-    
+
     if content.starts_with("#!/usr/bin/env node") || 
        content.starts_with("#!/usr/bin/node") {
         return Ok(Some(FileType::JavaScript));
     }
-    
+
     Ok(None)
 }
 /// Check if file content matches JSX patterns
 fn is_likely_jsx(content: &str) -> bool {
     let content_lower = content.to_lowercase();
-    
+
     // Common JSX patterns and keywords
     let jsx_patterns = [
         "import react", 
@@ -173,17 +315,17 @@ fn is_likely_jsx(content: &str) -> bool {
         "export default function",     // Function component export
         "react.memo"                   // React.memo for memoization
     ];
-    
+
     // Count JSX pattern matches
     let pattern_count = jsx_patterns.iter()
         .filter(|&pattern| content_lower.contains(pattern))
         .count();
-    
+
     // Check for JSX syntax patterns (HTML-like tags with JS expressions)
     let has_jsx_syntax = content.contains("<") && 
                          content.contains("/>") &&
                          (content.contains("{") && content.contains("}"));
-    
+
     // If we have multiple JSX patterns or clear JSX syntax, it's likely JSX
     pattern_count >= 2 || has_jsx_syntax
 }
@@ -192,10 +334,10 @@ fn is_likely_jsx(content: &str) -> bool {
 fn is_likely_tsx(content: &str) -> bool {
     // First check if it's TypeScript
     let is_ts = is_likely_typescript(content);
-    
+
     // Then check if it has JSX patterns too
     let is_jsx = is_likely_jsx(content);
-    
+
     // If both conditions are met, it's likely TSX
     is_ts && is_jsx
 }
@@ -203,7 +345,7 @@ fn is_likely_tsx(content: &str) -> bool {
 /// Check if file content matches JavaScript patterns
 fn is_likely_javascript(content: &str) -> bool {
     let content_lower = content.to_lowercase();
-    
+
     // Common JavaScript keywords and patterns
     let js_patterns = [
         // Functions
@@ -224,12 +366,12 @@ fn is_likely_javascript(content: &str) -> bool {
         "$(", "jquery", "react", "vue", "angular", "lodash", "underscore",
         "require(", "module.exports", "exports."
     ];
-    
+
     // Count how many JS patterns we find
     let pattern_count = js_patterns.iter()
         .filter(|&pattern| content_lower.contains(pattern))
         .count();
-    
+
     // If we find multiple JS patterns, it's likely JavaScript
     pattern_count >= 3
 }
@@ -237,7 +379,7 @@ fn is_likely_javascript(content: &str) -> bool {
 /// Check if file content matches TypeScript patterns
 fn is_likely_typescript(content: &str) -> bool {
     let content_lower = content.to_lowercase();
-    
+
     // Common TypeScript specific patterns and keywords
     let ts_patterns = [
         // TypeScript type annotations
@@ -263,12 +405,12 @@ fn is_likely_typescript(content: &str) -> bool {
         // Generic types
         "<T>", "<T extends", "<K, V>", "<T, K>", "<T, K extends", "T | null"
     ];
-    
+
     // Count TypeScript pattern matches
     let pattern_count = ts_patterns.iter()
         .filter(|&pattern| content_lower.contains(pattern))
         .count();
-    
+
     // If we find multiple TypeScript patterns, it's likely TypeScript
     pattern_count >= 3
 }
@@ -276,10 +418,10 @@ fn is_likely_typescript(content: &str) -> bool {
 /// Check if file content matches Vue component patterns
 fn is_likely_vue(content: &str) -> bool {
     let content_lower = content.to_lowercase();
-    
+
     // Quick check for Vue's signature structure
     let has_template_tag = content_lower.contains("<template") && content_lower.contains("</template>");
-    
+
     if has_template_tag {
         // Check for other Vue-specific sections
         let has_script_tag = content_lower.contains("<script") && content_lower.contains("</script>");
@@ -290,7 +432,7 @@ fn is_likely_vue(content: &str) -> bool {
             return true;
         }
     }
-    
+
     // Check for Vue-specific patterns
     let vue_patterns = [
         "export default {", 
@@ -317,12 +459,12 @@ fn is_likely_vue(content: &str) -> bool {
         "computed(", 
         "onmounted("
     ];
-    
+
     // Count Vue pattern matches
     let pattern_count = vue_patterns.iter()
         .filter(|&pattern| content_lower.contains(pattern))
         .count();
-    
+
     // If we find multiple Vue patterns, it's likely Vue
     pattern_count >= 2
 }
@@ -330,11 +472,11 @@ fn is_likely_vue(content: &str) -> bool {
 /// Check if file content matches Svelte component patterns
 fn is_likely_svelte(content: &str) -> bool {
     let content_lower = content.to_lowercase();
-    
+
     // Check for Svelte's signature structure: no enclosing <template> tags, but script and style tags
     let has_script_tag = content_lower.contains("<script") && content_lower.contains("</script>");
     let has_style_tag = content_lower.contains("<style") && content_lower.contains("</style>");
-    
+
     // Svelte-specific directives and patterns
     let svelte_patterns = [
         // Svelte-specific syntax
@@ -354,12 +496,12 @@ fn is_likely_svelte(content: &str) -> bool {
         // Svelte stores
         "writable(", "readable(", "derived(", "$store"
     ];
-    
+
     // Count Svelte pattern matches
     let pattern_count = svelte_patterns.iter()
         .filter(|&pattern| content_lower.contains(pattern))
         .count();
-    
+
     // If we have both structural indicators (script/style tags without template tags)
     // or multiple Svelte-specific patterns, it's likely Svelte
     (has_script_tag && has_style_tag && !content_lower.contains("<template")) || pattern_count >= 2
@@ -376,74 +518,45 @@ pub fn detect_file_type(path: &Path) -> Result<FileType> {
         None,  // watch_directory
         None,  // explicit_config_path
     )?;
-    
-    // No need for .context() since it's not implemented on Config
+
+    detect_file_type_with_mappings(path, &config.file_mappings)
+}
+
+/// Detect a file's type, consulting the given `file_mappings` for names and
+/// extensions the built-in rules don't know
+pub fn detect_file_type_with_mappings(path: &Path, file_mappings: &HashMap<String, String>) -> Result<FileType> {
+    // `.h` is shared by C and C++, so the content decides
+    if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("h")) {
+        return Ok(if is_cpp_header(path) { FileType::Cpp } else { FileType::C });
+    }
+
     // First try to detect by extension
-    if let Some(extension) = path.extension() {
-        let ext = extension.to_string_lossy().to_lowercase();
-        
-        match ext.as_str() {
-            "py" => return Ok(FileType::Python),
-            "js" => return Ok(FileType::JavaScript),
-            "jsx" => return Ok(FileType::Jsx),
-            "ts" => return Ok(FileType::TypeScript),
-            "tsx" => return Ok(FileType::Tsx),
-            "vue" => return Ok(FileType::Vue),
-            "svelte" => return Ok(FileType::Svelte),
-            "html" | "htm" => return Ok(FileType::Html),
-            "css" => return Ok(FileType::Css),
-            "scss" => return Ok(FileType::Scss),
-            "json" => return Ok(FileType::Json),
-            "yaml" | "yml" => return Ok(FileType::Yaml),
-            "toml" => return Ok(FileType::Toml),
-            "md" | "markdown" => return Ok(FileType::Markdown),
-            "c" => return Ok(FileType::C),
-            "cpp" | "cc" | "cxx" => return Ok(FileType::Cpp),
-            "rs" => return Ok(FileType::Rust),
-            "sh" | "bash" | "zsh" => return Ok(FileType::Shell),
-            _ => {}
-        }
+    if let Some(file_type) = path.extension().and_then(|ext| FileType::from_extension(&ext.to_string_lossy())) {
+        return Ok(file_type);
     }
-    
+
     // Check special file names (e.g., Dockerfile)
     let file_name = path.file_name()
         .map(|name| name.to_string_lossy().to_string())
         .unwrap_or_default();
-    
+
     // Check custom mappings from config
-    if let Some(file_type) = config.file_mappings.get(&file_name) {
-        match file_type.to_lowercase().as_str() {
-            "python" => return Ok(FileType::Python),
-            "javascript" => return Ok(FileType::JavaScript),
-            "jsx" => return Ok(FileType::Jsx),
-            "typescript" => return Ok(FileType::TypeScript),
-            "tsx" => return Ok(FileType::Tsx),
-            "vue" => return Ok(FileType::Vue),
-            "svelte" => return Ok(FileType::Svelte),
-            "html" => return Ok(FileType::Html),
-            "css" => return Ok(FileType::Css),
-            "scss" => return Ok(FileType::Scss),
-            "json" => return Ok(FileType::Json),
-            "yaml" => return Ok(FileType::Yaml),
-            "toml" => return Ok(FileType::Toml),
-            "dockerfile" => return Ok(FileType::Dockerfile),
-            "shell" => return Ok(FileType::Shell),
-            "markdown" => return Ok(FileType::Markdown),
-            "c" => return Ok(FileType::C),
-            "cpp" => return Ok(FileType::Cpp),
-            "rust" => return Ok(FileType::Rust),
-            _ => {}
+    if let Some(file_type) = find_file_mapping(path, file_mappings) {
+        if let Some(file_type) = FileType::from_name(file_type) {
+            return Ok(file_type);
         }
     }
-    
+
     // Common special files
+    if let Some(file_type) = FileType::from_file_name(&file_name) {
+        return Ok(file_type);
+    }
     match file_name.as_str() {
-        "Dockerfile" => return Ok(FileType::Dockerfile),
         "Makefile" | "makefile" => return Ok(FileType::Shell),
         ".gitignore" | ".dockerignore" => return Ok(FileType::Shell),
         _ => {}
     }
-    
+
     // Check for shebang line
     if let Ok(Some(file_type)) = check_for_shebang(path) {
         return Ok(file_type);
@@ -542,14 +655,14 @@ pub fn detect_file_type(path: &Path) -> Result<FileType> {
             }
         }
     }
-    
+
     // Use tree_magic_mini for content-based detection as a fallback
     let mime = magic::from_filepath(path).unwrap_or_default();
-    
+
     if let Some(file_type) = mime_to_file_type(&mime) {
         return Ok(file_type);
     }
-    
+
     // If all detection methods fail, return Unknown with the extension if any
     if let Some(extension) = path.extension() {
         Ok(FileType::Unknown(extension.to_string_lossy().to_string()))
@@ -558,6 +671,50 @@ pub fn detect_file_type(path: &Path) -> Result<FileType> {
     }
 }
 
+/// Look up the `file_mappings` entry for a path. An exact file name match
+/// takes precedence over glob patterns (e.g. `*.cfg`, `**/templates/*.html`),
+/// which are matched against the full path.
+pub fn find_file_mapping<'a>(path: &Path, file_mappings: &'a HashMap<String, String>) -> Option<&'a String> {
+    if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+        if let Some(mapping) = file_mappings.get(name) {
+            return Some(mapping);
+        }
+    }
+
+    // Check patterns in a fixed order so overlapping globs resolve the same way every run
+    let mut patterns: Vec<&String> = file_mappings.keys()
+        .filter(|pattern| pattern.contains(['*', '?', '[', '{']))
+        .collect();
+    patterns.sort();
+
+    patterns.into_iter()
+        .find(|pattern| {
+            Glob::new(pattern)
+                .map(|glob| glob.compile_matcher().is_match(path))
+                .unwrap_or(false)
+        })
+        .and_then(|pattern| file_mappings.get(pattern))
+}
+
+// Words that don't exist in C, and the standard library namespace
+static CPP_MARKERS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(class|namespace|template)\b|\bstd::").unwrap()
+});
+
+/// Whether a header is C++ rather than C, judging by its code (comments
+/// aside). Headers that can't be read count as C.
+pub fn is_cpp_header(path: &Path) -> bool {
+    let mut content = Vec::new();
+    let read = File::open(path).and_then(|file| file.take(64 * 1024).read_to_end(&mut content));
+    if read.is_err() {
+        return false;
+    }
+    String::from_utf8_lossy(&content).lines()
+        .map(str::trim_start)
+        .filter(|line| !line.starts_with("//") && !line.starts_with("/*") && !line.starts_with('*'))
+        .any(|line| CPP_MARKERS.is_match(line))
+}
+
 // Additional helper functions for file type detection can be added here
 
 #[cfg(test)]
@@ -589,6 +746,31 @@ mod tests {
         assert_eq!(detect_file_type(&json_file).unwrap(), FileType::Json);
     }
 
+    #[test]
+    fn test_from_extension() {
+        assert_eq!(FileType::from_extension("py"), Some(FileType::Python));
+        assert_eq!(FileType::from_extension("RS"), Some(FileType::Rust));
+        assert_eq!(FileType::from_extension("yml"), Some(FileType::Yaml));
+        assert_eq!(FileType::from_extension("jsonc"), Some(FileType::Json));
+        assert_eq!(FileType::from_extension("cc"), Some(FileType::Cpp));
+        assert_eq!(FileType::from_extension("cs"), Some(FileType::CSharp));
+        assert_eq!(FileType::from_extension("go"), Some(FileType::Go));
+        assert_eq!(FileType::from_extension("txt"), None);
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(FileType::from_path(Path::new("src/main.rs")).unwrap(), FileType::Rust);
+        assert_eq!(FileType::from_path(Path::new("web/App.TSX")).unwrap(), FileType::Tsx);
+        assert_eq!(FileType::from_path(Path::new("docker/Dockerfile")).unwrap(), FileType::Dockerfile);
+        assert_eq!(FileType::from_path(Path::new("Containerfile")).unwrap(), FileType::Dockerfile);
+        assert_eq!(FileType::from_path(Path::new("app.dockerfile")).unwrap(), FileType::Dockerfile);
+        assert_eq!(FileType::from_path(Path::new(".env")).unwrap(), FileType::DotEnv);
+        assert_eq!(FileType::from_path(Path::new("config/.env.production")).unwrap(), FileType::DotEnv);
+        assert!(FileType::from_path(Path::new("notes.txt")).is_err());
+        assert!(FileType::from_path(Path::new("LICENSE")).is_err());
+    }
+
     #[test]
     fn test_special_file_detection() {
         let dir = tempdir().unwrap();
@@ -600,6 +782,32 @@ mod tests {
         assert_eq!(detect_file_type(&dockerfile).unwrap(), FileType::Dockerfile);
     }
 
+    #[test]
+    fn test_glob_file_mapping() {
+        let dir = tempdir().unwrap();
+        let cfg_file = create_test_file(dir.path(), "app.cfg", "name = \"app\"\n");
+        
+        let mut mappings = HashMap::new();
+        mappings.insert("*.cfg".to_string(), "toml".to_string());
+        assert_eq!(detect_file_type_with_mappings(&cfg_file, &mappings).unwrap(), FileType::Toml);
+        
+        // An exact file name beats a glob that also matches
+        mappings.insert("app.cfg".to_string(), "yaml".to_string());
+        assert_eq!(detect_file_type_with_mappings(&cfg_file, &mappings).unwrap(), FileType::Yaml);
+    }
+
+    #[test]
+    fn test_glob_mapping_matches_full_path() {
+        let mut mappings = HashMap::new();
+        mappings.insert("**/templates/*.tpl".to_string(), "html".to_string());
+        
+        assert_eq!(
+            find_file_mapping(Path::new("site/templates/index.tpl"), &mappings),
+            Some(&"html".to_string())
+        );
+        assert_eq!(find_file_mapping(Path::new("site/partials/index.tpl"), &mappings), None);
+    }
+
     #[test]
     fn test_shebang_detection() {
         let dir = tempdir().unwrap();
@@ -613,6 +821,20 @@ mod tests {
         assert_eq!(detect_file_type(&py_file).unwrap(), FileType::Python);
     }
 
+    #[test]
+    fn test_header_detection() {
+        let dir = tempdir().unwrap();
+        let c_header = create_test_file(dir.path(), "point.h",
+            "// A class of points\nstruct point { int x; int y; };\n");
+        let cpp_header = create_test_file(dir.path(), "shape.h",
+            "#include <string>\nstd::string shape_name();\n");
+        let hpp = create_test_file(dir.path(), "widget.hpp", "int widget_count();\n");
+
+        assert_eq!(detect_file_type(&c_header).unwrap(), FileType::C);
+        assert_eq!(detect_file_type(&cpp_header).unwrap(), FileType::Cpp);
+        assert_eq!(detect_file_type(&hpp).unwrap(), FileType::Cpp);
+    }
+
     #[test]
     fn test_content_detection() {
         let dir = tempdir().unwrap();
@@ -675,7 +897,7 @@ class Person {
         this.name = name;
         this.age = age;
     }
-    
+
     introduce() {
         return `Hi, I'm ${this.name} and I'm ${this.age} years old.`;
     }
@@ -0,0 +1,37 @@
+//! Unified diffs for previewing changes before they are written
+
+use std::path::Path;
+use similar::TextDiff;
+
+/// Render the change from `before` to `after` as a unified diff with
+/// `a/` and `b/` headers. Returns an empty string if nothing changed.
+pub fn unified_diff(path: &Path, before: &str, after: &str) -> String {
+    if before == after {
+        return String::new();
+    }
+
+    let path = path.to_string_lossy();
+    TextDiff::from_lines(before, after)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", path), &format!("b/{}", path))
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff() {
+        let diff = unified_diff(Path::new("data.json"), "{\"a\":1}\n", "{\n  \"a\": 1\n}\n");
+        assert!(diff.starts_with("--- a/data.json\n+++ b/data.json\n"));
+        assert!(diff.contains("-{\"a\":1}"));
+        assert!(diff.contains("+  \"a\": 1"));
+    }
+
+    #[test]
+    fn test_no_changes_no_diff() {
+        assert!(unified_diff(Path::new("same.txt"), "x\n", "x\n").is_empty());
+    }
+}
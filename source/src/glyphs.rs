@@ -0,0 +1,169 @@
+//! Status glyphs for terminal output
+//!
+//! Emoji such as ✅ and ❌ render as boxes on some terminals (older Windows
+//! consoles, non-UTF-8 locales) and throw columns out of line. Every status
+//! mark goes through a [`Glyph`], which prints the emoji or a plain ASCII
+//! stand-in depending on the selected [`GlyphSet`]. `[ui] glyphs` picks the
+//! set; "auto", the default, asks the terminal.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use anyhow::{Result, anyhow};
+
+/// Which characters status marks are drawn with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlyphSet {
+    Unicode,
+    Ascii,
+}
+
+impl GlyphSet {
+    /// The set named by `[ui] glyphs`; None for "auto" (or unset)
+    pub fn from_name(name: Option<&str>) -> Result<Option<GlyphSet>> {
+        match name.unwrap_or("auto") {
+            "auto" => Ok(None),
+            "unicode" => Ok(Some(GlyphSet::Unicode)),
+            "ascii" => Ok(Some(GlyphSet::Ascii)),
+            other => Err(anyhow!("Invalid [ui] glyphs '{}' (expected \"auto\", \"unicode\" or \"ascii\")", other)),
+        }
+    }
+
+    /// What the terminal can show: Unicode on UTF-8 locales and terminals
+    /// known to draw emoji, ASCII elsewhere
+    pub fn detect() -> GlyphSet {
+        if console::Term::stdout().features().wants_emoji() {
+            GlyphSet::Unicode
+        } else {
+            GlyphSet::Ascii
+        }
+    }
+}
+
+const UNSET: u8 = 0;
+const UNICODE: u8 = 1;
+const ASCII: u8 = 2;
+
+static SELECTED: AtomicU8 = AtomicU8::new(UNSET);
+
+/// Use `set` for all output from now on
+pub fn select(set: GlyphSet) {
+    let value = match set {
+        GlyphSet::Unicode => UNICODE,
+        GlyphSet::Ascii => ASCII,
+    };
+    SELECTED.store(value, Ordering::Relaxed);
+}
+
+/// Apply `[ui] glyphs`; "auto" leaves the choice to [`GlyphSet::detect`]
+pub fn configure(ui: &crate::config::UiConfig) {
+    if let Ok(Some(set)) = GlyphSet::from_name(ui.glyphs.as_deref()) {
+        select(set);
+    }
+}
+
+/// The set in use, detected on first use if none was selected
+pub fn current() -> GlyphSet {
+    match SELECTED.load(Ordering::Relaxed) {
+        UNICODE => GlyphSet::Unicode,
+        ASCII => GlyphSet::Ascii,
+        _ => {
+            let set = GlyphSet::detect();
+            select(set);
+            set
+        }
+    }
+}
+
+/// A status mark with its ASCII stand-in
+#[derive(Debug, Clone, Copy)]
+pub struct Glyph {
+    unicode: &'static str,
+    ascii: &'static str,
+}
+
+impl Glyph {
+    pub const fn new(unicode: &'static str, ascii: &'static str) -> Self {
+        Self { unicode, ascii }
+    }
+
+    /// The mark in the current set
+    pub fn as_str(&self) -> &'static str {
+        match current() {
+            GlyphSet::Unicode => self.unicode,
+            GlyphSet::Ascii => self.ascii,
+        }
+    }
+}
+
+impl fmt::Display for Glyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+pub const OK: Glyph = Glyph::new("✅", "[OK]");
+pub const FAIL: Glyph = Glyph::new("❌", "[FAIL]");
+pub const WARN: Glyph = Glyph::new("⚠️", "[WARN]");
+pub const SCAN: Glyph = Glyph::new("🔍", ">>");
+pub const STOP: Glyph = Glyph::new("⏹️", "[STOP]");
+pub const STATS: Glyph = Glyph::new("📊", "[STATS]");
+pub const CHECK: Glyph = Glyph::new("✓", "+");
+pub const FILE: Glyph = Glyph::new("📄", "-");
+pub const FOLDER: Glyph = Glyph::new("📁", "+");
+pub const MANIFEST: Glyph = Glyph::new("🧾", "[MANIFEST]");
+pub const WATCH: Glyph = Glyph::new("👀", ">>");
+pub const RESULT: Glyph = Glyph::new("↪", "->");
+pub const RULE: Glyph = Glyph::new("─", "-");
+pub const PAUSED: Glyph = Glyph::new("⏸️", "[OFF]");
+pub const UNLOADED: Glyph = Glyph::new("⭕", "[--]");
+
+// Section headers; in ASCII they all read as "=="
+pub const NOTE: Glyph = Glyph::new("📝", "==");
+pub const LIST: Glyph = Glyph::new("📋", "==");
+pub const ANALYZE: Glyph = Glyph::new("🧠", "==");
+pub const START: Glyph = Glyph::new("🚀", "==");
+pub const HALT: Glyph = Glyph::new("🛑", "==");
+pub const RESTART: Glyph = Glyph::new("🔄", "==");
+pub const PACKAGE: Glyph = Glyph::new("📦", "==");
+pub const REMOVE: Glyph = Glyph::new("🗑️", "==");
+pub const SETTINGS: Glyph = Glyph::new("⚙️", "==");
+pub const TREND: Glyph = Glyph::new("📈", "==");
+pub const SPEED: Glyph = Glyph::new("⚡", "==");
+pub const CLEAN: Glyph = Glyph::new("🧹", "==");
+pub const RUN: Glyph = Glyph::new("🏃", "==");
+pub const SCREEN: Glyph = Glyph::new("🖥️", "==");
+pub const PLUGIN: Glyph = Glyph::new("🔌", "==");
+pub const TEST: Glyph = Glyph::new("🧪", "==");
+
+/// Progress bar fill characters in the current set
+pub fn progress_chars() -> &'static str {
+    match current() {
+        GlyphSet::Unicode => "█▇▆▅▄▃▂▁  ",
+        GlyphSet::Ascii => "#>-",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_set_names() {
+        assert_eq!(GlyphSet::from_name(None).unwrap(), None);
+        assert_eq!(GlyphSet::from_name(Some("auto")).unwrap(), None);
+        assert_eq!(GlyphSet::from_name(Some("ascii")).unwrap(), Some(GlyphSet::Ascii));
+        assert_eq!(GlyphSet::from_name(Some("unicode")).unwrap(), Some(GlyphSet::Unicode));
+        assert!(GlyphSet::from_name(Some("emoji")).is_err());
+    }
+
+    #[test]
+    fn test_ascii_stand_ins_are_ascii() {
+        for glyph in [
+            OK, FAIL, WARN, SCAN, STOP, STATS, CHECK, FILE, FOLDER, MANIFEST, WATCH, RESULT, RULE,
+            PAUSED, UNLOADED, NOTE, LIST, ANALYZE, START, HALT, RESTART, PACKAGE, REMOVE, SETTINGS,
+            TREND, SPEED, CLEAN, RUN, SCREEN, PLUGIN, TEST,
+        ] {
+            assert!(glyph.ascii.is_ascii(), "{}", glyph.unicode);
+        }
+    }
+}
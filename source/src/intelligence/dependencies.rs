@@ -0,0 +1,242 @@
+//! Import graph and cycle detection
+//!
+//! Imports are read straight from the source, per language: Python `import`
+//! and `from` statements, JavaScript/TypeScript relative `import`/`require`
+//! specifiers and C/C++ quoted `#include`s. Only imports that resolve to a
+//! file in the project become edges; a depth-first search over those edges
+//! finds the cycles.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static PYTHON_IMPORT: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*import\s+(.+)$").unwrap());
+static PYTHON_FROM: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*from\s+(\.*[\w.]*)\s+import\s+(.+)$").unwrap());
+static JS_IMPORT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:\bfrom\s*|\bimport\s*\(?\s*|\brequire\s*\(\s*)['"]([^'"]+)['"]"#).unwrap()
+});
+static C_INCLUDE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^\s*#\s*include\s*"([^"]+)""#).unwrap());
+
+const JS_EXTENSIONS: &[&str] = &["js", "jsx", "ts", "tsx", "mjs", "cjs"];
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default()
+}
+
+/// The modules `content` imports, as written. Python relative imports keep
+/// their leading dots; `from . import a` gives `.a`.
+pub fn extract_imports(path: &Path, content: &str) -> Vec<String> {
+    let mut imports = Vec::new();
+    match extension(path).as_str() {
+        "py" => {
+            for line in content.lines() {
+                let line = line.split('#').next().unwrap_or("");
+                if let Some(captures) = PYTHON_FROM.captures(line) {
+                    let module = &captures[1];
+                    if module.chars().all(|c| c == '.') {
+                        // `from . import a, b` imports sibling modules
+                        imports.extend(imported_names(&captures[2]).map(|name| format!("{}{}", module, name)));
+                    } else {
+                        // The imported names may be submodules as well as attributes
+                        imports.push(module.to_string());
+                        imports.extend(imported_names(&captures[2]).map(|name| format!("{}.{}", module, name)));
+                    }
+                } else if let Some(captures) = PYTHON_IMPORT.captures(line) {
+                    imports.extend(imported_names(&captures[1]).map(str::to_string));
+                }
+            }
+        }
+        ext if JS_EXTENSIONS.contains(&ext) => {
+            imports.extend(JS_IMPORT.captures_iter(content).map(|captures| captures[1].to_string()));
+        }
+        "c" | "h" | "cpp" | "cc" | "cxx" | "hpp" | "hxx" | "hh" => {
+            imports.extend(content.lines()
+                .filter_map(|line| C_INCLUDE.captures(line))
+                .map(|captures| captures[1].to_string()));
+        }
+        _ => {}
+    }
+    imports.sort();
+    imports.dedup();
+    imports
+}
+
+// The names in `a, b as c` or `(a, b)`, without aliases or `*`
+fn imported_names(list: &str) -> impl Iterator<Item = &str> {
+    list.trim_matches(|c: char| c == '(' || c == ')' || c.is_whitespace())
+        .split(',')
+        .filter_map(|name| name.split_whitespace().next())
+        .filter(|name| *name != "*")
+}
+
+/// The project file an import in `file` refers to, if it is one
+pub fn resolve_import(file: &Path, import: &str, root: &Path, files: &HashSet<PathBuf>) -> Option<PathBuf> {
+    let dir = file.parent()?;
+    let existing = |candidates: Vec<PathBuf>| candidates.into_iter().find(|candidate| files.contains(candidate));
+
+    match extension(file).as_str() {
+        "py" => {
+            let dots = import.len() - import.trim_start_matches('.').len();
+            let module = import[dots..].replace('.', "/");
+            let bases = if dots > 0 {
+                // One dot is the file's own package, each further dot a level up
+                vec![dir.ancestors().nth(dots - 1)?.to_path_buf()]
+            } else {
+                vec![dir.to_path_buf(), root.to_path_buf()]
+            };
+            existing(bases.iter()
+                .flat_map(|base| [base.join(format!("{}.py", module)), base.join(&module).join("__init__.py")])
+                .collect())
+        }
+        ext if JS_EXTENSIONS.contains(&ext) => {
+            if !import.starts_with('.') {
+                return None;
+            }
+            let target = dir.join(import);
+            let mut candidates = vec![target.clone()];
+            for extension in JS_EXTENSIONS {
+                candidates.push(PathBuf::from(format!("{}.{}", target.display(), extension)));
+                candidates.push(target.join(format!("index.{}", extension)));
+            }
+            existing(candidates.into_iter().map(|candidate| normalize(&candidate)).collect())
+        }
+        _ => existing(vec![normalize(&dir.join(import)), normalize(&root.join(import))]),
+    }
+}
+
+// Drop `.` and fold `..` without touching the file system
+fn normalize(path: &Path) -> PathBuf {
+    use std::path::Component;
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Which project files import which
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+    pub edges: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+impl DependencyGraph {
+    /// Build the graph from each file's imports, keeping those that resolve
+    /// to another file in the list
+    pub fn build<'a>(root: &Path, imports: impl IntoIterator<Item = (&'a Path, &'a [String])>) -> Self {
+        let imports: Vec<_> = imports.into_iter().collect();
+        let files: HashSet<PathBuf> = imports.iter().map(|(file, _)| file.to_path_buf()).collect();
+
+        let mut graph = DependencyGraph::default();
+        for (file, modules) in imports {
+            let targets = graph.edges.entry(file.to_path_buf()).or_default();
+            for module in modules {
+                if let Some(target) = resolve_import(file, module, root, &files).filter(|target| target != file) {
+                    targets.insert(target);
+                }
+            }
+        }
+        graph
+    }
+
+    /// The import cycles a depth-first search runs into, each starting from
+    /// its smallest path and listed once
+    pub fn cycles(&self) -> Vec<Vec<PathBuf>> {
+        let mut cycles = BTreeSet::new();
+        let mut done = HashSet::new();
+        for start in self.edges.keys() {
+            let mut stack = Vec::new();
+            self.visit(start, &mut stack, &mut done, &mut cycles);
+        }
+        cycles.into_iter().collect()
+    }
+
+    fn visit<'a>(
+        &'a self,
+        node: &'a PathBuf,
+        stack: &mut Vec<&'a PathBuf>,
+        done: &mut HashSet<&'a PathBuf>,
+        cycles: &mut BTreeSet<Vec<PathBuf>>,
+    ) {
+        if done.contains(node) {
+            return;
+        }
+        // Reaching a file that's still on the stack closes a cycle
+        if let Some(position) = stack.iter().position(|on_stack| *on_stack == node) {
+            let mut cycle: Vec<PathBuf> = stack[position..].iter().map(|path| path.to_path_buf()).collect();
+            let smallest = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+            cycle.rotate_left(smallest);
+            cycles.insert(cycle);
+            return;
+        }
+
+        stack.push(node);
+        for target in self.edges.get(node).into_iter().flatten() {
+            self.visit(target, stack, done, cycles);
+        }
+        stack.pop();
+        done.insert(node);
+    }
+}
+
+/// A cycle as `a → b → c → a`, naming files relative to `root`
+pub fn describe_cycle(cycle: &[PathBuf], root: &Path) -> String {
+    cycle.iter()
+        .chain(cycle.first())
+        .map(|path| path.strip_prefix(root).unwrap_or(path).display().to_string())
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_python_imports() {
+        let source = "import os, pkg.util as u\nfrom . import b, c\nfrom ..core import thing\nfrom a import x  # note\n";
+        assert_eq!(extract_imports(Path::new("m.py"), source), vec!["..core", "..core.thing", ".b", ".c", "a", "a.x", "os", "pkg.util"]);
+    }
+
+    #[test]
+    fn test_js_and_c_imports() {
+        let js = "import x from './x';\nconst y = require(\"../y\");\nimport('./lazy');\nimport fs from 'fs';\n";
+        assert_eq!(extract_imports(Path::new("a.ts"), js), vec!["../y", "./lazy", "./x", "fs"]);
+        assert_eq!(extract_imports(Path::new("a.c"), "#include <stdio.h>\n#include \"a.h\"\n"), vec!["a.h"]);
+    }
+
+    #[test]
+    fn test_js_cycle_through_index() {
+        let root = Path::new("/p");
+        let app = PathBuf::from("/p/src/app.js");
+        let index = PathBuf::from("/p/src/lib/index.ts");
+        let app_imports = vec!["./lib".to_string()];
+        let index_imports = vec!["../app.js".to_string()];
+        let graph = DependencyGraph::build(root, [(app.as_path(), &app_imports[..]), (index.as_path(), &index_imports[..])]);
+
+        assert_eq!(graph.cycles(), vec![vec![app, index]]);
+    }
+
+    #[test]
+    fn test_acyclic_graph_has_no_cycles() {
+        let root = Path::new("/p");
+        let a = PathBuf::from("/p/a.py");
+        let b = PathBuf::from("/p/b.py");
+        let a_imports = vec!["b".to_string()];
+        let b_imports = vec!["os".to_string()];
+        let graph = DependencyGraph::build(root, [(a.as_path(), &a_imports[..]), (b.as_path(), &b_imports[..])]);
+
+        assert!(graph.cycles().is_empty());
+        assert_eq!(graph.edges[&a], BTreeSet::from([b]));
+    }
+}
@@ -0,0 +1,285 @@
+//! Per-function metrics extracted from tree-sitter syntax trees
+//!
+//! Walks the parse tree for each supported language, finds function
+//! definitions and scores each one for cyclomatic and cognitive complexity.
+//! Nested functions are measured on their own and don't count towards the
+//! function that contains them.
+
+use anyhow::{Result, anyhow};
+use tree_sitter::{Node, Parser};
+
+use super::metrics::{ComplexityRating, FunctionMetrics};
+
+/// Node kinds that make up the grammar of one language, as far as metrics care
+struct LanguageSpec {
+    functions: &'static [&'static str],
+    /// Branching constructs that also increase nesting (if, loops, catch, ...)
+    branches: &'static [&'static str],
+    /// Extra decision points that don't nest (match arms, switch cases, elif)
+    decisions: &'static [&'static str],
+    /// `else`/`elif` style continuations: +1 cognitive, no nesting penalty
+    continuations: &'static [&'static str],
+    /// Binary expression kinds that may carry `&&`/`||`
+    binary: &'static [&'static str],
+    returns: &'static [&'static str],
+}
+
+const RUST: LanguageSpec = LanguageSpec {
+    functions: &["function_item"],
+    branches: &["if_expression", "while_expression", "for_expression", "loop_expression", "match_expression"],
+    decisions: &["match_arm"],
+    continuations: &["else_clause"],
+    binary: &["binary_expression"],
+    returns: &["return_expression"],
+};
+
+const PYTHON: LanguageSpec = LanguageSpec {
+    functions: &["function_definition"],
+    branches: &["if_statement", "while_statement", "for_statement", "except_clause", "conditional_expression"],
+    decisions: &["elif_clause"],
+    continuations: &["elif_clause", "else_clause"],
+    binary: &["boolean_operator"],
+    returns: &["return_statement"],
+};
+
+const JAVASCRIPT: LanguageSpec = LanguageSpec {
+    functions: &["function_declaration", "function", "arrow_function", "method_definition", "generator_function_declaration"],
+    branches: &["if_statement", "while_statement", "for_statement", "for_in_statement", "do_statement", "switch_statement", "catch_clause", "ternary_expression"],
+    decisions: &["switch_case"],
+    continuations: &["else_clause"],
+    binary: &["binary_expression"],
+    returns: &["return_statement"],
+};
+
+const C_FAMILY: LanguageSpec = LanguageSpec {
+    functions: &["function_definition"],
+    branches: &["if_statement", "while_statement", "for_statement", "for_range_loop", "do_statement", "switch_statement", "catch_clause", "conditional_expression"],
+    decisions: &["case_statement"],
+    continuations: &[],
+    binary: &["binary_expression"],
+    returns: &["return_statement"],
+};
+
+const GO: LanguageSpec = LanguageSpec {
+    functions: &["function_declaration", "method_declaration"],
+    branches: &["if_statement", "for_statement", "expression_switch_statement", "type_switch_statement", "select_statement"],
+    decisions: &["expression_case", "type_case", "communication_case"],
+    continuations: &[],
+    binary: &["binary_expression"],
+    returns: &["return_statement"],
+};
+
+const JAVA: LanguageSpec = LanguageSpec {
+    functions: &["method_declaration", "constructor_declaration"],
+    branches: &["if_statement", "while_statement", "for_statement", "enhanced_for_statement", "do_statement", "switch_expression", "catch_clause", "ternary_expression"],
+    decisions: &["switch_label"],
+    continuations: &[],
+    binary: &["binary_expression"],
+    returns: &["return_statement"],
+};
+
+/// Extract metrics for every function in `content`.
+///
+/// Returns `Ok(None)` for languages without a tree-sitter grammar, so callers
+/// can keep their own estimates.
+pub fn extract_functions(language: &str, content: &str) -> Result<Option<Vec<FunctionMetrics>>> {
+    let (mut parser, spec) = match language {
+        "rust" => (crate::tui::create_rust_parser()?, &RUST),
+        "python" => (crate::tui::create_python_parser()?, &PYTHON),
+        "javascript" => (crate::tui::create_javascript_parser()?, &JAVASCRIPT),
+        "typescript" => (crate::tui::create_typescript_parser()?, &JAVASCRIPT),
+        "c" => (crate::tui::create_c_parser()?, &C_FAMILY),
+        "cpp" => (crate::tui::create_cpp_parser()?, &C_FAMILY),
+        "go" => (crate::tui::create_go_parser()?, &GO),
+        "java" => (crate::tui::create_java_parser()?, &JAVA),
+        _ => return Ok(None),
+    };
+
+    let tree = parse(&mut parser, content)?;
+    let source = content.as_bytes();
+
+    let mut functions = Vec::new();
+    collect_functions(tree.root_node(), source, spec, &mut functions);
+    Ok(Some(functions))
+}
+
+fn parse(parser: &mut Parser, content: &str) -> Result<tree_sitter::Tree> {
+    parser.parse(content, None)
+        .ok_or_else(|| anyhow!("Failed to parse source"))
+}
+
+fn collect_functions(node: Node, source: &[u8], spec: &LanguageSpec, out: &mut Vec<FunctionMetrics>) {
+    if spec.functions.contains(&node.kind()) {
+        out.push(measure_function(node, source, spec));
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_functions(child, source, spec, out);
+    }
+}
+
+/// Running totals while walking one function body
+#[derive(Default)]
+struct Tally {
+    decisions: usize,
+    cognitive: usize,
+    max_nesting: usize,
+    returns: usize,
+}
+
+fn measure_function(node: Node, source: &[u8], spec: &LanguageSpec) -> FunctionMetrics {
+    let mut tally = Tally::default();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        walk_body(child, source, spec, 0, &mut tally);
+    }
+
+    let start_line = node.start_position().row + 1;
+    let end_line = node.end_position().row + 1;
+
+    FunctionMetrics {
+        name: function_name(node, source),
+        start_line,
+        end_line,
+        lines_of_code: end_line - start_line + 1,
+        cyclomatic_complexity: 1 + tally.decisions,
+        cognitive_complexity: tally.cognitive,
+        parameter_count: parameter_count(node),
+        local_variable_count: 0,
+        return_statements: tally.returns,
+        nesting_depth: tally.max_nesting,
+        fan_in: 0,
+        fan_out: 0,
+        complexity_rating: ComplexityRating::Simple,
+        suggestions: Vec::new(),
+    }
+}
+
+fn walk_body(node: Node, source: &[u8], spec: &LanguageSpec, nesting: usize, tally: &mut Tally) {
+    let kind = node.kind();
+
+    // Nested functions are reported separately
+    if spec.functions.contains(&kind) {
+        return;
+    }
+
+    let mut child_nesting = nesting;
+    if spec.branches.contains(&kind) {
+        tally.decisions += 1;
+        tally.cognitive += 1 + nesting;
+        child_nesting = nesting + 1;
+        tally.max_nesting = tally.max_nesting.max(child_nesting);
+    } else if spec.continuations.contains(&kind) {
+        tally.cognitive += 1;
+    }
+
+    if spec.decisions.contains(&kind) {
+        tally.decisions += 1;
+    }
+
+    if spec.binary.contains(&kind) && is_logical_operator(node, source) {
+        tally.decisions += 1;
+        tally.cognitive += 1;
+    }
+
+    if spec.returns.contains(&kind) {
+        tally.returns += 1;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        walk_body(child, source, spec, child_nesting, tally);
+    }
+}
+
+fn is_logical_operator(node: Node, source: &[u8]) -> bool {
+    node.child_by_field_name("operator")
+        .and_then(|op| op.utf8_text(source).ok())
+        .is_some_and(|op| matches!(op, "&&" | "||" | "and" | "or" | "??"))
+}
+
+fn function_name(node: Node, source: &[u8]) -> String {
+    let text = |n: Node| n.utf8_text(source).ok().map(str::to_string);
+
+    if let Some(name) = node.child_by_field_name("name").and_then(text) {
+        return name;
+    }
+
+    // C/C++ keep the name inside the (possibly nested) declarator
+    let mut declarator = node.child_by_field_name("declarator");
+    while let Some(current) = declarator {
+        match current.child_by_field_name("declarator") {
+            Some(inner) => declarator = Some(inner),
+            None => return text(current).unwrap_or_default(),
+        }
+    }
+
+    // Anonymous functions take the name of the variable they're assigned to
+    node.parent()
+        .filter(|parent| parent.kind() == "variable_declarator")
+        .and_then(|parent| parent.child_by_field_name("name"))
+        .and_then(text)
+        .unwrap_or_else(|| "<anonymous>".to_string())
+}
+
+fn parameter_count(node: Node) -> usize {
+    let parameters = node.child_by_field_name("parameters").or_else(|| {
+        node.child_by_field_name("declarator")
+            .and_then(|d| d.child_by_field_name("parameters"))
+    });
+
+    parameters.map_or(0, |params| {
+        let mut cursor = params.walk();
+        let count = params.named_children(&mut cursor)
+            .filter(|p| p.kind() != "comment")
+            .count();
+        count
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> String {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files/python/metrics").join(name);
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn test_most_complex_function_ranked_first() {
+        let functions = extract_functions("python", &fixture("functions.py")).unwrap().unwrap();
+        assert_eq!(functions.len(), 3);
+
+        let metrics = super::super::metrics::CodeMetrics {
+            functions,
+            ..Default::default()
+        };
+        let ranked = metrics.most_complex_functions(3);
+
+        assert_eq!(ranked[0].name, "classify");
+        assert!((6..=12).contains(&ranked[0].cyclomatic_complexity));
+        assert!(ranked[0].cognitive_complexity > ranked[1].cognitive_complexity);
+        assert_eq!(ranked[2].cyclomatic_complexity, 1);
+    }
+
+    #[test]
+    fn test_rust_function_lines_and_names() {
+        let source = "fn a() {}\n\nfn b(x: i32) -> i32 {\n    if x > 0 && x < 10 { 1 } else { 2 }\n}\n";
+        let functions = extract_functions("rust", source).unwrap().unwrap();
+
+        assert_eq!(functions.len(), 2);
+        assert_eq!(functions[1].name, "b");
+        assert_eq!(functions[1].start_line, 3);
+        assert_eq!(functions[1].lines_of_code, 3);
+        assert_eq!(functions[1].parameter_count, 1);
+        assert_eq!(functions[1].cyclomatic_complexity, 3);
+    }
+
+    #[test]
+    fn test_unsupported_language() {
+        assert!(extract_functions("ruby", "def a; end").unwrap().is_none());
+    }
+}
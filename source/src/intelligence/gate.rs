@@ -0,0 +1,120 @@
+//! Quality gate
+//!
+//! Holds a project's intelligence report against the budgets in the `[gate]`
+//! config section, so CI can fail a build whose quality slips. Unset budgets
+//! aren't checked, except the quality floor, which defaults to the bottom of
+//! the "Poor" grade.
+
+use crate::config::GateConfig;
+use super::ProjectIntelligence;
+
+/// Overall quality a project needs when `[gate]` doesn't say
+pub const DEFAULT_MIN_QUALITY: f64 = 60.0;
+
+/// One budget and how the project did against it
+#[derive(Debug, Clone)]
+pub struct GateCheck {
+    pub name: &'static str,
+    pub actual: f64,
+    pub limit: f64,
+    /// Whether the limit is a floor rather than a ceiling
+    pub minimum: bool,
+    pub passed: bool,
+}
+
+impl std::fmt::Display for GateCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bound = if self.minimum { "minimum" } else { "maximum" };
+        write!(f, "{}: {} ({} {})", self.name, number(self.actual), bound, number(self.limit))
+    }
+}
+
+// Counts print as counts, scores and ratios with two decimals
+fn number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+/// Check a project report against every configured budget
+pub fn evaluate(report: &ProjectIntelligence, config: &GateConfig) -> Vec<GateCheck> {
+    let mut checks = Vec::new();
+
+    let min_quality = config.min_quality.unwrap_or(DEFAULT_MIN_QUALITY);
+    checks.push(GateCheck {
+        name: "overall quality",
+        actual: report.overall_quality.overall,
+        limit: min_quality,
+        minimum: true,
+        passed: report.overall_quality.overall >= min_quality,
+    });
+
+    if let Some(max_ratio) = config.max_debt_ratio {
+        checks.push(GateCheck {
+            name: "technical debt ratio",
+            actual: report.technical_debt.debt_ratio,
+            limit: max_ratio,
+            minimum: false,
+            passed: report.technical_debt.debt_ratio <= max_ratio,
+        });
+    }
+
+    if let Some(max_files) = config.max_critical_complexity_files {
+        let critical = report.complexity_distribution.critical;
+        checks.push(GateCheck {
+            name: "critical-complexity files",
+            actual: critical as f64,
+            limit: max_files as f64,
+            minimum: false,
+            passed: critical <= max_files,
+        });
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intelligence::IntelligenceEngine;
+    use std::fs;
+
+    fn analyze(files: &[(&str, &str)]) -> ProjectIntelligence {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in files {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+        IntelligenceEngine::new().unwrap().analyze_project(dir.path()).unwrap()
+    }
+
+    #[test]
+    fn test_only_configured_budgets_are_checked() {
+        let report = analyze(&[("app.py", "def add(a, b):\n    return a + b\n")]);
+
+        let checks = evaluate(&report, &GateConfig::default());
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].limit, DEFAULT_MIN_QUALITY);
+
+        let config = GateConfig {
+            min_quality: Some(0.0),
+            max_debt_ratio: Some(f64::MAX),
+            max_critical_complexity_files: Some(0),
+        };
+        let checks = evaluate(&report, &config);
+        assert_eq!(checks.len(), 3);
+        assert!(checks.iter().all(|check| check.passed));
+    }
+
+    #[test]
+    fn test_critical_complexity_budget() {
+        let branches: String = (0..30).map(|i| format!("    if x == {}:\n        return {}\n", i, i)).collect();
+        let report = analyze(&[("tangled.py", &format!("def pick(x):\n{}    return -1\n", branches))]);
+
+        let config = GateConfig { max_critical_complexity_files: Some(0), ..Default::default() };
+        let check = evaluate(&report, &config).pop().unwrap();
+        assert_eq!(check.name, "critical-complexity files");
+        assert!(!check.passed);
+    }
+}
@@ -0,0 +1,151 @@
+//! Lines of code per language
+//!
+//! A quick size breakdown for `synx loc`, without a full intelligence run.
+//! Lines are sorted into code, comment and blank by the same rules the
+//! metrics analyzer uses. Excluded, generated and unrecognised files aren't
+//! counted.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use anyhow::Result;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use crate::config::Config;
+use crate::detectors::{detect_file_type_with_mappings, FileType};
+use crate::validators::generated;
+use super::metrics::{count_lines, LineCounts};
+
+/// Version control metadata is never source code
+const VCS_DIRS: &[&str] = &[".git", ".hg", ".svn"];
+
+/// Line counts for one language
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LanguageLines {
+    pub files: usize,
+    #[serde(flatten)]
+    pub lines: LineCounts,
+}
+
+/// Line counts for a tree, by language and in total
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LocReport {
+    pub languages: BTreeMap<String, LanguageLines>,
+    pub total: LanguageLines,
+}
+
+impl LocReport {
+    fn add(&mut self, language: String, lines: &LineCounts) {
+        for entry in [self.languages.entry(language).or_default(), &mut self.total] {
+            entry.files += 1;
+            entry.lines.add(lines);
+        }
+    }
+}
+
+/// Count the lines of every recognised file under `dir`, leaving out files
+/// matching `exclude` (glob patterns) and generated ones
+pub fn count_tree(dir: &Path, exclude: &[String], config: &Config) -> Result<LocReport> {
+    let patterns: Vec<glob::Pattern> = exclude.iter()
+        .filter_map(|pattern| glob::Pattern::new(pattern).ok())
+        .collect();
+    let entries = WalkDir::new(dir).into_iter().filter_entry(|entry| {
+        entry.depth() == 0
+            || !(entry.file_type().is_dir() && VCS_DIRS.iter().any(|name| entry.file_name() == *name))
+    });
+
+    let mut report = LocReport::default();
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if !entry.file_type().is_file()
+            || patterns.iter().any(|pattern| pattern.matches(&path.to_string_lossy()))
+            || generated::is_generated(path, &config.generated)
+        {
+            continue;
+        }
+        let file_type = match detect_file_type_with_mappings(path, &config.file_mappings) {
+            Ok(FileType::Unknown(_)) | Err(_) => continue,
+            Ok(file_type) => file_type,
+        };
+        // Binary and non-UTF-8 files aren't counted
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+        report.add(file_type.to_string(), &count_lines(&content, comment_syntax(&file_type)));
+    }
+    Ok(report)
+}
+
+/// The metrics analyzer's name for the comment syntax `file_type` uses
+fn comment_syntax(file_type: &FileType) -> &'static str {
+    match file_type {
+        FileType::Python => "python",
+        FileType::JavaScript | FileType::Jsx | FileType::Vue | FileType::Svelte => "javascript",
+        FileType::TypeScript | FileType::Tsx => "typescript",
+        FileType::Rust => "rust",
+        FileType::Java => "java",
+        FileType::Go => "go",
+        FileType::C => "c",
+        FileType::Cpp => "cpp",
+        FileType::CSharp => "csharp",
+        FileType::Css => "css",
+        FileType::Scss => "scss",
+        FileType::Shell | FileType::DotEnv => "shell",
+        FileType::Yaml => "yaml",
+        FileType::Toml => "toml",
+        FileType::Dockerfile => "dockerfile",
+        FileType::GraphQL => "graphql",
+        FileType::Html | FileType::Json | FileType::Markdown | FileType::Unknown(_) => "",
+    }
+}
+
+/// The report as a table for the terminal
+pub fn render(report: &LocReport) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{:<14} {:>7} {:>10} {:>10} {:>10}", "Language", "Files", "Code", "Comments", "Blank");
+    let _ = writeln!(out, "{}", "-".repeat(55));
+    let mut languages: Vec<_> = report.languages.iter().collect();
+    languages.sort_by(|a, b| b.1.lines.code.cmp(&a.1.lines.code).then(a.0.cmp(b.0)));
+    for (language, counts) in languages {
+        let _ = writeln!(out, "{:<14} {:>7} {:>10} {:>10} {:>10}",
+            language, counts.files, counts.lines.code, counts.lines.comments, counts.lines.blank);
+    }
+    let _ = writeln!(out, "{}", "-".repeat(55));
+    let _ = writeln!(out, "{:<14} {:>7} {:>10} {:>10} {:>10}",
+        "Total", report.total.files, report.total.lines.code, report.total.lines.comments, report.total.lines.blank);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_syntax_follows_language() {
+        let shell = count_lines("#!/bin/sh\n# setup\n\necho hi\n", comment_syntax(&FileType::Shell));
+        assert_eq!(shell, LineCounts { code: 1, comments: 2, blank: 1 });
+
+        let rust = count_lines("// add\nfn add() {}\n/* old\n * code\n */\n", comment_syntax(&FileType::Rust));
+        assert_eq!(rust, LineCounts { code: 1, comments: 4, blank: 0 });
+
+        // JSON has no comments, so every non-blank line is code
+        let json = count_lines("{\n  \"a\": 1\n}\n", comment_syntax(&FileType::Json));
+        assert_eq!(json, LineCounts { code: 3, comments: 0, blank: 0 });
+    }
+
+    #[test]
+    fn test_generated_and_vcs_files_are_left_out() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir(dir.path().join(".git"))?;
+        fs::write(dir.path().join(".git/hook.sh"), "echo hook\n")?;
+        fs::write(dir.path().join("app.py"), "# app\nx = 1\n")?;
+        fs::write(dir.path().join("app_pb2.py"), "# Generated by the protocol buffer compiler.  DO NOT EDIT!\nx = 1\n")?;
+
+        let report = count_tree(dir.path(), &[], &Config::default())?;
+        assert_eq!(report.total.files, 1);
+        assert_eq!(report.languages["Python"].lines, LineCounts { code: 1, comments: 1, blank: 0 });
+        Ok(())
+    }
+}
@@ -44,6 +44,20 @@ pub struct CodeMetrics {
     pub classes: Vec<ClassMetrics>,
 }
 
+impl CodeMetrics {
+    /// The `n` functions with the highest complexity, most complex first
+    pub fn most_complex_functions(&self, n: usize) -> Vec<&FunctionMetrics> {
+        let mut functions: Vec<&FunctionMetrics> = self.functions.iter().collect();
+        functions.sort_by(|a, b| {
+            b.cyclomatic_complexity.cmp(&a.cyclomatic_complexity)
+                .then(b.cognitive_complexity.cmp(&a.cognitive_complexity))
+                .then(b.lines_of_code.cmp(&a.lines_of_code))
+        });
+        functions.truncate(n);
+        functions
+    }
+}
+
 /// Halstead complexity metrics
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct HalsteadMetrics {
@@ -125,6 +139,59 @@ pub struct ClassMetrics {
     pub complexity_rating: ComplexityRating,
 }
 
+/// Code, comment and blank lines in one file or many
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct LineCounts {
+    pub code: usize,
+    pub comments: usize,
+    pub blank: usize,
+}
+
+impl LineCounts {
+    pub fn total(&self) -> usize {
+        self.code + self.comments + self.blank
+    }
+
+    pub fn add(&mut self, other: &LineCounts) {
+        self.code += other.code;
+        self.comments += other.comments;
+        self.blank += other.blank;
+    }
+}
+
+/// Sort each line of `content` into code, comment or blank, by the comment
+/// syntax of `language` (as in `CodeMetrics::language`)
+pub fn count_lines(content: &str, language: &str) -> LineCounts {
+    let mut counts = LineCounts::default();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            counts.blank += 1;
+        } else if is_comment_line(trimmed, language) {
+            counts.comments += 1;
+        } else {
+            counts.code += 1;
+        }
+    }
+    counts
+}
+
+/// Check if a line is a comment
+fn is_comment_line(line: &str, language: &str) -> bool {
+    match language {
+        "rust" | "javascript" | "typescript" | "java" | "cpp" | "c" | "csharp" | "go" | "scss" => {
+            line.starts_with("//") || line.starts_with("/*") || line.starts_with("*/") || line.starts_with("*")
+        }
+        "css" => {
+            line.starts_with("/*") || line.starts_with("*/") || line.starts_with("*")
+        }
+        "python" | "ruby" | "shell" | "yaml" | "toml" | "dockerfile" | "graphql" => {
+            line.starts_with("#")
+        }
+        _ => false,
+    }
+}
+
 /// Complexity rating based on thresholds
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ComplexityRating {
@@ -191,6 +258,14 @@ impl MetricsAnalyzer {
             _ => self.analyze_generic(&mut metrics, content)?,
         }
         
+        // Function-level metrics from the syntax tree replace the regex-based estimates
+        if let Some(functions) = super::functions::extract_functions(&language, content)? {
+            metrics.function_count = functions.len();
+            metrics.cognitive_complexity = functions.iter().map(|f| f.cognitive_complexity).sum();
+            metrics.nesting_depth = functions.iter().map(|f| f.nesting_depth).max().unwrap_or(0);
+            metrics.functions = functions;
+        }
+        
         // Calculate derived metrics
         self.calculate_derived_metrics(&mut metrics);
         
@@ -202,19 +277,11 @@ impl MetricsAnalyzer {
     
     /// Analyze basic metrics that apply to all languages
     fn analyze_basic_metrics(&self, metrics: &mut CodeMetrics, content: &str) {
-        let lines: Vec<&str> = content.lines().collect();
-        metrics.total_lines = lines.len();
-        
-        for line in &lines {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                metrics.blank_lines += 1;
-            } else if self.is_comment_line(trimmed, &metrics.language) {
-                metrics.comment_lines += 1;
-            } else {
-                metrics.lines_of_code += 1;
-            }
-        }
+        let counts = count_lines(content, &metrics.language);
+        metrics.total_lines = counts.total();
+        metrics.blank_lines = counts.blank;
+        metrics.comment_lines = counts.comments;
+        metrics.lines_of_code = counts.code;
     }
     
     /// Calculate derived complexity metrics
@@ -408,19 +475,6 @@ impl MetricsAnalyzer {
         }
     }
     
-    /// Check if a line is a comment
-    fn is_comment_line(&self, line: &str, language: &str) -> bool {
-        match language {
-            "rust" | "javascript" | "typescript" | "java" | "cpp" | "c" | "csharp" | "go" => {
-                line.starts_with("//") || line.starts_with("/*") || line.starts_with("*/") || line.starts_with("*")
-            }
-            "python" | "ruby" => {
-                line.starts_with("#")
-            }
-            _ => false,
-        }
-    }
-    
     /// Estimate cohesion based on variable usage patterns
     fn estimate_cohesion(&self, metrics: &CodeMetrics) -> f64 {
         if metrics.functions.is_empty() {
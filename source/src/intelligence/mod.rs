@@ -8,12 +8,16 @@ use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc};
 
 pub mod metrics;
+pub mod functions;
 pub mod patterns;
 pub mod quality;
 pub mod suggestions;
 pub mod trends;
 pub mod learning;
 pub mod sentinel;
+pub mod gate;
+pub mod loc;
+pub mod dependencies;
 
 pub use metrics::CodeMetrics;
 pub use patterns::ErrorPattern;
@@ -56,6 +60,9 @@ pub struct FileIntelligence {
     pub complexity_trends: Vec<ComplexityPoint>,
     pub risk_assessment: RiskAssessment,
     pub suggestions: Vec<SmartSuggestion>,
+    /// How far the file strays from its own history; see `SentinelAI::anomaly_score`
+    #[serde(default)]
+    pub anomaly_score: f64,
     pub last_analyzed: DateTime<Utc>,
 }
 
@@ -71,6 +78,12 @@ pub struct ProjectIntelligence {
     pub error_frequency: HashMap<String, usize>,
     pub team_metrics: TeamMetrics,
     pub trends: TrendSummary,
+    /// Files whose anomaly score reached the threshold, most anomalous first
+    #[serde(default)]
+    pub anomalies: Vec<(PathBuf, f64)>,
+    /// Files that import each other in a loop, each cycle from its smallest path
+    #[serde(default)]
+    pub import_cycles: Vec<Vec<PathBuf>>,
 }
 
 /// Historical snapshot for trend analysis
@@ -292,7 +305,8 @@ impl IntelligenceEngine {
         let content = fs::read_to_string(file_path)?;
         
         // Calculate metrics
-        let metrics = self.metrics_analyzer.analyze_file(file_path, &content)?;
+        let mut metrics = self.metrics_analyzer.analyze_file(file_path, &content)?;
+        metrics.dependencies = dependencies::extract_imports(file_path, &content);
         
         // Assess quality
         let quality_score = self.quality_assessor.assess_file(file_path, &content, &metrics)?;
@@ -313,11 +327,20 @@ impl IntelligenceEngine {
             .unwrap_or_default();
         
         // Get complexity trends
-        let complexity_trends = self.database.file_metrics
+        let mut complexity_trends = self.database.file_metrics
             .get(file_path)
             .map(|fi| fi.complexity_trends.clone())
             .unwrap_or_default();
         
+        // Compare against the earlier snapshots before adding this one
+        let anomaly_score = self.sentinel_ai.anomaly_score(&complexity_trends, &metrics);
+        complexity_trends.push(ComplexityPoint {
+            timestamp: Utc::now(),
+            cyclomatic: metrics.cyclomatic_complexity,
+            cognitive: metrics.cognitive_complexity,
+            lines_of_code: metrics.lines_of_code,
+        });
+        
         let file_intelligence = FileIntelligence {
             path: file_path.to_path_buf(),
             metrics,
@@ -326,6 +349,7 @@ impl IntelligenceEngine {
             complexity_trends,
             risk_assessment,
             suggestions,
+            anomaly_score,
             last_analyzed: Utc::now(),
         };
         
@@ -342,6 +366,8 @@ impl IntelligenceEngine {
         let mut total_files = 0;
         let mut overall_quality_sum = 0.0;
         let mut error_frequency = HashMap::new();
+        let mut anomalies = Vec::new();
+        let mut imports = Vec::new();
         
         // Walk through all files in the project
         for entry in walkdir::WalkDir::new(project_path) {
@@ -372,13 +398,28 @@ impl IntelligenceEngine {
                 
                 overall_quality_sum += file_intelligence.quality_score.overall;
                 
+                if file_intelligence.anomaly_score >= sentinel::ANOMALY_THRESHOLD {
+                    anomalies.push((path.to_path_buf(), file_intelligence.anomaly_score));
+                }
+                
                 // Update error frequency
                 for error in &file_intelligence.error_history {
                     *error_frequency.entry(error.error_type.clone()).or_insert(0) += 1;
                 }
+                
+                imports.push((path.to_path_buf(), file_intelligence.metrics.dependencies));
             }
         }
         
+        let graph = dependencies::DependencyGraph::build(
+            project_path,
+            imports.iter().map(|(path, modules)| (path.as_path(), &modules[..])),
+        );
+        let import_cycles = graph.cycles();
+        self.flag_import_cycles(&import_cycles, project_path);
+        
+        anomalies.sort_by(|a: &(PathBuf, f64), b| b.1.total_cmp(&a.1));
+        
         // Calculate averages
         for stats in languages.values_mut() {
             if stats.file_count > 0 {
@@ -416,6 +457,8 @@ impl IntelligenceEngine {
             error_frequency,
             team_metrics,
             trends,
+            anomalies,
+            import_cycles,
         };
         
         self.database.project_metrics = project_intelligence.clone();
@@ -550,6 +593,23 @@ impl IntelligenceEngine {
         })
     }
     
+    /// Add an import cycle risk factor to every file in each cycle
+    fn flag_import_cycles(&mut self, cycles: &[Vec<PathBuf>], root: &Path) {
+        for cycle in cycles {
+            let description = format!("Imports form a cycle: {}", dependencies::describe_cycle(cycle, root));
+            for file in cycle {
+                if let Some(file_intelligence) = self.database.file_metrics.get_mut(file) {
+                    file_intelligence.risk_assessment.risk_factors.push(RiskFactor {
+                        factor_type: "Import Cycle".to_string(),
+                        description: description.clone(),
+                        impact: 0.6,
+                        confidence: 0.9,
+                    });
+                }
+            }
+        }
+    }
+    
     fn is_source_file(&self, path: &Path) -> bool {
         if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
             matches!(ext.to_lowercase().as_str(), 
@@ -574,7 +634,8 @@ impl IntelligenceEngine {
         
         for (path, intelligence) in file_metrics {
             let complexity_debt = (intelligence.metrics.cyclomatic_complexity as f64 - 10.0).max(0.0) * 0.5;
-            let quality_debt = (1.0 - intelligence.quality_score.overall) * 8.0;
+            // Quality scores run 0-100; a file at zero carries a day of debt
+            let quality_debt = (100.0 - intelligence.quality_score.overall).max(0.0) / 100.0 * 8.0;
             let file_debt = complexity_debt + quality_debt;
             
             total_debt_hours += file_debt;
@@ -780,6 +841,8 @@ impl IntelligenceDatabase {
                     error_trend: TrendDirection::Stable,
                     productivity_trend: TrendDirection::Stable,
                 },
+                anomalies: Vec::new(),
+                import_cycles: Vec::new(),
             },
             error_patterns: Vec::new(),
             historical_data: Vec::new(),
@@ -871,6 +934,22 @@ pub fn format_file_report(report: &FileIntelligence) -> String {
         report.metrics.nesting_depth
     ));
     
+    // Complexity hotspots
+    let hotspots = report.metrics.most_complex_functions(5);
+    if !hotspots.is_empty() {
+        output.push_str("\n🔥 Most Complex Functions:\n");
+        for function in hotspots {
+            output.push_str(&format!(
+                "  {} (line {}) - Cyclomatic: {} | Cognitive: {} | Length: {} lines\n",
+                function.name,
+                function.start_line,
+                function.cyclomatic_complexity,
+                function.cognitive_complexity,
+                function.lines_of_code
+            ));
+        }
+    }
+    
     // Quality breakdown
     output.push_str("\n🎯 Quality Assessment:\n");
     output.push_str(&format!("  Maintainability: {:.1}%\n", report.quality_score.maintainability));
@@ -983,6 +1062,23 @@ pub fn format_project_report(report: &ProjectIntelligence) -> String {
         output.push('\n');
     }
     
+    // Files that changed out of character since the last analysis
+    if !report.anomalies.is_empty() {
+        output.push_str("⚠️ Anomalies:\n");
+        for (file, score) in report.anomalies.iter().take(10) {
+            output.push_str(&format!("  • {} (score {:.2})\n", file.display(), score));
+        }
+        output.push('\n');
+    }
+    
+    if !report.import_cycles.is_empty() {
+        output.push_str("🔁 Import Cycles:\n");
+        for cycle in report.import_cycles.iter().take(10) {
+            output.push_str(&format!("  • {}\n", dependencies::describe_cycle(cycle, Path::new(""))));
+        }
+        output.push('\n');
+    }
+    
     output
 }
 
@@ -1080,3 +1176,63 @@ pub fn generate_detailed_report(report: &ProjectIntelligence) -> String {
     
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complexity_spike_is_flagged_as_anomaly() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("pick.py");
+        let steady = dir.path().join("steady.py");
+        fs::write(&file, "def pick(x):\n    return x\n")?;
+        fs::write(&steady, "def add(a, b):\n    return a + b\n")?;
+
+        let mut engine = IntelligenceEngine::new()?;
+        let first = engine.analyze_project(dir.path())?;
+        assert!(first.anomalies.is_empty());
+
+        // Between snapshots the function grows thirty branches
+        let branches: String = (0..30).map(|i| format!("    if x == {}:\n        return {}\n", i, i)).collect();
+        fs::write(&file, format!("def pick(x):\n{}    return -1\n", branches))?;
+        let second = engine.analyze_project(dir.path())?;
+
+        assert_eq!(second.anomalies.len(), 1);
+        assert_eq!(second.anomalies[0].0, file);
+        assert!(second.anomalies[0].1 >= sentinel::ANOMALY_THRESHOLD);
+        assert_eq!(engine.database.file_metrics[&steady].anomaly_score, 0.0);
+        assert!(format_project_report(&second).contains("⚠️ Anomalies:"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_python_import_cycle_is_reported() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let pkg = dir.path().join("pkg");
+        fs::create_dir(&pkg)?;
+        fs::write(pkg.join("__init__.py"), "\"\"\"Package.\"\"\"\n")?;
+        fs::write(pkg.join("a.py"), "from . import b\n")?;
+        fs::write(pkg.join("b.py"), "from pkg import c\n")?;
+        fs::write(pkg.join("c.py"), "import pkg.a\nimport os\n")?;
+        fs::write(pkg.join("d.py"), "from .a import thing\n")?;
+
+        let mut engine = IntelligenceEngine::new()?;
+        let report = engine.analyze_project(dir.path())?;
+
+        assert_eq!(report.import_cycles, vec![vec![pkg.join("a.py"), pkg.join("b.py"), pkg.join("c.py")]]);
+        for name in ["a.py", "b.py", "c.py"] {
+            let factors = &engine.database.file_metrics[&pkg.join(name)].risk_assessment.risk_factors;
+            assert!(factors.iter().any(|factor| factor.factor_type == "Import Cycle"
+                && factor.description.contains("pkg/a.py → pkg/b.py → pkg/c.py → pkg/a.py")), "{:?}", factors);
+        }
+        // d.py imports into the cycle but isn't part of it
+        assert!(engine.database.file_metrics[&pkg.join("d.py")].risk_assessment.risk_factors.iter()
+            .all(|factor| factor.factor_type != "Import Cycle"));
+
+        let text = format_project_report(&report);
+        assert!(text.contains("🔁 Import Cycles:"), "{}", text);
+        assert!(text.contains("a.py → "), "{}", text);
+        Ok(())
+    }
+}
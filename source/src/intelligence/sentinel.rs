@@ -5,7 +5,17 @@ use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc};
 use anyhow::Result;
 
-use super::CodeMetrics;
+use super::{CodeMetrics, ComplexityPoint};
+
+/// Anomaly score at which a file is flagged
+pub const ANOMALY_THRESHOLD: f64 = 0.5;
+
+/// Smallest rise in cyclomatic complexity that counts as a spike, so a
+/// trivial file growing one branch isn't flagged
+const MIN_COMPLEXITY_SPIKE: f64 = 5.0;
+
+/// Files smaller than this are measured for churn as if they had this many lines
+const MIN_CHURN_BASE: f64 = 50.0;
 
 /// The Matrix Sentinel - Adaptive AI that scales intelligence based on user skill
 #[derive(Debug, Clone)]
@@ -386,6 +396,30 @@ impl SentinelAI {
         })
     }
 
+    /// How unusual a file's current metrics are against its earlier snapshots,
+    /// from 0.0 (in line with its history) to 1.0. A complexity spike over the
+    /// historical average counts most; a sudden change in size counts too.
+    /// Files without history score 0.0.
+    pub fn anomaly_score(&self, history: &[ComplexityPoint], metrics: &CodeMetrics) -> f64 {
+        let Some(last) = history.last() else {
+            return 0.0;
+        };
+
+        let average = history.iter().map(|point| point.cyclomatic as f64).sum::<f64>() / history.len() as f64;
+        let current = metrics.cyclomatic_complexity as f64;
+        let spike = if current - average >= MIN_COMPLEXITY_SPIKE {
+            // Three times the usual complexity is as anomalous as it gets
+            ((current / average.max(1.0) - 1.0) / 2.0).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let changed_lines = (metrics.lines_of_code as f64 - last.lines_of_code as f64).abs();
+        let churn = (changed_lines / (last.lines_of_code as f64).max(MIN_CHURN_BASE)).min(1.0);
+
+        spike.max(churn * 0.6)
+    }
+
     /// Analyze code with adaptive intelligence based on user skill level
     pub fn analyze_with_adaptation(
         &mut self,
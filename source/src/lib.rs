@@ -22,6 +22,8 @@ pub use crate::tools::{
     VerifiedTool,
     PolicyEnforcer,
 };
+pub use crate::validators::{validate_source, validate_files_with_progress, ProgressEvent, ProgressSummary, ValidationOptions, ValidationResult, ValidatorRegistry};
+pub use crate::detectors::FileType;
 
 // Module declarations
 pub mod tools;
@@ -33,6 +35,11 @@ pub mod daemon;
 pub mod performance;
 pub mod tui;
 pub mod plugin;
+pub mod intelligence;
+pub mod watch;
+pub mod diff;
+pub mod glyphs;
+pub mod logging;
 
 // Private modules
 mod banner;
@@ -43,6 +50,10 @@ pub type Result<T> = std::result::Result<T, anyhow::Error>;
 /// Version information
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const AUTHORS: &str = env!("CARGO_PKG_AUTHORS");
+/// Commit the binary was built from, when built from a git checkout
+pub const GIT_SHA: Option<&str> = option_env!("SYNX_GIT_SHA");
+/// Day the binary was built (YYYY-MM-DD)
+pub const BUILD_DATE: &str = env!("SYNX_BUILD_DATE");
 
 /// Configuration for the validation system
 #[derive(Debug, Clone)]
@@ -57,6 +68,91 @@ pub struct ValidationConfig {
     pub watch: bool,
     /// Security policy configuration
     pub security: SecurityConfig,
+    /// Validators registered in code, checked before the tool-based ones
+    pub validators: validators::ValidatorRegistry,
+}
+
+/// Read newline-separated file paths from `source`, or from stdin when it is `-`.
+/// Blank lines and lines starting with `#` are ignored.
+pub fn read_file_list(source: &str) -> Result<Vec<String>> {
+    use std::io::{BufRead, BufReader};
+    use anyhow::Context;
+    
+    let reader: Box<dyn BufRead> = if source == "-" {
+        Box::new(BufReader::new(std::io::stdin()))
+    } else {
+        let file = std::fs::File::open(source)
+            .context(format!("Failed to open file list {}", source))?;
+        Box::new(BufReader::new(file))
+    };
+    
+    let mut files = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let path = line.trim();
+        if !path.is_empty() && !path.starts_with('#') {
+            files.push(path.to_string());
+        }
+    }
+    Ok(files)
+}
+
+/// Combine file lists, keeping the first occurrence of each path
+pub fn merge_file_lists(lists: &[&[String]]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    lists.iter()
+        .flat_map(|list| list.iter())
+        .filter(|file| seen.insert(file.as_str()))
+        .cloned()
+        .collect()
+}
+
+// Options for validating individual files with the built-in validators
+fn validation_options(config: &config::Config) -> validators::ValidationOptions {
+    validators::ValidationOptions {
+        strict: config.strict,
+        verbose: config.verbose,
+        timeout: config.timeout,
+        config: Some(validators::FileValidationConfig::from_config(config)),
+        analyze_memory: false,
+        max_file_size: None,
+        allow_binary: config.allow_binary,
+        modified_since: config.modified_since,
+        max_depth: None,
+        profiler: None,
+        incremental: false,
+        follow_symlinks: false,
+        offline: config.offline,
+        registry: validators::ValidatorRegistry::default(),
+    }
+}
+
+/// Validate content read from stdin as `file_type`, printing the verdict
+/// like `run` does for a single file
+pub fn run_stdin(file_type: FileType, config: &config::Config) -> Result<bool> {
+    use std::io::Read;
+    
+    let mut content = String::new();
+    std::io::stdin().read_to_string(&mut content)?;
+    
+    let summary_only = config.summary_only;
+    let result = validators::validate_source(&content, file_type, &validation_options(config))?;
+    if result.success {
+        if !summary_only {
+            println!("{} <stdin>: Validation passed", glyphs::OK);
+        }
+    } else {
+        if summary_only {
+            eprintln!("{} <stdin>: Validation failed", glyphs::FAIL);
+        } else {
+            println!("{} <stdin>: Validation failed", glyphs::FAIL);
+        }
+        // Verbose validators have shown their issues already
+        if !config.verbose {
+            let _ = validators::display_validation_errors(&result.errors);
+        }
+    }
+    Ok(result.success)
 }
 
 /// Main entry point for running validation on files
@@ -69,9 +165,27 @@ pub fn run(files: &[String], config: &config::Config) -> Result<bool> {
         return Err(anyhow::anyhow!("No files specified for validation"));
     }
     
+    // With --since, files nobody touched recently are left alone
+    let recent: Vec<String>;
+    let files = match config.modified_since {
+        Some(since) => {
+            recent = files.iter()
+                .filter(|file| validators::modified_since(Path::new(file), since))
+                .cloned()
+                .collect();
+            if config.verbose && recent.len() < files.len() {
+                println!("Skipping {} file(s) not modified within the --since window", files.len() - recent.len());
+            }
+            &recent[..]
+        }
+        None => files,
+    };
+    
     let start_time = Instant::now();
     let mut overall_success = true;
     let total_files = files.len();
+    // Summary mode prints a single stdout line at the end; failures still go to stderr
+    let summary_only = config.summary_only;
     
     // Calculate total file sizes for better progress indication
     let total_size: u64 = files.iter()
@@ -80,21 +194,16 @@ pub fn run(files: &[String], config: &config::Config) -> Result<bool> {
         .sum();
     
     // Create validation options for built-in validators
-    let validation_options = validators::ValidationOptions {
-        strict: config.strict,
-        verbose: config.verbose,
-        timeout: 30, // 30 second timeout
-        config: Some(validators::FileValidationConfig::default()),
-    };
+    let validation_options = validation_options(config);
     
     // Create enhanced progress bar for multiple files
-    let progress = if total_files > 1 {
+    let progress = if total_files > 1 && !summary_only {
         let pb = ProgressBar::new(total_files as u64);
         pb.set_style(
             ProgressStyle::default_bar()
-                .template("🔍 [{elapsed_precise}] {bar:40.cyan/blue} {pos:>3}/{len:3} [{eta_precise}] {msg}")
+                .template(&format!("{} [{{elapsed_precise}}] {{bar:40.cyan/blue}} {{pos:>3}}/{{len:3}} [{{eta_precise}}] {{msg}}", glyphs::SCAN))
                 .unwrap()
-                .progress_chars("█▇▆▅▄▃▂▁  ")
+                .progress_chars(glyphs::progress_chars())
         );
         pb.set_message(format!("Validating {} files ({} total)", 
             total_files, 
@@ -107,69 +216,101 @@ pub fn run(files: &[String], config: &config::Config) -> Result<bool> {
     
     let mut valid_count = 0;
     let mut invalid_count = 0;
+    // Files whose validation couldn't run, with the reason; listed together at the end
+    let mut errored: Vec<(String, String)> = Vec::new();
+    // Set when --fail-fast should stop the run, which ends the file list early
+    let stopped = std::cell::Cell::new(false);
+    let per_file = !summary_only && (config.verbose || total_files == 1);
     
-    for (_index, file_path) in files.iter().enumerate() {
-        let path = Path::new(file_path);
-        
-        // Update progress bar message
-        if let Some(ref pb) = progress {
-            pb.set_message(format!("Validating: {}", 
-                path.file_name().unwrap_or_default().to_string_lossy()));
-        }
-        
-        if !path.exists() {
-            eprintln!("❌ File not found: {}", file_path);
-            overall_success = false;
-            invalid_count += 1;
-            if let Some(ref pb) = progress {
-                pb.inc(1);
+    let summary = validators::validate_files_with_progress(
+        files.iter().map(Path::new).take_while(|_| !stopped.get()),
+        &validation_options,
+        |event| match event {
+            validators::ProgressEvent::Started { path } => {
+                if let Some(ref pb) = progress {
+                    pb.set_message(format!("Validating: {}", 
+                        path.file_name().unwrap_or_default().to_string_lossy()));
+                }
+                if per_file && path.exists() {
+                    println!("{} Validating: {}", glyphs::SCAN, path.display());
+                }
             }
-            continue;
-        }
-        
-        if config.verbose || total_files == 1 {
-            println!("🔍 Validating: {}", file_path);
-        }
-        
-        // Use built-in validators instead of external tools
-        match validators::validate_file(path, &validation_options) {
-            Ok(success) => {
-                if success {
-                    valid_count += 1;
-                    if config.verbose || total_files == 1 {
-                        println!("✅ {}: Validation passed", file_path);
+            validators::ProgressEvent::Finished { path, result } => {
+                let file_path = path.display();
+                match result {
+                    Ok(result) if result.success => {
+                        valid_count += 1;
+                        if per_file {
+                            println!("{} {}: Validation passed", glyphs::OK, file_path);
+                        }
+                    }
+                    Ok(_) => {
+                        invalid_count += 1;
+                        if summary_only {
+                            eprintln!("{} {}: Validation failed", glyphs::FAIL, file_path);
+                        } else {
+                            println!("{} {}: Validation failed", glyphs::FAIL, file_path);
+                        }
+                        overall_success = false;
+                    }
+                    Err(_) if !path.exists() => {
+                        eprintln!("{} File not found: {}", glyphs::FAIL, file_path);
+                        invalid_count += 1;
+                        errored.push((file_path.to_string(), "file not found".to_string()));
+                        overall_success = false;
+                    }
+                    Err(e) if validators::progress::is_skip(&e) => {
+                        eprintln!("{} {}", glyphs::WARN, e);
+                    }
+                    Err(e) => {
+                        invalid_count += 1;
+                        eprintln!("{} {}: Error during validation: {}", glyphs::FAIL, file_path, e);
+                        errored.push((file_path.to_string(), e.to_string()));
+                        overall_success = false;
                     }
-                } else {
-                    invalid_count += 1;
-                    println!("❌ {}: Validation failed", file_path);
-                    overall_success = false;
+                }
+                
+                // Update progress bar
+                if let Some(ref pb) = progress {
+                    pb.inc(1);
+                    if invalid_count > 0 {
+                        pb.set_message(format!("{} {} passed, {} {} failed", glyphs::OK, valid_count, glyphs::FAIL, invalid_count));
+                    } else {
+                        pb.set_message(format!("{} {} passed", glyphs::OK, valid_count));
+                    }
+                }
+                
+                if config.fail_fast && !overall_success {
+                    stopped.set(true);
                 }
             }
-            Err(e) => {
-                invalid_count += 1;
-                eprintln!("❌ {}: Error during validation: {}", file_path, e);
-                overall_success = false;
-            }
-        }
-        
-        // Update progress bar
-        if let Some(ref pb) = progress {
-            pb.inc(1);
-            if invalid_count > 0 {
-                pb.set_message(format!("✅ {} passed, ❌ {} failed", valid_count, invalid_count));
-            } else {
-                pb.set_message(format!("✅ {} passed", valid_count));
-            }
+            validators::ProgressEvent::Done { .. } => {}
+        },
+    );
+    // Files left unchecked because --fail-fast stopped the run
+    let skipped = total_files - summary.files;
+    
+    if skipped > 0 {
+        eprintln!("{} Stopped at the first failure (--fail-fast); {} file(s) not checked", glyphs::STOP, skipped);
+    }
+    if !errored.is_empty() {
+        eprintln!("\n{} {} file(s) could not be validated:", glyphs::FAIL, errored.len());
+        for (file_path, error) in &errored {
+            eprintln!("  {}: {}", file_path, error);
         }
     }
     
     // Finish progress bar with final summary
     let elapsed = start_time.elapsed();
+    if summary_only {
+        println!("{} passed, {} failed in {:.2}s", valid_count, invalid_count, elapsed.as_secs_f64());
+    }
     if let Some(pb) = progress {
         pb.finish_with_message(format!(
-            "Completed: ✅ {} passed, {} {} total ({:.2}s)", 
+            "Completed: {} {} passed, {} {} total ({:.2}s)",
+            glyphs::OK, 
             valid_count,
-            if invalid_count > 0 { format!("❌ {} failed,", invalid_count) } else { "".to_string() },
+            if invalid_count > 0 { format!("{} {} failed,", glyphs::FAIL, invalid_count) } else { "".to_string() },
             total_files,
             elapsed.as_secs_f64()
         ));
@@ -178,16 +319,17 @@ pub fn run(files: &[String], config: &config::Config) -> Result<bool> {
         let files_per_sec = total_files as f64 / elapsed.as_secs_f64();
         let bytes_per_sec = total_size as f64 / elapsed.as_secs_f64();
         
-        println!("\n📊 Performance: {:.1} files/sec, {}/sec", 
+        println!("\n{} Performance: {:.1} files/sec, {}/sec",
+            glyphs::STATS, 
             files_per_sec, 
             format_file_size(bytes_per_sec as u64)
         );
         
         // Print final summary
         if overall_success {
-            println!("✅ All validations passed successfully!");
+            println!("{} All validations passed successfully!", glyphs::OK);
         } else {
-            println!("❌ Some validations failed!");
+            println!("{} Some validations failed!", glyphs::FAIL);
         }
     }
     
@@ -220,6 +362,7 @@ impl Default for ValidationConfig {
                 allowed_dirs: vec![],
                 strict_security: false,
             },
+            validators: validators::ValidatorRegistry::default(),
         }
     }
 }
@@ -245,6 +388,12 @@ impl Validator {
         })
     }
 
+    /// Validate files of `file_type` (an extension such as "proto") with
+    /// `validator` instead of an external tool
+    pub fn register(&mut self, file_type: &str, validator: Box<validators::ValidatorFn>) {
+        self.config.validators.register(file_type, validator);
+    }
+
     /// Validate a file
     pub fn validate_file(&mut self, path: &std::path::Path) -> Result<bool> {
         // Create secure path
@@ -262,6 +411,15 @@ impl Validator {
         // Determine file type and get appropriate validator
         let file_type = get_file_type(path)?;
         
+        if let Some(registered) = self.config.validators.get(&file_type) {
+            let options = ValidationOptions {
+                strict: self.config.strict,
+                verbose: self.config.verbose,
+                ..Default::default()
+            };
+            return Ok(registered(path, &options)?.success);
+        }
+        
         // Execute validator
         let args = if self.config.strict {
             vec!["--strict".to_string()]
@@ -0,0 +1,56 @@
+//! Log output
+//!
+//! Logs go to stderr through `env_logger`, so `RUST_LOG` picks what gets
+//! logged and stdout is left to validation results. `--log-format json`
+//! writes each record as one JSON object per line for log aggregators;
+//! the default is env_logger's usual human format.
+
+use std::io::Write;
+use anyhow::{Result, anyhow};
+
+/// Install the global logger in the named format
+pub fn init(format: &str) -> Result<()> {
+    let mut builder = env_logger::Builder::from_env(env_logger::Env::default());
+    match format {
+        "text" => {}
+        "json" => {
+            builder.format(|buf, record| writeln!(buf, "{}", json_line(record)));
+        }
+        other => return Err(anyhow!("Invalid log format '{}' (expected \"text\" or \"json\")", other)),
+    }
+    builder.try_init()?;
+    Ok(())
+}
+
+/// A record as a JSON object with timestamp, level, module and message
+pub fn json_line(record: &log::Record) -> String {
+    serde_json::json!({
+        "timestamp": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        "level": record.level().as_str(),
+        "module": record.module_path().unwrap_or_else(|| record.target()),
+        "message": record.args().to_string(),
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_line_fields() {
+        let line = json_line(&log::Record::builder()
+            .args(format_args!("loaded \"{}\"", "synx.toml"))
+            .level(log::Level::Warn)
+            .module_path(Some("synx::config"))
+            .target("synx::config")
+            .build());
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["level"], "WARN");
+        assert_eq!(value["module"], "synx::config");
+        assert_eq!(value["message"], "loaded \"synx.toml\"");
+        assert!(value["timestamp"].as_str().unwrap().ends_with('Z'));
+        assert!(!line.contains('\n'));
+    }
+}
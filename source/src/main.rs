@@ -1,8 +1,9 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use std::process;
 
 mod banner;
-mod intelligence;
+
+use synx::intelligence;
 
 /// CLI arguments
 #[derive(Parser)]
@@ -14,10 +15,26 @@ struct Args {
     /// Files to validate (when no subcommand is used)
     files: Vec<String>,
 
+    /// Also validate the newline-separated paths listed in this file ("-" for stdin)
+    #[arg(long, value_name = "PATH")]
+    files_from: Option<String>,
+
     /// Watch files for changes and revalidate
     #[arg(short = 'w', long)]
     watch: bool,
 
+    /// Shell command to run after a watched file passes validation ({file} is the changed path)
+    #[arg(long, value_name = "CMD", requires = "watch")]
+    on_success: Option<String>,
+
+    /// Shell command to run after a watched file fails validation ({file} is the changed path)
+    #[arg(long, value_name = "CMD", requires = "watch")]
+    on_failure: Option<String>,
+
+    /// Start watching right away instead of validating every file first
+    #[arg(long, requires = "watch")]
+    no_initial_scan: bool,
+
     /// Show verbose output
     #[arg(short = 'v', long)]
     verbose: bool,
@@ -30,10 +47,22 @@ struct Args {
     #[arg(long, default_value_t = 2)]
     interval: u64,
 
+    /// Validation timeout in seconds (per-file-type `timeout_secs` settings take precedence)
+    #[arg(short = 't', long)]
+    timeout: Option<u64>,
+
     /// Initialize default configuration file
     #[arg(long)]
     init_config: bool,
 
+    /// With --init-config, replace an existing configuration file
+    #[arg(long, requires = "init_config")]
+    force: bool,
+
+    /// With --init-config, the starting point for the configuration
+    #[arg(long, requires = "init_config", value_parser = ["minimal", "strict", "ci"], default_value = "minimal")]
+    template: String,
+
     /// Strict mode - treat warnings as errors
     #[arg(short = 's', long)]
     strict: bool,
@@ -41,6 +70,70 @@ struct Args {
     /// Show detailed error information with code context
     #[arg(long)]
     show_errors: bool,
+
+    /// Strip byte order marks and normalize line endings before validating
+    #[arg(long, global = true)]
+    fix_encoding: bool,
+
+    /// With --fix-encoding, print the changes as a diff instead of writing them (exits 1 if any file would change)
+    #[arg(long, requires = "fix_encoding")]
+    dry_run: bool,
+
+    /// Fail files with functions over the [complexity] budget
+    #[arg(long, global = true)]
+    check_complexity: bool,
+
+    /// Validate files that look binary instead of skipping them
+    #[arg(long, global = true)]
+    allow_binary: bool,
+
+    /// Fail files that break the .editorconfig or [hygiene] whitespace rules
+    #[arg(long, global = true)]
+    check_hygiene: bool,
+
+    /// Validate fenced code blocks in Markdown files with their language's validator
+    #[arg(long, global = true)]
+    check_code_blocks: bool,
+
+    /// Fail files that contain likely secrets (AWS keys, private keys, high-entropy strings, [secrets] patterns)
+    #[arg(long, global = true)]
+    scan_secrets: bool,
+
+    /// Keep validator tools off the network (no package restores or downloads)
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Only validate files modified within this window (e.g. 30m, 2h, 1d)
+    #[arg(long, global = true, value_name = "DURATION")]
+    since: Option<String>,
+
+    /// Report at most this many issues per file; the rest are counted, not listed
+    #[arg(long, global = true, value_name = "N")]
+    max_issues_per_file: Option<usize>,
+
+    /// Skip files of these languages instead of validating them (e.g. --disable css,yaml)
+    #[arg(long, global = true, value_name = "LANGS", value_delimiter = ',')]
+    disable: Vec<String>,
+
+    /// Only print a one-line summary (failing files are still listed on stderr)
+    #[arg(long)]
+    summary: bool,
+
+    /// Stop at the first file that fails validation or can't be validated
+    #[arg(long)]
+    fail_fast: bool,
+
+    /// Validate the files, or stdin given as "-", as this language instead of detecting it (same names as --only)
+    #[arg(long, value_name = "LANG", value_parser = parse_language)]
+    lang: Option<synx::FileType>,
+
+    /// Log format on stderr: text, or json for one object per line (levels come from RUST_LOG)
+    #[arg(long, global = true, value_name = "FORMAT", value_parser = ["text", "json"], default_value = "text")]
+    log_format: String,
+}
+
+fn parse_language(name: &str) -> Result<synx::FileType, String> {
+    synx::FileType::from_name(name).ok_or_else(|| format!("unknown language '{}'", name))
 }
 
 #[derive(Subcommand)]
@@ -48,24 +141,133 @@ enum Commands {
     /// Scan directories recursively for code files
     Scan {
         /// Directories to scan
-        #[arg(required = true)]
+        #[arg(required_unless_present = "from_archive")]
         paths: Vec<String>,
         
-        /// Exclude patterns (glob patterns)
+        /// Scan the contents of a .zip, .tar or .tar.gz archive instead of directories
+        #[arg(long, value_name = "ARCHIVE", conflicts_with = "paths")]
+        from_archive: Option<String>,
+        
+        /// Exclude patterns (glob patterns), on top of [scan] exclude
         #[arg(long, short = 'e')]
         exclude: Vec<String>,
         
-        /// Number of parallel workers
-        #[arg(long, short = 'j', default_value_t = 4)]
-        parallel: usize,
+        /// Number of parallel workers (default: [scan] default_parallel, else 4)
+        #[arg(long, short = 'j')]
+        parallel: Option<usize>,
         
-        /// Output format
-        #[arg(long, short = 'f', default_value = "text")]
-        format: String,
+        /// Output format: text, json, ndjson, checkstyle, sarif, gcc (alias compact) (default: [scan] default_format, else text)
+        #[arg(long, short = 'f')]
+        format: Option<String>,
         
-        /// Generate report file
+        /// Write a report file; repeat for several. The format follows the extension (.json, .sarif, .xml for checkstyle, .txt), else --format
         #[arg(long, short = 'r')]
-        report: Option<String>,
+        report: Vec<String>,
+        
+        /// Write one report per scanned file into this directory, plus an index.json
+        #[arg(long, value_name = "DIR")]
+        report_dir: Option<String>,
+        
+        /// Extra analysis passes to run (memory: build and run C/C++/Rust files under a leak checker)
+        #[arg(long, value_parser = ["memory"])]
+        analyze: Vec<String>,
+        
+        /// Only validate these languages (comma-separated, e.g. rust,python)
+        #[arg(long, value_delimiter = ',')]
+        only: Vec<String>,
+        
+        /// Skip these languages (comma-separated, e.g. yaml,json)
+        #[arg(long, value_delimiter = ',')]
+        skip: Vec<String>,
+        
+        /// Skip files larger than this (e.g. 500KB, 5MB)
+        #[arg(long, default_value = "10MB")]
+        max_file_size: String,
+        
+        /// Show paths in output relative to this directory (default: the scanned directory)
+        #[arg(long, value_name = "DIR")]
+        relative_to: Option<String>,
+        
+        /// Only descend this many levels below each scanned directory (1 = top-level files only, 0 = unlimited)
+        #[arg(long, default_value_t = 0)]
+        max_depth: usize,
+        
+        /// Print how long each phase of the scan took, and the slowest files, to stderr
+        #[arg(long)]
+        profile: bool,
+        
+        /// Reuse cached results for files unchanged since the last scan and only re-validate the rest
+        #[arg(long)]
+        incremental: bool,
+        
+        /// Print cache hits and misses, file counts and elapsed time after the scan, to stderr
+        #[arg(long)]
+        stats: bool,
+        
+        /// Descend into symlinked directories (each directory is still scanned once); by default symlinks are skipped
+        #[arg(long)]
+        follow_symlinks: bool,
+        
+        /// Write a manifest of every scanned file, its hash, validator and result
+        #[arg(long, value_name = "FILE")]
+        manifest: Option<String>,
+        
+        /// Sign the manifest with this key (32 bytes, or 64 hex characters)
+        #[arg(long, value_name = "KEY_FILE", requires = "manifest")]
+        sign_key: Option<String>,
+    },
+    /// Check the files listed in a scan manifest against their recorded hashes
+    VerifyManifest {
+        /// Manifest written by `scan --manifest`
+        manifest: String,
+        
+        /// Resolve file paths against this directory instead of the manifest's root
+        #[arg(long, value_name = "DIR")]
+        root: Option<String>,
+        
+        /// Check the manifest's signature with this key
+        #[arg(long, value_name = "KEY_FILE")]
+        key: Option<String>,
+    },
+    /// Check which validator tools are installed
+    Doctor {
+        /// Also run each tool's version command and show what it reports
+        #[arg(long)]
+        versions: bool,
+        
+        /// Output format (text, json)
+        #[arg(long, short = 'f', value_parser = ["text", "json"], default_value = "text")]
+        format: String,
+    },
+    /// Fail if a project's intelligence metrics break the [gate] quality budgets
+    Gate {
+        /// Project directory to analyze
+        path: String,
+    },
+    /// Count lines of code, comments and blank lines per language
+    Loc {
+        /// Directory to count
+        path: String,
+        
+        /// Exclude patterns (glob patterns), on top of [scan] exclude
+        #[arg(long, short = 'e')]
+        exclude: Vec<String>,
+        
+        /// Output format (text, json)
+        #[arg(long, short = 'f', value_parser = ["text", "json"], default_value = "text")]
+        format: String,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to complete for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Show version and build information
+    Version {
+        /// Print it as JSON, for tools that check which synx they have
+        #[arg(long)]
+        json: bool,
     },
     /// Configuration management commands
     Config {
@@ -99,6 +301,12 @@ enum Commands {
         /// Enable automatic validation
         #[arg(long)]
         auto_validate: bool,
+        /// Print the issue counts instead of opening the TUI
+        #[arg(long)]
+        summary: bool,
+        /// Print the fixes applied in the TUI as a diff instead of writing them
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Plugin management commands
     Plugin {
@@ -110,7 +318,14 @@ enum Commands {
 #[derive(Subcommand)]
 enum ConfigAction {
     /// Generate default configuration file
-    Init,
+    Init {
+        /// Replace an existing configuration file
+        #[arg(long)]
+        force: bool,
+        /// Starting point: minimal defaults, strict checking, or CI-friendly output and timeouts
+        #[arg(long, value_parser = ["minimal", "strict", "ci"], default_value = "minimal")]
+        template: String,
+    },
     /// Show current configuration
     Show,
     /// Validate configuration file
@@ -118,6 +333,27 @@ enum ConfigAction {
         /// Path to config file to validate
         path: Option<String>,
     },
+    /// Interactively create a configuration tailored to installed tools
+    Wizard {
+        /// Don't prompt; use detected defaults plus the answers given as flags
+        #[arg(long)]
+        non_interactive: bool,
+        /// Enable strict mode in the generated config
+        #[arg(long)]
+        strict: bool,
+        /// Languages to enable (comma-separated, e.g. rust,python)
+        #[arg(long, value_delimiter = ',')]
+        languages: Vec<String>,
+        /// Validation timeout in seconds
+        #[arg(long)]
+        timeout: Option<u64>,
+        /// Explicit tool path as NAME=PATH (repeatable)
+        #[arg(long = "tool")]
+        tools: Vec<String>,
+        /// Where to write the config (default: ~/.config/synx/config.toml)
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -172,7 +408,17 @@ enum DaemonAction {
     /// Stop the daemon
     Stop,
     /// Get daemon status
-    Status,
+    Status {
+        /// Address the daemon serves its status at (default: status_addr from the daemon config)
+        #[arg(long, value_name = "HOST:PORT")]
+        addr: Option<String>,
+        /// Daemon configuration file
+        #[arg(short = 'c', long)]
+        config: Option<String>,
+        /// Print the status as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Restart the daemon
     Restart,
     /// Install daemon as system service
@@ -186,12 +432,18 @@ enum DaemonAction {
         /// Daemon configuration file
         #[arg(short = 'c', long)]
         config: Option<String>,
+        /// Print the service file and where it would go, without installing anything
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Uninstall daemon system service
     Uninstall {
         /// Service name
         #[arg(long, default_value = "synx-daemon")]
         service_name: String,
+        /// Print the service file that would be removed, without stopping or removing anything
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Generate default daemon configuration
     InitConfig {
@@ -199,6 +451,11 @@ enum DaemonAction {
         #[arg(long, default_value = "synx-daemon.toml")]
         path: String,
     },
+    /// Check a daemon configuration file without starting the daemon
+    ValidateConfig {
+        /// Configuration file path
+        path: String,
+    },
     /// Show daemon statistics
     Stats,
 }
@@ -278,6 +535,9 @@ enum PluginAction {
         /// For format operations, only check (don't modify)
         #[arg(long)]
         check_only: bool,
+        /// For format operations, print the changes as a diff without writing them (exits 1 if any would change)
+        #[arg(long)]
+        dry_run: bool,
         /// Output format (text, json)
         #[arg(long, short = 'f', default_value = "text")]
         format: String,
@@ -285,46 +545,117 @@ enum PluginAction {
 }
 
 fn main() {
-    // Initialize logging
-    env_logger::init();
-
     // Parse command line arguments
     let args = Args::parse();
 
+    // Logs go to stderr, keeping stdout for results
+    if let Err(e) = synx::logging::init(&args.log_format) {
+        eprintln!("{} Failed to set up logging: {}", synx::glyphs::FAIL, e);
+        process::exit(2);
+    }
+
     // Handle init config command
     if args.init_config {
-        match synx::config::Config::generate_default_config() {
-            Ok(path) => {
-                println!("✅ Created default configuration at: {}", path.display());
-                process::exit(0);
-            }
-            Err(e) => {
-                eprintln!("❌ Failed to create config: {}", e);
-                process::exit(1);
-            }
-        }
+        init_config(&args.template, args.force);
+    }
+
+    // Doesn't need the configuration, so a broken one can't stop it
+    if let Some(Commands::Version { json }) = &args.command {
+        handle_version_command(*json);
+        return;
+    }
+    if let Some(Commands::Completions { shell }) = &args.command {
+        clap_complete::generate(*shell, &mut Args::command(), "synx", &mut std::io::stdout());
+        return;
     }
 
     // Create configuration
-    let config = match synx::config::Config::new(
+    let mut config = match synx::config::Config::new(
         Some(args.strict),
         Some(args.verbose),
         Some(args.watch),
         Some(args.interval),
-        None, // timeout - use default
+        args.timeout,
         args.config.as_deref(),
     ) {
         Ok(config) => config,
         Err(e) => {
-            eprintln!("❌ Failed to load configuration: {}", e);
+            eprintln!("{} Failed to load configuration: {}", synx::glyphs::FAIL, e);
             process::exit(2);
         }
     };
+    synx::glyphs::configure(&config.ui);
+    if args.fix_encoding {
+        config.encoding.fix = Some(true);
+    }
+    if args.summary {
+        config.summary_only = true;
+    }
+    if args.fail_fast {
+        config.fail_fast = true;
+    }
+    if args.no_initial_scan {
+        config.skip_initial_scan = true;
+    }
+    if let Some(max_issues) = args.max_issues_per_file {
+        config.max_issues_per_file = max_issues;
+    }
+    for language in &args.disable {
+        if let Err(e) = config.validators.disable(language) {
+            eprintln!("{} {}", synx::glyphs::FAIL, e);
+            process::exit(2);
+        }
+    }
+    if args.check_complexity {
+        config.complexity.enabled = Some(true);
+    }
+    if args.check_hygiene {
+        config.hygiene.enabled = Some(true);
+    }
+    if args.check_code_blocks {
+        config.check_code_blocks = true;
+    }
+    if args.scan_secrets {
+        config.secrets.enabled = Some(true);
+    }
+    if args.offline {
+        config.offline = true;
+    }
+    if args.allow_binary {
+        config.allow_binary = true;
+    }
+    if args.lang.is_some() {
+        config.language = args.lang.clone();
+    }
+    if let Some(since) = &args.since {
+        match synx::validators::parse_since(since) {
+            Ok(time) => config.modified_since = Some(time),
+            Err(e) => {
+                eprintln!("{} Invalid --since: {}", synx::glyphs::FAIL, e);
+                process::exit(2);
+            }
+        }
+    }
 
     // Handle subcommands
     match &args.command {
-        Some(Commands::Scan { paths, exclude, parallel, format, report }) => {
-            handle_scan_command(paths, exclude, *parallel, format, report, &config);
+        Some(Commands::Scan { paths, from_archive, exclude, parallel, format, report, report_dir, analyze, only, skip, max_file_size, relative_to, max_depth, profile, incremental, stats, follow_symlinks, manifest, sign_key }) => {
+            process::exit(handle_scan_command(paths, from_archive, exclude, *parallel, format.as_deref(), report, report_dir, analyze, only, skip, max_file_size, relative_to, *max_depth, *profile, *incremental, *stats, *follow_symlinks, manifest, sign_key, &config));
+        }
+        Some(Commands::VerifyManifest { manifest, root, key }) => {
+            handle_verify_manifest_command(manifest, root, key);
+        }
+        Some(Commands::Doctor { versions, format }) => {
+            handle_doctor_command(*versions, format, &config);
+        }
+        Some(Commands::Loc { path, exclude, format }) => {
+            handle_loc_command(path, exclude, format, &config);
+        }
+        Some(Commands::Gate { path }) => {
+            handle_gate_command(path, &config);
+        }
+        Some(Commands::Version { .. }) | Some(Commands::Completions { .. }) => {
+            // Handled before the configuration is loaded
         }
         Some(Commands::Config { action }) => {
             handle_config_command(action, &config);
@@ -341,8 +672,8 @@ fn main() {
         Some(Commands::Performance { action }) => {
             handle_performance_command(action, &config);
         }
-        Some(Commands::Monitor { paths, auto_validate }) => {
-            handle_monitor_command(paths, *auto_validate, &config);
+        Some(Commands::Monitor { paths, auto_validate, summary, dry_run }) => {
+            handle_monitor_command(paths, *auto_validate, *summary, *dry_run, &config);
         }
         Some(Commands::Plugin { action }) => {
             let rt = tokio::runtime::Runtime::new().unwrap();
@@ -350,25 +681,72 @@ fn main() {
         }
         None => {
             // Legacy mode: validate individual files
+            let listed = match &args.files_from {
+                Some(source) => match synx::read_file_list(source) {
+                    Ok(files) => files,
+                    Err(e) => {
+                        eprintln!("{} {}", synx::glyphs::FAIL, e);
+                        process::exit(2);
+                    }
+                },
+                None => Vec::new(),
+            };
+            let files = synx::merge_file_lists(&[&args.files, &listed]);
+            
+            if files.iter().any(|file| file == "-") {
+                let Some(language) = args.lang.clone() else {
+                    eprintln!("{} Reading from stdin (\"-\") needs --lang to say what the content is", synx::glyphs::FAIL);
+                    process::exit(2);
+                };
+                if files.len() > 1 {
+                    eprintln!("{} Stdin (\"-\") can't be validated together with files", synx::glyphs::FAIL);
+                    process::exit(2);
+                }
+                match synx::run_stdin(language, &config) {
+                    Ok(success) => process::exit(if success { 0 } else { 1 }),
+                    Err(e) => {
+                        eprintln!("\n{} Error: {}", synx::glyphs::FAIL, e);
+                        process::exit(2);
+                    }
+                }
+            }
+            
             if args.verbose {
-                println!("Validating files: {:?}", args.files);
+                println!("Validating files: {:?}", files);
+            }
+            
+            if args.dry_run {
+                process::exit(preview_encoding_fixes(&files));
             }
             
-            match synx::run(&args.files, &config) {
+            if args.watch {
+                let hooks = synx::watch::WatchHooks {
+                    on_success: args.on_success.clone(),
+                    on_failure: args.on_failure.clone(),
+                };
+                let stop = std::sync::atomic::AtomicBool::new(false);
+                if let Err(e) = synx::watch::watch(&files, &config, &hooks, &stop) {
+                    eprintln!("\n{} Error: {}", synx::glyphs::FAIL, e);
+                    process::exit(2);
+                }
+                process::exit(0);
+            }
+            
+            match synx::run(&files, &config) {
                 Ok(true) => {
                     if args.verbose {
-                        println!("\n✅ All validations passed successfully!");
+                        println!("\n{} All validations passed successfully!", synx::glyphs::OK);
                     }
                     process::exit(0);
                 }
                 Ok(false) => {
                     if args.verbose {
-                        println!("\n❌ Some validations failed!");
+                        println!("\n{} Some validations failed!", synx::glyphs::FAIL);
                     }
                     process::exit(1);
                 }
                 Err(e) => {
-                    eprintln!("\n❌ Error: {}", e);
+                    eprintln!("\n{} Error: {}", synx::glyphs::FAIL, e);
                     process::exit(2);
                 }
             }
@@ -376,95 +754,464 @@ fn main() {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_scan_command(
     paths: &[String],
+    from_archive: &Option<String>,
     exclude: &[String], 
-    _parallel: usize,
-    format: &str,
-    report: &Option<String>,
+    parallel: Option<usize>,
+    format: Option<&str>,
+    report: &[String],
+    report_dir: &Option<String>,
+    analyze: &[String],
+    only: &[String],
+    skip: &[String],
+    max_file_size: &str,
+    relative_to: &Option<String>,
+    max_depth: usize,
+    profile: bool,
+    incremental: bool,
+    stats: bool,
+    follow_symlinks: bool,
+    manifest: &Option<String>,
+    sign_key: &Option<String>,
     config: &synx::config::Config,
-) {
+) -> i32 {
+    // Flags win over [scan] defaults; exclude patterns from both apply
+    let parallel = parallel.or(config.scan.default_parallel).unwrap_or(4).max(1);
+    let format = format.or(config.scan.default_format.as_deref()).unwrap_or("text");
+    let exclude: Vec<String> = config.scan.exclude.iter().flatten()
+        .chain(exclude)
+        .cloned()
+        .collect();
+    let exclude = &exclude[..];
+    
+    // Languages chosen in the config apply unless --only overrides them
+    let only = if only.is_empty() { &config.languages[..] } else { only };
+    let language_filter = match synx::validators::LanguageFilter::from_names(only, skip) {
+        Ok(filter) => filter,
+        Err(e) => {
+            eprintln!("{} {}", synx::glyphs::FAIL, e);
+            return 1;
+        }
+    };
+    
+    let max_file_size = match synx::validators::parse_size(max_file_size) {
+        Ok(size) => size,
+        Err(e) => {
+            eprintln!("{} Invalid --max-file-size: {}", synx::glyphs::FAIL, e);
+            return 1;
+        }
+    };
+    
+    // NDJSON output streams one line per file, so nothing else may go to stdout
+    let streaming = format == "ndjson";
+    // gcc-style output is one line per issue; anything else goes to stderr
+    let compact = matches!(format, "gcc" | "compact");
+    
+    // An archive is unpacked to a temporary directory, removed when the scan
+    // returns, which then stands in for the paths
+    let extracted = match from_archive {
+        Some(archive) => match synx::validators::archive::extract_to_temp(std::path::Path::new(archive)) {
+            Ok(dir) => Some(dir),
+            Err(e) => {
+                eprintln!("{} Failed to extract {}: {}", synx::glyphs::FAIL, archive, e);
+                return 2;
+            }
+        },
+        None => None,
+    };
+    let extracted_paths: Vec<String> = extracted.iter()
+        .map(|dir| dir.path().to_string_lossy().to_string())
+        .collect();
+    let paths = if extracted.is_some() { &extracted_paths[..] } else { paths };
+    
+    // Check every path before spending time on any of them
     for path in paths {
-        println!("🔍 Scanning directory: {}", path);
-        
-        let path_buf = std::path::PathBuf::from(path);
+        let path_buf = std::path::Path::new(path);
         if !path_buf.exists() {
-            eprintln!("❌ Path does not exist: {}", path);
-            process::exit(1);
+            eprintln!("{} Path does not exist: {}", synx::glyphs::FAIL, path);
+            return 1;
         }
-        
         if !path_buf.is_dir() {
-            eprintln!("❌ Path is not a directory: {}", path);
-            process::exit(1);
+            eprintln!("{} Path is not a directory: {}", synx::glyphs::FAIL, path);
+            return 1;
+        }
+    }
+    
+    // A bad key should stop the run before the scan, not after it
+    let sign_key = match sign_key.as_deref().map(|path| synx::validators::manifest::load_signing_key(std::path::Path::new(path))) {
+        Some(Ok(key)) => Some(key),
+        Some(Err(e)) => {
+            eprintln!("{} {}", synx::glyphs::FAIL, e);
+            return 1;
         }
+        None => None,
+    };
+    
+    // Create validation options
+    let validation_options = synx::validators::ValidationOptions {
+        strict: config.strict,
+        verbose: config.verbose,
+        timeout: config.timeout,
+        config: Some(synx::validators::FileValidationConfig::from_config(config)),
+        analyze_memory: analyze.iter().any(|kind| kind == "memory"),
+        max_file_size: Some(max_file_size),
+        allow_binary: config.allow_binary,
+        modified_since: config.modified_since,
+        max_depth: if max_depth == 0 { None } else { Some(max_depth) },
+        profiler: profile.then(|| std::sync::Arc::new(synx::performance::Profiler::new())),
+        incremental,
+        follow_symlinks,
+        offline: config.offline,
+        registry: Default::default(),
+    };
+    
+    // Paths in every output are shown relative to this. A single scan root is
+    // the natural base; with several, the current directory keeps them apart.
+    let base = match (relative_to, paths) {
+        (Some(dir), _) => std::path::PathBuf::from(dir),
+        (None, [only]) => std::path::PathBuf::from(only),
+        (None, _) => std::path::PathBuf::from("."),
+    };
+    
+    // Files are validated on a pool of --parallel workers
+    let pool = match rayon::ThreadPoolBuilder::new().num_threads(parallel).build() {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("{} Failed to start {} workers: {}", synx::glyphs::FAIL, parallel, e);
+            return 2;
+        }
+    };
+    
+    let scan_started = std::time::Instant::now();
+    let mut combined = synx::validators::ScanResult::default();
+    // Every validated file, for the per-file reports
+    let mut scanned_files = Vec::new();
+    let mut manifest_builder = manifest.as_ref()
+        .map(|_| synx::validators::ManifestBuilder::new(validation_options.config.as_ref()));
+    for path in paths {
+        if !streaming && !compact {
+            match from_archive {
+                Some(archive) => println!("{} Scanning archive: {}", synx::glyphs::SCAN, archive),
+                None => println!("{} Scanning directory: {}", synx::glyphs::SCAN, path),
+            }
+        }
+        let path_buf = std::path::PathBuf::from(path);
         
-        // Create validation options
-        let validation_options = synx::validators::ValidationOptions {
-            strict: config.strict,
-            verbose: config.verbose,
-            timeout: 30,
-            config: Some(synx::validators::FileValidationConfig::default()),
+        // Stream records to stdout from a separate thread as the scan produces
+        // them, keeping them for the manifest if one was asked for. Listening
+        // also keeps the scan's own progress output off stdout.
+        let collect = manifest_builder.is_some() || report_dir.is_some();
+        let (events, listener) = if streaming || compact || collect {
+            let (sender, receiver) = std::sync::mpsc::channel::<synx::validators::FileScanRecord>();
+            let base = base.clone();
+            let listener = std::thread::spawn(move || {
+                let stdout = std::io::stdout();
+                let mut out = if streaming { Some(stdout.lock()) } else { None };
+                let mut records = Vec::new();
+                for record in receiver {
+                    if let Some(writer) = &mut out {
+                        let mut shown = record.clone();
+                        shown.path = synx::validators::relative_path(&shown.path, &base);
+                        if synx::validators::write_ndjson(writer, &shown).is_err() {
+                            out = None;
+                        }
+                    }
+                    if collect {
+                        records.push(record);
+                    }
+                }
+                records
+            });
+            (Some(sender), Some(listener))
+        } else {
+            (None, None)
         };
         
         // Run the scan
-        match synx::validators::scan_directory(&path_buf, &validation_options, exclude) {
+        let scan_result = pool.install(|| synx::validators::scan_directory_with_events(
+            &path_buf, &validation_options, exclude, &language_filter, events,
+        ));
+        let records = listener
+            .and_then(|listener| listener.join().ok())
+            .unwrap_or_default();
+        
+        match scan_result {
             Ok(result) => {
-                // Display results based on format
-                match format {
-                    "json" => {
-                        let json_output = serde_json::json!({
-                            "total_files": result.total_files,
-                            "valid_files": result.valid_files,
-                            "invalid_files": result.invalid_files.len(),
-                            "results_by_type": result.results_by_type
-                        });
-                        println!("{}", serde_json::to_string_pretty(&json_output).unwrap());
-                    }
-                    _ => {
-                        // Default text output
-                        synx::validators::display_scan_results(&result, &path_buf);
-                    }
-                }
-                
-                // Save report if specified
-                if let Some(report_path) = report {
-                    match save_report(&result, report_path, format) {
-                        Ok(()) => println!("📊 Report saved to: {}", report_path),
-                        Err(e) => eprintln!("❌ Failed to save report: {}", e),
+                if let Some(builder) = &mut manifest_builder {
+                    if let Err(e) = builder.add_scan(&records, &result) {
+                        eprintln!("{} Failed to record {} in the manifest: {}", synx::glyphs::FAIL, path, e);
+                        return 2;
                     }
                 }
-                
-                // Exit with appropriate code
-                if result.invalid_files.is_empty() {
-                    process::exit(0);
-                } else {
-                    process::exit(1);
+                if report_dir.is_some() {
+                    scanned_files.extend(records.iter()
+                        .map(|record| synx::validators::relative_path(&record.path, &base)));
                 }
+                combined.merge(result.relative_to(&base));
             }
             Err(e) => {
-                eprintln!("❌ Scan failed: {}", e);
+                eprintln!("{} Scan of {} failed: {}", synx::glyphs::FAIL, path, e);
+                return 2;
+            }
+        }
+    }
+    let result = combined;
+    let reporting_started = std::time::Instant::now();
+    
+    // Each report file's extension picks its format; other names get the console's
+    let reports: Vec<(&String, &str)> = report.iter()
+        .map(|path| (path, synx::validators::format_for_path(std::path::Path::new(path)).unwrap_or(format)))
+        .collect();
+    
+    // Console and report files share the same reporters
+    let report_options = synx::validators::ReportOptions {
+        title: from_archive.clone().unwrap_or_else(|| paths.join(", ")),
+        color: true,
+        tool_versions: if std::iter::once(format).chain(reports.iter().map(|(_, format)| *format)).any(|format| matches!(format, "json" | "sarif")) {
+            synx::validators::tool_versions(validation_options.config.as_ref()).into_iter()
+                .filter_map(|(tool, version)| version.map(|version| (tool.to_string(), version)))
+                .collect()
+        } else {
+            Default::default()
+        },
+    };
+    
+    // NDJSON records have already been written by the printer thread
+    if !streaming {
+        let console = synx::validators::reporter(format, &report_options)
+            .or_else(|| synx::validators::reporter("text", &report_options))
+            .unwrap();
+        if let Err(e) = console.write(&result, &mut std::io::stdout()) {
+            eprintln!("{} Failed to print results: {}", synx::glyphs::FAIL, e);
+        }
+    }
+    if compact {
+        eprintln!("{} {} of {} files invalid, {} issues",
+            synx::glyphs::STATS, result.invalid_files.len(), result.total_files, result.issues.len());
+    }
+    
+    // Save reports if specified
+    for (report_path, report_format) in &reports {
+        match save_report(&result, report_path, report_format, &report_options) {
+            Ok(()) if streaming || compact => eprintln!("{} Report saved to: {}", synx::glyphs::STATS, report_path),
+            Ok(()) => println!("{} Report saved to: {}", synx::glyphs::STATS, report_path),
+            Err(e) => eprintln!("{} Failed to save report: {}", synx::glyphs::FAIL, e),
+        }
+    }
+    
+    if let Some(dir) = report_dir {
+        scanned_files.sort();
+        scanned_files.dedup();
+        let written = synx::validators::write_report_dir(
+            std::path::Path::new(dir), &result, &scanned_files, format, &report_options,
+        );
+        match written {
+            Ok(_) if streaming || compact => eprintln!("{} Per-file reports saved to: {}", synx::glyphs::FOLDER, dir),
+            Ok(_) => println!("{} Per-file reports saved to: {}", synx::glyphs::FOLDER, dir),
+            Err(e) => eprintln!("{} Failed to save per-file reports: {}", synx::glyphs::FAIL, e),
+        }
+    }
+    
+    if let (Some(manifest_path), Some(builder)) = (manifest, manifest_builder) {
+        let mut scan_manifest = builder.finish(&base);
+        let written = match &sign_key {
+            Some(key) => scan_manifest.sign(key),
+            None => Ok(()),
+        }.and_then(|()| scan_manifest.save(std::path::Path::new(manifest_path)));
+        match written {
+            Ok(()) if streaming || compact => eprintln!("{} Manifest saved to: {}", synx::glyphs::MANIFEST, manifest_path),
+            Ok(()) => println!("{} Manifest saved to: {}", synx::glyphs::MANIFEST, manifest_path),
+            Err(e) => eprintln!("{} Failed to save manifest: {}", synx::glyphs::FAIL, e),
+        }
+    }
+    
+    // On stderr, so it can't get mixed into JSON or NDJSON output
+    if let Some(profiler) = &validation_options.profiler {
+        profiler.record(synx::performance::Phase::Reporting, reporting_started.elapsed());
+        eprintln!("\n{}", profiler.report(10));
+    }
+    if stats {
+        eprintln!("\n{}", synx::validators::ScanStats::new(&result, scan_started.elapsed()));
+    }
+    
+    // Exit with appropriate code
+    if result.invalid_files.is_empty() { 0 } else { 1 }
+}
+
+fn handle_loc_command(path: &str, exclude: &[String], format: &str, config: &synx::config::Config) {
+    let dir = std::path::Path::new(path);
+    if !dir.is_dir() {
+        eprintln!("{} Path is not a directory: {}", synx::glyphs::FAIL, path);
+        process::exit(2);
+    }
+    let exclude: Vec<String> = config.scan.exclude.iter().flatten()
+        .chain(exclude)
+        .cloned()
+        .collect();
+    
+    match intelligence::loc::count_tree(dir, &exclude, config) {
+        Ok(report) if format == "json" => {
+            println!("{}", serde_json::to_string_pretty(&report).unwrap_or_default());
+        }
+        Ok(report) => print!("{}", intelligence::loc::render(&report)),
+        Err(e) => {
+            eprintln!("{} Failed to count lines: {}", synx::glyphs::FAIL, e);
+            process::exit(2);
+        }
+    }
+}
+
+fn handle_gate_command(path: &str, config: &synx::config::Config) {
+    let project_path = std::path::Path::new(path);
+    if !project_path.is_dir() {
+        eprintln!("{} Project path is not a directory: {}", synx::glyphs::FAIL, path);
+        process::exit(2);
+    }
+    
+    let report = match intelligence::IntelligenceEngine::new()
+        .and_then(|mut engine| engine.analyze_project(project_path))
+    {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{} Analysis failed: {}", synx::glyphs::FAIL, e);
+            process::exit(2);
+        }
+    };
+    
+    let checks = intelligence::gate::evaluate(&report, &config.gate);
+    for check in &checks {
+        let mark = if check.passed { synx::glyphs::OK } else { synx::glyphs::FAIL };
+        println!("{} {}", mark, check);
+    }
+    
+    let failed = checks.iter().filter(|check| !check.passed).count();
+    if failed == 0 {
+        println!("\n{} Quality gate passed", synx::glyphs::OK);
+        process::exit(0);
+    }
+    println!("\n{} Quality gate failed: {} of {} thresholds breached", synx::glyphs::FAIL, failed, checks.len());
+    process::exit(1);
+}
+
+fn handle_version_command(json: bool) {
+    if json {
+        let info = serde_json::json!({
+            "version": synx::VERSION,
+            "authors": synx::AUTHORS,
+            "git_sha": synx::GIT_SHA,
+            "build_date": synx::BUILD_DATE,
+        });
+        println!("{}", serde_json::to_string_pretty(&info).unwrap());
+        return;
+    }
+    match synx::GIT_SHA {
+        Some(sha) => println!("synx {} ({}, built {})", synx::VERSION, sha, synx::BUILD_DATE),
+        None => println!("synx {} (built {})", synx::VERSION, synx::BUILD_DATE),
+    }
+}
+
+fn handle_doctor_command(versions: bool, format: &str, config: &synx::config::Config) {
+    let validation_config = synx::validators::FileValidationConfig::from_config(config);
+    let tools: Vec<(&str, Option<std::path::PathBuf>, Option<String>)> = synx::validators::VALIDATOR_TOOLS.iter()
+        .map(|&tool| {
+            let program = validation_config.tools.get(tool).map(String::as_str).unwrap_or(tool);
+            let path = which::which(program).ok();
+            let version = if versions && path.is_some() {
+                synx::validators::tool_version(tool, Some(&validation_config))
+            } else {
+                None
+            };
+            (tool, path, version)
+        })
+        .collect();
+    
+    if format == "json" {
+        let report: Vec<serde_json::Value> = tools.iter()
+            .map(|(tool, path, version)| {
+                let mut entry = serde_json::json!({ "tool": tool, "path": path });
+                if versions {
+                    entry["version"] = serde_json::json!(version);
+                }
+                entry
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        return;
+    }
+    
+    println!("Validator tools:");
+    for (tool, path, version) in &tools {
+        match (path, version) {
+            (Some(_), Some(version)) => println!("  {} {:<12} {}", synx::glyphs::OK, tool, version),
+            (Some(path), None) if versions => println!("  {}  {:<12} {} (version unknown)", synx::glyphs::WARN, tool, path.display()),
+            (Some(path), None) => println!("  {} {:<12} {}", synx::glyphs::OK, tool, path.display()),
+            (None, _) => println!("  {} {:<12} not found", synx::glyphs::FAIL, tool),
+        }
+    }
+}
+
+fn handle_verify_manifest_command(manifest: &str, root: &Option<String>, key: &Option<String>) {
+    let scan_manifest = match synx::validators::ScanManifest::load(std::path::Path::new(manifest)) {
+        Ok(scan_manifest) => scan_manifest,
+        Err(e) => {
+            eprintln!("{} {}", synx::glyphs::FAIL, e);
+            process::exit(2);
+        }
+    };
+    
+    if let Some(key_path) = key {
+        let verified = synx::validators::manifest::load_signing_key(std::path::Path::new(key_path))
+            .and_then(|key| scan_manifest.verify_signature(&key));
+        match verified {
+            Ok(true) => println!("{} Signature is valid", synx::glyphs::OK),
+            Ok(false) => {
+                eprintln!("{} Signature does not match; the manifest was altered or signed with another key", synx::glyphs::FAIL);
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("{} {}", synx::glyphs::FAIL, e);
                 process::exit(2);
             }
         }
     }
+    
+    let drift = scan_manifest.check_drift(root.as_deref().map(std::path::Path::new));
+    if drift.is_empty() {
+        println!("{} All {} files match the manifest", synx::glyphs::OK, scan_manifest.files.len());
+        process::exit(0);
+    }
+    
+    for changed in &drift {
+        println!("  {} {}", synx::glyphs::FAIL, changed);
+    }
+    println!("\n{} of {} files drifted from the manifest", drift.len(), scan_manifest.files.len());
+    process::exit(1);
+}
+
+fn init_config(template: &str, force: bool) -> ! {
+    let created = synx::config::ConfigTemplate::from_name(template)
+        .and_then(|template| synx::config::Config::generate_default_config(template, force));
+    match created {
+        Ok(path) => {
+            println!("{} Created {} configuration at: {}", synx::glyphs::OK, template, path.display());
+            process::exit(0);
+        }
+        Err(e) => {
+            eprintln!("{} Failed to create config: {}", synx::glyphs::FAIL, e);
+            process::exit(1);
+        }
+    }
 }
 
 fn handle_config_command(action: &ConfigAction, config: &synx::config::Config) {
     match action {
-        ConfigAction::Init => {
-            match synx::config::Config::generate_default_config() {
-                Ok(path) => {
-                    println!("✅ Created default configuration at: {}", path.display());
-                    process::exit(0);
-                }
-                Err(e) => {
-                    eprintln!("❌ Failed to create config: {}", e);
-                    process::exit(1);
-                }
-            }
+        ConfigAction::Init { force, template } => {
+            init_config(template, *force);
         }
         ConfigAction::Show => {
-            println!("📝 Current Configuration:");
+            println!("{} Current Configuration:", synx::glyphs::NOTE);
             println!("=======================\n");
             
             println!("General Settings:");
@@ -473,6 +1220,9 @@ fn handle_config_command(action: &ConfigAction, config: &synx::config::Config) {
             println!("  Watch mode: {}", config.watch);
             println!("  Watch interval: {}s", config.watch_interval);
             println!("  Timeout: {}s", config.timeout);
+            if !config.languages.is_empty() {
+                println!("  Languages: {}", config.languages.join(", "));
+            }
             
             println!("\nLoaded Configuration Files:");
             if config.loaded_config_paths.is_empty() {
@@ -483,6 +1233,13 @@ fn handle_config_command(action: &ConfigAction, config: &synx::config::Config) {
                 }
             }
             
+            if !config.tools.is_empty() {
+                println!("\nTool Paths:");
+                for (tool, path) in &config.tools {
+                    println!("  {} -> {}", tool, path);
+                }
+            }
+            
             if !config.file_mappings.is_empty() {
                 println!("\nFile Mappings:");
                 for (name, mapping) in &config.file_mappings {
@@ -499,37 +1256,93 @@ fn handle_config_command(action: &ConfigAction, config: &synx::config::Config) {
                 match synx::config::get_default_config_path() {
                     Ok(path) => path,
                     Err(e) => {
-                        eprintln!("❌ Failed to get default config path: {}", e);
+                        eprintln!("{} Failed to get default config path: {}", synx::glyphs::FAIL, e);
                         process::exit(1);
                     }
                 }
             };
             
             if !config_path.exists() {
-                eprintln!("❌ Configuration file does not exist: {}", config_path.display());
+                eprintln!("{} Configuration file does not exist: {}", synx::glyphs::FAIL, config_path.display());
                 process::exit(1);
             }
             
-            match std::fs::read_to_string(&config_path) {
-                Ok(content) => {
-                    match toml::from_str::<toml::Value>(&content) {
-                        Ok(_) => {
-                            println!("✅ Configuration file is valid: {}", config_path.display());
-                            process::exit(0);
-                        }
-                        Err(e) => {
-                            eprintln!("❌ Invalid configuration file: {}", e);
-                            process::exit(1);
+            match synx::config::validate_config_file(&config_path) {
+                Ok(issues) => {
+                    let mut has_errors = false;
+                    for issue in &issues {
+                        match issue.level {
+                            synx::config::ConfigIssueLevel::Error => {
+                                has_errors = true;
+                                eprintln!("{} {}", synx::glyphs::FAIL, issue);
+                            }
+                            synx::config::ConfigIssueLevel::Warning => {
+                                eprintln!("{}  {}", synx::glyphs::WARN, issue);
+                            }
                         }
                     }
+                    
+                    if has_errors {
+                        eprintln!("{} Invalid configuration file: {}", synx::glyphs::FAIL, config_path.display());
+                        process::exit(1);
+                    }
+                    println!("{} Configuration file is valid: {}", synx::glyphs::OK, config_path.display());
+                    process::exit(0);
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to read configuration file: {}", e);
+                    eprintln!("{} Failed to read configuration file: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
+        ConfigAction::Wizard { non_interactive, strict, languages, timeout, tools, output } => {
+            if let Err(e) = run_config_wizard(*non_interactive, *strict, languages, *timeout, tools, output) {
+                eprintln!("{} {}", synx::glyphs::FAIL, e);
+                process::exit(1);
+            }
+            process::exit(0);
+        }
+    }
+}
+
+fn run_config_wizard(
+    non_interactive: bool,
+    strict: bool,
+    languages: &[String],
+    timeout: Option<u64>,
+    tools: &[String],
+    output: &Option<String>,
+) -> anyhow::Result<()> {
+    use synx::config::wizard;
+
+    // Reject unknown language names before anything is written
+    synx::validators::LanguageFilter::from_names(languages, &[])?;
+
+    let detected = wizard::detect_tools();
+    let mut answers = wizard::default_answers(&detected);
+    answers.strict |= strict;
+    if !languages.is_empty() {
+        answers.languages = languages.to_vec();
     }
+    if let Some(timeout) = timeout {
+        answers.timeout = timeout;
+    }
+    for tool in tools {
+        let (name, path) = wizard::parse_tool_arg(tool)?;
+        answers.tools.insert(name, path);
+    }
+
+    if !non_interactive {
+        answers = wizard::prompt_answers(&detected, &answers)?;
+    }
+
+    let path = match output {
+        Some(path) => std::path::PathBuf::from(path),
+        None => synx::config::get_default_config_path()?,
+    };
+    wizard::write_config(&answers, &path)?;
+    println!("{} Wrote configuration to: {}", synx::glyphs::OK, path.display());
+    Ok(())
 }
 
 fn handle_cache_command(action: &CacheAction) {
@@ -540,7 +1353,7 @@ fn handle_cache_command(action: &CacheAction) {
     
     match action {
         CacheAction::Info => {
-            println!("📁 Cache Information:");
+            println!("{} Cache Information:", synx::glyphs::FOLDER);
             println!("===================\n");
             println!("Cache directory: {}", cache_dir.display());
             println!("Cache file: {}", cache_file.display());
@@ -558,7 +1371,7 @@ fn handle_cache_command(action: &CacheAction) {
         }
         CacheAction::Stats => {
             if !cache_file.exists() {
-                println!("📊 No cache file found. Run a scan to create cache.");
+                println!("{} No cache file found. Run a scan to create cache.", synx::glyphs::STATS);
                 process::exit(0);
             }
             
@@ -566,7 +1379,7 @@ fn handle_cache_command(action: &CacheAction) {
                 Ok(content) => {
                     match serde_json::from_str::<std::collections::HashMap<std::path::PathBuf, serde_json::Value>>(&content) {
                         Ok(cache_data) => {
-                            println!("📊 Cache Statistics:");
+                            println!("{} Cache Statistics:", synx::glyphs::STATS);
                             println!("==================\n");
                             println!("Total cached files: {}", cache_data.len());
                             
@@ -583,13 +1396,13 @@ fn handle_cache_command(action: &CacheAction) {
                             }
                         }
                         Err(e) => {
-                            eprintln!("❌ Failed to parse cache file: {}", e);
+                            eprintln!("{} Failed to parse cache file: {}", synx::glyphs::FAIL, e);
                             process::exit(1);
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to read cache file: {}", e);
+                    eprintln!("{} Failed to read cache file: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
@@ -599,16 +1412,16 @@ fn handle_cache_command(action: &CacheAction) {
             if cache_file.exists() {
                 match std::fs::remove_file(&cache_file) {
                     Ok(()) => {
-                        println!("✅ Cache cleared successfully");
+                        println!("{} Cache cleared successfully", synx::glyphs::OK);
                         process::exit(0);
                     }
                     Err(e) => {
-                        eprintln!("❌ Failed to clear cache: {}", e);
+                        eprintln!("{} Failed to clear cache: {}", synx::glyphs::FAIL, e);
                         process::exit(1);
                     }
                 }
             } else {
-                println!("📋 No cache file found - nothing to clear");
+                println!("{} No cache file found - nothing to clear", synx::glyphs::LIST);
                 process::exit(0);
             }
         }
@@ -618,11 +1431,11 @@ fn handle_cache_command(action: &CacheAction) {
 fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::config::Config) {
     match action {
         IntelligenceAction::Analyze { path, format } => {
-            println!("🧠 Analyzing file: {}", path);
+            println!("{} Analyzing file: {}", synx::glyphs::ANALYZE, path);
             
             let file_path = std::path::PathBuf::from(path);
             if !file_path.exists() {
-                eprintln!("❌ File does not exist: {}", path);
+                eprintln!("{} File does not exist: {}", synx::glyphs::FAIL, path);
                 process::exit(1);
             }
             
@@ -630,7 +1443,7 @@ fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::conf
             let _content = match std::fs::read_to_string(&file_path) {
                 Ok(content) => content,
                 Err(e) => {
-                    eprintln!("❌ Failed to read file: {}", e);
+                    eprintln!("{} Failed to read file: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -639,7 +1452,7 @@ fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::conf
             let mut intelligence = match intelligence::IntelligenceEngine::new() {
                 Ok(engine) => engine,
                 Err(e) => {
-                    eprintln!("❌ Failed to initialize intelligence engine: {}", e);
+                    eprintln!("{} Failed to initialize intelligence engine: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -652,7 +1465,7 @@ fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::conf
                             match serde_json::to_string_pretty(&report) {
                                 Ok(json) => println!("{}", json),
                                 Err(e) => {
-                                    eprintln!("❌ Failed to serialize report: {}", e);
+                                    eprintln!("{} Failed to serialize report: {}", synx::glyphs::FAIL, e);
                                     process::exit(1);
                                 }
                             }
@@ -665,22 +1478,22 @@ fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::conf
                     process::exit(0);
                 }
                 Err(e) => {
-                    eprintln!("❌ Analysis failed: {}", e);
+                    eprintln!("{} Analysis failed: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         IntelligenceAction::Project { path, format, report } => {
-            println!("🧠 Analyzing project: {}", path);
+            println!("{} Analyzing project: {}", synx::glyphs::ANALYZE, path);
             
             let project_path = std::path::PathBuf::from(path);
             if !project_path.exists() {
-                eprintln!("❌ Project path does not exist: {}", path);
+                eprintln!("{} Project path does not exist: {}", synx::glyphs::FAIL, path);
                 process::exit(1);
             }
             
             if !project_path.is_dir() {
-                eprintln!("❌ Project path is not a directory: {}", path);
+                eprintln!("{} Project path is not a directory: {}", synx::glyphs::FAIL, path);
                 process::exit(1);
             }
             
@@ -688,7 +1501,7 @@ fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::conf
             let mut intelligence = match intelligence::IntelligenceEngine::new() {
                 Ok(engine) => engine,
                 Err(e) => {
-                    eprintln!("❌ Failed to initialize intelligence engine: {}", e);
+                    eprintln!("{} Failed to initialize intelligence engine: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -701,7 +1514,7 @@ fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::conf
                             match serde_json::to_string_pretty(&project_report) {
                                 Ok(json) => println!("{}", json),
                                 Err(e) => {
-                                    eprintln!("❌ Failed to serialize report: {}", e);
+                                    eprintln!("{} Failed to serialize report: {}", synx::glyphs::FAIL, e);
                                     process::exit(1);
                                 }
                             }
@@ -716,28 +1529,28 @@ fn handle_intelligence_command(action: &IntelligenceAction, _config: &synx::conf
                     if let Some(report_path) = report {
                         let detailed_report = intelligence::generate_detailed_report(&project_report);
                         match std::fs::write(report_path, detailed_report) {
-                            Ok(()) => println!("📊 Detailed report saved to: {}", report_path),
-                            Err(e) => eprintln!("❌ Failed to save report: {}", e),
+                            Ok(()) => println!("{} Detailed report saved to: {}", synx::glyphs::STATS, report_path),
+                            Err(e) => eprintln!("{} Failed to save report: {}", synx::glyphs::FAIL, e),
                         }
                     }
                     
                     process::exit(0);
                 }
                 Err(e) => {
-                    eprintln!("❌ Project analysis failed: {}", e);
+                    eprintln!("{} Project analysis failed: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         IntelligenceAction::Stats => {
-            println!("🧠 Intelligence Engine Statistics");
+            println!("{} Intelligence Engine Statistics", synx::glyphs::ANALYZE);
             println!("================================\n");
             
             // Create intelligence engine to get stats
             let intelligence = match intelligence::IntelligenceEngine::new() {
                 Ok(engine) => engine,
                 Err(e) => {
-                    eprintln!("❌ Failed to initialize intelligence engine: {}", e);
+                    eprintln!("{} Failed to initialize intelligence engine: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -773,14 +1586,14 @@ async fn handle_daemon_command(action: &DaemonAction, _config: &synx::config::Co
         DaemonAction::Start { watch_paths, config, foreground } => {
             // Show banner for long-running daemon operations
             banner::print_banner();
-            println!("🚀 Starting Synx Daemon");
+            println!("{} Starting Synx Daemon", synx::glyphs::START);
             
             // Load daemon configuration
             let mut daemon_config = if let Some(config_path) = config {
                 match DaemonConfig::from_file(config_path) {
                     Ok(config) => config,
                     Err(e) => {
-                        eprintln!("❌ Failed to load daemon config: {}", e);
+                        eprintln!("{} Failed to load daemon config: {}", synx::glyphs::FAIL, e);
                         process::exit(1);
                     }
                 }
@@ -788,7 +1601,7 @@ async fn handle_daemon_command(action: &DaemonAction, _config: &synx::config::Co
                 match DaemonConfig::load_default() {
                     Ok(config) => config,
                     Err(e) => {
-                        eprintln!("❌ Failed to load default daemon config: {}", e);
+                        eprintln!("{} Failed to load default daemon config: {}", synx::glyphs::FAIL, e);
                         process::exit(1);
                     }
                 }
@@ -806,7 +1619,7 @@ async fn handle_daemon_command(action: &DaemonAction, _config: &synx::config::Co
             let synx_config = match synx::config::Config::new(None, None, None, None, None, None) {
                 Ok(config) => config,
                 Err(e) => {
-                    eprintln!("❌ Failed to load synx config: {}", e);
+                    eprintln!("{} Failed to load synx config: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -815,33 +1628,63 @@ async fn handle_daemon_command(action: &DaemonAction, _config: &synx::config::Co
             let mut daemon = match SynxDaemon::new(daemon_config, synx_config) {
                 Ok(daemon) => daemon,
                 Err(e) => {
-                    eprintln!("❌ Failed to create daemon: {}", e);
+                    eprintln!("{} Failed to create daemon: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
             
             if let Err(e) = daemon.start().await {
-                eprintln!("❌ Daemon failed: {}", e);
+                eprintln!("{} Daemon failed: {}", synx::glyphs::FAIL, e);
                 process::exit(1);
             }
         }
         
         DaemonAction::Stop => {
-            println!("🛑 Stopping Synx Daemon");
+            println!("{} Stopping Synx Daemon", synx::glyphs::HALT);
             // In a real implementation, this would send a signal to the running daemon
             // For now, we'll just show how to stop a service
             let manager = ServiceManager::new("synx-daemon".to_string(), PathBuf::new());
             match manager.stop() {
-                Ok(()) => println!("✅ Daemon stopped successfully"),
+                Ok(()) => println!("{} Daemon stopped successfully", synx::glyphs::OK),
                 Err(e) => {
-                    eprintln!("❌ Failed to stop daemon: {}", e);
+                    eprintln!("{} Failed to stop daemon: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         
-        DaemonAction::Status => {
-            println!("📊 Synx Daemon Status");
+        DaemonAction::Status { addr, config, json } => {
+            // A daemon serving its status can say what it's doing, not just whether it runs
+            let addr = match (addr, config) {
+                (Some(addr), _) => Some(addr.clone()),
+                (None, Some(config_path)) => match DaemonConfig::from_file(config_path) {
+                    Ok(config) => config.status_addr,
+                    Err(e) => {
+                        eprintln!("{} Failed to load daemon config: {}", synx::glyphs::FAIL, e);
+                        process::exit(1);
+                    }
+                },
+                (None, None) => DaemonConfig::load_default().ok().and_then(|config| config.status_addr),
+            };
+            if let Some(addr) = addr {
+                match synx::daemon::status::fetch(&addr).await {
+                    Ok(status) if *json => {
+                        println!("{}", serde_json::to_string_pretty(&status).unwrap_or_default());
+                        process::exit(0);
+                    }
+                    Ok(status) => {
+                        println!("{} Synx Daemon Status\n", synx::glyphs::STATS);
+                        print!("{}", synx::daemon::status::render(&status));
+                        process::exit(0);
+                    }
+                    Err(e) => {
+                        eprintln!("{} {}", synx::glyphs::FAIL, e);
+                        process::exit(1);
+                    }
+                }
+            }
+            
+            println!("{} Synx Daemon Status", synx::glyphs::STATS);
             let manager = ServiceManager::new("synx-daemon".to_string(), PathBuf::new());
             match manager.status() {
                 Ok(status) => {
@@ -849,34 +1692,36 @@ async fn handle_daemon_command(action: &DaemonAction, _config: &synx::config::Co
                     process::exit(0);
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to get daemon status: {}", e);
+                    eprintln!("{} Failed to get daemon status: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         
         DaemonAction::Restart => {
-            println!("🔄 Restarting Synx Daemon");
+            println!("{} Restarting Synx Daemon", synx::glyphs::RESTART);
             let manager = ServiceManager::new("synx-daemon".to_string(), PathBuf::new());
             
             // Stop then start
             if let Err(e) = manager.stop() {
-                eprintln!("⚠️ Warning: Failed to stop daemon: {}", e);
+                eprintln!("{} Warning: Failed to stop daemon: {}", synx::glyphs::WARN, e);
             }
             
             tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
             
             match manager.start() {
-                Ok(()) => println!("✅ Daemon restarted successfully"),
+                Ok(()) => println!("{} Daemon restarted successfully", synx::glyphs::OK),
                 Err(e) => {
-                    eprintln!("❌ Failed to restart daemon: {}", e);
+                    eprintln!("{} Failed to restart daemon: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         
-        DaemonAction::Install { service_name, binary_path, config } => {
-            println!("📦 Installing Synx Daemon as system service");
+        DaemonAction::Install { service_name, binary_path, config, dry_run } => {
+            if !*dry_run {
+                println!("{} Installing Synx Daemon as system service", synx::glyphs::PACKAGE);
+            }
             
             let binary_path = if let Some(path) = binary_path {
                 PathBuf::from(path)
@@ -887,50 +1732,79 @@ async fn handle_daemon_command(action: &DaemonAction, _config: &synx::config::Co
             
             let config_path = config.as_ref().map(|c| std::path::Path::new(c));
             
-            match install_service(service_name, &binary_path, config_path) {
-                Ok(()) => {
-                    println!("✅ Service installed successfully");
+            match install_service(service_name, &binary_path, config_path, *dry_run) {
+                Ok(service) if *dry_run => {
+                    println!("# Would write {}", service.target.display());
+                    print!("{}", service.content);
+                    process::exit(0);
+                }
+                Ok(_) => {
+                    println!("{} Service installed successfully", synx::glyphs::OK);
                     process::exit(0);
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to install service: {}", e);
+                    eprintln!("{} Failed to install service: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         
-        DaemonAction::Uninstall { service_name } => {
-            println!("🗑️ Uninstalling Synx Daemon service");
+        DaemonAction::Uninstall { service_name, dry_run } => {
+            if !*dry_run {
+                println!("{} Uninstalling Synx Daemon service", synx::glyphs::REMOVE);
+            }
             
-            match uninstall_service(service_name) {
-                Ok(()) => {
-                    println!("✅ Service uninstalled successfully");
+            match uninstall_service(service_name, *dry_run) {
+                Ok(service) if *dry_run => {
+                    if service.content.is_empty() {
+                        println!("# {} is not installed; nothing would be removed", service.target.display());
+                    } else {
+                        println!("# Would remove {}", service.target.display());
+                        print!("{}", service.content);
+                    }
+                    process::exit(0);
+                }
+                Ok(_) => {
+                    println!("{} Service uninstalled successfully", synx::glyphs::OK);
                     process::exit(0);
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to uninstall service: {}", e);
+                    eprintln!("{} Failed to uninstall service: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         
         DaemonAction::InitConfig { path } => {
-            println!("⚙️ Generating default daemon configuration");
+            println!("{} Generating default daemon configuration", synx::glyphs::SETTINGS);
             
             match DaemonConfig::generate_default_config(path) {
                 Ok(()) => {
-                    println!("✅ Created default daemon configuration at: {}", path);
+                    println!("{} Created default daemon configuration at: {}", synx::glyphs::OK, path);
+                    process::exit(0);
+                }
+                Err(e) => {
+                    eprintln!("{} Failed to create daemon config: {}", synx::glyphs::FAIL, e);
+                    process::exit(1);
+                }
+            }
+        }
+        
+        DaemonAction::ValidateConfig { path } => {
+            match DaemonConfig::from_file(path) {
+                Ok(_) => {
+                    println!("{} Daemon configuration is valid: {}", synx::glyphs::OK, path);
                     process::exit(0);
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to create daemon config: {}", e);
+                    eprintln!("{} {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
         }
         
         DaemonAction::Stats => {
-            println!("📈 Synx Daemon Statistics");
+            println!("{} Synx Daemon Statistics", synx::glyphs::TREND);
             println!("=========================\n");
             println!("This command would show daemon runtime statistics.");
             println!("Currently not implemented - daemon must be running to provide stats.");
@@ -944,7 +1818,7 @@ fn handle_performance_command(action: &PerformanceAction, _config: &synx::config
     
     match action {
         PerformanceAction::Stats => {
-            println!("⚡ Performance Statistics");
+            println!("{} Performance Statistics", synx::glyphs::SPEED);
             println!("========================\n");
             
             let perf_config = PerformanceConfig::default();
@@ -967,13 +1841,13 @@ fn handle_performance_command(action: &PerformanceAction, _config: &synx::config
                             println!("  Files/Second: {:.2}", stats.validation_metrics.files_per_second);
                         }
                         Err(e) => {
-                            eprintln!("❌ Failed to get performance stats: {}", e);
+                            eprintln!("{} Failed to get performance stats: {}", synx::glyphs::FAIL, e);
                             process::exit(1);
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to create performance engine: {}", e);
+                    eprintln!("{} Failed to create performance engine: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
@@ -982,23 +1856,23 @@ fn handle_performance_command(action: &PerformanceAction, _config: &synx::config
         }
         
         PerformanceAction::Clear => {
-            println!("🧹 Clearing performance caches...");
+            println!("{} Clearing performance caches...", synx::glyphs::CLEAN);
             
             let perf_config = PerformanceConfig::default();
             match PerformanceEngine::new(perf_config) {
                 Ok(mut engine) => {
                     match engine.reset() {
                         Ok(()) => {
-                            println!("✅ Performance caches cleared successfully");
+                            println!("{} Performance caches cleared successfully", synx::glyphs::OK);
                         }
                         Err(e) => {
-                            eprintln!("❌ Failed to clear caches: {}", e);
+                            eprintln!("{} Failed to clear caches: {}", synx::glyphs::FAIL, e);
                             process::exit(1);
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to create performance engine: {}", e);
+                    eprintln!("{} Failed to create performance engine: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
@@ -1007,23 +1881,23 @@ fn handle_performance_command(action: &PerformanceAction, _config: &synx::config
         }
         
         PerformanceAction::Optimize => {
-            println!("⚙️ Optimizing performance caches...");
+            println!("{} Optimizing performance caches...", synx::glyphs::SETTINGS);
             
             let perf_config = PerformanceConfig::default();
             match PerformanceEngine::new(perf_config) {
                 Ok(mut engine) => {
                     match engine.optimize_cache() {
                         Ok(()) => {
-                            println!("✅ Performance caches optimized successfully");
+                            println!("{} Performance caches optimized successfully", synx::glyphs::OK);
                         }
                         Err(e) => {
-                            eprintln!("❌ Failed to optimize caches: {}", e);
+                            eprintln!("{} Failed to optimize caches: {}", synx::glyphs::FAIL, e);
                             process::exit(1);
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to create performance engine: {}", e);
+                    eprintln!("{} Failed to create performance engine: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             }
@@ -1032,133 +1906,124 @@ fn handle_performance_command(action: &PerformanceAction, _config: &synx::config
         }
         
         PerformanceAction::Benchmark { path, iterations } => {
-            println!("🏃 Running performance benchmark on: {}", path);
+            println!("{} Running performance benchmark on: {}", synx::glyphs::RUN, path);
             println!("Iterations: {}\n", iterations);
             
             let path_buf = std::path::PathBuf::from(path);
             if !path_buf.exists() {
-                eprintln!("❌ Path does not exist: {}", path);
+                eprintln!("{} Path does not exist: {}", synx::glyphs::FAIL, path);
                 process::exit(1);
             }
             
             if !path_buf.is_dir() {
-                eprintln!("❌ Path is not a directory: {}", path);
+                eprintln!("{} Path is not a directory: {}", synx::glyphs::FAIL, path);
                 process::exit(1);
             }
             
             let mut total_times = Vec::new();
+            // One profiler across iterations collects every file's timing
+            let profiler = std::sync::Arc::new(synx::performance::Profiler::new());
             let validation_options = synx::validators::ValidationOptions {
-                strict: false,
-                verbose: false,
                 timeout: 30,
                 config: Some(synx::validators::FileValidationConfig::default()),
+                profiler: Some(profiler.clone()),
+                ..Default::default()
             };
             
             for i in 1..=*iterations {
-                println!("🔄 Running iteration {} of {}...", i, iterations);
+                println!("{} Running iteration {} of {}...", synx::glyphs::RESTART, i, iterations);
                 
                 let start = std::time::Instant::now();
-                match synx::validators::scan_directory(&path_buf, &validation_options, &[]) {
+                match synx::validators::scan_directory(&path_buf, &validation_options, &[], &synx::validators::LanguageFilter::default()) {
                     Ok(result) => {
                         let elapsed = start.elapsed();
                         total_times.push(elapsed);
                         
-                        println!("  ✅ Completed in {:.2}s ({} files)", 
+                        println!("  {} Completed in {:.2}s ({} files)",
+                               synx::glyphs::OK, 
                                elapsed.as_secs_f64(), result.total_files);
                     }
                     Err(e) => {
-                        eprintln!("  ❌ Iteration {} failed: {}", i, e);
+                        eprintln!("  {} Iteration {} failed: {}", synx::glyphs::FAIL, i, e);
                         process::exit(1);
                     }
                 }
             }
             
-            // Calculate statistics
-            let total_time: std::time::Duration = total_times.iter().sum();
-            let avg_time = total_time / total_times.len() as u32;
-            let min_time = total_times.iter().min().unwrap();
-            let max_time = total_times.iter().max().unwrap();
+            let report = synx::performance::BenchmarkReport::new(total_times, &profiler.file_timings(), 5);
             
-            println!("\n📊 Benchmark Results:");
+            println!("\n{} Benchmark Results:", synx::glyphs::STATS);
             println!("====================\n");
-            println!("Average Time: {:.2}s", avg_time.as_secs_f64());
-            println!("Minimum Time: {:.2}s", min_time.as_secs_f64());
-            println!("Maximum Time: {:.2}s", max_time.as_secs_f64());
-            println!("Total Time: {:.2}s", total_time.as_secs_f64());
+            print!("{}", report);
             
             process::exit(0);
         }
     }
 }
 
-fn handle_monitor_command(paths: &[String], _auto_validate: bool, _config: &synx::config::Config) {
-    // Show banner for interactive TUI
-    banner::print_banner();
-    println!("🖥️ Starting Interactive TUI Monitor");
-    
+fn handle_monitor_command(paths: &[String], _auto_validate: bool, summary: bool, dry_run: bool, config: &synx::config::Config) {
     // Convert paths to PathBuf
-    let watch_paths: Vec<std::path::PathBuf> = paths.iter().map(|p| std::path::PathBuf::from(p)).collect();
+    let watch_paths: Vec<std::path::PathBuf> = paths.iter().map(std::path::PathBuf::from).collect();
     
     // Validate paths exist
     for path in &watch_paths {
         if !path.exists() {
-            eprintln!("❌ Path does not exist: {}", path.display());
+            eprintln!("{} Path does not exist: {}", synx::glyphs::FAIL, path.display());
             process::exit(1);
         }
     }
     
-    // Create a basic validation report for the TUI
-    let mut file_issues = std::collections::HashMap::new();
+    let options = synx::validators::ValidationOptions {
+        strict: config.strict,
+        verbose: false,
+        timeout: config.timeout,
+        config: Some(synx::validators::FileValidationConfig::from_config(config)),
+        allow_binary: config.allow_binary,
+        offline: config.offline,
+        ..Default::default()
+    };
+    let validation_report = match synx::tui::ValidationReport::from_paths(&watch_paths, &options) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{} Validation failed: {}", synx::glyphs::FAIL, e);
+            process::exit(2);
+        }
+    };
+    let issue_count = validation_report.issue_count();
     
-    // For demonstration, create some sample issues
-    for path in &watch_paths {
-        if path.is_file() {
-            let sample_issue = synx::tui::ValidationIssue {
-                file_path: path.clone(),
-                issue_type: "demo_issue".to_string(),
-                severity: synx::analysis::IssueSeverity::Low,
-                message: "This is a demonstration issue for TUI testing".to_string(),
-                line_start: 1,
-                line_end: 1,
-                suggested_fix: Some("Fix suggestion".to_string()),
-                context: std::collections::HashMap::new(),
-            };
-            file_issues.insert(path.clone(), vec![sample_issue]);
+    if summary {
+        print!("{} issues in {} files", issue_count, validation_report.file_issues.len());
+        if validation_report.hidden_issues > 0 {
+            print!(" ({} more not shown)", validation_report.hidden_issues);
         }
+        println!();
+        process::exit(if issue_count == 0 { 0 } else { 1 });
     }
     
-    if file_issues.is_empty() {
-        println!("⚠️ No files found to monitor. Creating a dummy validation report.");
-        // Create a dummy file issue for testing
-        let dummy_path = std::path::PathBuf::from("dummy_file.txt");
-        let sample_issue = synx::tui::ValidationIssue {
-            file_path: dummy_path.clone(),
-            issue_type: "demo_issue".to_string(),
-            severity: synx::analysis::IssueSeverity::Low,
-            message: "This is a demonstration issue for TUI testing".to_string(),
-            line_start: 1,
-            line_end: 1,
-            suggested_fix: Some("Fix suggestion".to_string()),
-            context: std::collections::HashMap::new(),
-        };
-        file_issues.insert(dummy_path, vec![sample_issue]);
+    // Nothing to step through, so there's no point opening the TUI
+    if issue_count == 0 {
+        println!("{} No issues found", synx::glyphs::OK);
+        process::exit(0);
     }
     
-    let validation_report = synx::tui::ValidationReport {
-        file_issues,
-    };
+    // Show banner for interactive TUI
+    banner::print_banner();
+    println!("{} Starting Interactive TUI Monitor", synx::glyphs::SCREEN);
     
-    // Start the basic TUI
-    match synx::tui::run_interactive_mode(validation_report) {
+    let grammars = config.treesitter.grammars.clone().unwrap_or_default();
+    match synx::tui::run_interactive_mode(validation_report, &grammars, dry_run) {
         Ok(results) => {
-            println!("✅ Interactive TUI exited successfully");
+            for diff in &results.diffs {
+                print!("{}", diff);
+            }
+            println!("{} Interactive TUI exited successfully", synx::glyphs::OK);
             println!("  Fixed issues: {}", results.fixed_issues);
             println!("  Ignored issues: {}", results.ignored_issues);
             println!("  Remaining issues: {}", results.remaining_issues);
             process::exit(0);
         }
         Err(e) => {
-            eprintln!("❌ Interactive TUI failed: {}", e);
+            eprintln!("{} Interactive TUI failed: {}", synx::glyphs::FAIL, e);
             process::exit(1);
         }
     }
@@ -1188,17 +2053,18 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
             allowed_dirs: vec![std::env::current_dir().unwrap()],
             strict_security: false,
         },
+        validators: Default::default(),
     };
 
     match action {
         PluginAction::List { format, enabled_only, category } => {
-            println!("📋 Available Plugins");
+            println!("{} Available Plugins", synx::glyphs::LIST);
             println!("===================\n");
 
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -1252,10 +2118,10 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
                     // Default text format
                     for plugin in &filtered_plugins {
                         let status_icon = match plugin.status {
-                            synx::plugin::PluginStatus::Active => "✅",
-                            synx::plugin::PluginStatus::Disabled => "⏸️",
-                            synx::plugin::PluginStatus::Failed(_) => "❌",
-                            synx::plugin::PluginStatus::Unloaded => "⭕",
+                            synx::plugin::PluginStatus::Active => synx::glyphs::OK,
+                            synx::plugin::PluginStatus::Disabled => synx::glyphs::PAUSED,
+                            synx::plugin::PluginStatus::Failed(_) => synx::glyphs::FAIL,
+                            synx::plugin::PluginStatus::Unloaded => synx::glyphs::UNLOADED,
                         };
                         
                         println!("{} {} ({})", status_icon, plugin.metadata.name, plugin.metadata.id);
@@ -1280,13 +2146,13 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
         }
 
         PluginAction::Status => {
-            println!("🔌 Plugin System Status");
+            println!("{} Plugin System Status", synx::glyphs::PLUGIN);
             println!("======================\n");
 
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -1308,7 +2174,7 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -1318,7 +2184,7 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
             if let Some(id) = plugin_id {
                 // Show stats for specific plugin
                 if let Some(stats) = all_stats.get(id) {
-                    println!("📊 Plugin Statistics: {}", id);
+                    println!("{} Plugin Statistics: {}", synx::glyphs::STATS, id);
                     println!("=============================\n");
                     println!("Total Executions: {}", stats.executions);
                     println!("Successful Executions: {}", stats.successful_executions);
@@ -1330,12 +2196,12 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
                         println!("Last Execution: {:?} ago", last_exec.elapsed());
                     }
                 } else {
-                    eprintln!("❌ No statistics found for plugin: {}", id);
+                    eprintln!("{} No statistics found for plugin: {}", synx::glyphs::FAIL, id);
                     process::exit(1);
                 }
             } else {
                 // Show stats for all plugins
-                println!("📊 Plugin Statistics (All Plugins)");
+                println!("{} Plugin Statistics (All Plugins)", synx::glyphs::STATS);
                 println!("==================================\n");
                 
                 if all_stats.is_empty() {
@@ -1356,22 +2222,22 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
         }
 
         PluginAction::Enable { plugin_id } => {
-            println!("🔌 Enabling plugin: {}", plugin_id);
+            println!("{} Enabling plugin: {}", synx::glyphs::PLUGIN, plugin_id);
 
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
 
             match validator.enable_plugin(plugin_id).await {
                 Ok(()) => {
-                    println!("✅ Plugin '{}' enabled successfully", plugin_id);
+                    println!("{} Plugin '{}' enabled successfully", synx::glyphs::OK, plugin_id);
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to enable plugin '{}': {}", plugin_id, e);
+                    eprintln!("{} Failed to enable plugin '{}': {}", synx::glyphs::FAIL, plugin_id, e);
                     validator.shutdown().await.unwrap();
                     process::exit(1);
                 }
@@ -1387,17 +2253,17 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
 
             match validator.disable_plugin(plugin_id).await {
                 Ok(()) => {
-                    println!("✅ Plugin '{}' disabled successfully", plugin_id);
+                    println!("{} Plugin '{}' disabled successfully", synx::glyphs::OK, plugin_id);
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to disable plugin '{}': {}", plugin_id, e);
+                    eprintln!("{} Failed to disable plugin '{}': {}", synx::glyphs::FAIL, plugin_id, e);
                     validator.shutdown().await.unwrap();
                     process::exit(1);
                 }
@@ -1408,13 +2274,13 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
         }
 
         PluginAction::Schema { plugin_id } => {
-            println!("📋 Plugin Configuration Schema: {}", plugin_id);
+            println!("{} Plugin Configuration Schema: {}", synx::glyphs::LIST, plugin_id);
             println!("=====================================\n");
             
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -1429,12 +2295,12 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
         }
 
         PluginAction::Configure { plugin_id, config: config_json } => {
-            println!("⚙️ Configuring plugin: {}", plugin_id);
+            println!("{} Configuring plugin: {}", synx::glyphs::SETTINGS, plugin_id);
 
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -1443,7 +2309,7 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
             let config_value: serde_json::Value = match serde_json::from_str(config_json) {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Invalid JSON configuration: {}", e);
+                    eprintln!("{} Invalid JSON configuration: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
@@ -1458,14 +2324,14 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
             process::exit(0);
         }
 
-        PluginAction::Test { file, plugin_id, operation, check_only, format } => {
+        PluginAction::Test { file, plugin_id, operation, check_only, dry_run, format } => {
             let file_path = PathBuf::from(file);
             if !file_path.exists() {
-                eprintln!("❌ File does not exist: {}", file);
+                eprintln!("{} File does not exist: {}", synx::glyphs::FAIL, file);
                 process::exit(1);
             }
 
-            println!("🧪 Testing file with plugins: {}", file);
+            println!("{} Testing file with plugins: {}", synx::glyphs::TEST, file);
             println!("Operation: {}", operation);
             if let Some(id) = plugin_id {
                 println!("Using plugin: {}", id);
@@ -1477,22 +2343,37 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
             let validator = match create_plugin_validator(validation_config, logger).await {
                 Ok(v) => v,
                 Err(e) => {
-                    eprintln!("❌ Failed to create plugin validator: {}", e);
+                    eprintln!("{} Failed to create plugin validator: {}", synx::glyphs::FAIL, e);
                     process::exit(1);
                 }
             };
 
             let result = match operation.as_str() {
                 "validate" => validator.validate_file(&file_path).await,
-                "format" => validator.format_file(&file_path, *check_only).await,
+                "format" => validator.format_file(&file_path, *check_only || *dry_run).await,
                 "analyze" => validator.analyze_file(&file_path).await,
                 _ => {
-                    eprintln!("❌ Unknown operation: {}", operation);
+                    eprintln!("{} Unknown operation: {}", synx::glyphs::FAIL, operation);
                     process::exit(1);
                 }
             };
 
             match result {
+                Ok(summary) if *dry_run && operation == "format" => {
+                    let diffs: Vec<&str> = summary.plugin_results.values()
+                        .filter_map(|result| result.data.as_ref())
+                        .filter_map(|data| data.get("diff").and_then(|diff| diff.as_str()))
+                        .filter(|diff| !diff.is_empty())
+                        .collect();
+                    for diff in &diffs {
+                        print!("{}", diff);
+                    }
+                    if diffs.is_empty() {
+                        println!("{} {} is already formatted", synx::glyphs::OK, file_path.display());
+                        process::exit(0);
+                    }
+                    process::exit(1);
+                }
                 Ok(summary) => {
                     match format.as_str() {
                         "json" => {
@@ -1509,46 +2390,46 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
                         }
                         _ => {
                             // Default text format
-                            println!("📄 File: {}", summary.file_path.display());
-                            println!("✅ Success: {}", summary.success);
-                            println!("📊 Summary: {}", summary.summary_message());
+                            println!("{} File: {}", synx::glyphs::FILE, summary.file_path.display());
+                            println!("{} Success: {}", synx::glyphs::OK, summary.success);
+                            println!("{} Summary: {}", synx::glyphs::STATS, summary.summary_message());
                             
                             if !summary.plugin_results.is_empty() {
-                                println!("\n🔌 Plugin Results:");
+                                println!("\n{} Plugin Results:", synx::glyphs::PLUGIN);
                                 for (plugin_id, result) in &summary.plugin_results {
-                                    let status = if result.success { "✅" } else { "❌" };
+                                    let status = if result.success { synx::glyphs::OK } else { synx::glyphs::FAIL };
                                     println!("  {} {}: {}", status, plugin_id, result.message);
                                     
                                     if !result.warnings.is_empty() {
                                         for warning in &result.warnings {
-                                            println!("    ⚠️ {}", warning);
+                                            println!("    {} {}", synx::glyphs::WARN, warning);
                                         }
                                     }
                                     
                                     if !result.errors.is_empty() {
                                         for error in &result.errors {
-                                            println!("    ❌ {}", error);
+                                            println!("    {} {}", synx::glyphs::FAIL, error);
                                         }
                                     }
                                 }
                             }
                             
                             if !summary.metrics.is_empty() {
-                                println!("\n📊 Metrics:");
+                                println!("\n{} Metrics:", synx::glyphs::STATS);
                                 for (key, value) in &summary.metrics {
                                     println!("  {}: {}", key, value);
                                 }
                             }
                             
                             if !summary.warnings.is_empty() {
-                                println!("\n⚠️ Warnings:");
+                                println!("\n{} Warnings:", synx::glyphs::WARN);
                                 for warning in &summary.warnings {
                                     println!("  - {}", warning);
                                 }
                             }
                             
                             if !summary.errors.is_empty() {
-                                println!("\n❌ Errors:");
+                                println!("\n{} Errors:", synx::glyphs::FAIL);
                                 for error in &summary.errors {
                                     println!("  - {}", error);
                                 }
@@ -1561,7 +2442,7 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
                     process::exit(exit_code);
                 }
                 Err(e) => {
-                    eprintln!("❌ Plugin test failed: {}", e);
+                    eprintln!("{} Plugin test failed: {}", synx::glyphs::FAIL, e);
                     validator.shutdown().await.unwrap();
                     process::exit(1);
                 }
@@ -1570,39 +2451,36 @@ async fn handle_plugin_command(action: &PluginAction, config: &synx::config::Con
     }
 }
 
+/// Print the diff `--fix-encoding` would apply to each file; returns the exit code
+fn preview_encoding_fixes(files: &[String]) -> i32 {
+    let mut would_change = false;
+    for file in files {
+        match synx::validators::encoding::preview_fix(std::path::Path::new(file)) {
+            Ok(Some(diff)) => {
+                print!("{}", diff);
+                would_change = true;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("{} {}", synx::glyphs::FAIL, e);
+                return 2;
+            }
+        }
+    }
+    if would_change { 1 } else { 0 }
+}
+
 fn save_report(
     result: &synx::validators::ScanResult,
     path: &str,
     format: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let content = match format {
-        "json" => {
-            let json_output = serde_json::json!({
-                "total_files": result.total_files,
-                "valid_files": result.valid_files,
-                "invalid_files": result.invalid_files.len(),
-                "invalid_file_paths": result.invalid_files,
-                "skipped_files": result.skipped_files,
-                "results_by_type": result.results_by_type
-            });
-            serde_json::to_string_pretty(&json_output)?
-        }
-        _ => {
-            // Default text format
-            format!(
-                "Synx Validation Report\n======================\n\nTotal files scanned: {}\nValid files: {}\nInvalid files: {}\nSkipped files: {}\n\nInvalid files:\n{}\n",
-                result.total_files,
-                result.valid_files,
-                result.invalid_files.len(),
-                result.skipped_files.len(),
-                result.invalid_files.iter()
-                    .map(|p| format!("  - {}", p.display()))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            )
-        }
-    };
-    
-    std::fs::write(path, content)?;
-    Ok(())
+    options: &synx::validators::ReportOptions,
+) -> anyhow::Result<()> {
+    // Files get plain text; formats without a file form (ndjson) fall back to it
+    let options = synx::validators::ReportOptions { color: false, ..options.clone() };
+    let reporter = synx::validators::reporter(format, &options)
+        .or_else(|| synx::validators::reporter("text", &options))
+        .unwrap();
+    let mut file = std::fs::File::create(path)?;
+    reporter.write(result, &mut file)
 }
@@ -0,0 +1,133 @@
+//! Results of `synx performance benchmark`
+//!
+//! Every iteration scans the same tree, so each file is timed once per
+//! iteration. Latency percentiles are taken over all of those timings, and
+//! the slowest files are ranked by their average.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Summary of a benchmark run
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+    /// Wall-clock time of each iteration
+    pub iterations: Vec<Duration>,
+    /// Files validated over all iterations
+    pub files: usize,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    /// Files validated per second of wall-clock time
+    pub files_per_sec: f64,
+    /// Files with the highest average time, slowest first
+    pub slowest_files: Vec<(PathBuf, Duration)>,
+}
+
+impl BenchmarkReport {
+    /// Summarize iteration times and per-file timings, keeping the `slowest` slowest files
+    pub fn new(iterations: Vec<Duration>, file_timings: &[(PathBuf, Duration)], slowest: usize) -> Self {
+        let mut durations: Vec<Duration> = file_timings.iter().map(|(_, duration)| *duration).collect();
+        durations.sort();
+
+        let total: Duration = iterations.iter().sum();
+        let files_per_sec = if total.is_zero() { 0.0 } else { durations.len() as f64 / total.as_secs_f64() };
+
+        let mut by_file: BTreeMap<&PathBuf, Vec<Duration>> = BTreeMap::new();
+        for (path, duration) in file_timings {
+            by_file.entry(path).or_default().push(*duration);
+        }
+        let mut slowest_files: Vec<(PathBuf, Duration)> = by_file.into_iter()
+            .map(|(path, times)| (path.clone(), times.iter().sum::<Duration>() / times.len() as u32))
+            .collect();
+        slowest_files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        slowest_files.truncate(slowest);
+
+        Self {
+            iterations,
+            files: durations.len(),
+            p50: percentile(&durations, 50.0),
+            p90: percentile(&durations, 90.0),
+            p99: percentile(&durations, 99.0),
+            files_per_sec,
+            slowest_files,
+        }
+    }
+}
+
+/// The nearest-rank percentile of sorted durations; zero when there are none
+pub fn percentile(sorted: &[Duration], percent: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (percent * sorted.len() as f64 / 100.0).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total: Duration = self.iterations.iter().sum();
+        if let (Some(min), Some(max)) = (self.iterations.iter().min(), self.iterations.iter().max()) {
+            writeln!(f, "Average Time: {:.2}s", (total / self.iterations.len() as u32).as_secs_f64())?;
+            writeln!(f, "Minimum Time: {:.2}s", min.as_secs_f64())?;
+            writeln!(f, "Maximum Time: {:.2}s", max.as_secs_f64())?;
+        }
+        writeln!(f, "Total Time: {:.2}s", total.as_secs_f64())?;
+        writeln!(f, "Throughput: {:.1} files/sec", self.files_per_sec)?;
+
+        writeln!(f, "\nPer-file latency ({} timings):", self.files)?;
+        writeln!(f, "  p50: {:>10.2}ms", ms(self.p50))?;
+        writeln!(f, "  p90: {:>10.2}ms", ms(self.p90))?;
+        writeln!(f, "  p99: {:>10.2}ms", ms(self.p99))?;
+
+        if !self.slowest_files.is_empty() {
+            writeln!(f, "\nSlowest files (average):")?;
+            for (path, duration) in &self.slowest_files {
+                writeln!(f, "  {:>10.2}ms  {}", ms(*duration), path.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles_from_recorded_durations() {
+        // 100 files timed at 1ms..100ms, split over two 1s iterations
+        let timings: Vec<(PathBuf, Duration)> = (1..=100)
+            .map(|i| (PathBuf::from(format!("file{}.py", i % 50)), Duration::from_millis(i)))
+            .collect();
+        let report = BenchmarkReport::new(vec![Duration::from_secs(1); 2], &timings, 2);
+
+        assert_eq!(report.p50, Duration::from_millis(50));
+        assert_eq!(report.p90, Duration::from_millis(90));
+        assert_eq!(report.p99, Duration::from_millis(99));
+        assert_eq!(report.files_per_sec, 50.0);
+        // file0.py took 50ms and 100ms, the highest average
+        assert_eq!(report.slowest_files[0], (PathBuf::from("file0.py"), Duration::from_millis(75)));
+        assert_eq!(report.slowest_files.len(), 2);
+
+        let text = report.to_string();
+        assert!(text.contains("p50:      50.00ms"), "{}", text);
+        assert!(text.contains("p90:      90.00ms"), "{}", text);
+        assert!(text.contains("p99:      99.00ms"), "{}", text);
+        assert!(text.contains("Throughput: 50.0 files/sec"), "{}", text);
+        assert!(text.contains("file0.py"), "{}", text);
+    }
+
+    #[test]
+    fn test_percentile_edges() {
+        assert_eq!(percentile(&[], 50.0), Duration::ZERO);
+        let one = [Duration::from_millis(7)];
+        assert_eq!(percentile(&one, 1.0), one[0]);
+        assert_eq!(percentile(&one, 99.0), one[0]);
+    }
+}
@@ -12,9 +12,11 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use blake3::Hasher;
-use std::fs::{self, File};
-use std::io::Read;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
 use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use fs2::FileExt;
 
 /// Configuration for the validation cache
 #[derive(Debug, Clone)]
@@ -385,36 +387,70 @@ impl ValidationCache {
     }
     
     fn load_cache(cache_file: &Path) -> Result<HashMap<PathBuf, CacheEntry>> {
-        if !cache_file.exists() {
-            return Ok(HashMap::new());
-        }
-        
-        match fs::read_to_string(cache_file) {
-            Ok(content) => {
-                match serde_json::from_str::<HashMap<PathBuf, CacheEntry>>(&content) {
-                    Ok(entries) => Ok(entries),
-                    Err(e) => {
-                        eprintln!("Warning: Failed to deserialize cache file ({}), starting with fresh cache", e);
-                        // Remove corrupted cache file
-                        let _ = fs::remove_file(cache_file);
-                        Ok(HashMap::new())
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("Warning: Failed to read cache file ({}), starting with fresh cache", e);
-                Ok(HashMap::new())
-            }
-        }
+        with_cache_lock(cache_file, || Ok(read_cache_file(cache_file)))
     }
     
     fn save_cache(&self, entries: &HashMap<PathBuf, CacheEntry>) -> Result<()> {
-        let json = serde_json::to_string_pretty(entries)?;
-        fs::write(&self.cache_file, json)?;
-        Ok(())
+        with_cache_lock(&self.cache_file, || write_cache_file(&self.cache_file, entries))
+    }
+}
+
+/// Run `f` while holding an exclusive advisory lock on `<cache_file>.lock`, so
+/// synx processes sharing a cache directory take turns reading and writing it
+pub fn with_cache_lock<T>(cache_file: &Path, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let mut lock_path = cache_file.as_os_str().to_owned();
+    lock_path.push(".lock");
+    
+    if let Some(parent) = cache_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // The lock file only exists to be locked; never truncate what's there
+    let lock_file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(&lock_path)?;
+    lock_file.lock_exclusive()?;
+    
+    let result = f();
+    let _ = lock_file.unlock();
+    result
+}
+
+/// Read a JSON cache file. A missing file gives an empty cache; a corrupt one
+/// is discarded with a warning. Call with the cache lock held.
+pub fn read_cache_file<T: DeserializeOwned + Default>(cache_file: &Path) -> T {
+    let content = match fs::read_to_string(cache_file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return T::default(),
+        Err(e) => {
+            eprintln!("Warning: Failed to read cache file ({}), starting with fresh cache", e);
+            return T::default();
+        }
+    };
+    
+    match serde_json::from_str(&content) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Warning: Failed to deserialize cache file ({}), starting with fresh cache", e);
+            // Remove corrupted cache file
+            let _ = fs::remove_file(cache_file);
+            T::default()
+        }
     }
 }
 
+/// Write a JSON cache file atomically: readers see either the old contents or
+/// the new ones, never a partial write. Call with the cache lock held.
+pub fn write_cache_file<T: Serialize>(cache_file: &Path, entries: &T) -> Result<()> {
+    let dir = cache_file.parent().unwrap_or_else(|| Path::new("."));
+    let mut temp = tempfile::NamedTempFile::new_in(dir)?;
+    serde_json::to_writer_pretty(&mut temp, entries)?;
+    temp.flush()?;
+    temp.persist(cache_file).map_err(|e| e.error)?;
+    Ok(())
+}
+
 impl Drop for ValidationCache {
     fn drop(&mut self) {
         if self.config.persistent {
@@ -0,0 +1,94 @@
+//! Per-tool concurrency limits
+//!
+//! Heavy tools such as `dotnet` or `tsc` can exhaust memory when a scan runs
+//! one per worker. Each limited tool gets its own semaphore; tools without a
+//! limit are only bounded by the worker pool.
+
+use std::collections::HashMap;
+use std::sync::{Condvar, Mutex};
+
+/// A counting semaphore for the blocking worker pool
+pub struct Semaphore {
+    available: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Semaphore {
+    pub fn new(permits: usize) -> Self {
+        Self { available: Mutex::new(permits), released: Condvar::new() }
+    }
+
+    /// Block until a permit is free; it is given back when the guard drops
+    pub fn acquire(&self) -> Permit<'_> {
+        let mut available = self.available.lock().unwrap();
+        while *available == 0 {
+            available = self.released.wait(available).unwrap();
+        }
+        *available -= 1;
+        Permit { semaphore: self }
+    }
+}
+
+/// A held semaphore permit
+pub struct Permit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        *self.semaphore.available.lock().unwrap() += 1;
+        self.semaphore.released.notify_one();
+    }
+}
+
+/// The concurrency limit of every tool that has one
+#[derive(Default)]
+pub struct ToolLimits {
+    semaphores: HashMap<String, Semaphore>,
+}
+
+impl ToolLimits {
+    /// Limits keyed by tool name; a limit of 0 is treated as no limit
+    pub fn new(limits: &HashMap<String, usize>) -> Self {
+        let semaphores = limits.iter()
+            .filter(|(_, &limit)| limit > 0)
+            .map(|(tool, &limit)| (tool.clone(), Semaphore::new(limit)))
+            .collect();
+        Self { semaphores }
+    }
+
+    /// Wait for a turn to run `tool`. Tools without a limit return straight away.
+    pub fn acquire(&self, tool: &str) -> Option<Permit<'_>> {
+        self.semaphores.get(tool).map(Semaphore::acquire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_limit_caps_concurrent_holders() {
+        let limits = ToolLimits::new(&HashMap::from([("dotnet".to_string(), 2)]));
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    let _permit = limits.acquire("dotnet");
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(20));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert!(limits.acquire("node").is_none());
+    }
+}
@@ -45,6 +45,12 @@ pub struct PerformanceMonitor {
     start_time: Instant,
 }
 
+impl Default for PerformanceMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PerformanceMonitor {
     /// Create a new performance monitor
     pub fn new() -> Self {
@@ -13,10 +13,16 @@ use rayon::ThreadPoolBuilder;
 pub mod cache;
 pub mod parallel;
 pub mod metrics;
+pub mod profile;
+pub mod concurrency;
+pub mod benchmark;
 
 pub use cache::{ValidationCache, CacheConfig, CacheEntry, CacheStats};
 pub use parallel::{ParallelValidator, WorkloadDistributor, ValidationJob};
 pub use metrics::{PerformanceMonitor, ValidationMetrics, ResourceUsage};
+pub use profile::{Phase, Profiler, ProfileReport};
+pub use concurrency::ToolLimits;
+pub use benchmark::BenchmarkReport;
 
 /// Performance configuration for validation operations
 #[derive(Debug, Clone)]
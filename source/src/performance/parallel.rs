@@ -92,6 +92,16 @@ impl WorkloadDistributor {
                             verbose: false,
                             timeout: 30,
                             config: Some(crate::validators::FileValidationConfig::default()),
+                            analyze_memory: false,
+                            max_file_size: None,
+                            allow_binary: false,
+                            modified_since: None,
+                            max_depth: None,
+                            profiler: None,
+                            incremental: false,
+                            follow_symlinks: false,
+                            offline: false,
+                            registry: Default::default(),
                         };
                         
                         match validate_file(path, &validation_options) {
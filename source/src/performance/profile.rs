@@ -0,0 +1,151 @@
+//! Per-phase timing for `scan --profile`
+//!
+//! Phases that run on the worker pool add up the time of every worker, so
+//! they can exceed the wall-clock time of the whole scan.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::metrics::PerformanceMonitor;
+
+/// A part of a scan whose time is tracked separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    FileWalk,
+    TypeDetection,
+    ToolExecution,
+    MemoryAnalysis,
+    Reporting,
+}
+
+impl Phase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::FileWalk => "file walking",
+            Phase::TypeDetection => "type detection",
+            Phase::ToolExecution => "tool execution",
+            Phase::MemoryAnalysis => "memory analysis",
+            Phase::Reporting => "reporting",
+        }
+    }
+}
+
+/// Collects phase and per-file timings; shared between scan workers
+#[derive(Default)]
+pub struct Profiler {
+    phases: Mutex<BTreeMap<Phase, Duration>>,
+    files: Mutex<Vec<(PathBuf, Duration)>>,
+    monitor: PerformanceMonitor,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add time spent in a phase
+    pub fn record(&self, phase: Phase, duration: Duration) {
+        *self.phases.lock().unwrap().entry(phase).or_default() += duration;
+    }
+
+    /// Run `f`, counting its time towards `phase`
+    pub fn time<T>(&self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let value = f();
+        self.record(phase, start.elapsed());
+        value
+    }
+
+    /// Record how long validating one file took
+    pub fn record_file(&self, path: &Path, success: bool, duration: Duration) {
+        self.files.lock().unwrap().push((path.to_path_buf(), duration));
+        self.monitor.record_validation(path, success, duration);
+    }
+
+    /// Every file timing recorded so far, in the order they were recorded
+    pub fn file_timings(&self) -> Vec<(PathBuf, Duration)> {
+        self.files.lock().unwrap().clone()
+    }
+
+    /// The validation metrics gathered for the files recorded so far
+    pub fn monitor(&self) -> &PerformanceMonitor {
+        &self.monitor
+    }
+
+    /// Summarize the timings, listing the `slowest` slowest files
+    pub fn report(&self, slowest: usize) -> ProfileReport {
+        let phases = self.phases.lock().unwrap()
+            .iter()
+            .map(|(phase, duration)| (*phase, *duration))
+            .collect();
+
+        let mut files = self.files.lock().unwrap().clone();
+        files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        files.truncate(slowest);
+
+        ProfileReport { phases, slowest_files: files }
+    }
+}
+
+/// Where the time of a scan went
+#[derive(Debug, Clone)]
+pub struct ProfileReport {
+    pub phases: Vec<(Phase, Duration)>,
+    pub slowest_files: Vec<(PathBuf, Duration)>,
+}
+
+impl ProfileReport {
+    /// Total time recorded for a phase
+    pub fn phase(&self, phase: Phase) -> Duration {
+        self.phases.iter()
+            .find(|(p, _)| *p == phase)
+            .map(|(_, duration)| *duration)
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Profile")?;
+        writeln!(f, "=======")?;
+        for (phase, duration) in &self.phases {
+            writeln!(f, "  {:<16} {:>10.2}ms", phase.name(), duration.as_secs_f64() * 1000.0)?;
+        }
+        if !self.slowest_files.is_empty() {
+            writeln!(f, "\nSlowest files:")?;
+            for (path, duration) in &self.slowest_files {
+                writeln!(f, "  {:>10.2}ms  {}", duration.as_secs_f64() * 1000.0, path.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_sums_phases_and_ranks_files() {
+        let profiler = Profiler::new();
+        profiler.record(Phase::ToolExecution, Duration::from_millis(30));
+        profiler.record(Phase::ToolExecution, Duration::from_millis(20));
+        profiler.record(Phase::FileWalk, Duration::from_millis(5));
+        profiler.record_file(Path::new("fast.py"), true, Duration::from_millis(20));
+        profiler.record_file(Path::new("slow.py"), false, Duration::from_millis(30));
+
+        let report = profiler.report(1);
+
+        assert_eq!(report.phase(Phase::ToolExecution), Duration::from_millis(50));
+        assert_eq!(report.phase(Phase::Reporting), Duration::ZERO);
+        assert_eq!(report.slowest_files, vec![(PathBuf::from("slow.py"), Duration::from_millis(30))]);
+        assert_eq!(profiler.monitor().get_metrics().total_files, 2);
+
+        let text = report.to_string();
+        assert!(text.contains("tool execution"));
+        assert!(text.contains("slow.py"));
+    }
+}
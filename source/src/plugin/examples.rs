@@ -363,7 +363,8 @@ impl FormatterPlugin for JsonFormatterPlugin {
                     .with_data(serde_json::json!({
                         "needs_formatting": true,
                         "original_length": content.len(),
-                        "formatted_length": formatted.len()
+                        "formatted_length": formatted.len(),
+                        "diff": crate::diff::unified_diff(file_path, &content, &formatted)
                     }))
             } else {
                 PluginResult::success("File is properly formatted")
@@ -627,7 +628,7 @@ mod tests {
             working_dir: std::env::current_dir().unwrap(),
             config: HashMap::new(),
             security_policy: crate::tools::policy::SecurityPolicy::default(),
-            resource_limits: super::ResourceLimits::default(),
+            resource_limits: crate::plugin::ResourceLimits::default(),
             logger,
         };
 
@@ -645,6 +646,31 @@ mod tests {
         assert_eq!(result.metrics.get("non_blank_lines"), Some(&3.0));
     }
 
+    #[tokio::test]
+    async fn test_json_format_check_reports_diff() {
+        let formatter = JsonFormatterPlugin::new();
+        let context = PluginContext {
+            working_dir: std::env::current_dir().unwrap(),
+            config: HashMap::new(),
+            security_policy: crate::tools::policy::SecurityPolicy::default(),
+            resource_limits: crate::plugin::ResourceLimits::default(),
+            logger: create_test_logger(),
+        };
+
+        let fixture = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/files/json/misformatted.json");
+        let before = std::fs::read(&fixture).unwrap();
+
+        let result = formatter.format_file(&fixture, &context, true).await.unwrap();
+
+        assert!(!result.success);
+        let diff = result.data.as_ref().unwrap()["diff"].as_str().unwrap();
+        assert!(diff.contains("+++ b/"));
+        assert!(diff.contains("+  \"name\": \"synx\","));
+        // Checking never writes the file
+        assert_eq!(std::fs::read(&fixture).unwrap(), before);
+    }
+
     #[test]
     fn test_json_formatting() {
         let json_str = r#"{"b": 2, "a": 1, "c": [3, 2, 1]}"#;
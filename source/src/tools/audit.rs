@@ -205,17 +205,7 @@ impl AuditEvent {
             &self.context
         )).unwrap_or_default();
         
-        // Use regular hash if key is not the expected size
-        let signature = if key.len() == blake3::KEY_LEN {
-            // Convert slice to array reference
-            let key_array: &[u8; blake3::KEY_LEN] = key.try_into().unwrap_or(&[0; blake3::KEY_LEN]);
-            blake3::keyed_hash(key_array, &event_data)
-        } else {
-            // Fall back to regular hash if key size is incorrect
-            blake3::hash(&event_data)
-        };
-        
-        self.signature = Some(signature.to_hex().to_string());
+        self.signature = Some(sign_data(key, &event_data));
     }
 
     /// Verify the event signature
@@ -229,23 +219,23 @@ impl AuditEvent {
                 &self.context
             )).unwrap_or_default();
             
-            // Use regular hash if key is not the expected size
-            let expected = if key.len() == blake3::KEY_LEN {
-                // Convert slice to array reference
-                let key_array: &[u8; blake3::KEY_LEN] = key.try_into().unwrap_or(&[0; blake3::KEY_LEN]);
-                blake3::keyed_hash(key_array, &event_data)
-            } else {
-                // Fall back to regular hash if key size is incorrect
-                blake3::hash(&event_data)
-            };
-            
-            sig == &expected.to_hex().to_string()
+            sig == &sign_data(key, &event_data)
         } else {
             false
         }
     }
 }
 
+/// Sign data with a keyed BLAKE3 hash, returned as hex. A key that isn't
+/// `blake3::KEY_LEN` bytes long falls back to a plain hash.
+pub fn sign_data(key: &[u8], data: &[u8]) -> String {
+    let signature = match <&[u8; blake3::KEY_LEN]>::try_from(key) {
+        Ok(key_array) => blake3::keyed_hash(key_array, data),
+        Err(_) => blake3::hash(data),
+    };
+    signature.to_hex().to_string()
+}
+
 struct AlertSender {
     enabled: bool,
     tx: Option<mpsc::Sender<AuditEvent>>,
@@ -15,7 +15,7 @@ mod audit;
 pub mod policy;
 
 pub use secure::{SecureCommand, SecurityConfig};
-pub use paths::{SecurePath, PathSecurityConfig};
+pub use paths::{SecurePath, PathSecurityConfig, SecureTempFile};
 pub use verify::{VerifiedTool, ToolVerificationConfig};
 pub use policy::{
     SecurityPolicy, 
@@ -27,7 +27,7 @@ pub use policy::{
     FileSecurityChecks,
     FileOperation,
 };
-pub use audit::AuditConfig;
+pub use audit::{AuditConfig, sign_data};
 
 use std::path::Path;
 use anyhow::Result;
@@ -210,6 +210,63 @@ pub fn create_secure_tempfile() -> Result<(PathBuf, fs::File)> {
     Ok((path, file))
 }
 
+/// Content written out for a tool that only takes paths.
+///
+/// The file sits alone in a fresh directory that only the current user can
+/// enter, is itself readable only by them (0600 on Unix), and keeps the name
+/// it was given so tools that go by extension or file name recognize it. The
+/// file and its directory are removed when this is dropped.
+#[derive(Debug)]
+pub struct SecureTempFile {
+    dir: tempfile::TempDir,
+    path: PathBuf,
+}
+
+impl SecureTempFile {
+    /// Write `content` to a private file called `file_name`
+    pub fn new(file_name: &str, content: &[u8]) -> Result<Self> {
+        use std::io::Write;
+
+        if matches!(file_name, "" | "." | "..") || file_name.contains(['/', '\\']) {
+            return Err(anyhow!("Invalid temporary file name '{}'", file_name));
+        }
+        let dir = tempfile::Builder::new()
+            .prefix("synx-source-")
+            .rand_bytes(16)
+            .tempdir()?;
+        // tempdir() leaves the directory at the umask default (usually 0755)
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o700))?;
+        }
+        let path = dir.path().join(file_name);
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&path)
+            .context(format!("Failed to create {}", path.display()))?;
+        file.write_all(content)?;
+
+        Ok(Self { dir, path })
+    }
+
+    /// Where the content was written
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The private directory holding the file, for tools that write next to it
+    pub fn dir(&self) -> &Path {
+        self.dir.path()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +334,30 @@ mod tests {
         // Clean up
         fs::remove_file(path).unwrap();
     }
+
+    #[test]
+    fn test_secure_temp_file_keeps_its_name() {
+        let temp = SecureTempFile::new(".env", b"PORT=80\n").unwrap();
+        assert_eq!(temp.path().file_name().unwrap(), ".env");
+        assert_eq!(fs::read_to_string(temp.path()).unwrap(), "PORT=80\n");
+
+        assert!(SecureTempFile::new("../escape.rs", b"").is_err());
+        assert!(SecureTempFile::new("", b"").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_secure_temp_file_is_private_and_removed() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = SecureTempFile::new("source.rs", b"fn main() {}\n").unwrap();
+        let path = temp.path().to_path_buf();
+        let dir = temp.dir().to_path_buf();
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        assert_eq!(fs::metadata(&dir).unwrap().permissions().mode() & 0o777, 0o700);
+
+        drop(temp);
+        assert!(!path.exists());
+        assert!(!dir.exists());
+    }
 }
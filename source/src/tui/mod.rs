@@ -12,9 +12,10 @@
 
 // pub mod interactive; // Temporarily disabled due to compilation issues
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use std::time::Duration;
 
 use anyhow::{Result, Context, anyhow};
@@ -40,6 +41,9 @@ use uuid::Uuid;
 // Remove lints module import as it doesn't exist
 
 use crate::analysis::IssueSeverity;
+use crate::validators::{
+    scan_directory_with_events, validate_file_detailed, LanguageFilter, ValidationError, ValidationOptions,
+};
 
 // Temporary ValidationIssue type for TUI compatibility
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,6 +62,104 @@ pub struct ValidationIssue {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationReport {
     pub file_issues: std::collections::HashMap<PathBuf, Vec<ValidationIssue>>,
+    /// Issues dropped past `max_issues_per_file`; each capped file's list
+    /// ends with a warning saying how many it had
+    #[serde(default)]
+    pub hidden_issues: usize,
+}
+
+impl ValidationReport {
+    /// Validate files and directories, keeping the issues of every file that fails.
+    /// Files that couldn't be validated (binary, missing tool) are left out.
+    pub fn from_paths(paths: &[PathBuf], options: &ValidationOptions) -> Result<Self> {
+        let mut files = Vec::new();
+        for path in paths {
+            if path.is_dir() {
+                // A listener keeps the scan from printing progress
+                let (events, _receiver) = mpsc::channel();
+                let result = scan_directory_with_events(path, options, &[], &LanguageFilter::default(), Some(events))?;
+                files.extend(result.invalid_files);
+            } else {
+                files.push(path.clone());
+            }
+        }
+
+        let mut file_issues = HashMap::new();
+        let mut hidden_issues = 0;
+        for file in files {
+            let result = match validate_file_detailed(&file, options) {
+                Ok(result) if !result.success => result,
+                _ => continue,
+            };
+            hidden_issues += result.hidden_issues;
+            let mut issues: Vec<ValidationIssue> = result.errors.into_iter()
+                .map(|error| ValidationIssue::from_error(&file, error))
+                .collect();
+            if issues.is_empty() {
+                issues.push(ValidationIssue {
+                    file_path: file.clone(),
+                    issue_type: "ValidationFailed".to_string(),
+                    severity: IssueSeverity::High,
+                    message: "File failed validation".to_string(),
+                    line_start: 1,
+                    line_end: 1,
+                    suggested_fix: None,
+                    context: HashMap::new(),
+                });
+            }
+            file_issues.insert(file, issues);
+        }
+        Ok(Self { file_issues, hidden_issues })
+    }
+
+    /// Total issues across all files
+    pub fn issue_count(&self) -> usize {
+        self.file_issues.values().map(Vec::len).sum()
+    }
+}
+
+impl ValidationIssue {
+    fn from_error(file_path: &Path, error: ValidationError) -> Self {
+        let line = error.line.unwrap_or(1).max(1);
+        Self {
+            file_path: file_path.to_path_buf(),
+            issue_type: format!("{:?}", error.error_type),
+            severity: error.severity,
+            message: error.message,
+            line_start: line,
+            line_end: line,
+            suggested_fix: error.suggestion,
+            context: HashMap::new(),
+        }
+    }
+}
+
+/// The byte range a fix replaces in `content` and the text it puts there,
+/// for fixes that edit text directly
+fn fix_edit(content: &str, fix: FixImplementation) -> Option<(usize, usize, String)> {
+    let with_newline = |mut text: String| {
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text
+    };
+    match fix {
+        FixImplementation::ReplaceText { line_start, line_end, replacement } if !replacement.is_empty() => {
+            let (start, end) = syntax::line_range(content, line_start, line_end);
+            Some((start, end, with_newline(replacement)))
+        }
+        FixImplementation::DeleteText { line_start, line_end } => {
+            let (start, end) = syntax::line_range(content, line_start, line_end);
+            Some((start, end, String::new()))
+        }
+        FixImplementation::AddText { line, text } => {
+            let (start, _) = syntax::line_range(content, line, line);
+            // Appending to a file without a final newline starts a new line first
+            let prefix = if start == content.len() && !content.is_empty() && !content.ends_with('\n') { "\n" } else { "" };
+            Some((start, start, format!("{}{}", prefix, with_newline(text))))
+        }
+        _ => None,
+    }
 }
 
 // Temporary LintRules type for TUI compatibility
@@ -98,8 +200,12 @@ mod syntax;
 mod issue_state;
 mod widgets;
 
-use issue_state::{IssueState, IssueAction};
+use issue_state::{IssueState, IssueAction, FixImplementation};
 use syntax::SyntaxHighlighter;
+pub use syntax::{
+    create_rust_parser, create_python_parser, create_javascript_parser, create_typescript_parser,
+    create_c_parser, create_cpp_parser, create_go_parser, create_java_parser, load_grammar,
+};
 use widgets::{CodeView, SyntaxTreeView, IssuePanel, ActionMenu};
 
 // TUI application state
@@ -115,6 +221,12 @@ pub struct TuiApp {
     
     // Tree-sitter parsers
     parsers: HashMap<String, Parser>,
+    
+    // Keep applied fixes in memory and report them as diffs instead of writing
+    dry_run: bool,
+    
+    // Content each file would have with the fixes applied so far (dry run only)
+    previews: BTreeMap<PathBuf, String>,
 }
 
 #[derive(Debug, Clone)]
@@ -172,7 +284,7 @@ enum Tab {
 
 impl TuiApp {
     /// Initialize a new TUI application
-    pub fn new(validation_report: ValidationReport) -> Result<Self> {
+    pub fn new(validation_report: ValidationReport, grammars: &HashMap<String, String>) -> Result<Self> {
         // Setup terminal
         let backend = CrosstermBackend::new(io::stdout());
         let terminal = Terminal::new(backend)?;
@@ -190,6 +302,7 @@ impl TuiApp {
         parsers.insert("cpp".to_string(), syntax::create_cpp_parser()?);
         parsers.insert("go".to_string(), syntax::create_go_parser()?);
         parsers.insert("java".to_string(), syntax::create_java_parser()?);
+        syntax::load_custom_grammars(&mut parsers, grammars);
         
         // Collect files with issues
         let mut issue_files = Vec::new();
@@ -221,7 +334,7 @@ impl TuiApp {
         }
         
         // Parse syntax tree for the file
-        let syntax_tree = syntax::parse_file(&first_file, &parsers)?;
+        let syntax_tree = syntax::parse_file(&first_file, &file_content, None, &mut parsers)?;
         
         // Initialize lint rules
         let lint_rules = LintRules::new();
@@ -248,9 +361,17 @@ impl TuiApp {
             state,
             syntax_highlighter,
             parsers,
+            dry_run: false,
+            previews: BTreeMap::new(),
         })
     }
     
+    /// Leave files untouched; applied fixes come back as diffs in the results
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+    
     /// Run the TUI application
     pub fn run(&mut self) -> Result<()> {
         // Enter alternate screen and enable raw mode
@@ -858,9 +979,12 @@ impl TuiApp {
         
         let file_path = &self.state.issue_files[file_index];
         
-        // Load file content
-        self.state.file_content = std::fs::read_to_string(file_path)
-            .context(format!("Failed to read file: {}", file_path.display()))?;
+        // Load file content, with any fixes held back by a dry run
+        self.state.file_content = match self.previews.get(file_path) {
+            Some(content) => content.clone(),
+            None => std::fs::read_to_string(file_path)
+                .context(format!("Failed to read file: {}", file_path.display()))?,
+        };
         
         // Update issues for this file
         // In a real implementation, this would come from a validation report
@@ -868,7 +992,7 @@ impl TuiApp {
         self.state.issues = Vec::new();
         
         // Parse syntax tree for the file
-        self.state.syntax_tree = syntax::parse_file(file_path, &self.parsers)?;
+        self.state.syntax_tree = syntax::parse_file(file_path, &self.state.file_content, None, &mut self.parsers)?;
         
         // Reset scroll position
         self.state.scroll_position = 0;
@@ -930,30 +1054,54 @@ impl TuiApp {
             return Ok(());
         }
         
+        let issue_id = self.get_current_issue_id();
+        let implementation = self.state.issue_states.get(&issue_id)
+            .and_then(IssueState::get_selected_fix)
+            .map(|fix| fix.implementation.clone());
+        if let Some((start, end, text)) = implementation.and_then(|fix| fix_edit(&self.state.file_content, fix)) {
+            self.edit_content(start, end, &text)?;
+        }
+
         let current_issue = &self.state.issues[self.state.current_issue];
         let file_path = &self.state.issue_files[self.state.current_file];
-        
-        // In a real implementation, this would:
-        // 1. Get the fix from the issue
-        // 2. Apply it to the file content
-        // 3. Write the changes back to disk
-        
-        // For demonstration purposes, let's simulate a fix
-        // Here we'd update the file_content and write it back to disk
-        
         info!("Applied fix to {} for issue at line {}", 
             file_path.display(), 
             current_issue.line_start
         );
         
         // Mark the issue as fixed in the state
-        let issue_id = self.get_current_issue_id();
         let issue_state = self.state.issue_states.get_mut(&issue_id).unwrap();
         issue_state.is_fixed = true;
         
         Ok(())
     }
     
+    /// Replace a byte range of the current file and save it. The syntax tree
+    /// is edited in step, so only the changed region is re-parsed.
+    fn edit_content(&mut self, start_byte: usize, old_end_byte: usize, new_text: &str) -> Result<()> {
+        let file_path = &self.state.issue_files[self.state.current_file];
+        syntax::edit_source(
+            &mut self.state.file_content,
+            self.state.syntax_tree.as_mut(),
+            start_byte,
+            old_end_byte,
+            new_text,
+        );
+        self.state.syntax_tree = syntax::parse_file(
+            file_path,
+            &self.state.file_content,
+            self.state.syntax_tree.as_ref(),
+            &mut self.parsers,
+        )?;
+        if self.dry_run {
+            self.previews.insert(file_path.clone(), self.state.file_content.clone());
+        } else {
+            std::fs::write(file_path, &self.state.file_content)
+                .context(format!("Failed to write file: {}", file_path.display()))?;
+        }
+        Ok(())
+    }
+    
     //
     // Helper methods for state persistence
     //
@@ -992,10 +1140,19 @@ impl TuiApp {
             
         let remaining_issues = self.state.issue_states.len() - fixed_issues - ignored_issues;
         
+        let diffs = self.previews.iter()
+            .filter_map(|(path, content)| {
+                let before = std::fs::read_to_string(path).ok()?;
+                Some(crate::diff::unified_diff(path, &before, content))
+            })
+            .filter(|diff| !diff.is_empty())
+            .collect();
+        
         InteractiveResults {
             fixed_issues,
             ignored_issues,
             remaining_issues,
+            diffs,
         }
     }
     
@@ -1199,17 +1356,26 @@ impl TuiApp {
 }
 
 /// Results of the interactive fixing session
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct InteractiveResults {
     pub fixed_issues: usize,
     pub ignored_issues: usize,
     pub remaining_issues: usize,
+    /// With a dry run, the unified diff of each file the fixes would change
+    pub diffs: Vec<String>,
 }
 
-/// Run the TUI application with the provided validation report
-pub fn run_interactive_mode(validation_report: ValidationReport) -> Result<InteractiveResults> {
+/// Run the TUI application with the provided validation report, adding
+/// parsers for the configured tree-sitter grammars. With nothing to fix
+/// there is no session, and every count is zero. A dry run writes nothing
+/// and returns the applied fixes as diffs.
+pub fn run_interactive_mode(validation_report: ValidationReport, grammars: &HashMap<String, String>, dry_run: bool) -> Result<InteractiveResults> {
+    if validation_report.issue_count() == 0 {
+        return Ok(InteractiveResults::default());
+    }
+    
     // Initialize the TUI app
-    let mut app = TuiApp::new(validation_report)?;
+    let mut app = TuiApp::new(validation_report, grammars)?.with_dry_run(dry_run);
     
     // Run the app
     app.run()?;
@@ -6,13 +6,13 @@
 use std::collections::HashMap;
 use std::path::Path;
 
-use anyhow::{Result, Context, anyhow};
+use anyhow::{Result, anyhow};
 use syntect::highlighting::{Theme, ThemeSet};
 use syntect::parsing::{SyntaxSet, SyntaxReference};
 use syntect::util::LinesWithEndings;
 use tui::style::{Color, Style};
 use tui::text::{Span, Spans};
-use tree_sitter::{Parser, Tree, Node};
+use tree_sitter::{InputEdit, Node, Parser, Point, Tree};
 
 /// Syntax highlighter using syntect
 #[derive(Clone)]
@@ -161,24 +161,98 @@ impl TreeFormatter {
     }
 }
 
-/// Parse a file using the appropriate tree-sitter parser
-pub fn parse_file(path: &Path, parsers: &HashMap<String, Parser>) -> Result<Option<Tree>> {
-    let extension = path.extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-    
-    let _parser = match parsers.get(&extension) {
+/// The key of the parser for a file, by extension
+pub fn language_key(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "rs" => Some("rust"),
+        "py" | "pyi" => Some("python"),
+        "js" | "jsx" | "mjs" | "cjs" => Some("javascript"),
+        "ts" | "tsx" => Some("typescript"),
+        "c" | "h" => Some("c"),
+        "cpp" | "cc" | "cxx" | "hpp" | "hxx" | "hh" => Some("cpp"),
+        "go" => Some("go"),
+        "java" => Some("java"),
+        _ => None,
+    }
+}
+
+/// Parse a file's content using the appropriate tree-sitter parser
+///
+/// Given the previous tree, already adjusted with [`edit_source`], only the
+/// edited region is re-parsed.
+pub fn parse_file(
+    path: &Path,
+    content: &str,
+    old_tree: Option<&Tree>,
+    parsers: &mut HashMap<String, Parser>,
+) -> Result<Option<Tree>> {
+    // Grammars from the config are keyed by file extension
+    let key = match language_key(path) {
+        Some(key) => key.to_string(),
+        None => match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) => extension.to_lowercase(),
+            None => return Ok(None),
+        },
+    };
+    let parser = match parsers.get_mut(&key) {
         Some(parser) => parser,
         None => return Ok(None),
     };
-    
-    let _code = std::fs::read_to_string(path)
-        .context(format!("Failed to read file: {}", path.display()))?;
-    
-    // For now, return None since we can't mutably borrow from the HashMap
-    // In a real implementation, we'd need to restructure this to allow mutable access
-    Ok(None)
+
+    let tree = parser.parse(content, old_tree)
+        .ok_or_else(|| anyhow!("Failed to parse file: {}", path.display()))?;
+    Ok(Some(tree))
+}
+
+/// Byte range of lines `line_start..=line_end` (1-based), including the
+/// final newline. Lines past the end clamp to the end of the content.
+pub fn line_range(content: &str, line_start: usize, line_end: usize) -> (usize, usize) {
+    let line_offset = |line: usize| {
+        if line <= 1 {
+            return 0;
+        }
+        content.match_indices('\n')
+            .nth(line - 2)
+            .map_or(content.len(), |(index, _)| index + 1)
+    };
+    let start = line_offset(line_start);
+    (start, line_offset(line_end + 1).max(start))
+}
+
+// Row and byte column of an offset, as tree-sitter counts them
+fn point_at(content: &str, byte: usize) -> Point {
+    let before = &content.as_bytes()[..byte];
+    let row = before.iter().filter(|&&b| b == b'\n').count();
+    let column = before.iter().rposition(|&b| b == b'\n').map_or(byte, |newline| byte - newline - 1);
+    Point::new(row, column)
+}
+
+/// Replace `start_byte..old_end_byte` of `content` with `new_text`, keeping
+/// the tree's offsets in step so the next parse can reuse it
+pub fn edit_source(
+    content: &mut String,
+    tree: Option<&mut Tree>,
+    start_byte: usize,
+    old_end_byte: usize,
+    new_text: &str,
+) -> InputEdit {
+    let new_end_byte = start_byte + new_text.len();
+    let start_position = point_at(content, start_byte);
+    let old_end_position = point_at(content, old_end_byte);
+    content.replace_range(start_byte..old_end_byte, new_text);
+    let edit = InputEdit {
+        start_byte,
+        old_end_byte,
+        new_end_byte,
+        start_position,
+        old_end_position,
+        new_end_position: point_at(content, new_end_byte),
+    };
+    if let Some(tree) = tree {
+        tree.edit(&edit);
+    }
+    edit
 }
 
 /// Create a Rust parser
@@ -245,3 +319,107 @@ pub fn create_java_parser() -> Result<Parser> {
     Ok(parser)
 }
 
+/// Create a parser from a grammar compiled as a shared library (e.g.
+/// `libtree-sitter-zig.so`), which exports the language as
+/// `tree_sitter_<language>`; dashes in the name become underscores
+pub fn load_grammar(language: &str, library: &Path) -> Result<Parser> {
+    let symbol = format!("tree_sitter_{}", language.replace('-', "_"));
+    // SAFETY: loading a library runs its initializers, and the symbol is
+    // trusted to have the signature every tree-sitter grammar exports
+    let grammar = unsafe {
+        let loaded = libloading::Library::new(library)
+            .map_err(|e| anyhow!("Failed to load {}: {}", library.display(), e))?;
+        let grammar = {
+            let constructor: libloading::Symbol<unsafe extern "C" fn() -> tree_sitter::Language> = loaded
+                .get(symbol.as_bytes())
+                .map_err(|e| anyhow!("{} doesn't export {}: {}", library.display(), symbol, e))?;
+            constructor()
+        };
+        // The language's tables live in the library, so it stays loaded for good
+        std::mem::forget(loaded);
+        grammar
+    };
+
+    let mut parser = Parser::new();
+    parser.set_language(grammar)
+        .map_err(|e| anyhow!("Failed to create {} parser from {}: {}", language, library.display(), e))?;
+    Ok(parser)
+}
+
+/// Add a parser for each `[treesitter] grammars` entry. A grammar that
+/// fails to load is left out with a warning.
+pub fn load_custom_grammars(parsers: &mut HashMap<String, Parser>, grammars: &HashMap<String, String>) {
+    for (language, library) in grammars {
+        match load_grammar(language, Path::new(library)) {
+            Ok(parser) => {
+                parsers.insert(language.to_lowercase(), parser);
+            }
+            Err(e) => eprintln!("{} Skipping the {} grammar: {:#}", crate::glyphs::WARN, language, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incremental_reparse_matches_full_parse() {
+        let path = Path::new("app.py");
+        let mut parsers = HashMap::new();
+        parsers.insert("python".to_string(), create_python_parser().unwrap());
+
+        let mut content = "def first():\n    x = 1\n    return x\n\n\ndef second():\n    return 2\n".to_string();
+        let mut tree = parse_file(path, &content, None, &mut parsers).unwrap().unwrap();
+
+        // Replace the body of the first function's assignment
+        let (start, end) = line_range(&content, 2, 2);
+        let edit = edit_source(&mut content, Some(&mut tree), start, end, "    x = compute(1, [2, 3])\n");
+        assert_eq!(edit.start_position, Point::new(1, 0));
+        assert_eq!(edit.old_end_position, Point::new(2, 0));
+        assert_eq!(edit.new_end_position, Point::new(2, 0));
+
+        let incremental = parse_file(path, &content, Some(&tree), &mut parsers).unwrap().unwrap();
+        let full = parse_file(path, &content, None, &mut parsers).unwrap().unwrap();
+        assert_eq!(incremental.root_node().to_sexp(), full.root_node().to_sexp());
+        assert_eq!(incremental.root_node().end_byte(), content.len());
+        assert!(!incremental.root_node().has_error());
+    }
+
+    #[test]
+    fn test_unloadable_grammar_is_skipped() {
+        let mut parsers = HashMap::new();
+        parsers.insert("rust".to_string(), create_rust_parser().unwrap());
+        let grammars = HashMap::from([("zig".to_string(), "/nonexistent/libtree-sitter-zig.so".to_string())]);
+
+        load_custom_grammars(&mut parsers, &grammars);
+        assert!(!parsers.contains_key("zig"));
+        assert!(parsers.contains_key("rust"));
+        assert!(parse_file(Path::new("main.zig"), "const x = 1;", None, &mut parsers).unwrap().is_none());
+    }
+
+    // Needs a compiled grammar: SYNX_TEST_GRAMMAR=<language>=<library>,
+    // e.g. zig=/usr/local/lib/libtree-sitter-zig.so
+    #[test]
+    fn test_custom_grammar_is_loaded() {
+        let Ok(spec) = std::env::var("SYNX_TEST_GRAMMAR") else {
+            eprintln!("SYNX_TEST_GRAMMAR not set, skipping");
+            return;
+        };
+        let (language, library) = spec.split_once('=').expect("SYNX_TEST_GRAMMAR is <language>=<library>");
+        let mut parsers = HashMap::new();
+
+        load_custom_grammars(&mut parsers, &HashMap::from([(language.to_string(), library.to_string())]));
+        assert!(parsers.contains_key(&language.to_lowercase()));
+        let path = format!("sample.{}", language);
+        assert!(parse_file(Path::new(&path), "", None, &mut parsers).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_line_range() {
+        let content = "one\ntwo\nthree";
+        assert_eq!(line_range(content, 1, 1), (0, 4));
+        assert_eq!(line_range(content, 2, 3), (4, content.len()));
+        assert_eq!(line_range(content, 9, 9), (content.len(), content.len()));
+    }
+}
@@ -0,0 +1,121 @@
+//! Gradual adoption of strict mode
+//!
+//! `[adoption] strict_after = "2024-01-01"` validates files created on or
+//! after that date in strict mode and older files leniently, so a team can
+//! hold new code to the standard without fixing the whole backlog first.
+//! A file's creation date comes from the commit that added it, or from its
+//! modification time when git doesn't know it (or `source = "mtime"`).
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use anyhow::{Result, anyhow};
+use chrono::NaiveDate;
+
+use crate::config::AdoptionConfig;
+
+/// Where a file's creation date comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgeSource {
+    /// The commit that added the file, falling back to its modification time
+    Git,
+    /// The file's modification time
+    Mtime,
+}
+
+/// The configured source, defaulting to git
+pub fn age_source(config: &AdoptionConfig) -> Result<AgeSource> {
+    match config.source.as_deref().unwrap_or("git") {
+        "git" => Ok(AgeSource::Git),
+        "mtime" => Ok(AgeSource::Mtime),
+        other => Err(anyhow!("Invalid adoption source '{}' (expected \"git\" or \"mtime\")", other)),
+    }
+}
+
+/// Parse a `strict_after` date (YYYY-MM-DD) as midnight UTC
+pub fn parse_cutoff(date: &str) -> Result<SystemTime> {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|e| anyhow!("Invalid strict_after date '{}' (expected YYYY-MM-DD): {}", date, e))?;
+    let seconds = date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+    Ok(UNIX_EPOCH + Duration::from_secs(seconds.max(0) as u64))
+}
+
+/// The strictness `[adoption]` picks for a file, or `None` when there is no
+/// policy or the file's age can't be told
+pub fn strict_for(path: &Path, config: &AdoptionConfig) -> Option<bool> {
+    let cutoff = parse_cutoff(config.strict_after.as_deref()?).ok()?;
+    let source = age_source(config).ok()?;
+    let created = match source {
+        AgeSource::Git => git_added(path).or_else(|| modified(path)),
+        AgeSource::Mtime => modified(path),
+    }?;
+    Some(created >= cutoff)
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|metadata| metadata.modified()).ok()
+}
+
+/// When the file was first committed, if it's in a git repository's history
+fn git_added(path: &Path) -> Option<SystemTime> {
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let output = Command::new("git")
+        .arg("-C").arg(dir)
+        .args(["log", "--follow", "--diff-filter=A", "--format=%at", "--"])
+        .arg(path.file_name()?)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Newest first, so the last addition is the original one
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let seconds: u64 = stdout.lines().last()?.trim().parse().ok()?;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn mtime_policy(date: &str) -> AdoptionConfig {
+        AdoptionConfig {
+            strict_after: Some(date.to_string()),
+            source: Some("mtime".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_files_straddling_the_cutoff() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = dir.path().join("old.py");
+        let new = dir.path().join("new.py");
+        fs::write(&old, "x = 1\n").unwrap();
+        fs::write(&new, "x = 1\n").unwrap();
+        let cutoff = parse_cutoff("2024-01-01").unwrap();
+        fs::File::options().write(true).open(&old).unwrap()
+            .set_modified(cutoff - Duration::from_secs(86400)).unwrap();
+
+        let policy = mtime_policy("2024-01-01");
+        assert_eq!(strict_for(&old, &policy), Some(false));
+        assert_eq!(strict_for(&new, &policy), Some(true));
+    }
+
+    #[test]
+    fn test_no_policy_leaves_strictness_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("app.py");
+        fs::write(&file, "x = 1\n").unwrap();
+        assert_eq!(strict_for(&file, &AdoptionConfig::default()), None);
+    }
+
+    #[test]
+    fn test_config_parsing() {
+        assert_eq!(parse_cutoff("1970-01-02").unwrap(), UNIX_EPOCH + Duration::from_secs(86400));
+        assert!(parse_cutoff("01/02/2024").is_err());
+        assert_eq!(age_source(&AdoptionConfig::default()).unwrap(), AgeSource::Git);
+        let bad = AdoptionConfig { source: Some("ctime".to_string()), ..Default::default() };
+        assert!(age_source(&bad).is_err());
+    }
+}
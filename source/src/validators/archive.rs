@@ -0,0 +1,190 @@
+//! Scanning the contents of archives
+//!
+//! CI artifacts arrive as `.zip`, `.tar` or `.tar.gz` files. They are
+//! unpacked into a temporary directory and scanned like any other tree.
+//! Only regular files and directories are extracted. An entry whose path
+//! would land outside the target directory (`../`, an absolute path) fails
+//! the extraction instead of being written.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use anyhow::{Context, Result, anyhow, bail};
+use flate2::read::GzDecoder;
+use tempfile::TempDir;
+
+/// Archive formats `scan --from-archive` understands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveKind {
+    /// The format, from the file name
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveKind::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveKind::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// Unpack `archive` into a new temporary directory, removed when dropped
+pub fn extract_to_temp(archive: &Path) -> Result<TempDir> {
+    let dir = tempfile::Builder::new().prefix("synx-archive-").tempdir()?;
+    extract(archive, dir.path())?;
+    Ok(dir)
+}
+
+/// Unpack `archive` into `dest`
+pub fn extract(archive: &Path, dest: &Path) -> Result<()> {
+    let kind = ArchiveKind::from_path(archive)
+        .ok_or_else(|| anyhow!("Unsupported archive {} (expected .zip, .tar or .tar.gz)", archive.display()))?;
+    let file = File::open(archive).with_context(|| format!("Failed to open {}", archive.display()))?;
+    match kind {
+        ArchiveKind::Zip => extract_zip(file, dest),
+        ArchiveKind::Tar => extract_tar(file, dest),
+        ArchiveKind::TarGz => extract_tar(GzDecoder::new(file), dest),
+    }
+}
+
+fn extract_zip(file: File, dest: &Path) -> Result<()> {
+    let mut archive = zip::ZipArchive::new(file)?;
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        let Some(target) = entry_target(dest, Path::new(entry.name()))? else {
+            continue;
+        };
+        if entry.is_dir() {
+            fs::create_dir_all(&target)?;
+        } else if !is_zip_symlink(entry.unix_mode()) {
+            write_entry(&mut entry, &target)?;
+        }
+    }
+    Ok(())
+}
+
+// Zip stores symlinks as files holding the link target, marked by their mode
+fn is_zip_symlink(mode: Option<u32>) -> bool {
+    const S_IFMT: u32 = 0o170000;
+    const S_IFLNK: u32 = 0o120000;
+    mode.is_some_and(|mode| mode & S_IFMT == S_IFLNK)
+}
+
+fn extract_tar<R: io::Read>(reader: R, dest: &Path) -> Result<()> {
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        let Some(target) = entry_target(dest, &path)? else {
+            continue;
+        };
+        match entry.header().entry_type() {
+            tar::EntryType::Directory => fs::create_dir_all(&target)?,
+            tar::EntryType::Regular | tar::EntryType::Continuous => write_entry(&mut entry, &target)?,
+            // Links could point anywhere, and devices and fifos aren't source code
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn write_entry(entry: &mut impl io::Read, target: &Path) -> Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    io::copy(entry, &mut File::create(target)?)?;
+    Ok(())
+}
+
+/// Where an entry named `name` goes under `dest`: None for the archive root
+/// itself, an error if it would escape `dest`
+fn entry_target(dest: &Path, name: &Path) -> Result<Option<PathBuf>> {
+    let mut relative = PathBuf::new();
+    for component in name.components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                bail!("Archive entry {} points outside the archive", name.display());
+            }
+        }
+    }
+    Ok((!relative.as_os_str().is_empty()).then(|| dest.join(relative)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn zip_with(entries: &[(&str, &str)]) -> Result<(TempDir, PathBuf)> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("artifact.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&path)?);
+        for (name, content) in entries {
+            writer.start_file(*name, zip::write::FileOptions::default())?;
+            writer.write_all(content.as_bytes())?;
+        }
+        writer.finish()?;
+        Ok((dir, path))
+    }
+
+    #[test]
+    fn test_kind_from_name() {
+        assert_eq!(ArchiveKind::from_path(Path::new("build/out.ZIP")), Some(ArchiveKind::Zip));
+        assert_eq!(ArchiveKind::from_path(Path::new("out.tar.gz")), Some(ArchiveKind::TarGz));
+        assert_eq!(ArchiveKind::from_path(Path::new("out.tgz")), Some(ArchiveKind::TarGz));
+        assert_eq!(ArchiveKind::from_path(Path::new("out.tar")), Some(ArchiveKind::Tar));
+        assert_eq!(ArchiveKind::from_path(Path::new("out.rar")), None);
+    }
+
+    #[test]
+    fn test_entry_target_stays_inside() {
+        let dest = Path::new("/tmp/x");
+        assert_eq!(entry_target(dest, Path::new("./src/a.py")).unwrap(), Some(PathBuf::from("/tmp/x/src/a.py")));
+        assert_eq!(entry_target(dest, Path::new("./")).unwrap(), None);
+        assert!(entry_target(dest, Path::new("../evil.sh")).is_err());
+        assert!(entry_target(dest, Path::new("src/../../evil.sh")).is_err());
+        assert!(entry_target(dest, Path::new("/etc/passwd")).is_err());
+    }
+
+    #[test]
+    fn test_zip_slip_is_rejected() -> Result<()> {
+        let (_dir, archive) = zip_with(&[("ok.json", "{}"), ("../escaped.json", "{}")])?;
+        let dest = tempfile::tempdir()?;
+        let target = dest.path().join("inner");
+        fs::create_dir(&target)?;
+
+        let error = extract(&archive, &target).unwrap_err();
+        assert!(error.to_string().contains("outside the archive"));
+        assert!(!dest.path().join("escaped.json").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tar_gz_is_extracted() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("artifact.tar.gz");
+        let encoder = flate2::write::GzEncoder::new(File::create(&path)?, flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        let content = b"key: value\n";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        builder.append_data(&mut header, "config/app.yaml", &content[..])?;
+        builder.into_inner()?.finish()?;
+
+        let extracted = extract_to_temp(&path)?;
+        assert_eq!(fs::read(extracted.path().join("config/app.yaml"))?, content);
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Checkstyle XML reporter
+//!
+//! Many IDE and CI plugins understand Checkstyle's report format, so scan
+//! results can be handed to them as-is.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::analysis::IssueSeverity;
+use super::{ScanResult, ValidationError};
+
+/// Render a scan result as a Checkstyle XML report, one `<file>` per file with issues
+pub fn to_checkstyle(result: &ScanResult) -> String {
+    let mut files: BTreeMap<String, Vec<&ValidationError>> = BTreeMap::new();
+    for issue in &result.issues {
+        files.entry(issue.file_path.clone()).or_default().push(issue);
+    }
+    // Files that failed validation without detailed errors still need to show up
+    for path in &result.invalid_files {
+        files.entry(path.to_string_lossy().to_string()).or_default();
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    for (name, errors) in &files {
+        let _ = writeln!(xml, "  <file name=\"{}\">", escape(name));
+        if errors.is_empty() {
+            let _ = writeln!(
+                xml,
+                "    <error line=\"0\" severity=\"error\" message=\"File failed validation\" source=\"synx\"/>"
+            );
+        }
+        for error in errors {
+            let _ = write!(xml, "    <error line=\"{}\"", error.line.unwrap_or(0));
+            if let Some(column) = error.column {
+                let _ = write!(xml, " column=\"{}\"", column);
+            }
+            let _ = writeln!(
+                xml,
+                " severity=\"{}\" message=\"{}\" source=\"{}\"/>",
+                severity(error.severity),
+                escape(&error.message),
+                escape(&source(error)),
+            );
+        }
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</checkstyle>\n");
+    xml
+}
+
+fn severity(severity: IssueSeverity) -> &'static str {
+    match severity {
+        IssueSeverity::Critical | IssueSeverity::High => "error",
+        IssueSeverity::Medium => "warning",
+        IssueSeverity::Low => "info",
+    }
+}
+
+// The tool's own rule code when it gave one, otherwise the kind of check
+fn source(error: &ValidationError) -> String {
+    match &error.code {
+        Some(code) => format!("synx.{}", code),
+        None => format!("synx.{:?}", error.error_type),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+    use crate::validators::ErrorType;
+
+    fn error(file_path: &str, line: usize, error_type: ErrorType, message: &str) -> ValidationError {
+        ValidationError {
+            file_path: file_path.to_string(),
+            severity: error_type.default_severity(),
+            error_type,
+            message: message.to_string(),
+            line: Some(line),
+            column: None,
+            code: None,
+            suggestion: None,
+        }
+    }
+
+    #[test]
+    fn test_errors_grouped_by_file() {
+        let result = ScanResult {
+            total_files: 3,
+            valid_files: 1,
+            valid_file_paths: Vec::new(),
+            invalid_files: vec![PathBuf::from("src/a.py"), PathBuf::from("src/b.rs")],
+            skipped_files: Vec::new(),
+            results_by_type: BTreeMap::new(),
+            issues: vec![
+                error("src/a.py", 3, ErrorType::SyntaxError, "invalid syntax"),
+                error("src/b.rs", 7, ErrorType::Complexity, "Function 'run' is \"too\" <complex>"),
+                error("src/a.py", 9, ErrorType::Lint, "unused import"),
+            ],
+            cached_files: 0,
+            cache_misses: 0,
+            suppressed_issues: 0,
+            hidden_issues: 0,
+        };
+
+        let xml = to_checkstyle(&result);
+
+        assert_eq!(xml.matches("<file ").count(), 2);
+        assert_eq!(xml.matches("<error ").count(), 3);
+        assert_eq!(xml.matches("<file name=\"src/a.py\">").count(), 1);
+
+        // Both errors for a.py sit inside its single <file> element
+        let a_start = xml.find("<file name=\"src/a.py\">").unwrap();
+        let a_end = a_start + xml[a_start..].find("</file>").unwrap();
+        let a_file = &xml[a_start..a_end];
+        assert_eq!(a_file.matches("<error ").count(), 2);
+        assert!(a_file.contains("line=\"3\" severity=\"error\" message=\"invalid syntax\" source=\"synx.SyntaxError\""));
+        assert!(a_file.contains("line=\"9\" severity=\"info\""));
+
+        assert!(xml.contains("severity=\"warning\" message=\"Function &apos;run&apos; is &quot;too&quot; &lt;complex&gt;\""));
+    }
+
+    #[test]
+    fn test_invalid_file_without_details_is_reported() {
+        let result = ScanResult {
+            total_files: 1,
+            valid_files: 0,
+            valid_file_paths: Vec::new(),
+            invalid_files: vec![PathBuf::from("broken.json")],
+            skipped_files: Vec::new(),
+            results_by_type: BTreeMap::new(),
+            issues: Vec::new(),
+            cached_files: 0,
+            cache_misses: 0,
+            suppressed_issues: 0,
+            hidden_issues: 0,
+        };
+
+        let xml = to_checkstyle(&result);
+        assert!(xml.contains("<file name=\"broken.json\">"));
+        assert_eq!(xml.matches("<error ").count(), 1);
+    }
+}
@@ -0,0 +1,92 @@
+//! Complexity budget enforcement
+//!
+//! Measures every function with the intelligence `MetricsAnalyzer` and
+//! reports those whose cyclomatic or cognitive complexity is over the
+//! limits in the `[complexity]` config section.
+
+use std::fs;
+use std::path::Path;
+use anyhow::{Result, Context};
+
+use crate::config::ComplexityConfig;
+use crate::intelligence::metrics::{FunctionMetrics, MetricsAnalyzer};
+use super::{ValidationError, ErrorType};
+
+/// Whether the complexity pass is switched on
+pub fn is_enabled(config: &ComplexityConfig) -> bool {
+    config.enabled.unwrap_or(false)
+}
+
+/// Report every function in the file that is over the configured budget
+pub fn check_complexity(file_path: &Path, config: &ComplexityConfig) -> Result<Vec<ValidationError>> {
+    let content = fs::read_to_string(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+    let metrics = MetricsAnalyzer::new().analyze_file(file_path, &content)?;
+
+    // Thresholds left out of the config fall back to the defaults
+    let defaults = ComplexityConfig::default();
+    let max_cyclomatic = config.max_cyclomatic.or(defaults.max_cyclomatic);
+    let max_cognitive = config.max_cognitive.or(defaults.max_cognitive);
+
+    let mut errors = Vec::new();
+    for function in &metrics.functions {
+        let checks = [
+            ("cyclomatic", function.cyclomatic_complexity, max_cyclomatic),
+            ("cognitive", function.cognitive_complexity, max_cognitive),
+        ];
+        for (kind, value, max) in checks {
+            if let Some(max) = max.filter(|&max| value > max) {
+                errors.push(over_budget(file_path, function, kind, value, max));
+            }
+        }
+    }
+
+    Ok(errors)
+}
+
+fn over_budget(file_path: &Path, function: &FunctionMetrics, kind: &str, value: usize, max: usize) -> ValidationError {
+    ValidationError {
+        file_path: file_path.to_string_lossy().to_string(),
+        error_type: ErrorType::Complexity,
+        message: format!("Function '{}' has {} complexity {} (max {})", function.name, kind, value, max),
+        line: Some(function.start_line),
+        column: None,
+        code: None,
+        suggestion: Some(format!("Split '{}' into smaller functions", function.name)),
+        severity: ErrorType::Complexity.default_severity(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files/python/metrics").join(name)
+    }
+
+    fn budget(max_cyclomatic: usize, max_cognitive: usize) -> ComplexityConfig {
+        ComplexityConfig {
+            enabled: Some(true),
+            max_cyclomatic: Some(max_cyclomatic),
+            max_cognitive: Some(max_cognitive),
+        }
+    }
+
+    #[test]
+    fn test_function_over_budget_is_reported() {
+        let errors = check_complexity(&fixture("functions.py"), &budget(5, 100)).unwrap();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_type, ErrorType::Complexity);
+        assert!(errors[0].message.contains("'classify'"));
+        assert!(errors[0].message.contains("cyclomatic"));
+        assert!(errors[0].line.is_some());
+    }
+
+    #[test]
+    fn test_generous_budget_passes() {
+        assert!(check_complexity(&fixture("functions.py"), &budget(50, 100)).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+//! User-defined validators from `[validators.custom.<type>]` config sections
+//!
+//! `command`, `args` and `strict_args` may reference environment variables
+//! (`$HOME`, `${CI_PROJECT_DIR}`) and the placeholders `{file}`, `{dir}` and
+//! `{ext}`. If no argument mentions `{file}`, the file path is appended.
+//! The command runs from the file's project root unless `working_dir`
+//! (interpolated the same way) says otherwise.
+
+use std::env;
+use std::ffi::OsString;
+use std::path::Path;
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::config::CustomValidatorConfig;
+use super::{output_limit, project, run_with_timeout, validator_timeout, ValidationOptions, ValidationResult};
+
+/// Expand environment variables and `{file}`/`{dir}`/`{ext}` in one config value.
+/// Unset variables and unknown placeholders are left as written, with a warning.
+pub fn interpolate(text: &str, file_path: &Path) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['$', '{']) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let (replacement, consumed) = if rest.starts_with("$$") {
+            (Some("$".to_string()), 2)
+        } else if rest.starts_with('$') {
+            expand_variable(rest)
+        } else {
+            expand_placeholder(rest, file_path)
+        };
+
+        match replacement {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[..consumed]),
+        }
+        rest = &rest[consumed..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+// `$NAME` or `${NAME}` at the start of `text`: the value and how much was read
+fn expand_variable(text: &str) -> (Option<String>, usize) {
+    let (name, consumed) = match text[1..].strip_prefix('{') {
+        Some(braced) => match braced.find('}') {
+            Some(end) => (&braced[..end], end + 3),
+            None => return (None, 1),
+        },
+        None => {
+            let len = identifier_len(&text[1..]);
+            (&text[1..1 + len], len + 1)
+        }
+    };
+
+    if name.is_empty() {
+        return (None, 1);
+    }
+    match env::var(name) {
+        Ok(value) => (Some(value), consumed),
+        Err(_) => {
+            eprintln!("Warning: environment variable '{}' is not set; leaving '{}' as is", name, &text[..consumed]);
+            (None, consumed)
+        }
+    }
+}
+
+// `{name}` at the start of `text`; braces around anything but an identifier are just text
+fn expand_placeholder(text: &str, file_path: &Path) -> (Option<String>, usize) {
+    let len = identifier_len(&text[1..]);
+    if len == 0 || !text[1 + len..].starts_with('}') {
+        return (None, 1);
+    }
+
+    let value = match &text[1..1 + len] {
+        "file" => file_path.to_string_lossy().to_string(),
+        "dir" => file_path.parent().unwrap_or(Path::new(".")).to_string_lossy().to_string(),
+        "ext" => file_path.extension().unwrap_or_default().to_string_lossy().to_string(),
+        _ => {
+            eprintln!("Warning: unknown placeholder '{}' in custom validator config", &text[..len + 2]);
+            return (None, len + 2);
+        }
+    };
+    (Some(value), len + 2)
+}
+
+fn identifier_len(text: &str) -> usize {
+    text.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(text.len())
+}
+
+/// The program and arguments to run for a file, with everything interpolated.
+/// A bare `{file}` or `{dir}` argument is passed as the path itself, so paths
+/// that aren't valid UTF-8 reach the tool intact.
+pub fn build_command(config: &CustomValidatorConfig, file_path: &Path, strict: bool) -> (String, Vec<OsString>) {
+    let mut templates: Vec<&String> = config.args.iter().flatten().collect();
+    if strict {
+        templates.extend(config.strict_args.iter().flatten());
+    }
+
+    let dir = file_path.parent().unwrap_or(Path::new("."));
+    let mut args: Vec<OsString> = templates.iter()
+        .map(|arg| match arg.as_str() {
+            "{file}" => file_path.as_os_str().to_owned(),
+            "{dir}" => dir.as_os_str().to_owned(),
+            _ => interpolate(arg, file_path).into(),
+        })
+        .collect();
+    if !templates.iter().any(|arg| arg.contains("{file}")) {
+        args.push(file_path.as_os_str().to_owned());
+    }
+
+    (interpolate(&config.command, file_path), args)
+}
+
+/// Run a custom validator. With a `success_pattern`, the file passes when the
+/// pattern matches the tool's output; otherwise the exit status decides.
+pub fn validate_custom(
+    name: &str,
+    config: &CustomValidatorConfig,
+    file_path: &Path,
+    options: &ValidationOptions,
+) -> Result<ValidationResult> {
+    let (program, args) = build_command(config, file_path, options.strict);
+    if options.verbose {
+        eprintln!("Validating {} with custom validator '{}': {} {}", file_path.display(), name, program,
+            args.iter().map(|arg| arg.to_string_lossy()).collect::<Vec<_>>().join(" "));
+    }
+
+    let mut cmd = std::process::Command::new(&program);
+    cmd.args(&args);
+    match &config.working_dir {
+        Some(dir) => {
+            let dir = interpolate(dir, file_path);
+            // A missing directory would otherwise be reported as a missing tool
+            if !Path::new(&dir).is_dir() {
+                return Err(anyhow!("working_dir '{}' of custom validator '{}' is not a directory", dir, name));
+            }
+            cmd.current_dir(dir);
+        }
+        None => project::run_from_project_root(&mut cmd, file_path),
+    }
+    let output = run_with_timeout(&mut cmd, validator_timeout(name, options), output_limit(options))?;
+
+    let success = match &config.success_pattern {
+        Some(pattern) => {
+            let pattern = Regex::new(pattern)
+                .map_err(|e| anyhow!("Invalid success_pattern for custom validator '{}': {}", name, e))?;
+            let text = format!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+            pattern.is_match(&text)
+        }
+        None => output.status.success(),
+    };
+
+    Ok(ValidationResult::from_output(file_path, name, success, &output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(command: &str, args: &[&str]) -> CustomValidatorConfig {
+        CustomValidatorConfig {
+            command: command.to_string(),
+            args: Some(args.iter().map(|arg| arg.to_string()).collect()),
+            strict_args: None,
+            success_pattern: None,
+            working_dir: None,
+        }
+    }
+
+    #[test]
+    fn test_environment_variables_are_expanded() {
+        env::set_var("SYNX_TEST_PROJECT_DIR", "/work/project");
+        let file = Path::new("src/app.lua");
+
+        assert_eq!(interpolate("${SYNX_TEST_PROJECT_DIR}/bin/luacheck", file), "/work/project/bin/luacheck");
+        assert_eq!(interpolate("--root=$SYNX_TEST_PROJECT_DIR", file), "--root=/work/project");
+        assert_eq!(interpolate("cost: $$5", file), "cost: $5");
+        // Unset variables stay as written
+        assert_eq!(interpolate("$SYNX_TEST_UNSET_VARIABLE/x", file), "$SYNX_TEST_UNSET_VARIABLE/x");
+    }
+
+    #[test]
+    fn test_file_placeholders() {
+        let file = Path::new("src/app.lua");
+
+        assert_eq!(interpolate("--input={file}", file), "--input=src/app.lua");
+        assert_eq!(interpolate("{dir}/.luacheckrc", file), "src/.luacheckrc");
+        assert_eq!(interpolate("--lang={ext}", file), "--lang=lua");
+        // Unknown placeholders and non-placeholder braces are left alone
+        assert_eq!(interpolate("{line}:{}", file), "{line}:{}");
+        assert_eq!(interpolate(r#"{"strict": true}"#, file), r#"{"strict": true}"#);
+    }
+
+    #[test]
+    fn test_file_argument_placement() {
+        let file = Path::new("src/app.lua");
+
+        // {file} mid-list goes exactly where it was written
+        let (program, args) = build_command(&custom("luacheck", &["{file}", "--formatter", "plain"]), file, false);
+        assert_eq!(program, "luacheck");
+        assert_eq!(args, vec!["src/app.lua", "--formatter", "plain"]);
+
+        // Without {file} the path is appended
+        let (_, args) = build_command(&custom("luacheck", &["--formatter", "plain"]), file, false);
+        assert_eq!(args, vec!["--formatter", "plain", "src/app.lua"]);
+    }
+}
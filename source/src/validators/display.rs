@@ -1,42 +1,96 @@
 use colored::*;
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use super::scan::ScanResult;
-use console::{style, Emoji};
+use super::ValidationError;
+use crate::analysis::IssueSeverity;
+use console::style;
+use crate::glyphs::Glyph;
 
-static CHECK_MARK: Emoji<'_, '_> = Emoji("✓", "√");
-static CROSS_MARK: Emoji<'_, '_> = Emoji("✗", "x");
-static WARN_MARK: Emoji<'_, '_> = Emoji("⚠", "!");
-static FILE_MARK: Emoji<'_, '_> = Emoji("📄", "-");
-static FOLDER_MARK: Emoji<'_, '_> = Emoji("📁", "+");
-static SEARCH_MARK: Emoji<'_, '_> = Emoji("🔍", ">");
+static CHECK_MARK: Glyph = Glyph::new("✓", "+");
+static CROSS_MARK: Glyph = Glyph::new("✗", "x");
+static WARN_MARK: Glyph = Glyph::new("⚠", "!");
+static FILE_MARK: Glyph = Glyph::new("📄", "-");
+static FOLDER_MARK: Glyph = Glyph::new("📁", "+");
+static SEARCH_MARK: Glyph = Glyph::new("🔍", ">");
 
+/// Files with more issues than this only show their worst few
+const COLLAPSE_AFTER: usize = 5;
+/// Issues shown for a collapsed file
+const COLLAPSED_SHOWN: usize = 3;
+
+const SEVERITIES: [IssueSeverity; 4] =
+    [IssueSeverity::Critical, IssueSeverity::High, IssueSeverity::Medium, IssueSeverity::Low];
+
+fn severity_colored(severity: IssueSeverity, text: String) -> ColoredString {
+    match severity {
+        IssueSeverity::Critical => text.red().bold(),
+        IssueSeverity::High => text.red(),
+        IssueSeverity::Medium => text.yellow(),
+        IssueSeverity::Low => text.blue(),
+    }
+}
+
+/// How a path appears in reports: relative to `base` when it is inside it,
+/// otherwise absolute
+pub fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    if let Ok(relative) = path.strip_prefix(base) {
+        return relative.to_path_buf();
+    }
+    // The two may be spelled differently, e.g. `./src/a.py` against `/home/me/src`
+    match (path.canonicalize(), base.canonicalize()) {
+        (Ok(path), Ok(base)) => path.strip_prefix(&base).map(Path::to_path_buf).unwrap_or(path),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Print a scan summary. Paths are shown as they are in the result, so
+/// shorten them with `ScanResult::relative_to` first.
 pub fn display_scan_results(result: &ScanResult, root_dir: &Path) {
-    println!("\n{} {} Scan Results for: {}", 
+    let _ = write_scan_results(result, &root_dir.display().to_string(), &mut io::stdout());
+}
+
+/// Write the scan summary `display_scan_results` prints, headed with `title`
+pub fn write_scan_results(result: &ScanResult, title: &str, w: &mut dyn Write) -> io::Result<()> {
+    writeln!(w, "\n{} {} Scan Results for: {}", 
         SEARCH_MARK,
         "Directory".bright_blue().bold(),
-        root_dir.display().to_string().bright_white().underline()
-    );
+        title.bright_white().underline()
+    )?;
 
-    println!("\n{} Summary:", FOLDER_MARK);
-    println!("  {} Total Files:    {}", 
+    writeln!(w, "\n{} Summary:", FOLDER_MARK)?;
+    writeln!(w, "  {} Total Files:    {}", 
         FILE_MARK,
         result.total_files.to_string().bright_white()
-    );
-    println!("  {} Valid Files:    {}", 
+    )?;
+    writeln!(w, "  {} Valid Files:    {}", 
         CHECK_MARK,
         result.valid_files.to_string().green()
-    );
-    println!("  {} Invalid Files:  {}", 
+    )?;
+    writeln!(w, "  {} Invalid Files:  {}", 
         CROSS_MARK,
         result.invalid_files.len().to_string().red()
-    );
-    println!("  {} Skipped Files:  {}", 
+    )?;
+    writeln!(w, "  {} Skipped Files:  {}", 
         WARN_MARK,
         result.skipped_files.len().to_string().yellow()
-    );
+    )?;
+    if result.suppressed_issues > 0 {
+        writeln!(w, "  {} Suppressed:     {}",
+            WARN_MARK,
+            result.suppressed_issues.to_string().bright_black()
+        )?;
+    }
+    if result.hidden_issues > 0 {
+        writeln!(w, "  {} Not shown:      {} (over max_issues_per_file)",
+            WARN_MARK,
+            result.hidden_issues.to_string().yellow()
+        )?;
+    }
 
     if !result.results_by_type.is_empty() {
-        println!("\n{} Results by File Type:", FOLDER_MARK);
+        writeln!(w, "\n{} Results by File Type:", FOLDER_MARK)?;
         for (ext, type_result) in &result.results_by_type {
             let success_rate = (type_result.valid as f32 / type_result.total as f32 * 100.0) as i32;
             let _status_color = match success_rate {
@@ -45,37 +99,38 @@ pub fn display_scan_results(result: &ScanResult, root_dir: &Path) {
                 _ => "red"
             };
             
-            println!("  {} .{:<8} [{:>3}%] {} valid, {} total", 
+            writeln!(w, "  {} .{:<8} [{:>3}%] {} valid, {} total", 
                 FILE_MARK,
                 ext,
                 style(format!("{}", success_rate)).to_string().as_str(),
                 type_result.valid.to_string().green(),
                 type_result.total
-            );
+            )?;
         }
     }
 
     if !result.invalid_files.is_empty() {
-        println!("\n{} Invalid Files:", CROSS_MARK);
+        writeln!(w, "\n{} Invalid Files:", CROSS_MARK)?;
         for file in &result.invalid_files {
-            if let Some(relative) = file.strip_prefix(root_dir).ok() {
-                println!("  {} {}", 
-                    CROSS_MARK,
-                    relative.display().to_string().red()
-                );
-            }
+            writeln!(w, "  {} {}", 
+                CROSS_MARK,
+                file.display().to_string().red()
+            )?;
         }
     }
 
+    if !result.issues.is_empty() {
+        write_issues(result, w)?;
+    }
+
     if !result.skipped_files.is_empty() {
-        println!("\n{} Skipped Files:", WARN_MARK);
-        for file in &result.skipped_files {
-            if let Some(relative) = file.strip_prefix(root_dir).ok() {
-                println!("  {} {}", 
-                    WARN_MARK,
-                    relative.display().to_string().yellow()
-                );
-            }
+        writeln!(w, "\n{} Skipped Files:", WARN_MARK)?;
+        for (file, reason) in &result.skipped_files {
+            writeln!(w, "  {} {} {}", 
+                WARN_MARK,
+                file.display().to_string().yellow(),
+                format!("({})", reason).bright_black()
+            )?;
         }
     }
 
@@ -86,6 +141,113 @@ pub fn display_scan_results(result: &ScanResult, root_dir: &Path) {
         "FAILED".red().bold()
     };
     
-    println!("\n{} Final Status: {}", FOLDER_MARK, status);
-    println!("{}", "=".repeat(60).bright_black());
+    writeln!(w, "\n{} Final Status: {}", FOLDER_MARK, status)?;
+    writeln!(w, "{}", "=".repeat(60).bright_black())?;
+    Ok(())
+}
+
+/// Issues grouped by file, worst first: files by their most severe issue,
+/// and a file's issues by severity, then line
+fn write_issues(result: &ScanResult, w: &mut dyn Write) -> io::Result<()> {
+    let counts: Vec<String> = SEVERITIES.iter()
+        .map(|&severity| (severity, result.issues.iter().filter(|issue| issue.severity == severity).count()))
+        .filter(|&(_, count)| count > 0)
+        .map(|(severity, count)| severity_colored(severity, format!("{} {}", count, severity)).to_string())
+        .collect();
+    writeln!(w, "\n{} Issues: {}", WARN_MARK, counts.join(", "))?;
+
+    let mut by_file: BTreeMap<&str, Vec<&ValidationError>> = BTreeMap::new();
+    for issue in &result.issues {
+        by_file.entry(issue.file_path.as_str()).or_default().push(issue);
+    }
+    for issues in by_file.values_mut() {
+        issues.sort_by(|a, b| b.severity.cmp(&a.severity)
+            .then(a.line.unwrap_or(0).cmp(&b.line.unwrap_or(0)))
+            .then(a.column.unwrap_or(0).cmp(&b.column.unwrap_or(0))));
+    }
+    let mut files: Vec<(&str, Vec<&ValidationError>)> = by_file.into_iter().collect();
+    // Stable, so files with equally bad issues stay in path order
+    files.sort_by(|(_, a), (_, b)| b[0].severity.cmp(&a[0].severity));
+
+    for (file, issues) in files {
+        let noun = if issues.len() == 1 { "issue" } else { "issues" };
+        writeln!(w, "  {} {} ({} {})", FILE_MARK, file.bright_white(), issues.len(), noun)?;
+
+        let shown = if issues.len() > COLLAPSE_AFTER { COLLAPSED_SHOWN } else { issues.len() };
+        for issue in &issues[..shown] {
+            let location = match (issue.line, issue.column) {
+                (Some(line), Some(column)) => format!("{}:{} ", line, column),
+                (Some(line), None) => format!("{} ", line),
+                _ => String::new(),
+            };
+            writeln!(w, "    {} [{}] {}{}",
+                issue.error_type.emoji(),
+                severity_colored(issue.severity, issue.severity.to_string()),
+                location.bright_black(),
+                issue.message
+            )?;
+        }
+        if shown < issues.len() {
+            writeln!(w, "    {}", format!("... and {} more", issues.len() - shown).bright_black())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators::ErrorType;
+
+    fn issue(file: &str, severity: IssueSeverity, line: usize, message: &str) -> ValidationError {
+        ValidationError {
+            file_path: file.to_string(),
+            error_type: ErrorType::Lint,
+            message: message.to_string(),
+            line: Some(line),
+            column: None,
+            code: None,
+            suggestion: None,
+            severity,
+        }
+    }
+
+    fn render(result: &ScanResult) -> String {
+        let mut output = Vec::new();
+        write_scan_results(result, "project", &mut output).unwrap();
+        console::strip_ansi_codes(&String::from_utf8(output).unwrap()).to_string()
+    }
+
+    #[test]
+    fn test_issues_are_listed_worst_first() {
+        let result = ScanResult {
+            issues: vec![
+                issue("a.py", IssueSeverity::Low, 1, "trailing whitespace"),
+                issue("b.py", IssueSeverity::Medium, 7, "unused import"),
+                issue("a.py", IssueSeverity::Critical, 20, "syntax error"),
+                issue("a.py", IssueSeverity::Low, 3, "line too long"),
+            ],
+            ..Default::default()
+        };
+        let output = render(&result);
+
+        let position = |text: &str| output.find(text).unwrap_or_else(|| panic!("missing {:?} in:\n{}", text, output));
+        assert!(position("syntax error") < position("trailing whitespace"));
+        assert!(position("trailing whitespace") < position("line too long"));
+        assert!(position("a.py (3 issues)") < position("b.py (1 issue)"));
+        assert!(output.contains("Issues: 1 Critical, 1 Medium, 2 Low"));
+    }
+
+    #[test]
+    fn test_files_with_many_issues_are_collapsed() {
+        let result = ScanResult {
+            issues: (1..=8).map(|line| issue("big.py", IssueSeverity::Medium, line, &format!("problem {}", line))).collect(),
+            ..Default::default()
+        };
+        let output = render(&result);
+
+        assert!(output.contains("problem 3"));
+        assert!(!output.contains("problem 4"));
+        assert!(output.contains("... and 5 more"));
+    }
 }
@@ -0,0 +1,244 @@
+//! Validation for `.env` files
+//!
+//! There's no standard tool for `.env` files, so they're checked here. Each
+//! line is blank, a `#` comment or `KEY=VALUE` (optionally after `export`).
+//! Spaces around `=`, unquoted values that a shell would split or expand,
+//! unterminated quotes and duplicate keys fail the file. Values of keys that
+//! look like secrets get a warning, since a committed `.env` leaks them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use anyhow::{Result, Context};
+
+use super::{ValidationOptions, ValidationResult, ValidationError, ErrorType, display_validation_errors};
+
+/// Key suffixes that usually hold credentials
+const SECRET_SUFFIXES: &[&str] = &["_SECRET", "_TOKEN", "_PASSWORD", "_API_KEY", "_PRIVATE_KEY"];
+
+/// Characters a shell would split on or treat specially in an unquoted value
+const SPECIAL_CHARS: &[char] = &[' ', '\t', '"', '\'', '`', ';', '&', '|', '<', '>', '(', ')'];
+
+pub fn validate_dotenv(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let errors = check_dotenv(file_path)?;
+    if !errors.is_empty() && options.verbose {
+        let _ = display_validation_errors(&errors);
+    }
+    // Secret warnings only fail the file in strict mode
+    let success = errors.iter().all(|error| error.error_type == ErrorType::Warning && !options.strict);
+    Ok(ValidationResult { success, errors, suppressed: 0, hidden_issues: 0 })
+}
+
+/// Check a `.env` file, returning every problem found
+pub fn check_dotenv(file_path: &Path) -> Result<Vec<ValidationError>> {
+    let content = fs::read_to_string(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+    let path = file_path.to_string_lossy();
+    // Templates hold placeholders, not real secrets
+    let template = file_path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| [".example", ".sample", ".template"].iter().any(|suffix| name.ends_with(suffix)));
+
+    Ok(check_content(&content, template)
+        .into_iter()
+        .map(|issue| issue.into_error(&path))
+        .collect())
+}
+
+// One problem on one line, before it's tied to a file
+#[derive(Debug)]
+struct Issue {
+    error_type: ErrorType,
+    line: usize,
+    column: usize,
+    message: String,
+    suggestion: Option<String>,
+}
+
+impl Issue {
+    fn new(error_type: ErrorType, line: usize, column: usize, message: String) -> Self {
+        Self { error_type, line, column, message, suggestion: None }
+    }
+
+    fn suggest(mut self, suggestion: &str) -> Self {
+        self.suggestion = Some(suggestion.to_string());
+        self
+    }
+
+    fn into_error(self, file_path: &str) -> ValidationError {
+        ValidationError {
+            file_path: file_path.to_string(),
+            severity: self.error_type.default_severity(),
+            error_type: self.error_type,
+            message: self.message,
+            line: Some(self.line),
+            column: Some(self.column),
+            code: None,
+            suggestion: self.suggestion,
+        }
+    }
+}
+
+fn check_content(content: &str, template: bool) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    // Where each key was first set
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+
+    for (index, text) in content.lines().enumerate() {
+        let line = index + 1;
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = text.len() - trimmed.len();
+        let (offset, assignment) = match trimmed.strip_prefix("export ") {
+            Some(rest) => (indent + trimmed.len() - rest.len(), rest),
+            None => (indent, trimmed),
+        };
+
+        let Some((raw_key, raw_value)) = assignment.split_once('=') else {
+            issues.push(Issue::new(ErrorType::SyntaxError, line, offset + 1,
+                format!("Expected KEY=VALUE, found '{}'", assignment.trim_end())));
+            continue;
+        };
+        // Where the value starts, counting from 0
+        let value_start = offset + raw_key.len() + 1;
+
+        if raw_key.ends_with(char::is_whitespace) {
+            issues.push(Issue::new(ErrorType::SyntaxError, line, offset + raw_key.trim_end().len() + 1, "Spaces around '='".to_string())
+                .suggest("Write KEY=VALUE with no spaces around '='"));
+        } else if raw_value.starts_with(char::is_whitespace) {
+            issues.push(Issue::new(ErrorType::SyntaxError, line, value_start + 1, "Spaces around '='".to_string())
+                .suggest("Write KEY=VALUE with no spaces around '='"));
+        }
+
+        let key = raw_key.trim();
+        if !is_valid_key(key) {
+            issues.push(Issue::new(ErrorType::SyntaxError, line, offset + 1,
+                format!("Invalid key '{}'; keys are letters, digits and underscores, not starting with a digit", key)));
+            continue;
+        }
+        match seen.get(key) {
+            Some(first) => issues.push(Issue::new(ErrorType::Lint, line, offset + 1,
+                format!("Duplicate key {} (first set on line {})", key, first))
+                .suggest("Remove one of the assignments; which one wins depends on the loader")),
+            None => {
+                seen.insert(key, line);
+            }
+        }
+
+        let value = raw_value.trim_start();
+        let value_column = value_start + raw_value.len() - value.len() + 1;
+        let value = match check_value(value) {
+            Ok(value) => value,
+            Err((column, message, suggestion)) => {
+                issues.push(Issue::new(ErrorType::SyntaxError, line, value_column + column, message).suggest(suggestion));
+                continue;
+            }
+        };
+
+        if !template && is_secret_key(key) && !value.is_empty() && !is_reference(value) {
+            issues.push(Issue::new(ErrorType::Warning, line, offset + 1,
+                format!("{} looks like a secret committed in plaintext", key))
+                .suggest("Keep real secrets out of version control, e.g. in a .env.example with placeholders"));
+        }
+    }
+
+    issues
+}
+
+/// The value without quotes or a trailing comment, or where and why it's malformed
+fn check_value(value: &str) -> std::result::Result<&str, (usize, String, &'static str)> {
+    let value = value.trim_end();
+    if let Some(quote) = value.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let Some(end) = value[1..].find(quote) else {
+            return Err((0, format!("Unterminated {} quote", if quote == '"' { "double" } else { "single" }),
+                "Close the quote on the same line"));
+        };
+        let rest = value[end + 2..].trim_start();
+        if !rest.is_empty() && !rest.starts_with('#') {
+            return Err((value.len() - rest.len(), format!("Unexpected '{}' after the quoted value", rest),
+                "Put the whole value inside the quotes"));
+        }
+        return Ok(&value[1..end + 1]);
+    }
+
+    // ` #` starts a comment after an unquoted value
+    let value = value.split_once(" #").map_or(value, |(value, _)| value).trim_end();
+    match value.find(SPECIAL_CHARS) {
+        Some(position) => Err((position, "Unquoted value contains spaces or special characters".to_string(),
+            "Wrap the value in quotes")),
+        None => Ok(value),
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_ascii_uppercase();
+    ["SECRET", "TOKEN", "PASSWORD"].contains(&key.as_str())
+        || SECRET_SUFFIXES.iter().any(|suffix| key.ends_with(suffix))
+}
+
+// `${OTHER}` just points at another variable
+fn is_reference(value: &str) -> bool {
+    value.starts_with("${") && value.ends_with('}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture(path: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files/dotenv").join(path)
+    }
+
+    #[test]
+    fn test_valid_dotenv() {
+        let errors = check_dotenv(&fixture("valid/.env")).unwrap();
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_malformed_dotenv() {
+        let errors = check_dotenv(&fixture("invalid/.env")).unwrap();
+        let found: Vec<_> = errors.iter().map(|e| (e.line.unwrap(), e.error_type.clone())).collect();
+        assert_eq!(found, vec![
+            (2, ErrorType::SyntaxError),
+            (3, ErrorType::SyntaxError),
+            (4, ErrorType::SyntaxError),
+            (5, ErrorType::Lint),
+            (6, ErrorType::SyntaxError),
+            (7, ErrorType::Warning),
+        ], "{:?}", errors);
+    }
+
+    #[test]
+    fn test_positions() {
+        let issues = check_content("export PORT = 80\n", false);
+        assert_eq!((issues[0].line, issues[0].column), (1, 12));
+
+        let issues = check_content("NAME=my app\n", false);
+        assert_eq!((issues[0].line, issues[0].column), (1, 8));
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(check_value("\"a b\" # note"), Ok("a b"));
+        assert_eq!(check_value("plain # note"), Ok("plain"));
+        assert!(check_value("'open").is_err());
+        assert!(check_value("\"a\"b").is_err());
+    }
+
+    #[test]
+    fn test_templates_dont_warn_about_secrets() {
+        assert_eq!(check_content("API_TOKEN=abc123\n", false).len(), 1);
+        assert!(check_content("API_TOKEN=abc123\n", true).is_empty());
+        assert!(check_content("API_TOKEN=${VAULT_TOKEN}\n", false).is_empty());
+    }
+}
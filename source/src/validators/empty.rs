@@ -0,0 +1,117 @@
+//! Empty file handling
+//!
+//! Tools disagree about empty files: some accept them, others complain about
+//! a missing module, element or document. The `[scan] empty_files` policy
+//! settles it before any tool runs, the same way for every language. Files
+//! holding nothing but whitespace count as empty.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use anyhow::{Result, anyhow};
+
+use super::{ValidationError, ValidationResult, ErrorType};
+use crate::analysis::IssueSeverity;
+
+/// What happens to an empty file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyFilePolicy {
+    /// Leave it out, as if it weren't there
+    Skip,
+    /// Count it as valid without running a tool
+    Pass,
+    /// Count it as invalid
+    Fail,
+}
+
+/// The configured policy, defaulting to pass
+pub fn policy(name: Option<&str>) -> Result<EmptyFilePolicy> {
+    match name.unwrap_or("pass") {
+        "skip" => Ok(EmptyFilePolicy::Skip),
+        "pass" => Ok(EmptyFilePolicy::Pass),
+        "fail" => Ok(EmptyFilePolicy::Fail),
+        other => Err(anyhow!("Invalid [scan] empty_files '{}' (expected \"skip\", \"pass\" or \"fail\")", other)),
+    }
+}
+
+/// Whether the file has no content other than whitespace
+pub fn is_empty(path: &Path) -> Result<bool> {
+    let mut file = File::open(path)?;
+    let mut buffer = [0u8; 8192];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(true);
+        }
+        if !buffer[..read].iter().all(u8::is_ascii_whitespace) {
+            return Ok(false);
+        }
+    }
+}
+
+/// The verdict on an empty file under `policy`
+pub fn empty_result(path: &Path, policy: EmptyFilePolicy) -> Result<ValidationResult> {
+    match policy {
+        EmptyFilePolicy::Skip => Err(EmptyFileError { path: path.to_path_buf() }.into()),
+        EmptyFilePolicy::Pass => Ok(ValidationResult::passed()),
+        EmptyFilePolicy::Fail => Ok(ValidationResult {
+            success: false,
+            errors: vec![ValidationError {
+                file_path: path.to_string_lossy().to_string(),
+                error_type: ErrorType::Lint,
+                message: "File is empty".to_string(),
+                line: None,
+                column: None,
+                code: Some("empty-file".to_string()),
+                suggestion: Some("Add content or delete the file ([scan] empty_files decides how empty files are treated)".to_string()),
+                severity: IssueSeverity::Medium,
+            }],
+            suppressed: 0,
+            hidden_issues: 0,
+        }),
+    }
+}
+
+/// The file is empty and `empty_files = "skip"`, so it wasn't validated
+#[derive(Debug)]
+pub struct EmptyFileError {
+    pub path: PathBuf,
+}
+
+impl std::fmt::Display for EmptyFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is empty; skipped", self.path.display())
+    }
+}
+
+impl std::error::Error for EmptyFileError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_whitespace_only_counts_as_empty() -> Result<()> {
+        let dir = tempdir()?;
+        let cases: [(&str, &[u8], bool); 4] = [
+            ("zero.py", b"", true),
+            ("blank.py", b"\n  \t\r\n\n", true),
+            ("comment.py", b"\n# nothing here\n", false),
+            ("late.py", &[b' '; 10000], true),
+        ];
+        for (name, content, empty) in cases {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content)?;
+            assert_eq!(is_empty(&path)?, empty, "{}", name);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_policy_is_rejected() {
+        assert_eq!(policy(None).unwrap(), EmptyFilePolicy::Pass);
+        assert_eq!(policy(Some("skip")).unwrap(), EmptyFilePolicy::Skip);
+        assert!(policy(Some("ignore")).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+//! Encoding pre-checks run before a file is handed to its language tool
+//!
+//! Invalid UTF-8, byte order marks and mixed line endings make many tools
+//! fail with confusing errors, so they are reported up front. Whether a
+//! problem fails the file is controlled by the `[encoding]` config section.
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+use anyhow::{Result, Context};
+
+use crate::analysis::IssueSeverity;
+use crate::config::EncodingConfig;
+use super::{ValidationError, ErrorType};
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+/// How much of a file is sniffed when deciding whether it is binary
+const BINARY_SNIFF_LEN: u64 = 8 * 1024;
+
+/// Check a file's bytes for encoding problems.
+///
+/// Problems the config doesn't forbid are returned as `ErrorType::Warning`.
+pub fn check_encoding(file_path: &Path, config: &EncodingConfig) -> Result<Vec<ValidationError>> {
+    let bytes = fs::read(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+
+    Ok(check_bytes(file_path, &bytes, config))
+}
+
+/// Whether the file looks binary: a NUL byte in its first few KB. UTF-16 text
+/// is full of NULs, so files starting with a UTF-16 BOM never count.
+pub fn looks_binary(file_path: &Path) -> Result<bool> {
+    let mut head = Vec::new();
+    File::open(file_path)
+        .and_then(|file| file.take(BINARY_SNIFF_LEN).read_to_end(&mut head))
+        .context(format!("Failed to read {}", file_path.display()))?;
+
+    if head.starts_with(UTF16_LE_BOM) || head.starts_with(UTF16_BE_BOM) {
+        return Ok(false);
+    }
+    Ok(head.contains(&0))
+}
+
+/// Show what `fix_encoding` would change, as a unified diff, without writing
+/// anything. Returns `None` if the file is already clean.
+pub fn preview_fix(file_path: &Path) -> Result<Option<String>> {
+    let bytes = fs::read(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+
+    let fixed = fix_bytes(&bytes);
+    if fixed == bytes {
+        return Ok(None);
+    }
+
+    // Make the otherwise invisible changes visible in the diff
+    let show = |bytes: &[u8]| {
+        String::from_utf8_lossy(bytes)
+            .replace('\u{FEFF}', "<BOM>")
+            .replace("\r\n", "<CR>\n")
+    };
+    Ok(Some(crate::diff::unified_diff(file_path, &show(&bytes), &show(&fixed))))
+}
+
+/// Strip a UTF-8 BOM and normalize line endings to the file's dominant style.
+/// Returns whether the file was modified.
+pub fn fix_encoding(file_path: &Path) -> Result<bool> {
+    let bytes = fs::read(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+
+    let fixed = fix_bytes(&bytes);
+    if fixed == bytes {
+        return Ok(false);
+    }
+
+    fs::write(file_path, &fixed)
+        .context(format!("Failed to write {}", file_path.display()))?;
+    Ok(true)
+}
+
+fn check_bytes(file_path: &Path, bytes: &[u8], config: &EncodingConfig) -> Vec<ValidationError> {
+    let require_utf8 = config.require_utf8.unwrap_or(false);
+    let forbid_bom = config.forbid_bom.unwrap_or(false);
+    let mut errors = Vec::new();
+
+    // The byte-level checks below assume an ASCII-compatible encoding
+    if bytes.starts_with(UTF16_LE_BOM) || bytes.starts_with(UTF16_BE_BOM) {
+        errors.push(encoding_error(
+            file_path,
+            require_utf8,
+            "File is UTF-16 encoded, expected UTF-8".to_string(),
+            Some(1),
+            "Re-save the file as UTF-8",
+        ));
+        return errors;
+    }
+
+    let body = match bytes.strip_prefix(UTF8_BOM) {
+        Some(body) => {
+            errors.push(encoding_error(
+                file_path,
+                forbid_bom,
+                "File starts with a UTF-8 byte order mark".to_string(),
+                Some(1),
+                "Remove the BOM (run with --fix-encoding)",
+            ));
+            body
+        }
+        None => bytes,
+    };
+
+    if let Err(e) = std::str::from_utf8(body) {
+        let offset = e.valid_up_to();
+        let (line, column) = position_of(body, offset);
+        let mut error = encoding_error(
+            file_path,
+            require_utf8,
+            format!("Invalid UTF-8 byte 0x{:02X}", body[offset]),
+            Some(line),
+            "Convert the file to UTF-8 (e.g. iconv -f latin1 -t utf-8)",
+        );
+        error.column = Some(column);
+        errors.push(error);
+    }
+
+    let (crlf, lf) = count_line_endings(body);
+    if crlf > 0 && lf > 0 {
+        errors.push(encoding_error(
+            file_path,
+            false,
+            format!("Mixed line endings ({} CRLF, {} LF)", crlf, lf),
+            None,
+            "Normalize line endings (run with --fix-encoding)",
+        ));
+    }
+
+    errors
+}
+
+// Build an error, downgraded to a warning when the config doesn't forbid it
+fn encoding_error(
+    file_path: &Path,
+    fatal: bool,
+    message: String,
+    line: Option<usize>,
+    suggestion: &str,
+) -> ValidationError {
+    let (error_type, severity) = if fatal {
+        (ErrorType::EncodingError, IssueSeverity::High)
+    } else {
+        (ErrorType::Warning, IssueSeverity::Low)
+    };
+
+    ValidationError {
+        file_path: file_path.to_string_lossy().to_string(),
+        error_type,
+        message,
+        line,
+        column: None,
+        code: None,
+        suggestion: Some(suggestion.to_string()),
+        severity,
+    }
+}
+
+// 1-based line and byte column of an offset
+fn position_of(bytes: &[u8], offset: usize) -> (usize, usize) {
+    let before = &bytes[..offset];
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let line_start = before.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    (line, offset - line_start + 1)
+}
+
+// Count (CRLF, bare LF) line endings
+fn count_line_endings(bytes: &[u8]) -> (usize, usize) {
+    let mut crlf = 0;
+    let mut lf = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'\n' {
+            if i > 0 && bytes[i - 1] == b'\r' {
+                crlf += 1;
+            } else {
+                lf += 1;
+            }
+        }
+    }
+    (crlf, lf)
+}
+
+fn fix_bytes(bytes: &[u8]) -> Vec<u8> {
+    // Leave UTF-16 files alone; rewriting them byte-wise would corrupt them
+    if bytes.starts_with(UTF16_LE_BOM) || bytes.starts_with(UTF16_BE_BOM) {
+        return bytes.to_vec();
+    }
+
+    let body = bytes.strip_prefix(UTF8_BOM).unwrap_or(bytes);
+    let (crlf, lf) = count_line_endings(body);
+    if crlf == 0 || lf == 0 {
+        return body.to_vec();
+    }
+
+    let use_crlf = crlf > lf;
+    let mut fixed = Vec::with_capacity(body.len() + crlf);
+    for (i, &b) in body.iter().enumerate() {
+        if b == b'\n' {
+            let has_cr = i > 0 && body[i - 1] == b'\r';
+            if use_crlf && !has_cr {
+                fixed.push(b'\r');
+            } else if !use_crlf && has_cr {
+                fixed.pop();
+            }
+        }
+        fixed.push(b);
+    }
+    fixed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files/python/encoding").join(name)
+    }
+
+    fn strict_config() -> EncodingConfig {
+        EncodingConfig {
+            require_utf8: Some(true),
+            forbid_bom: Some(true),
+            fix: Some(false),
+        }
+    }
+
+    #[test]
+    fn test_bom_fixture() {
+        let errors = check_encoding(&fixture("bom.py"), &EncodingConfig::default()).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_type, ErrorType::Warning);
+
+        let errors = check_encoding(&fixture("bom.py"), &strict_config()).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_type, ErrorType::EncodingError);
+        assert!(errors[0].message.contains("byte order mark"));
+    }
+
+    #[test]
+    fn test_latin1_fixture() {
+        let errors = check_encoding(&fixture("latin1.py"), &strict_config()).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_type, ErrorType::EncodingError);
+        assert!(errors[0].message.contains("Invalid UTF-8"));
+        assert_eq!(errors[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_mixed_line_endings_are_a_warning() {
+        let errors = check_bytes(Path::new("mixed.py"), b"a = 1\r\nb = 2\nc = 3\r\n", &strict_config());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_type, ErrorType::Warning);
+    }
+
+    #[test]
+    fn test_fix_encoding() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("fixme.py");
+        fs::write(&path, b"\xEF\xBB\xBFa = 1\r\nb = 2\r\nc = 3\n").unwrap();
+
+        assert!(fix_encoding(&path).unwrap());
+        assert_eq!(fs::read(&path).unwrap(), b"a = 1\r\nb = 2\r\nc = 3\r\n");
+        assert!(check_encoding(&path, &strict_config()).unwrap().is_empty());
+
+        // Already clean, nothing to do
+        assert!(!fix_encoding(&path).unwrap());
+    }
+
+    #[test]
+    fn test_preview_fix_leaves_file_alone() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("fixme.py");
+        let original = b"\xEF\xBB\xBFa = 1\r\nb = 2\r\nc = 3\n";
+        fs::write(&path, original).unwrap();
+
+        let diff = preview_fix(&path).unwrap().unwrap();
+        assert!(diff.contains("-<BOM>a = 1<CR>"));
+        assert!(diff.contains("+c = 3<CR>"));
+        assert_eq!(fs::read(&path).unwrap(), original);
+
+        fs::write(&path, "a = 1\n").unwrap();
+        assert!(preview_fix(&path).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_looks_binary() {
+        let temp_dir = TempDir::new().unwrap();
+        let binary = temp_dir.path().join("image.py");
+        fs::write(&binary, b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR").unwrap();
+        let utf16 = temp_dir.path().join("wide.py");
+        fs::write(&utf16, b"\xFF\xFEa\x00=\x001\x00").unwrap();
+        let text = temp_dir.path().join("text.py");
+        fs::write(&text, "a = 1\n").unwrap();
+
+        assert!(looks_binary(&binary).unwrap());
+        assert!(!looks_binary(&utf16).unwrap());
+        assert!(!looks_binary(&text).unwrap());
+    }
+}
@@ -1,16 +1,19 @@
 use std::path::Path;
 use std::fs;
 use colored::*;
-use console::Emoji;
+use crate::glyphs::Glyph;
 use anyhow::Result;
+use serde::{Serialize, Deserialize};
 
-static ERROR_MARK: Emoji<'_, '_> = Emoji("❌", "x");
-static WARNING_MARK: Emoji<'_, '_> = Emoji("⚠️", "!");
-static INFO_MARK: Emoji<'_, '_> = Emoji("ℹ️", "i");
-static LINE_MARK: Emoji<'_, '_> = Emoji("│", "|");
+use crate::analysis::IssueSeverity;
+
+static ERROR_MARK: Glyph = Glyph::new("❌", "x");
+static WARNING_MARK: Glyph = Glyph::new("⚠️", "!");
+static INFO_MARK: Glyph = Glyph::new("ℹ️", "i");
+static LINE_MARK: Glyph = Glyph::new("│", "|");
 
 /// Represents a validation error with context
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationError {
     pub file_path: String,
     pub error_type: ErrorType,
@@ -19,9 +22,10 @@ pub struct ValidationError {
     pub column: Option<usize>,
     pub code: Option<String>,
     pub suggestion: Option<String>,
+    pub severity: IssueSeverity,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ErrorType {
     SyntaxError,
     TypeError,
@@ -29,25 +33,34 @@ pub enum ErrorType {
     Lint,
     CompileError,
     RuntimeError,
+    MemoryLeak,
+    EncodingError,
+    FileTooLarge,
+    Complexity,
+    ToolCrashed,
+    /// A credential committed in the file
+    Secret,
 }
 
 impl ErrorType {
     pub fn color(&self) -> Color {
         match self {
-            ErrorType::SyntaxError | ErrorType::CompileError => Color::Red,
+            ErrorType::SyntaxError | ErrorType::CompileError | ErrorType::EncodingError => Color::Red,
             ErrorType::TypeError => Color::Magenta,
-            ErrorType::Warning => Color::Yellow,
-            ErrorType::Lint => Color::Cyan,
-            ErrorType::RuntimeError => Color::BrightRed,
+            ErrorType::Warning | ErrorType::FileTooLarge => Color::Yellow,
+            ErrorType::Lint | ErrorType::Complexity => Color::Cyan,
+            ErrorType::RuntimeError | ErrorType::MemoryLeak | ErrorType::ToolCrashed | ErrorType::Secret => Color::BrightRed,
         }
     }
 
-    pub fn emoji(&self) -> &'static Emoji<'static, 'static> {
+    pub fn emoji(&self) -> &'static Glyph {
         match self {
             ErrorType::SyntaxError | ErrorType::CompileError | ErrorType::RuntimeError => &ERROR_MARK,
-            ErrorType::TypeError => &ERROR_MARK,
-            ErrorType::Warning => &WARNING_MARK,
+            ErrorType::MemoryLeak | ErrorType::EncodingError => &ERROR_MARK,
+            ErrorType::TypeError | ErrorType::ToolCrashed | ErrorType::Secret => &ERROR_MARK,
+            ErrorType::Warning | ErrorType::FileTooLarge => &WARNING_MARK,
             ErrorType::Lint => &INFO_MARK,
+            ErrorType::Complexity => &WARNING_MARK,
         }
     }
 
@@ -59,6 +72,28 @@ impl ErrorType {
             ErrorType::Lint => "Lint",
             ErrorType::CompileError => "Compile Error",
             ErrorType::RuntimeError => "Runtime Error",
+            ErrorType::MemoryLeak => "Memory Leak",
+            ErrorType::EncodingError => "Encoding Error",
+            ErrorType::FileTooLarge => "File Too Large",
+            ErrorType::Complexity => "Complexity",
+            ErrorType::ToolCrashed => "Tool Crashed",
+            ErrorType::Secret => "Secret",
+        }
+    }
+
+    /// Severity assumed for this error type when the tool output doesn't say
+    pub fn default_severity(&self) -> IssueSeverity {
+        match self {
+            ErrorType::SyntaxError
+            | ErrorType::TypeError
+            | ErrorType::CompileError
+            | ErrorType::RuntimeError
+            | ErrorType::MemoryLeak
+            | ErrorType::EncodingError
+            | ErrorType::ToolCrashed => IssueSeverity::High,
+            ErrorType::Warning | ErrorType::FileTooLarge | ErrorType::Complexity => IssueSeverity::Medium,
+            ErrorType::Lint => IssueSeverity::Low,
+            ErrorType::Secret => IssueSeverity::Critical,
         }
     }
 }
@@ -115,8 +150,8 @@ impl<'a> ErrorDisplay<'a> {
             error.message.color(error_type.color())
         );
 
-        // Show code context if available and requested
-        if self.show_code_context && error.line.is_some() {
+        // Show code context if available and requested; the line with a secret would reveal it
+        if self.show_code_context && error.line.is_some() && *error_type != ErrorType::Secret {
             self.display_code_context()?;
         }
 
@@ -209,36 +244,148 @@ pub fn parse_validation_output(
     }
 }
 
+/// Work out what kind of problem a line of tool output reports, and how bad
+/// it is, from the labels the common tools use:
+/// - gcc/clang/javac `file:line:col: error:` / `warning:` / `fatal error:`
+/// - rustc `error[E0425]:` / `warning:` headers
+/// - eslint's `line:col  error|warning  message  rule` rows
+/// - pylint's C/R/W/E/F message codes
+///
+/// Returns `None` for lines that aren't a diagnostic, including notes.
+pub fn classify_diagnostic(line: &str) -> Option<(ErrorType, IssueSeverity)> {
+    if let Some(code) = pylint_code(line) {
+        return Some(match &code[..1] {
+            "F" => (ErrorType::CompileError, IssueSeverity::Critical),
+            "E" => (ErrorType::CompileError, IssueSeverity::High),
+            "W" => (ErrorType::Warning, IssueSeverity::Medium),
+            _ => (ErrorType::Lint, IssueSeverity::Low),
+        });
+    }
+
+    match diagnostic_label(line)?.0 {
+        "fatal error" => Some((ErrorType::CompileError, IssueSeverity::Critical)),
+        "error" => Some((ErrorType::CompileError, IssueSeverity::High)),
+        "warning" => Some((ErrorType::Warning, IssueSeverity::Medium)),
+        _ => None,
+    }
+}
+
+/// Find the severity label on a diagnostic line, returning it with the
+/// message that follows
+fn diagnostic_label(line: &str) -> Option<(&'static str, &str)> {
+    const LABELS: [&str; 3] = ["fatal error", "error", "warning"];
+    let trimmed = line.trim_start();
+
+    // rustc headers start with the label, optionally followed by a code
+    for label in LABELS {
+        if let Some(rest) = trimmed.strip_prefix(label) {
+            let rest = match rest.strip_prefix('[') {
+                Some(coded) => &coded[coded.find(']')? + 1..],
+                None => rest,
+            };
+            if let Some(message) = rest.strip_prefix(':') {
+                return Some((label, message.trim()));
+            }
+        }
+    }
+
+    // gcc/clang/javac, and rustc's short format, put the label after the location
+    for label in LABELS {
+        let marker = format!(": {}", label);
+        for (start, _) in line.match_indices(&marker) {
+            let rest = &line[start + marker.len()..];
+            let rest = match rest.strip_prefix('[') {
+                Some(coded) => match coded.find(']') {
+                    Some(end) => &coded[end + 1..],
+                    None => continue,
+                },
+                None => rest,
+            };
+            if let Some(message) = rest.strip_prefix(':') {
+                return Some((label, message.trim()));
+            }
+        }
+    }
+
+    // eslint's stylish format: "  3:10  error  message  rule"
+    let mut words = trimmed.splitn(2, char::is_whitespace);
+    let location = words.next()?;
+    let is_location = location.split(':').count() == 2
+        && location.split(':').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+    if is_location {
+        let rest = words.next()?.trim_start();
+        for label in ["error", "warning"] {
+            if let Some(message) = rest.strip_prefix(label) {
+                if message.starts_with(char::is_whitespace) {
+                    return Some((label, message.trim()));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// A pylint message code such as `C0114` or `E0602`
+fn pylint_code(line: &str) -> Option<&str> {
+    line.split(": ").skip(1).find(|part| {
+        let part = part.trim_end_matches(':');
+        part.len() == 5
+            && part.starts_with(['C', 'R', 'W', 'E', 'F', 'I'])
+            && part[1..].chars().all(|c| c.is_ascii_digit())
+    }).map(|part| part.trim_end_matches(':'))
+}
+
+/// The code in brackets after rustc's label, e.g. `E0425` from `error[E0425]:`
+fn rustc_code(line: &str) -> Option<String> {
+    ["error[", "warning["].iter()
+        .find_map(|label| line.find(label).map(|start| &line[start + label.len()..]))
+        .and_then(|coded| coded.split_once(']'))
+        .map(|(code, _)| code.to_string())
+}
+
 fn parse_rust_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
-    let mut errors = Vec::new();
+    let mut errors: Vec<ValidationError> = Vec::new();
     let path_str = file_path.to_string_lossy().to_string();
     
     for line in output.lines() {
-        if line.contains("error:") || line.contains("warning:") {
-            let error_type = if line.contains("error:") {
-                ErrorType::CompileError
-            } else {
-                ErrorType::Warning
-            };
-            
-            // Parse Rust error format: file:line:column: error: message
-            if let Some(location_end) = line.find(": ") {
-                let location_part = &line[..location_end];
-                let message_part = &line[location_end + 2..];
-                
-                let (line_num, column_num) = parse_location(location_part);
-                
-                errors.push(ValidationError {
-                    file_path: path_str.clone(),
-                    error_type,
-                    message: message_part.to_string(),
-                    line: line_num,
-                    column: column_num,
-                    code: None,
-                    suggestion: None,
-                });
+        // rustc gives the location on a "  --> file:line:column" line after the header
+        if let Some(location) = line.trim_start().strip_prefix("--> ") {
+            if let Some(error) = errors.last_mut().filter(|error| error.line.is_none()) {
+                let (line_num, column_num) = parse_location(location);
+                error.line = line_num;
+                error.column = column_num;
             }
+            continue;
+        }
+        
+        let (error_type, severity) = match classify_diagnostic(line) {
+            Some(classified) => classified,
+            None => continue,
+        };
+        let message = diagnostic_label(line).map(|(_, message)| message).unwrap_or(line);
+        
+        // Summary lines repeat what the individual diagnostics already said
+        if message.starts_with("aborting due to") || message.ends_with("emitted") {
+            continue;
         }
+        
+        // Headers like "error[E0425]: message" get their location from a later
+        // "-->" line; short-format lines are "file:line:column: error[E0425]: message"
+        let is_header = line.trim_start().starts_with("error") || line.trim_start().starts_with("warning");
+        let (line_num, column_num) = if is_header { (None, None) } else { parse_location(line) };
+        let code = rustc_code(line);
+        
+        errors.push(ValidationError {
+            file_path: path_str.clone(),
+            error_type,
+            message: message.to_string(),
+            line: line_num,
+            column: column_num,
+            code,
+            suggestion: None,
+            severity,
+        });
     }
     
     if errors.is_empty() && !output.is_empty() {
@@ -250,6 +397,7 @@ fn parse_rust_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
             column: None,
             code: None,
             suggestion: None,
+            severity: IssueSeverity::High,
         });
     }
     
@@ -260,23 +408,47 @@ fn parse_python_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
     let mut errors = Vec::new();
     let path_str = file_path.to_string_lossy().to_string();
     
+    // The exception line (e.g. "SyntaxError: invalid syntax") is the most useful message
+    let message = output.lines()
+        .rev()
+        .find(|line| line.contains("Error: "))
+        .map(|line| line.trim().to_string())
+        .unwrap_or_else(|| "Python syntax error".to_string());
+    
     for line in output.lines() {
-        if line.trim().starts_with("File") && line.contains("line") {
-            // Python error format: File "filename", line N
+        // pylint: "file.py:3:0: C0114: Missing module docstring (missing-module-docstring)"
+        if let Some(code) = pylint_code(line) {
+            if let Some((error_type, severity)) = classify_diagnostic(line) {
+                let (line_num, column_num) = parse_location(line);
+                let message = line.split_once(&format!("{}: ", code)).map(|(_, message)| message).unwrap_or(line);
+                
+                errors.push(ValidationError {
+                    file_path: path_str.clone(),
+                    error_type,
+                    message: message.to_string(),
+                    line: line_num,
+                    column: column_num.map(|column| column + 1),
+                    code: Some(code.to_string()),
+                    suggestion: None,
+                    severity,
+                });
+            }
+        } else if line.trim().starts_with("File") && line.contains("line") {
+            // Python error format: File "filename", line N[, in module]
             if let Some(line_start) = line.find("line ") {
                 let line_part = &line[line_start + 5..];
-                if let Some(line_end) = line_part.find(',') {
-                    if let Ok(line_num) = line_part[..line_end].parse::<usize>() {
-                        errors.push(ValidationError {
-                            file_path: path_str.clone(),
-                            error_type: ErrorType::SyntaxError,
-                            message: "Python syntax error".to_string(),
-                            line: Some(line_num),
-                            column: None,
-                            code: None,
-                            suggestion: None,
-                        });
-                    }
+                let line_end = line_part.find(|c: char| !c.is_ascii_digit()).unwrap_or(line_part.len());
+                if let Ok(line_num) = line_part[..line_end].parse::<usize>() {
+                    errors.push(ValidationError {
+                        file_path: path_str.clone(),
+                        error_type: ErrorType::SyntaxError,
+                        message: message.clone(),
+                        line: Some(line_num),
+                        column: None,
+                        code: None,
+                        suggestion: None,
+                        severity: IssueSeverity::High,
+                    });
                 }
             }
         }
@@ -291,6 +463,7 @@ fn parse_python_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
             column: None,
             code: None,
             suggestion: None,
+            severity: IssueSeverity::High,
         });
     }
     
@@ -302,12 +475,33 @@ fn parse_js_ts_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
     let path_str = file_path.to_string_lossy().to_string();
     
     for line in output.lines() {
-        if line.contains("SyntaxError") || line.contains("TypeError") {
+        // eslint rows: "  3:10  error  'x' is not defined  no-undef"
+        if let Some((label, message)) = diagnostic_label(line) {
+            let (error_type, severity) = match label {
+                "warning" => (ErrorType::Warning, IssueSeverity::Medium),
+                _ => (ErrorType::Lint, IssueSeverity::High),
+            };
+            let location = line.split_whitespace().next().and_then(|location| location.split_once(':'));
+            let line_num = location.and_then(|(line, _)| line.parse().ok());
+            let column_num = location.and_then(|(_, column)| column.parse().ok());
+            
+            errors.push(ValidationError {
+                file_path: path_str.clone(),
+                error_type,
+                message: message.to_string(),
+                line: line_num,
+                column: column_num,
+                code: None,
+                suggestion: None,
+                severity,
+            });
+        } else if line.contains("SyntaxError") || line.contains("TypeError") {
             let error_type = if line.contains("SyntaxError") {
                 ErrorType::SyntaxError
             } else {
                 ErrorType::TypeError
             };
+            let severity = error_type.default_severity();
             
             errors.push(ValidationError {
                 file_path: path_str.clone(),
@@ -317,6 +511,7 @@ fn parse_js_ts_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
                 column: None,
                 code: None,
                 suggestion: None,
+                severity,
             });
         }
     }
@@ -330,6 +525,7 @@ fn parse_js_ts_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
             column: None,
             code: None,
             suggestion: None,
+            severity: IssueSeverity::High,
         });
     }
     
@@ -341,23 +537,10 @@ fn parse_c_cpp_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
     let path_str = file_path.to_string_lossy().to_string();
     
     for line in output.lines() {
-        if line.contains(": error:") || line.contains(": warning:") {
-            let error_type = if line.contains(": error:") {
-                ErrorType::CompileError
-            } else {
-                ErrorType::Warning
-            };
-            
+        if let Some((error_type, severity)) = classify_diagnostic(line) {
             // Parse GCC/Clang format: file:line:column: error: message
             let (line_num, column_num) = parse_location(line);
-            
-            let message = if let Some(msg_start) = line.find(": error:") {
-                &line[msg_start + 8..]
-            } else if let Some(msg_start) = line.find(": warning:") {
-                &line[msg_start + 10..]
-            } else {
-                line
-            };
+            let message = diagnostic_label(line).map(|(_, message)| message).unwrap_or(line);
             
             errors.push(ValidationError {
                 file_path: path_str.clone(),
@@ -367,6 +550,7 @@ fn parse_c_cpp_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
                 column: column_num,
                 code: None,
                 suggestion: None,
+                severity,
             });
         }
     }
@@ -380,6 +564,7 @@ fn parse_c_cpp_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
             column: None,
             code: None,
             suggestion: None,
+            severity: IssueSeverity::High,
         });
     }
     
@@ -391,13 +576,10 @@ fn parse_java_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
     let path_str = file_path.to_string_lossy().to_string();
     
     for line in output.lines() {
-        if line.contains(".java:") && (line.contains("error:") || line.contains("warning:")) {
-            let error_type = if line.contains("error:") {
-                ErrorType::CompileError
-            } else {
-                ErrorType::Warning
-            };
-            
+        if !line.contains(".java:") {
+            continue;
+        }
+        if let Some((error_type, severity)) = classify_diagnostic(line) {
             let (line_num, column_num) = parse_location(line);
             
             errors.push(ValidationError {
@@ -408,6 +590,7 @@ fn parse_java_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
                 column: column_num,
                 code: None,
                 suggestion: None,
+                severity,
             });
         }
     }
@@ -421,6 +604,7 @@ fn parse_java_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
             column: None,
             code: None,
             suggestion: None,
+            severity: IssueSeverity::High,
         });
     }
     
@@ -443,6 +627,7 @@ fn parse_go_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
                 column: column_num,
                 code: None,
                 suggestion: None,
+                severity: IssueSeverity::High,
             });
         }
     }
@@ -456,6 +641,7 @@ fn parse_go_errors(file_path: &Path, output: &str) -> Vec<ValidationError> {
             column: None,
             code: None,
             suggestion: None,
+            severity: IssueSeverity::High,
         });
     }
     
@@ -475,6 +661,7 @@ fn parse_generic_errors(file_path: &Path, output: &str) -> Vec<ValidationError>
         column: None,
         code: None,
         suggestion: None,
+        severity: IssueSeverity::High,
     }]
 }
 
@@ -606,9 +793,94 @@ pub fn display_validation_errors(errors: &[ValidationError]) -> Result<()> {
         
         // Add separator between errors (except for the last one)
         if i < errors.len() - 1 {
-            println!("{}", "─".repeat(60).bright_black());
+            println!("{}", crate::glyphs::RULE.as_str().repeat(60).bright_black());
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn severities(errors: &[ValidationError]) -> Vec<(Option<usize>, IssueSeverity)> {
+        errors.iter().map(|error| (error.line, error.severity)).collect()
+    }
+
+    #[test]
+    fn test_gcc_severities() {
+        let output = "main.c: In function 'main':\n\
+            main.c:4:9: warning: unused variable 'x' [-Wunused-variable]\n\
+            main.c:5:5: error: expected ';' before 'return'\n\
+            main.c:1:10: fatal error: missing.h: No such file or directory\n\
+            main.c:4:9: note: declared here";
+        let errors = parse_validation_output(Path::new("main.c"), output, "c");
+
+        assert_eq!(severities(&errors), vec![
+            (Some(4), IssueSeverity::Medium),
+            (Some(5), IssueSeverity::High),
+            (Some(1), IssueSeverity::Critical),
+        ]);
+        assert_eq!(errors[0].error_type, ErrorType::Warning);
+        assert_eq!(errors[1].message, "expected ';' before 'return'");
+    }
+
+    #[test]
+    fn test_rustc_severities() {
+        let output = "warning: unused variable: `x`\n \
+            --> src/main.rs:2:9\n\
+            error[E0425]: cannot find value `y` in this scope\n \
+            --> src/main.rs:3:20\n\
+            error: aborting due to 1 previous error";
+        let errors = parse_validation_output(Path::new("src/main.rs"), output, "rust");
+
+        assert_eq!(severities(&errors), vec![
+            (Some(2), IssueSeverity::Medium),
+            (Some(3), IssueSeverity::High),
+        ]);
+        assert_eq!(errors[1].code.as_deref(), Some("E0425"));
+        assert_eq!(errors[1].column, Some(20));
+        assert_eq!(errors[1].message, "cannot find value `y` in this scope");
+    }
+
+    #[test]
+    fn test_pylint_severities() {
+        let output = "app.py:1:0: C0114: Missing module docstring (missing-module-docstring)\n\
+            app.py:3:0: R1710: Either all return statements return an expression, or none (inconsistent-return-statements)\n\
+            app.py:4:4: W0612: Unused variable 'unused' (unused-variable)\n\
+            app.py:5:11: E0602: Undefined variable 'missing' (undefined-variable)\n\
+            app.py:1:0: F0001: No module named app (fatal)";
+        let errors = parse_validation_output(Path::new("app.py"), output, "python");
+
+        assert_eq!(severities(&errors), vec![
+            (Some(1), IssueSeverity::Low),
+            (Some(3), IssueSeverity::Low),
+            (Some(4), IssueSeverity::Medium),
+            (Some(5), IssueSeverity::High),
+            (Some(1), IssueSeverity::Critical),
+        ]);
+        assert_eq!(errors[3].code.as_deref(), Some("E0602"));
+        assert_eq!(errors[3].column, Some(12));
+    }
+
+    #[test]
+    fn test_eslint_severities() {
+        let output = "/src/app.js\n  \
+            3:10  error    'x' is not defined      no-undef\n  \
+            5:1   warning  Unexpected console statement  no-console";
+        let errors = parse_validation_output(Path::new("app.js"), output, "javascript");
+
+        assert_eq!(severities(&errors), vec![
+            (Some(3), IssueSeverity::High),
+            (Some(5), IssueSeverity::Medium),
+        ]);
+        assert_eq!(errors[0].column, Some(10));
+    }
+
+    #[test]
+    fn test_unrecognized_output_is_high() {
+        let errors = parse_validation_output(Path::new("file.c"), "ld: something went wrong", "c");
+        assert_eq!(severities(&errors), vec![(None, IssueSeverity::High)]);
+    }
+}
@@ -0,0 +1,82 @@
+//! Third-party validators found on `$PATH`
+//!
+//! Like git subcommands, an executable named `synx-validator-<type>` adds
+//! support for files synx has no built-in validator for, where `<type>` is
+//! the file extension (or the type a `file_mappings` entry maps it to).
+//!
+//! The plugin is run as `synx-validator-<type> <file>` and receives a JSON
+//! request on stdin:
+//!
+//! ```json
+//! {"file": "src/main.zig", "file_type": "zig", "strict": false, "verbose": false}
+//! ```
+//!
+//! It must print a `ValidationResult` as JSON on stdout. `errors` may be left
+//! out, as may the optional fields of each error:
+//!
+//! ```json
+//! {"success": false, "errors": [{"file_path": "src/main.zig", "error_type": "SyntaxError",
+//!   "message": "expected ';'", "line": 3, "severity": "High"}]}
+//! ```
+//!
+//! The exit status is ignored; `success` decides the verdict.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use super::{output_limit, project, run_with_input, validator_timeout, ValidationOptions, ValidationResult};
+
+/// Executable name prefix for PATH validators
+pub const PLUGIN_PREFIX: &str = "synx-validator-";
+
+#[derive(Serialize)]
+struct PluginRequest<'a> {
+    file: &'a Path,
+    file_type: &'a str,
+    strict: bool,
+    verbose: bool,
+}
+
+/// Find the `synx-validator-<file_type>` executable on `$PATH`
+pub fn find_plugin(file_type: &str) -> Option<PathBuf> {
+    // Keep odd type names (e.g. from mime detection) from turning into paths
+    if file_type.is_empty() || !file_type.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return None;
+    }
+    which::which(format!("{}{}", PLUGIN_PREFIX, file_type)).ok()
+}
+
+/// Validate a file with a PATH plugin
+pub fn validate_with_plugin(
+    plugin: &Path,
+    file_path: &Path,
+    file_type: &str,
+    options: &ValidationOptions,
+) -> Result<ValidationResult> {
+    if options.verbose {
+        eprintln!("Validating {} with {}", file_path.display(), plugin.display());
+    }
+
+    let request = serde_json::to_vec(&PluginRequest {
+        file: file_path,
+        file_type,
+        strict: options.strict,
+        verbose: options.verbose,
+    })?;
+
+    let mut cmd = Command::new(plugin);
+    cmd.arg(file_path);
+    project::run_from_project_root(&mut cmd, file_path);
+    let output = run_with_input(&mut cmd, Some(request), validator_timeout(file_type, options), output_limit(options))?;
+
+    serde_json::from_slice(&output.stdout).map_err(|e| {
+        anyhow!(
+            "{} did not print a valid result ({}): {}",
+            plugin.display(),
+            e,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+    })
+}
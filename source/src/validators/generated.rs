@@ -0,0 +1,144 @@
+//! Generated file detection
+//!
+//! Files written by tools (protobuf output, minified bundles, anything
+//! headed `@generated` or `DO NOT EDIT`) aren't worth failing a scan over.
+//! The `[generated]` config section decides whether they are skipped,
+//! validated with failures reported as warnings, or validated as usual.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use anyhow::{Result, anyhow};
+
+use crate::config::GeneratedConfig;
+
+/// Header text that marks a file as generated, unless the config lists its own
+pub const DEFAULT_MARKERS: &[&str] = &["@generated", "DO NOT EDIT"];
+
+/// File name endings of generated files, unless the config lists its own
+pub const DEFAULT_SUFFIXES: &[&str] = &[
+    ".min.js", ".min.css", "_pb2.py", "_pb2_grpc.py", ".pb.go", ".pb.cc", ".pb.h",
+];
+
+// Markers only count near the top of a file
+const HEADER_LINES: usize = 10;
+const HEADER_BYTES: u64 = 4096;
+
+/// What a scan does with a generated file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedAction {
+    /// Leave it out of the scan
+    Skip,
+    /// Validate it, but report failures as warnings
+    Warn,
+    /// Validate it like any other file
+    Validate,
+}
+
+/// The configured action, defaulting to skip
+pub fn action(config: &GeneratedConfig) -> Result<GeneratedAction> {
+    match config.action.as_deref().unwrap_or("skip") {
+        "skip" => Ok(GeneratedAction::Skip),
+        "warn" => Ok(GeneratedAction::Warn),
+        "validate" => Ok(GeneratedAction::Validate),
+        other => Err(anyhow!("Invalid generated action '{}' (expected \"skip\", \"warn\" or \"validate\")", other)),
+    }
+}
+
+/// Whether a file looks generated, by its name or by a marker in its header.
+/// Files that can't be read aren't considered generated.
+pub fn is_generated(path: &Path, config: &GeneratedConfig) -> bool {
+    let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    let by_name = match &config.suffixes {
+        Some(suffixes) => suffixes.iter().any(|suffix| name.ends_with(suffix.as_str())),
+        None => DEFAULT_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)),
+    };
+    by_name || has_marker(path, config)
+}
+
+fn has_marker(path: &Path, config: &GeneratedConfig) -> bool {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return false,
+    };
+    let matches = |line: &str| match &config.markers {
+        Some(markers) => markers.iter().any(|marker| line.contains(marker.as_str())),
+        None => DEFAULT_MARKERS.iter().any(|marker| line.contains(marker)),
+    };
+
+    // Lossy decoding so a stray byte in the header doesn't end the search
+    let mut reader = BufReader::new(file.take(HEADER_BYTES));
+    let mut line = Vec::new();
+    for _ in 0..HEADER_LINES {
+        line.clear();
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {
+                if matches(&String::from_utf8_lossy(&line)) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_detects_markers_and_suffixes() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratedConfig::default();
+
+        let marked = dir.path().join("schema.py");
+        fs::write(&marked, "# Code generated by protoc. DO NOT EDIT.\nx = 1\n").unwrap();
+        assert!(is_generated(&marked, &config));
+
+        let bundle = dir.path().join("app.min.js");
+        fs::write(&bundle, "var a=1;").unwrap();
+        assert!(is_generated(&bundle, &config));
+
+        let handwritten = dir.path().join("app.js");
+        fs::write(&handwritten, "// Written by hand\nvar a = 1;\n").unwrap();
+        assert!(!is_generated(&handwritten, &config));
+
+        // A marker far below the header doesn't count
+        let late = dir.path().join("late.py");
+        fs::write(&late, format!("{}# @generated\n", "x = 1\n".repeat(20))).unwrap();
+        assert!(!is_generated(&late, &config));
+    }
+
+    #[test]
+    fn test_configured_lists_replace_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratedConfig {
+            markers: Some(vec!["AUTOGENERATED".to_string()]),
+            suffixes: Some(vec![".gen.ts".to_string()]),
+            ..Default::default()
+        };
+
+        let marked = dir.path().join("api.ts");
+        fs::write(&marked, "// AUTOGENERATED\n").unwrap();
+        assert!(is_generated(&marked, &config));
+
+        let suffixed = dir.path().join("api.gen.ts");
+        fs::write(&suffixed, "export {};\n").unwrap();
+        assert!(is_generated(&suffixed, &config));
+
+        let old_marker = dir.path().join("old.ts");
+        fs::write(&old_marker, "// @generated\n").unwrap();
+        assert!(!is_generated(&old_marker, &config));
+    }
+
+    #[test]
+    fn test_action_parsing() {
+        assert_eq!(action(&GeneratedConfig::default()).unwrap(), GeneratedAction::Skip);
+        let warn = GeneratedConfig { action: Some("warn".to_string()), ..Default::default() };
+        assert_eq!(action(&warn).unwrap(), GeneratedAction::Warn);
+        let bad = GeneratedConfig { action: Some("ignore".to_string()), ..Default::default() };
+        assert!(action(&bad).is_err());
+    }
+}
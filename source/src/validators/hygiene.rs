@@ -0,0 +1,210 @@
+//! Whitespace hygiene checks
+//!
+//! Enforces indentation, line length, trailing whitespace and final newline
+//! rules. They come from the nearest `.editorconfig` that applies to the file,
+//! and any rule set in the `[hygiene]` config section takes precedence.
+
+use std::fs;
+use std::path::Path;
+use anyhow::{Result, Context};
+use ec4rs::property::{FinalNewline, IndentSize, IndentStyle, MaxLineLen, TrimTrailingWs};
+
+use crate::config::HygieneConfig;
+use super::{ValidationError, ErrorType};
+
+/// Whether the hygiene pass is switched on
+pub fn is_enabled(config: &HygieneConfig) -> bool {
+    config.enabled.unwrap_or(false)
+}
+
+/// The rules that apply to one file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HygieneRules {
+    pub indent_with_tabs: Option<bool>,
+    pub indent_size: Option<usize>,
+    pub max_line_length: Option<usize>,
+    pub insert_final_newline: Option<bool>,
+    pub trim_trailing_whitespace: Option<bool>,
+}
+
+/// Work out the rules for a file: `.editorconfig` first, then the config on top
+pub fn rules_for(file_path: &Path, config: &HygieneConfig) -> Result<HygieneRules> {
+    let mut rules = if config.editorconfig.unwrap_or(true) {
+        editorconfig_rules(file_path)
+    } else {
+        HygieneRules::default()
+    };
+
+    if let Some(style) = &config.indent_style {
+        rules.indent_with_tabs = Some(match style.as_str() {
+            "tab" => true,
+            "space" => false,
+            other => return Err(anyhow::anyhow!("Invalid hygiene indent_style '{}' (expected \"space\" or \"tab\")", other)),
+        });
+    }
+    rules.indent_size = config.indent_size.or(rules.indent_size);
+    rules.max_line_length = config.max_line_length.or(rules.max_line_length);
+    rules.insert_final_newline = config.insert_final_newline.or(rules.insert_final_newline);
+    rules.trim_trailing_whitespace = config.trim_trailing_whitespace.or(rules.trim_trailing_whitespace);
+
+    Ok(rules)
+}
+
+// Missing or unreadable .editorconfig files just mean no rules
+fn editorconfig_rules(file_path: &Path) -> HygieneRules {
+    let path = file_path.canonicalize().unwrap_or_else(|_| file_path.to_path_buf());
+    let properties = match ec4rs::properties_of(&path) {
+        Ok(properties) => properties,
+        Err(_) => return HygieneRules::default(),
+    };
+
+    HygieneRules {
+        indent_with_tabs: properties.get::<IndentStyle>().ok().map(|style| matches!(style, IndentStyle::Tabs)),
+        indent_size: match properties.get::<IndentSize>() {
+            Ok(IndentSize::Value(size)) => Some(size),
+            _ => None,
+        },
+        max_line_length: match properties.get::<MaxLineLen>() {
+            Ok(MaxLineLen::Value(length)) => Some(length),
+            _ => None,
+        },
+        insert_final_newline: match properties.get::<FinalNewline>() {
+            Ok(FinalNewline::Value(value)) => Some(value),
+            _ => None,
+        },
+        trim_trailing_whitespace: match properties.get::<TrimTrailingWs>() {
+            Ok(TrimTrailingWs::Value(value)) => Some(value),
+            _ => None,
+        },
+    }
+}
+
+/// Report every line that breaks the rules for the file. Files that aren't
+/// UTF-8 text are left to the encoding checks.
+pub fn check_hygiene(file_path: &Path, config: &HygieneConfig) -> Result<Vec<ValidationError>> {
+    let rules = rules_for(file_path, config)?;
+    let bytes = fs::read(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+    match String::from_utf8(bytes) {
+        Ok(content) => Ok(check_content(file_path, &content, &rules)),
+        Err(_) => Ok(Vec::new()),
+    }
+}
+
+/// Check text against a set of rules
+pub fn check_content(file_path: &Path, content: &str, rules: &HygieneRules) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        if let Some(max) = rules.max_line_length {
+            let length = line.chars().count();
+            if length > max {
+                errors.push(violation(file_path, line_number, format!("Line is {} characters long (max {})", length, max)));
+            }
+        }
+
+        if rules.trim_trailing_whitespace == Some(true) && line.ends_with([' ', '\t']) {
+            errors.push(violation(file_path, line_number, "Trailing whitespace".to_string()));
+        }
+
+        let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+        if indent.is_empty() || indent.len() == line.len() {
+            continue;
+        }
+        match rules.indent_with_tabs {
+            Some(true) if indent.starts_with(' ') => {
+                errors.push(violation(file_path, line_number, "Indented with spaces, expected tabs".to_string()));
+            }
+            Some(false) if indent.contains('\t') => {
+                errors.push(violation(file_path, line_number, "Indented with tabs, expected spaces".to_string()));
+            }
+            Some(false) => {
+                if let Some(size) = rules.indent_size.filter(|&size| size > 0 && indent.len() % size != 0) {
+                    errors.push(violation(
+                        file_path,
+                        line_number,
+                        format!("Indentation of {} spaces is not a multiple of {}", indent.len(), size),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if rules.insert_final_newline == Some(true) && !content.is_empty() && !content.ends_with('\n') {
+        let last_line = content.lines().count();
+        errors.push(violation(file_path, last_line, "No newline at end of file".to_string()));
+    }
+
+    errors
+}
+
+fn violation(file_path: &Path, line: usize, message: String) -> ValidationError {
+    ValidationError {
+        file_path: file_path.to_string_lossy().to_string(),
+        error_type: ErrorType::Lint,
+        message,
+        line: Some(line),
+        column: None,
+        code: None,
+        suggestion: None,
+        severity: ErrorType::Lint.default_severity(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(content: &str, rules: &HygieneRules) -> Vec<(usize, String)> {
+        check_content(Path::new("file.txt"), content, rules).into_iter()
+            .map(|error| (error.line.unwrap(), error.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_whitespace_rules() {
+        let rules = HygieneRules {
+            indent_with_tabs: Some(false),
+            indent_size: Some(4),
+            insert_final_newline: Some(true),
+            trim_trailing_whitespace: Some(true),
+            ..Default::default()
+        };
+
+        let found = messages("ok\n    fine\n\tbad\n   odd\ntrailing \nend", &rules);
+        assert_eq!(found, vec![
+            (3, "Indented with tabs, expected spaces".to_string()),
+            (4, "Indentation of 3 spaces is not a multiple of 4".to_string()),
+            (5, "Trailing whitespace".to_string()),
+            (6, "No newline at end of file".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_config_overrides_editorconfig() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".editorconfig"), "root = true\n\n[*]\nmax_line_length = 80\nindent_style = tab\n").unwrap();
+        let file = dir.path().join("notes.txt");
+        fs::write(&file, "text\n").unwrap();
+
+        let from_file = rules_for(&file, &HygieneConfig::default()).unwrap();
+        assert_eq!(from_file.max_line_length, Some(80));
+        assert_eq!(from_file.indent_with_tabs, Some(true));
+
+        let config = HygieneConfig {
+            max_line_length: Some(120),
+            indent_style: Some("space".to_string()),
+            ..Default::default()
+        };
+        let overridden = rules_for(&file, &config).unwrap();
+        assert_eq!(overridden.max_line_length, Some(120));
+        assert_eq!(overridden.indent_with_tabs, Some(false));
+
+        let ignored = HygieneConfig { editorconfig: Some(false), ..Default::default() };
+        assert_eq!(rules_for(&file, &ignored).unwrap(), HygieneRules::default());
+    }
+}
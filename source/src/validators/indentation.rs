@@ -0,0 +1,140 @@
+//! Python indentation consistency
+//!
+//! Python rejects indentation whose meaning depends on how wide a tab is,
+//! but `py_compile` only says "inconsistent use of tabs and spaces". This
+//! check finds the offending line first so the report can say what to do.
+
+/// The first line (1-based) whose indentation only lines up with the
+/// enclosing block for some tab widths, the way Python's tokenizer judges it
+pub fn inconsistent_python_indentation(content: &str) -> Option<usize> {
+    // Indentation of the open blocks, measured with tabs 8 and 1 columns wide
+    let mut levels: Vec<(usize, usize)> = vec![(0, 0)];
+    let mut scanner = LineScanner::default();
+
+    for (index, line) in content.lines().enumerate() {
+        let starts_statement = scanner.at_statement_start();
+        scanner.scan(line);
+
+        let code = line.trim_start_matches([' ', '\t', '\x0c']);
+        if !starts_statement || code.is_empty() || code.starts_with('#') {
+            continue;
+        }
+        let indent = &line[..line.len() - code.len()];
+        let wide = indent_width(indent, 8);
+        let narrow = indent_width(indent, 1);
+
+        let &(top_wide, top_narrow) = levels.last().unwrap();
+        if wide > top_wide {
+            if narrow <= top_narrow {
+                return Some(index + 1);
+            }
+            levels.push((wide, narrow));
+        } else {
+            while levels.len() > 1 && wide < levels.last().unwrap().0 {
+                levels.pop();
+            }
+            let &(top_wide, top_narrow) = levels.last().unwrap();
+            // A dedent to no open level is an IndentationError the compiler explains well
+            if wide == top_wide && narrow != top_narrow {
+                return Some(index + 1);
+            }
+        }
+    }
+    None
+}
+
+// Columns an indentation reaches with tab stops every `tab_size` columns
+fn indent_width(indent: &str, tab_size: usize) -> usize {
+    indent.chars().fold(0, |column, c| match c {
+        '\t' => (column / tab_size + 1) * tab_size,
+        // A form feed resets the count, as in Python's tokenizer
+        '\x0c' => 0,
+        _ => column + 1,
+    })
+}
+
+/// Tracks strings, brackets and backslashes across lines, so only lines that
+/// start a statement have their indentation checked
+#[derive(Default)]
+struct LineScanner {
+    /// The open string's quote character, and whether it's triple-quoted
+    string: Option<(char, bool)>,
+    brackets: usize,
+    backslash: bool,
+}
+
+impl LineScanner {
+    fn at_statement_start(&self) -> bool {
+        self.string.is_none() && self.brackets == 0 && !self.backslash
+    }
+
+    fn scan(&mut self, line: &str) {
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        self.backslash = false;
+        while i < chars.len() {
+            let c = chars[i];
+            match self.string {
+                Some((quote, triple)) => {
+                    if c == '\\' {
+                        if i + 1 == chars.len() {
+                            self.backslash = true;
+                        }
+                        i += 1;
+                    } else if c == quote {
+                        if !triple {
+                            self.string = None;
+                        } else if chars[i..].starts_with(&[quote, quote, quote]) {
+                            self.string = None;
+                            i += 2;
+                        }
+                    }
+                }
+                None => match c {
+                    '#' => break,
+                    '\'' | '"' => {
+                        let triple = chars[i..].starts_with(&[c, c, c]);
+                        self.string = Some((c, triple));
+                        if triple {
+                            i += 2;
+                        }
+                    }
+                    '(' | '[' | '{' => self.brackets += 1,
+                    ')' | ']' | '}' => self.brackets = self.brackets.saturating_sub(1),
+                    '\\' if i + 1 == chars.len() => self.backslash = true,
+                    _ => {}
+                },
+            }
+            i += 1;
+        }
+        // A single-quoted string can't run past the line unless it's escaped
+        if matches!(self.string, Some((_, false))) && !self.backslash {
+            self.string = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consistent_indentation() {
+        assert_eq!(inconsistent_python_indentation("def f():\n    if x:\n        return 1\n    return 2\n"), None);
+        assert_eq!(inconsistent_python_indentation("def f():\n\tif x:\n\t\treturn 1\n\treturn 2\n"), None);
+        // Tabs inside strings, brackets and comments aren't indentation
+        let content = "def f():\n    s = '''\n\tdata\n    '''\n    t = (1,\n\t2)\n#\tnote\n    return s\n";
+        assert_eq!(inconsistent_python_indentation(content), None);
+    }
+
+    #[test]
+    fn test_mixed_indentation_within_a_block() {
+        // Eight spaces and a tab line up only when tabs are 8 wide
+        let content = "def f():\n        x = 1\n\ty = 2\n";
+        assert_eq!(inconsistent_python_indentation(content), Some(3));
+
+        // A tab-indented body dedenting to a space-indented level
+        let content = "class A:\n    def f(self):\n    \tpass\n\tdef g(self):\n        pass\n";
+        assert_eq!(inconsistent_python_indentation(content), Some(4));
+    }
+}
@@ -0,0 +1,295 @@
+//! Scan manifests
+//!
+//! A manifest records every file a scan looked at: its content hash, detected
+//! type, the validator that checked it and the outcome. It can be signed with
+//! a key, and checked later against the files on disk to spot drift.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Result, Context, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+use crate::detectors::FileType;
+use crate::tools::sign_data;
+use super::{FileValidationConfig, FileScanRecord, ScanResult, SkipReason, relative_path, validator_tool, tool_version};
+
+/// Everything a scan checked, and what it found
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScanManifest {
+    pub synx_version: String,
+    pub generated_at: DateTime<Utc>,
+    /// Directory the file paths are relative to
+    pub root: PathBuf,
+    pub files: Vec<ManifestEntry>,
+    /// Keyed BLAKE3 hash of the rest of the manifest, when it was signed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// One scanned file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ManifestEntry {
+    pub path: PathBuf,
+    /// BLAKE3 hash of the file's content when the manifest was written
+    pub hash: String,
+    pub file_type: Option<String>,
+    pub validator: Option<String>,
+    pub validator_version: Option<String>,
+    pub result: ManifestOutcome,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<SkipReason>,
+}
+
+/// What the scan concluded about a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManifestOutcome {
+    Valid,
+    Invalid,
+    Skipped,
+}
+
+/// A file that no longer matches its manifest entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Drift {
+    /// The content hash changed
+    Modified(PathBuf),
+    /// The file is gone
+    Missing(PathBuf),
+}
+
+impl std::fmt::Display for Drift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Drift::Modified(path) => write!(f, "modified: {}", path.display()),
+            Drift::Missing(path) => write!(f, "missing: {}", path.display()),
+        }
+    }
+}
+
+/// Collects manifest entries across the scans of one run
+pub struct ManifestBuilder<'a> {
+    config: Option<&'a FileValidationConfig>,
+    entries: Vec<ManifestEntry>,
+}
+
+impl<'a> ManifestBuilder<'a> {
+    pub fn new(config: Option<&'a FileValidationConfig>) -> Self {
+        Self { config, entries: Vec::new() }
+    }
+
+    /// Add the files of one scan: the records it streamed for validated files
+    /// and its list of skipped ones. Paths are as the scan found them.
+    pub fn add_scan(&mut self, records: &[FileScanRecord], result: &ScanResult) -> Result<()> {
+        for record in records {
+            let outcome = if record.success { ManifestOutcome::Valid } else { ManifestOutcome::Invalid };
+            self.add(&record.path, outcome, None)?;
+        }
+        for (path, reason) in &result.skipped_files {
+            self.add(path, ManifestOutcome::Skipped, Some(*reason))?;
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, path: &Path, result: ManifestOutcome, skip_reason: Option<SkipReason>) -> Result<()> {
+        let file_type = FileType::from_path(path).ok();
+        let validator = file_type.as_ref().and_then(validator_tool);
+        let validator_version = validator.and_then(|tool| tool_version(tool, self.config));
+
+        self.entries.push(ManifestEntry {
+            path: path.to_path_buf(),
+            hash: hash_file(path)?,
+            file_type: file_type.map(|file_type| file_type.to_string()),
+            validator: validator.map(str::to_string),
+            validator_version,
+            result,
+            skip_reason,
+        });
+        Ok(())
+    }
+
+    /// The finished manifest, with paths relative to `root`
+    pub fn finish(self, root: &Path) -> ScanManifest {
+        let mut files: Vec<ManifestEntry> = self.entries.into_iter()
+            .map(|mut entry| {
+                entry.path = relative_path(&entry.path, root);
+                entry
+            })
+            .collect();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files.dedup_by(|a, b| a.path == b.path);
+
+        ScanManifest {
+            synx_version: crate::VERSION.to_string(),
+            generated_at: Utc::now(),
+            root: root.canonicalize().unwrap_or_else(|_| root.to_path_buf()),
+            files,
+            signature: None,
+        }
+    }
+}
+
+impl ScanManifest {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .context(format!("Failed to read manifest {}", path.display()))?;
+        serde_json::from_str(&content)
+            .context(format!("Invalid manifest {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .context(format!("Failed to write manifest {}", path.display()))
+    }
+
+    /// Sign everything but the signature itself
+    pub fn sign(&mut self, key: &[u8]) -> Result<()> {
+        self.signature = None;
+        self.signature = Some(sign_data(key, &serde_json::to_vec(self)?));
+        Ok(())
+    }
+
+    /// Whether the manifest carries a signature that matches `key`
+    pub fn verify_signature(&self, key: &[u8]) -> Result<bool> {
+        let signature = match &self.signature {
+            Some(signature) => signature,
+            None => return Ok(false),
+        };
+        let unsigned = ScanManifest { signature: None, ..self.clone() };
+        Ok(*signature == sign_data(key, &serde_json::to_vec(&unsigned)?))
+    }
+
+    /// Compare every entry with the file on disk, resolving paths against
+    /// `root` (the manifest's own root when `None`)
+    pub fn check_drift(&self, root: Option<&Path>) -> Vec<Drift> {
+        let root = root.unwrap_or(&self.root);
+        self.files.iter()
+            .filter_map(|entry| match hash_file(&root.join(&entry.path)) {
+                Ok(hash) if hash == entry.hash => None,
+                Ok(_) => Some(Drift::Modified(entry.path.clone())),
+                Err(_) => Some(Drift::Missing(entry.path.clone())),
+            })
+            .collect()
+    }
+}
+
+/// Read a signing key: 64 hex characters, or exactly 32 raw bytes
+pub fn load_signing_key(path: &Path) -> Result<Vec<u8>> {
+    let bytes = fs::read(path)
+        .context(format!("Failed to read signing key {}", path.display()))?;
+    let text = String::from_utf8_lossy(&bytes);
+    let text = text.trim();
+
+    if text.len() == blake3::KEY_LEN * 2 && text.chars().all(|c| c.is_ascii_hexdigit()) {
+        return (0..text.len()).step_by(2)
+            .map(|i| u8::from_str_radix(&text[i..i + 2], 16).map_err(anyhow::Error::from))
+            .collect();
+    }
+    if bytes.len() == blake3::KEY_LEN {
+        return Ok(bytes);
+    }
+    Err(anyhow!("Signing key {} must be {} bytes or {} hex characters", path.display(), blake3::KEY_LEN, blake3::KEY_LEN * 2))
+}
+
+fn hash_file(path: &Path) -> Result<String> {
+    let content = fs::read(path)
+        .context(format!("Failed to read {}", path.display()))?;
+    Ok(blake3::hash(&content).to_hex().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(path: PathBuf, success: bool) -> FileScanRecord {
+        FileScanRecord { path, file_type: "txt".to_string(), success, issue_count: 0 }
+    }
+
+    fn manifest_for(dir: &TempDir) -> ScanManifest {
+        let valid = dir.path().join("valid.unknownext");
+        let invalid = dir.path().join("invalid.unknownext");
+        let skipped = dir.path().join("skipped.bin");
+        fs::write(&valid, "fine\n").unwrap();
+        fs::write(&invalid, "broken\n").unwrap();
+        fs::write(&skipped, [0u8, 1, 2]).unwrap();
+
+        let result = ScanResult {
+            skipped_files: vec![(skipped, SkipReason::BinaryFile)],
+            ..Default::default()
+        };
+        let mut builder = ManifestBuilder::new(None);
+        builder.add_scan(&[record(valid, true), record(invalid, false)], &result).unwrap();
+        builder.finish(dir.path())
+    }
+
+    #[test]
+    fn test_manifest_lists_every_file() {
+        let dir = TempDir::new().unwrap();
+        let manifest = manifest_for(&dir);
+
+        let summary: Vec<(&Path, ManifestOutcome)> = manifest.files.iter()
+            .map(|entry| (entry.path.as_path(), entry.result))
+            .collect();
+        assert_eq!(summary, vec![
+            (Path::new("invalid.unknownext"), ManifestOutcome::Invalid),
+            (Path::new("skipped.bin"), ManifestOutcome::Skipped),
+            (Path::new("valid.unknownext"), ManifestOutcome::Valid),
+        ]);
+        assert_eq!(manifest.files[1].skip_reason, Some(SkipReason::BinaryFile));
+        assert_eq!(manifest.files[2].hash, blake3::hash(b"fine\n").to_hex().to_string());
+        assert_eq!(manifest.synx_version, crate::VERSION);
+
+        // Survives a round trip through the file
+        let path = dir.path().join("manifest.json");
+        manifest.save(&path).unwrap();
+        assert_eq!(ScanManifest::load(&path).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_drift_is_detected() {
+        let dir = TempDir::new().unwrap();
+        let manifest = manifest_for(&dir);
+        assert!(manifest.check_drift(None).is_empty());
+
+        fs::write(dir.path().join("valid.unknownext"), "changed\n").unwrap();
+        fs::remove_file(dir.path().join("skipped.bin")).unwrap();
+
+        assert_eq!(manifest.check_drift(None), vec![
+            Drift::Missing(PathBuf::from("skipped.bin")),
+            Drift::Modified(PathBuf::from("valid.unknownext")),
+        ]);
+    }
+
+    #[test]
+    fn test_signature_covers_contents() {
+        let dir = TempDir::new().unwrap();
+        let mut manifest = manifest_for(&dir);
+        let key = [7u8; 32];
+
+        assert!(!manifest.verify_signature(&key).unwrap());
+        manifest.sign(&key).unwrap();
+        assert!(manifest.verify_signature(&key).unwrap());
+        assert!(!manifest.verify_signature(&[8u8; 32]).unwrap());
+
+        manifest.files[0].result = ManifestOutcome::Valid;
+        assert!(!manifest.verify_signature(&key).unwrap());
+    }
+
+    #[test]
+    fn test_signing_key_formats() {
+        let dir = TempDir::new().unwrap();
+        let hex_key = dir.path().join("hex.key");
+        fs::write(&hex_key, format!("{}\n", "ab".repeat(32))).unwrap();
+        assert_eq!(load_signing_key(&hex_key).unwrap(), vec![0xab; 32]);
+
+        let raw_key = dir.path().join("raw.key");
+        fs::write(&raw_key, [1u8; 32]).unwrap();
+        assert_eq!(load_signing_key(&raw_key).unwrap(), vec![1u8; 32]);
+
+        let short_key = dir.path().join("short.key");
+        fs::write(&short_key, "abc").unwrap();
+        assert!(load_signing_key(&short_key).is_err());
+    }
+}
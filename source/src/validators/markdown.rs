@@ -0,0 +1,178 @@
+//! Validation for code blocks embedded in Markdown
+//!
+//! With `--check-code-blocks`, each fenced block (```` ``` ```` or `~~~`)
+//! whose info string names a language synx has a validator for is checked
+//! as a file of that language. Errors are mapped back to lines in the
+//! Markdown file. Blocks tagged `no-check` (```` ```python no-check ````) and
+//! blocks in other languages are left alone.
+
+use std::fs;
+use std::path::Path;
+use anyhow::{Result, Context};
+
+use crate::detectors::FileType;
+use super::{ValidationOptions, ValidationResult, ValidationError, validate_source, has_builtin_validator};
+
+/// Info-string word that keeps a block from being validated
+const NO_CHECK: &str = "no-check";
+
+/// A fenced code block extracted from a Markdown document
+#[derive(Debug, Clone, PartialEq)]
+struct Block {
+    /// First word of the info string, e.g. "python"
+    lang: String,
+    content: String,
+    /// Line of the opening fence; line N of `content` is this plus N
+    fence_line: usize,
+    /// Tagged `no-check`
+    skip: bool,
+}
+
+/// Validate the code blocks in a Markdown file. Errors refer to the Markdown
+/// file and its lines.
+pub fn check_code_blocks(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let content = fs::read_to_string(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+    let path = file_path.to_string_lossy();
+    let block_options = block_options(options);
+
+    let mut result = ValidationResult { success: true, errors: Vec::new(), suppressed: 0, hidden_issues: 0 };
+    for block in extract_blocks(&content) {
+        let Some(file_type) = block_type(&block).filter(|_| !block.skip) else {
+            continue;
+        };
+        let block_result = validate_source(&block.content, file_type, &block_options).with_context(|| {
+            format!("Failed to validate the {} block on line {} of {}", block.lang, block.fence_line, file_path.display())
+        })?;
+
+        result.success &= block_result.success;
+        result.errors.extend(block_result.errors.into_iter().map(|error| ValidationError {
+            file_path: path.to_string(),
+            // Errors about the whole block point at its fence
+            line: Some(error.line.map_or(block.fence_line, |line| block.fence_line + line)),
+            ..error
+        }));
+    }
+    Ok(result)
+}
+
+/// Options for validating one block: only the language's own validator runs,
+/// and errors are displayed once mapped to the Markdown file
+fn block_options(options: &ValidationOptions) -> ValidationOptions {
+    let mut options = options.clone();
+    options.verbose = false;
+    if let Some(config) = options.config.as_mut() {
+        config.complexity.enabled = Some(false);
+        config.hygiene.enabled = Some(false);
+        config.secrets.enabled = Some(false);
+        config.check_code_blocks = false;
+    }
+    options
+}
+
+/// The file type a block is validated as, if synx has a validator for its language
+fn block_type(block: &Block) -> Option<FileType> {
+    FileType::from_name(&block.lang)
+        .or_else(|| FileType::from_extension(&block.lang))
+        .filter(has_builtin_validator)
+}
+
+/// An opening code fence
+struct Fence<'a> {
+    marker: char,
+    len: usize,
+    indent: usize,
+    info: &'a str,
+}
+
+impl<'a> Fence<'a> {
+    fn open(line: &'a str) -> Option<Self> {
+        let (indent, rest) = split_indent(line)?;
+        let marker = rest.chars().next().filter(|c| *c == '`' || *c == '~')?;
+        let len = rest.len() - rest.trim_start_matches(marker).len();
+        let info = rest[len..].trim();
+        // A backtick in a backtick fence's info string makes it inline code
+        if len < 3 || (marker == '`' && info.contains('`')) {
+            return None;
+        }
+        Some(Self { marker, len, indent, info })
+    }
+
+    fn closed_by(&self, line: &str) -> bool {
+        let Some((_, rest)) = split_indent(line) else {
+            return false;
+        };
+        let len = rest.len() - rest.trim_start_matches(self.marker).len();
+        len >= self.len && rest[len..].trim().is_empty()
+    }
+}
+
+// Leading spaces and the rest of the line; fences are indented at most 3 spaces
+fn split_indent(line: &str) -> Option<(usize, &str)> {
+    let rest = line.trim_start_matches(' ');
+    let indent = line.len() - rest.len();
+    (indent <= 3).then_some((indent, rest))
+}
+
+fn extract_blocks(content: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = content.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let Some(fence) = Fence::open(line) else {
+            continue;
+        };
+
+        // An unclosed fence runs to the end of the document
+        let mut content = String::new();
+        for (_, line) in lines.by_ref() {
+            if fence.closed_by(line) {
+                break;
+            }
+            // Content loses as much indentation as the fence had
+            let indent = line.len() - line.trim_start_matches(' ').len();
+            content.push_str(&line[indent.min(fence.indent)..]);
+            content.push('\n');
+        }
+
+        // `rust,ignore` style info strings separate words with commas
+        let mut words = fence.info.split(|c: char| c.is_whitespace() || c == ',').filter(|word| !word.is_empty());
+        let lang = words.next().unwrap_or("").to_string();
+        let skip = lang == NO_CHECK || words.any(|word| word == NO_CHECK);
+        blocks.push(Block { lang, content, fence_line: index + 1, skip });
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_blocks() {
+        let markdown = "# Title\n\n```python\nx = 1\n```\n\n  ~~~~sh no-check\n  echo hi\n    indented\n  ~~~~\n\n``not a fence``\n";
+        let blocks = extract_blocks(markdown);
+        assert_eq!(blocks, vec![
+            Block { lang: "python".to_string(), content: "x = 1\n".to_string(), fence_line: 3, skip: false },
+            Block { lang: "sh".to_string(), content: "echo hi\n  indented\n".to_string(), fence_line: 7, skip: true },
+        ]);
+    }
+
+    #[test]
+    fn test_fence_closing() {
+        // A shorter fence or other marker doesn't close the block; an unclosed block runs to the end
+        let blocks = extract_blocks("````js\n```\n~~~\n````\n```rust,no-check\nfn main() {}\n");
+        assert_eq!(blocks[0].content, "```\n~~~\n");
+        assert_eq!((blocks[1].lang.as_str(), blocks[1].skip), ("rust", true));
+        assert_eq!(blocks[1].content, "fn main() {}\n");
+    }
+
+    #[test]
+    fn test_block_types() {
+        let block = |lang: &str| Block { lang: lang.to_string(), content: String::new(), fence_line: 1, skip: false };
+        assert_eq!(block_type(&block("Python")), Some(FileType::Python));
+        assert_eq!(block_type(&block("py")), Some(FileType::Python));
+        assert_eq!(block_type(&block("text")), None);
+        assert_eq!(block_type(&block("")), None);
+        assert_eq!(block_type(&block("markdown")), None);
+    }
+}
@@ -0,0 +1,152 @@
+//! Memory-leak analysis for compiled languages
+//!
+//! Bridges the `analysis` module's memory analyzers into the validation
+//! pipeline. Analysis builds and runs the file, so it is opt-in and only
+//! applies to C, C++ and Rust sources.
+
+use std::path::Path;
+use anyhow::{Result, anyhow};
+
+use crate::analysis::{
+    Analyzer, AnalysisOptions, AnalysisDetails, IssueSeverity,
+    MemoryAnalyzer, AddressSanitizer, RustMemoryLeakDetector,
+};
+use super::{ValidationOptions, ValidationError, ErrorType};
+
+/// Check whether memory analysis applies to this file under the given options.
+///
+/// The `--analyze memory` flag enables it for all compiled languages; for C
+/// files it can also be switched on with `check_memory = true` in `[validators.c]`.
+pub fn should_analyze(file_path: &Path, options: &ValidationOptions) -> bool {
+    let ext = file_path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "c" => {
+            let check_memory = options.config.as_ref()
+                .and_then(|c| c.validators.c.check_memory)
+                .unwrap_or(false);
+            options.analyze_memory || check_memory
+        }
+        "cpp" | "cxx" | "cc" | "rs" => options.analyze_memory,
+        _ => false,
+    }
+}
+
+/// Build and run the file under the appropriate memory analyzer and return
+/// each detected leak as a high-severity `ValidationError`.
+pub fn analyze_memory(file_path: &Path, options: &ValidationOptions) -> Result<Vec<ValidationError>> {
+    let analyzer = select_analyzer(file_path)?;
+
+    if options.verbose {
+        eprintln!("Running {} on {}", analyzer.name(), file_path.display());
+    }
+
+    let analysis_options = AnalysisOptions {
+        verbose: options.verbose,
+        ..Default::default()
+    };
+    let result = analyzer.analyze(file_path, &analysis_options)?;
+
+    let mut errors = Vec::new();
+    if let AnalysisDetails::Memory(details) = &result.details {
+        for leak in &details.leaks {
+            errors.push(ValidationError {
+                file_path: file_path.to_string_lossy().to_string(),
+                error_type: ErrorType::MemoryLeak,
+                message: format!(
+                    "Memory leak of {} bytes allocated at {}",
+                    leak.size, leak.allocation_location
+                ),
+                line: None,
+                column: None,
+                code: Some(result.analyzer_name.clone()),
+                suggestion: Some("Ensure all allocated memory is properly freed.".to_string()),
+                severity: IssueSeverity::High,
+            });
+        }
+    }
+
+    Ok(errors)
+}
+
+/// Pick the analyzer for the file's language, preferring Valgrind for C/C++
+fn select_analyzer(file_path: &Path) -> Result<Box<dyn Analyzer>> {
+    let ext = file_path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    let candidates: Vec<Box<dyn Analyzer>> = match ext.as_str() {
+        "c" | "cpp" | "cxx" | "cc" => vec![
+            Box::new(MemoryAnalyzer::new()),
+            Box::new(AddressSanitizer),
+        ],
+        "rs" => vec![Box::new(RustMemoryLeakDetector)],
+        _ => return Err(anyhow!("Memory analysis is not supported for {}", file_path.display())),
+    };
+
+    candidates.into_iter()
+        .find(|analyzer| analyzer.is_available())
+        .ok_or_else(|| anyhow!("No memory analyzer available for {}", file_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::FileValidationConfig;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files/c/memory").join(name)
+    }
+
+    #[test]
+    fn test_should_analyze_is_opt_in() {
+        let options = ValidationOptions::default();
+        assert!(!should_analyze(Path::new("main.c"), &options));
+        assert!(!should_analyze(Path::new("main.rs"), &options));
+
+        let options = ValidationOptions {
+            analyze_memory: true,
+            ..Default::default()
+        };
+        assert!(should_analyze(Path::new("main.c"), &options));
+        assert!(should_analyze(Path::new("main.cpp"), &options));
+        assert!(should_analyze(Path::new("main.rs"), &options));
+        assert!(!should_analyze(Path::new("main.py"), &options));
+    }
+
+    #[test]
+    fn test_should_analyze_respects_check_memory() {
+        let mut config = FileValidationConfig::default();
+        config.validators.c.check_memory = Some(true);
+        let options = ValidationOptions {
+            config: Some(config),
+            ..Default::default()
+        };
+
+        assert!(should_analyze(Path::new("main.c"), &options));
+        assert!(!should_analyze(Path::new("main.cpp"), &options));
+    }
+
+    #[test]
+    fn test_leaking_c_fixture_is_reported() {
+        if !MemoryAnalyzer::new().is_available() {
+            eprintln!("valgrind not available, skipping");
+            return;
+        }
+
+        let options = ValidationOptions {
+            analyze_memory: true,
+            ..Default::default()
+        };
+        let errors = analyze_memory(&fixture("leak.c"), &options).unwrap();
+
+        assert!(!errors.is_empty());
+        assert!(errors.iter().all(|e| e.error_type == ErrorType::MemoryLeak));
+        assert!(errors.iter().all(|e| e.severity == IssueSeverity::High));
+    }
+}
@@ -1,62 +1,831 @@
 use anyhow::{Result, anyhow};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::io::{Read, Write};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 use std::str;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use once_cell::sync::Lazy;
+use std::fs;
+use serde::{Serialize, Deserialize};
 
 pub mod scan;
-pub use scan::{scan_directory, ScanResult};
+pub use scan::{
+    scan_directory, scan_directory_streaming, scan_directory_with_events, ScanStream, ScannedFile, FileOutcome, ScanResult, ScanStats, SkipReason, LanguageFilter, FileScanRecord,
+    ScanReportV1, SkippedFileReport, TypeReport, SCAN_REPORT_SCHEMA_VERSION,
+    write_ndjson, parse_size, parse_since, modified_since,
+};
+pub mod memory;
+pub mod encoding;
+pub mod sfc;
+pub mod yaml;
+pub mod dotenv;
+pub mod markdown;
+pub mod package_manifest;
+pub mod indentation;
+pub mod complexity;
+pub mod hygiene;
+pub mod secrets;
+pub mod generated;
+pub mod empty;
+pub use empty::EmptyFileError;
+pub mod adoption;
+pub mod suppress;
+pub mod external;
+pub mod custom;
+pub mod manifest;
+pub mod registry;
+pub mod archive;
+pub mod project;
+pub mod progress;
+pub use progress::{validate_files_with_progress, ProgressEvent, ProgressSummary};
+pub use registry::{ValidatorRegistry, ValidatorFn};
+pub use manifest::{ScanManifest, ManifestBuilder, ManifestEntry, ManifestOutcome, Drift};
+mod checkstyle;
+pub use checkstyle::to_checkstyle;
 mod display;
-pub use display::display_scan_results;
+pub use display::{display_scan_results, write_scan_results, relative_path};
+pub mod report;
+pub use report::{Reporter, ReportOptions, reporter, format_for_path, write_report_dir, REPORT_FORMATS};
 mod error_display;
 pub use error_display::{ValidationError, ErrorType, ErrorDisplay, parse_validation_output, display_validation_errors};
 
 // Import the configuration module
-
-#[derive(Default)]
+use crate::config::{
+    Config, ValidatorConfigs, EncodingConfig, ComplexityConfig, HygieneConfig, SecretsConfig, GeneratedConfig, AdoptionConfig, CustomValidatorConfig,
+    DEFAULT_MAX_OUTPUT_BYTES, DEFAULT_MAX_ISSUES_PER_FILE,
+};
+use crate::detectors::FileType;
+use crate::analysis::IssueSeverity;
+use crate::performance::Profiler;
+
+#[derive(Clone, Default)]
 pub struct ValidationOptions {
     pub strict: bool,
     pub verbose: bool,
+    /// Global timeout in seconds; 0 falls back to `DEFAULT_TIMEOUT_SECS`
     pub timeout: u64,
     pub config: Option<FileValidationConfig>,
+    /// Run memory-leak analysis on compiled languages (opt-in, slow)
+    pub analyze_memory: bool,
+    /// Files larger than this many bytes are skipped by scans
+    pub max_file_size: Option<u64>,
+    /// Validate files even if they look binary
+    pub allow_binary: bool,
+    /// Scans leave out files last modified before this time
+    pub modified_since: Option<SystemTime>,
+    /// How deep scans descend below the root; 1 means top-level files only
+    pub max_depth: Option<usize>,
+    /// Where scans record how long each phase took
+    pub profiler: Option<Arc<Profiler>>,
+    /// Scans reuse cached results for files whose content hasn't changed
+    pub incremental: bool,
+    /// Scans descend into symlinked directories (each directory at most once)
+    /// instead of skipping symlinks
+    pub follow_symlinks: bool,
+    /// Keep validator tools off the network: package managers run offline and
+    /// nothing is restored or downloaded
+    pub offline: bool,
+    /// Validators registered in code; these win over config and built-in ones
+    pub registry: ValidatorRegistry,
 }
 
 #[derive(Debug, Clone)]
 pub struct FileValidationConfig {
     pub file_mappings: Option<HashMap<String, String>>,
+    pub validators: ValidatorConfigs,
+    pub encoding: EncodingConfig,
+    pub tools: HashMap<String, String>,
+    pub complexity: ComplexityConfig,
+    pub hygiene: HygieneConfig,
+    pub secrets: SecretsConfig,
+    pub max_output_bytes: usize,
+    pub max_issues_per_file: usize,
+    pub concurrency: HashMap<String, usize>,
+    pub generated: GeneratedConfig,
+    pub adoption: AdoptionConfig,
+    /// Validate every file as this type instead of detecting it
+    pub language: Option<FileType>,
+    /// Config files already applied; scans don't layer these on again
+    pub loaded_config_paths: Vec<PathBuf>,
+    /// `[scan] empty_files`: "pass" (default), "skip" or "fail"
+    pub empty_files: Option<String>,
+    /// Validate fenced code blocks in Markdown files
+    pub check_code_blocks: bool,
 }
 
 impl Default for FileValidationConfig {
     fn default() -> Self {
         Self {
             file_mappings: None,
+            validators: ValidatorConfigs::default(),
+            encoding: EncodingConfig::default(),
+            tools: HashMap::new(),
+            complexity: ComplexityConfig::default(),
+            hygiene: HygieneConfig::default(),
+            secrets: SecretsConfig::default(),
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
+            max_issues_per_file: DEFAULT_MAX_ISSUES_PER_FILE,
+            concurrency: HashMap::new(),
+            generated: GeneratedConfig::default(),
+            adoption: AdoptionConfig::default(),
+            language: None,
+            loaded_config_paths: Vec::new(),
+            empty_files: None,
+            check_code_blocks: false,
+        }
+    }
+}
+
+impl FileValidationConfig {
+    /// Build the per-file validation settings from a loaded configuration
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            file_mappings: None,
+            validators: config.validators.clone(),
+            encoding: config.encoding.clone(),
+            tools: config.tools.clone(),
+            complexity: config.complexity.clone(),
+            hygiene: config.hygiene.clone(),
+            secrets: config.secrets.clone(),
+            max_output_bytes: config.max_output_bytes,
+            max_issues_per_file: config.max_issues_per_file,
+            concurrency: config.concurrency.clone(),
+            generated: config.generated.clone(),
+            adoption: config.adoption.clone(),
+            language: config.language.clone(),
+            loaded_config_paths: config.loaded_config_paths.clone(),
+            empty_files: config.scan.empty_files.clone(),
+            check_code_blocks: config.check_code_blocks,
+        }
+    }
+}
+
+impl ValidationOptions {
+    /// These options with the config file at `path` layered on top, as used
+    /// for files below a subproject's own `.synx.toml`
+    pub fn with_config_file(&self, path: &Path) -> Result<ValidationOptions> {
+        let mut config = Config {
+            strict: self.strict,
+            verbose: self.verbose,
+            timeout: self.timeout,
+            ..Config::default()
+        };
+        if let Some(current) = &self.config {
+            config.validators = current.validators.clone();
+            config.encoding = current.encoding.clone();
+            config.tools = current.tools.clone();
+            config.complexity = current.complexity.clone();
+            config.hygiene = current.hygiene.clone();
+            config.secrets = current.secrets.clone();
+            config.max_output_bytes = current.max_output_bytes;
+            config.max_issues_per_file = current.max_issues_per_file;
+            config.concurrency = current.concurrency.clone();
+            config.generated = current.generated.clone();
+            config.adoption = current.adoption.clone();
+            config.language = current.language.clone();
+            config.loaded_config_paths = current.loaded_config_paths.clone();
+            config.scan.empty_files = current.empty_files.clone();
+            config.check_code_blocks = current.check_code_blocks;
+        }
+        config.merge_file(path)?;
+
+        Ok(ValidationOptions {
+            strict: config.strict,
+            verbose: config.verbose,
+            timeout: config.timeout,
+            config: Some(FileValidationConfig::from_config(&config)),
+            ..self.clone()
+        })
+    }
+}
+
+/// Timeout used when neither the file type nor the command line sets one
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Resolve the timeout for a validator: per-file-type config, then the global
+/// `--timeout`, then the default
+fn validator_timeout(language: &str, options: &ValidationOptions) -> Duration {
+    let per_type = options.config.as_ref()
+        .and_then(|config| config.validators.timeout_for(language));
+    let global = Some(options.timeout).filter(|&secs| secs > 0);
+    Duration::from_secs(per_type.or(global).unwrap_or(DEFAULT_TIMEOUT_SECS))
+}
+
+/// A validator's external tool isn't installed (or isn't where the config says)
+#[derive(Debug)]
+pub struct MissingToolError {
+    pub tool: String,
+}
+
+impl std::fmt::Display for MissingToolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Validator tool '{}' is not installed", self.tool)
+    }
+}
+
+impl std::error::Error for MissingToolError {}
+
+/// A validator's external tool was killed by a signal (e.g. the OOM killer)
+/// instead of exiting, so its output says nothing about the file
+#[derive(Debug)]
+pub struct ToolCrashedError {
+    pub tool: String,
+    pub signal: i32,
+}
+
+impl std::fmt::Display for ToolCrashedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Validator tool '{}' was killed by signal {}", self.tool, self.signal)
+    }
+}
+
+impl std::error::Error for ToolCrashedError {}
+
+impl ToolCrashedError {
+    /// The crash as a failed result for `file_path`
+    fn into_result(self, file_path: &Path) -> ValidationResult {
+        ValidationResult {
+            success: false,
+            errors: vec![ValidationError {
+                file_path: file_path.to_string_lossy().to_string(),
+                error_type: ErrorType::ToolCrashed,
+                message: self.to_string(),
+                line: None,
+                column: None,
+                code: Some(format!("signal-{}", self.signal)),
+                suggestion: Some("The tool crashed before checking the file; it may have run out of memory".to_string()),
+                severity: ErrorType::ToolCrashed.default_severity(),
+            }],
+            suppressed: 0,
+            hidden_issues: 0,
+        }
+    }
+}
+
+/// The signal that ended a process, if one did
+#[cfg(unix)]
+fn terminating_signal(status: &ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+#[cfg(not(unix))]
+fn terminating_signal(_status: &ExitStatus) -> Option<i32> {
+    None
+}
+
+/// The file looks binary, so it wasn't handed to a validator
+#[derive(Debug)]
+pub struct BinaryFileError {
+    pub path: PathBuf,
+}
+
+impl std::fmt::Display for BinaryFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} looks like a binary file; skipped (use --allow-binary to validate it)", self.path.display())
+    }
+}
+
+impl std::error::Error for BinaryFileError {}
+
+/// The file's validator is turned off with `enabled = false`, so it wasn't validated
+#[derive(Debug)]
+pub struct DisabledValidatorError {
+    pub path: PathBuf,
+    /// The `[validators.<language>]` section that turned it off
+    pub language: String,
+}
+
+impl std::fmt::Display for DisabledValidatorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: the {} validator is disabled; skipped", self.path.display(), self.language)
+    }
+}
+
+impl std::error::Error for DisabledValidatorError {}
+
+/// The file was gone by the time it was validated, e.g. deleted by a build
+/// running alongside a scan
+#[derive(Debug)]
+pub struct VanishedFileError {
+    pub path: PathBuf,
+}
+
+impl std::fmt::Display for VanishedFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} does not exist (it may have been removed during the scan)", self.path.display())
+    }
+}
+
+impl std::error::Error for VanishedFileError {}
+
+/// Most bytes of a validator's stdout and of its stderr that are kept
+fn output_limit(options: &ValidationOptions) -> usize {
+    options.config.as_ref()
+        .map(|config| config.max_output_bytes)
+        .unwrap_or(DEFAULT_MAX_OUTPUT_BYTES)
+}
+
+/// Run a validator command, killing it if it is still running after `timeout`.
+/// Only the first `output_limit` bytes of each output stream are kept.
+fn run_with_timeout(cmd: &mut Command, timeout: Duration, output_limit: usize) -> Result<Output> {
+    run_with_input(cmd, None, timeout, output_limit)
+}
+
+/// Like `run_with_timeout`, but feeding `input` to the command's stdin
+fn run_with_input(cmd: &mut Command, input: Option<Vec<u8>>, timeout: Duration, output_limit: usize) -> Result<Output> {
+    let stdin = if input.is_some() { Stdio::piped() } else { Stdio::null() };
+    let mut child = cmd
+        .stdin(stdin)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => anyhow::Error::new(MissingToolError {
+                tool: cmd.get_program().to_string_lossy().to_string(),
+            }),
+            _ => e.into(),
+        })?;
+
+    // Write and drain the pipes on their own threads so a chatty tool can't block on a full pipe
+    if let (Some(mut stdin), Some(input)) = (child.stdin.take(), input) {
+        thread::spawn(move || {
+            let _ = stdin.write_all(&input);
+        });
+    }
+    let stdout = child.stdout.take().map(|pipe| read_pipe(pipe, output_limit));
+    let stderr = child.stderr.take().map(|pipe| read_pipe(pipe, output_limit));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow!("Validation timed out after {} seconds", timeout.as_secs()));
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    if let Some(signal) = terminating_signal(&status) {
+        return Err(ToolCrashedError {
+            tool: cmd.get_program().to_string_lossy().to_string(),
+            signal,
+        }.into());
+    }
+
+    let collect = |handle: Option<thread::JoinHandle<Vec<u8>>>| {
+        handle.and_then(|h| h.join().ok()).unwrap_or_default()
+    };
+    Ok(Output { status, stdout: collect(stdout), stderr: collect(stderr) })
+}
+
+/// Marker appended to output that went over the capture limit
+pub const TRUNCATED_MARKER: &str = "\n... (truncated)";
+
+/// Read a pipe to the end, keeping at most `limit` bytes. The rest is read and
+/// dropped so the tool never blocks on a full pipe.
+fn read_pipe<R: Read + Send + 'static>(mut pipe: R, limit: usize) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 8192];
+        let mut truncated = false;
+        loop {
+            let read = match pipe.read(&mut chunk) {
+                Ok(0) | Err(_) => break,
+                Ok(read) => read,
+            };
+            let room = limit.saturating_sub(buffer.len());
+            buffer.extend_from_slice(&chunk[..read.min(room)]);
+            truncated |= read > room;
         }
+        if truncated {
+            buffer.extend_from_slice(TRUNCATED_MARKER.as_bytes());
+        }
+        buffer
+    })
+}
+
+/// Build a command for a validator tool checking `file_path`, using the
+/// configured path if one is set. It runs from the file's project root.
+fn tool_command(tool: &str, file_path: &Path, options: &ValidationOptions) -> Command {
+    let program = options.config.as_ref()
+        .and_then(|config| config.tools.get(tool))
+        .map(String::as_str)
+        .unwrap_or(tool);
+    let mut cmd = Command::new(program);
+    project::run_from_project_root(&mut cmd, file_path);
+    if options.offline {
+        cmd.envs(OFFLINE_ENV.iter().copied());
     }
+    cmd
+}
+
+/// Environment that stops package managers from reaching the network, so a
+/// tool that needs a download fails with its own offline error instead of
+/// waiting on the connection until the timeout
+pub const OFFLINE_ENV: &[(&str, &str)] = &[
+    ("CARGO_NET_OFFLINE", "true"),
+    ("npm_config_offline", "true"),
+    ("GOPROXY", "off"),
+];
+
+/// Outcome of validating one file, with the issues the tool reported
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationResult {
+    pub success: bool,
+    #[serde(default)]
+    pub errors: Vec<ValidationError>,
+    /// Issues left out because of a `synx-disable` comment
+    #[serde(default)]
+    pub suppressed: usize,
+    /// Issues past `max_issues_per_file` that were dropped; the last entry
+    /// in `errors` then says how many were found in all
+    #[serde(default)]
+    pub hidden_issues: usize,
 }
 
+impl ValidationResult {
+    pub fn passed() -> Self {
+        Self { success: true, errors: Vec::new(), suppressed: 0, hidden_issues: 0 }
+    }
+
+    /// Build a result from a finished tool run, parsing its output into errors on failure
+    fn from_output(file_path: &Path, language: &str, success: bool, output: &Output) -> Self {
+        if success {
+            return Self::passed();
+        }
+
+        let text = if !output.stderr.is_empty() {
+            String::from_utf8_lossy(&output.stderr)
+        } else {
+            String::from_utf8_lossy(&output.stdout)
+        };
+        Self {
+            success,
+            errors: parse_validation_output(file_path, text.trim_end(), language),
+            suppressed: 0,
+            hidden_issues: 0,
+        }
+    }
+}
+
+type Validator = fn(&Path, &ValidationOptions) -> Result<ValidationResult>;
+
 pub fn validate_file(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let file_type = detect_file_type(file_path)?;
+    validate_file_detailed(file_path, options).map(|result| result.success)
+}
+
+/// Validate a file and return the individual issues found along with the verdict
+pub fn validate_file_detailed(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    // Stat again at read time: a build may have deleted the file since a scan found it
+    if vanished(file_path) {
+        return Err(VanishedFileError { path: file_path.to_path_buf() }.into());
+    }
     
-    // Check for custom validation rules
-    if let Some(config) = &options.config {
-        if let Some(mapped_type) = process_mappings(config, &file_type) {
-            // Use the mapped file type for validation
-            let validator = get_validator_for_type(&mapped_type);
-            return validator(file_path, options);
+    // A file deleted while it was being checked shows up as whatever read or
+    // tool run failed, so look again before reporting that
+    match validate_present_file(file_path, options) {
+        Err(_) if vanished(file_path) => Err(VanishedFileError { path: file_path.to_path_buf() }.into()),
+        Ok(result) if !result.success && vanished(file_path) => {
+            Err(VanishedFileError { path: file_path.to_path_buf() }.into())
+        }
+        validated => validated,
+    }
+}
+
+/// Whether `path` no longer exists
+fn vanished(path: &Path) -> bool {
+    matches!(fs::metadata(path), Err(e) if e.kind() == std::io::ErrorKind::NotFound)
+}
+
+fn validate_present_file(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    // A language whose validator is turned off is left alone entirely
+    let file_type = validator_type(file_path, options);
+    if let Some(language) = file_type.as_ref().ok().and_then(|file_type| disabled_section(file_type, options)) {
+        return Err(DisabledValidatorError { path: file_path.to_path_buf(), language: language.to_string() }.into());
+    }
+    
+    // Linters fed binary data emit garbage, so don't hand it to them
+    if !options.allow_binary && encoding::looks_binary(file_path)? {
+        return Err(BinaryFileError { path: file_path.to_path_buf() }.into());
+    }
+    
+    // Tools disagree about empty files, so one policy decides for every language
+    let empty_files = empty::policy(options.config.as_ref().and_then(|config| config.empty_files.as_deref()))?;
+    if empty::is_empty(file_path)? {
+        return empty::empty_result(file_path, empty_files);
+    }
+    
+    // Under an `[adoption]` policy the file's age decides how strict to be,
+    // otherwise the language's own `strict` setting overrides --strict
+    let adopted;
+    let strict = options.config.as_ref().and_then(|config| {
+        adoption::strict_for(file_path, &config.adoption).or_else(|| {
+            file_type.as_ref().ok()
+                .and_then(|file_type| language_section(file_type))
+                .and_then(|section| config.validators.strict_for(section))
+        })
+    });
+    let options = match strict {
+        Some(strict) if strict != options.strict => {
+            adopted = ValidationOptions { strict, ..options.clone() };
+            &adopted
+        }
+        _ => options,
+    };
+    
+    validate_as(file_path, &file_type?, options)
+}
+
+/// The config section that disables the validator for `file_type`, if one does
+fn disabled_section(file_type: &str, options: &ValidationOptions) -> Option<&'static str> {
+    let config = options.config.as_ref()?;
+    language_section(file_type)
+        .filter(|section| config.validators.is_disabled(section))
+}
+
+/// The `[validators.<language>]` section that configures `file_type`
+fn language_section(file_type: &str) -> Option<&'static str> {
+    FileType::from_extension(file_type)
+        .or_else(|| FileType::from_name(file_type))
+        .as_ref()
+        .and_then(crate::config::validator_section)
+}
+
+/// Whether synx has a validator for this file once config mappings are applied
+pub fn has_validator(file_path: &Path, options: &ValidationOptions) -> bool {
+    validator_type(file_path, options)
+        .map(|file_type| {
+            options.registry.get(&file_type).is_some()
+                || custom_validator(&file_type, options).is_some()
+                || get_validator_for_type(&file_type).is_some()
+                || external::find_plugin(&file_type).is_some()
+        })
+        .unwrap_or(false)
+}
+
+/// The key used to pick a validator: the forced type if there is one, then
+/// the mapped file type if the config has one, else the detected one
+fn validator_type(file_path: &Path, options: &ValidationOptions) -> Result<String> {
+    if let Some(language) = options.config.as_ref().and_then(|config| config.language.as_ref()) {
+        return Ok(validator_key(language));
+    }
+    let mut file_type = detect_file_type(file_path)?;
+    // A `.h` file written in C++ is checked like any other C++ header
+    if file_type == "h" && crate::detectors::is_cpp_header(file_path) {
+        file_type = "hpp".to_string();
+    }
+    let mapped_type = options.config.as_ref()
+        .and_then(|config| process_mappings(config, file_path, &file_type));
+    Ok(mapped_type.unwrap_or(file_type))
+}
+
+/// The key `validator_type` gives files of a type: their extension, as
+/// detection would find it
+fn validator_key(file_type: &FileType) -> String {
+    match file_type {
+        FileType::Dockerfile => "dockerfile".to_string(),
+        FileType::DotEnv => "env".to_string(),
+        _ => file_type.file_name().trim_start_matches("source.").to_string(),
+    }
+}
+
+/// Validate source code held in memory.
+///
+/// The content is written to a private temporary file (see
+/// `tools::SecureTempFile`) that is removed again before returning, whether
+/// or not validation succeeds. Reported
+/// errors refer to the file by its bare name (e.g. `source.py`).
+pub fn validate_source(content: &str, file_type: FileType, options: &ValidationOptions) -> Result<ValidationResult> {
+    let file_name = file_type.file_name();
+    let temp = crate::tools::SecureTempFile::new(&file_name, content.as_bytes())?;
+    
+    let mut result = validate_as(temp.path(), &validator_key(&file_type), options)?;
+    
+    // Don't leak the temporary location into the results
+    let temp_path = temp.path().to_string_lossy().to_string();
+    for error in &mut result.errors {
+        error.file_path = file_name.clone();
+        error.message = error.message.replace(&temp_path, &file_name);
+    }
+    Ok(result)
+}
+
+fn validate_as(file_path: &Path, file_type: &str, options: &ValidationOptions) -> Result<ValidationResult> {
+    // Encoding problems make language tools fail in confusing ways, so check them first
+    let encoding_result = check_file_encoding(file_path, options)?;
+    if !encoding_result.success {
+        return Ok(encoding_result);
+    }
+    
+    // Tools run from the project root, where a relative path no longer leads to the file
+    let tool_path = project::absolute(file_path).unwrap_or_else(|| file_path.to_path_buf());
+    
+    // A validator registered in code comes first, then a custom one from the
+    // config, which replaces the built-in one for its type
+    let validated = if let Some(registered) = options.registry.get(file_type) {
+        registered(&tool_path, options)
+    } else if let Some(custom) = custom_validator(file_type, options) {
+        custom::validate_custom(file_type, custom, &tool_path, options)
+    } else {
+        match get_validator_for_type(file_type) {
+            Some(validator) => validator(&tool_path, options),
+            // No built-in validator, so look for a synx-validator-<type> on PATH
+            None => match external::find_plugin(file_type) {
+                Some(plugin) => external::validate_with_plugin(&plugin, &tool_path, file_type, options),
+                None => validate_unknown(&tool_path, options),
+            },
+        }
+    };
+    // A crashed tool fails the file, but as a crash rather than as a lint failure
+    let mut result = match validated {
+        Ok(result) => result,
+        Err(e) => match e.downcast::<ToolCrashedError>() {
+            Ok(crash) => return Ok(crash.into_result(file_path)),
+            Err(e) => return Err(e),
+        },
+    };
+    if tool_path != file_path {
+        restore_path(&mut result.errors, &tool_path, file_path);
+    }
+    // Encoding warnings that didn't fail the file are still worth reporting
+    result.errors.splice(0..0, encoding_result.errors);
+    
+    // The complexity budget only means something for code that parses
+    if let Some(config) = options.config.as_ref().filter(|c| complexity::is_enabled(&c.complexity)) {
+        if result.success {
+            let over_budget = complexity::check_complexity(file_path, &config.complexity)?;
+            if !over_budget.is_empty() {
+                if options.verbose {
+                    let _ = display_validation_errors(&over_budget);
+                }
+                result.success = false;
+                result.errors.extend(over_budget);
+            }
+        }
+    }
+    
+    if let Some(config) = options.config.as_ref().filter(|c| hygiene::is_enabled(&c.hygiene)) {
+        let violations = hygiene::check_hygiene(file_path, &config.hygiene)?;
+        if !violations.is_empty() {
+            if options.verbose {
+                let _ = display_validation_errors(&violations);
+            }
+            result.success = false;
+            result.errors.extend(violations);
+        }
+    }
+    
+    let markdown = matches!(FileType::from_extension(file_type).or_else(|| FileType::from_name(file_type)), Some(FileType::Markdown));
+    if markdown && options.config.as_ref().is_some_and(|c| c.check_code_blocks) {
+        let blocks = markdown::check_code_blocks(file_path, options)?;
+        if !blocks.errors.is_empty() && options.verbose {
+            let _ = display_validation_errors(&blocks.errors);
+        }
+        result.success &= blocks.success;
+        result.errors.extend(blocks.errors);
+    }
+    
+    // Cargo.toml and package.json have rules beyond their syntax
+    if package_manifest::is_manifest(file_path) {
+        let problems = package_manifest::check_manifest(file_path)?;
+        if !problems.is_empty() {
+            if options.verbose {
+                let _ = display_validation_errors(&problems);
+            }
+            result.success = false;
+            result.errors.extend(problems);
+        }
+    }
+    
+    if let Some(config) = options.config.as_ref().filter(|c| secrets::is_enabled(&c.secrets)) {
+        let secrets = secrets::check_secrets(file_path, &config.secrets)?;
+        if !secrets.is_empty() {
+            if options.verbose {
+                let _ = display_validation_errors(&secrets);
+            }
+            result.success = false;
+            result.errors.extend(secrets);
+        }
+    }
+    
+    apply_suppressions(&mut result, file_path, options);
+    
+    let max_issues = options.config.as_ref()
+        .map(|config| config.max_issues_per_file)
+        .unwrap_or(DEFAULT_MAX_ISSUES_PER_FILE);
+    cap_issues(&mut result, file_path, max_issues);
+    
+    Ok(result)
+}
+
+/// Refer to the file as it was given rather than by the absolute path the tool saw
+fn restore_path(errors: &mut [ValidationError], tool_path: &Path, file_path: &Path) {
+    let tool_path = tool_path.to_string_lossy().to_string();
+    let file_path = file_path.to_string_lossy().to_string();
+    for error in errors {
+        if error.file_path == tool_path {
+            error.file_path = file_path.clone();
         }
+        error.message = error.message.replace(&tool_path, &file_path);
+    }
+}
+
+/// Drop the issues covered by `synx-disable` comments. A failed file whose
+/// every failing issue was suppressed passes.
+fn apply_suppressions(result: &mut ValidationResult, file_path: &Path, options: &ValidationOptions) {
+    if result.errors.is_empty() {
+        return;
+    }
+    let content = match fs::read(file_path) {
+        Ok(content) => content,
+        Err(_) => return,
+    };
+    let suppressions = suppress::Suppressions::parse(&String::from_utf8_lossy(&content));
+    if suppressions.is_empty() {
+        return;
+    }
+
+    result.suppressed = suppress::apply(&mut result.errors, &suppressions);
+    if result.suppressed > 0 && !result.success {
+        result.success = !result.errors.iter().any(|e| options.strict || e.error_type != ErrorType::Warning);
+    }
+}
+
+/// Code of the warning that stands in for issues past `max_issues_per_file`
+pub const TRUNCATED_ISSUES_CODE: &str = "issues-truncated";
+
+/// Keep the first `max_issues` errors and summarize the rest in one warning
+fn cap_issues(result: &mut ValidationResult, file_path: &Path, max_issues: usize) {
+    if result.errors.len() <= max_issues {
+        return;
+    }
+    let total = result.errors.len();
+    result.hidden_issues = total - max_issues;
+    result.errors.truncate(max_issues);
+    result.errors.push(ValidationError {
+        file_path: file_path.to_string_lossy().to_string(),
+        error_type: ErrorType::Warning,
+        message: format!("Showing {} of {} issues", max_issues, total),
+        line: None,
+        column: None,
+        code: Some(TRUNCATED_ISSUES_CODE.to_string()),
+        suggestion: Some("Raise max_issues_per_file in the [general] config section, or pass --max-issues-per-file, to see them all".to_string()),
+        severity: IssueSeverity::Low,
+    });
+}
+
+/// Run the encoding pre-check (fixing the file first if configured).
+/// The result is unsuccessful if the file should be treated as invalid.
+fn check_file_encoding(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let default_config = EncodingConfig::default();
+    let config = options.config.as_ref()
+        .map(|c| &c.encoding)
+        .unwrap_or(&default_config);
+    
+    if config.fix.unwrap_or(false) && encoding::fix_encoding(file_path)? && options.verbose {
+        eprintln!("Fixed encoding of {}", file_path.display());
+    }
+    
+    let errors = encoding::check_encoding(file_path, config)?;
+    if errors.is_empty() {
+        return Ok(ValidationResult::passed());
+    }
+    
+    if options.verbose {
+        let _ = display_validation_errors(&errors);
     }
     
-    // Use default validator for the file type
-    let validator = get_validator_for_type(&file_type);
-    validator(file_path, options)
+    // Warnings (an allowed BOM, mixed line endings) only fail in strict mode
+    let success = !errors.iter().any(|e| options.strict || e.error_type != ErrorType::Warning);
+    Ok(ValidationResult { success, errors, suppressed: 0, hidden_issues: 0 })
 }
 
-fn process_mappings(config: &FileValidationConfig, file_type: &str) -> Option<String> {
+fn custom_validator<'a>(file_type: &str, options: &'a ValidationOptions) -> Option<&'a CustomValidatorConfig> {
+    options.config.as_ref()
+        .and_then(|config| config.validators.custom.get(file_type))
+}
+
+fn process_mappings(config: &FileValidationConfig, file_path: &Path, file_type: &str) -> Option<String> {
     config.file_mappings.as_ref()
-        .and_then(|mappings| mappings.get(file_type).cloned())
+        .and_then(|mappings| {
+            mappings.get(file_type)
+                .or_else(|| crate::detectors::find_file_mapping(file_path, mappings))
+                .cloned()
+        })
 }
 
 fn detect_file_type(file_path: &Path) -> Result<String> {
+    // `.env.local` is named for what it is, not by its extension
+    let name = file_path.file_name().and_then(|name| name.to_str());
+    if let Some(file_type @ FileType::DotEnv) = name.and_then(FileType::from_file_name) {
+        return Ok(validator_key(&file_type));
+    }
     if let Some(ext) = file_path.extension() {
         if let Some(ext_str) = ext.to_str() {
             return Ok(ext_str.to_lowercase());
@@ -67,28 +836,174 @@ fn detect_file_type(file_path: &Path) -> Result<String> {
     Ok(mime.split("/").last().unwrap_or("unknown").to_string())
 }
 
-fn get_validator_for_type(file_type: &str) -> fn(&Path, &ValidationOptions) -> Result<bool> {
-    match file_type {
-        "rs" => validate_rust,
-        "cpp" | "cxx" | "cc" => validate_cpp,
-        "c" => validate_c,
-        "cs" => validate_csharp,
-        "py" | "python" => validate_python,
-        "js" | "javascript" => validate_javascript,
-        "java" => validate_java,
-        "go" => validate_go,
-        "ts" | "tsx" => validate_typescript,
-        "json" => validate_json,
-        "yaml" | "yml" => validate_yaml,
-        "html" | "htm" => validate_html,
-        "css" => validate_css,
-        "sh" | "bash" => validate_shell,
-        "dockerfile" => validate_dockerfile,
-        _ => validate_unknown,
-    }
-}
-
-fn validate_rust(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
+// `file_type` is an extension or, from a file mapping, a language name
+fn get_validator_for_type(file_type: &str) -> Option<Validator> {
+    // shellcheck can't parse zsh, so .zsh files are left to custom validators
+    if file_type == "zsh" {
+        return None;
+    }
+    FileType::from_extension(file_type)
+        .or_else(|| FileType::from_name(file_type))
+        .and_then(|file_type| builtin_validator(&file_type))
+}
+
+fn builtin_validator(file_type: &FileType) -> Option<Validator> {
+    let validator: Validator = match file_type {
+        FileType::Rust => validate_rust,
+        FileType::Cpp => validate_cpp,
+        FileType::C => validate_c,
+        FileType::CSharp => validate_csharp,
+        FileType::Python => validate_python,
+        FileType::JavaScript => validate_javascript,
+        FileType::Java => validate_java,
+        FileType::Go => validate_go,
+        FileType::TypeScript | FileType::Tsx => validate_typescript,
+        FileType::Json => validate_json,
+        FileType::Yaml => yaml::validate_yaml,
+        FileType::Html => validate_html,
+        FileType::Css => validate_css,
+        FileType::Shell => validate_shell,
+        FileType::Dockerfile => validate_dockerfile,
+        FileType::DotEnv => dotenv::validate_dotenv,
+        FileType::Vue => sfc::validate_vue,
+        FileType::Svelte => sfc::validate_svelte,
+        _ => return None,
+    };
+    Some(validator)
+}
+
+/// Whether synx has a built-in validator for a file type
+pub fn has_builtin_validator(file_type: &FileType) -> bool {
+    builtin_validator(file_type).is_some()
+}
+
+/// The external tool a file type's built-in validator mainly relies on
+pub fn validator_tool(file_type: &FileType) -> Option<&'static str> {
+    let tool = match file_type {
+        FileType::Rust => "rustc",
+        FileType::Cpp => "g++",
+        FileType::C => "gcc",
+        FileType::CSharp => "dotnet",
+        FileType::Python => "python3",
+        FileType::JavaScript | FileType::Vue | FileType::Svelte => "node",
+        FileType::Java => "javac",
+        FileType::Go => "go",
+        FileType::TypeScript | FileType::Tsx => "tsc",
+        FileType::Json => "jq",
+        FileType::Yaml => "yamllint",
+        FileType::Html => "tidy",
+        FileType::Css => "stylelint",
+        FileType::Shell => "shellcheck",
+        FileType::Dockerfile => "hadolint",
+        _ => return None,
+    };
+    Some(tool)
+}
+
+/// Every tool a built-in validator runs, in the order `synx doctor` lists them
+pub const VALIDATOR_TOOLS: &[&str] = &[
+    "rustc", "gcc", "g++", "dotnet", "python3", "node", "javac", "go", "tsc",
+    "jq", "yamllint", "tidy", "stylelint", "shellcheck", "hadolint",
+];
+
+// Versions already probed, keyed by the program run, so a session asks each tool once
+static TOOL_VERSIONS: Lazy<Mutex<HashMap<String, Option<String>>>> = Lazy::new(Default::default);
+
+/// The first line a tool prints for its version command, using the configured
+/// path if one is set. Each program is only run once per session.
+pub fn tool_version(tool: &str, config: Option<&FileValidationConfig>) -> Option<String> {
+    let program = config
+        .and_then(|config| config.tools.get(tool))
+        .map(String::as_str)
+        .unwrap_or(tool);
+    if let Some(version) = TOOL_VERSIONS.lock().unwrap().get(program) {
+        return version.clone();
+    }
+
+    // `go` is the odd one out with a subcommand instead of a flag
+    let args: &[&str] = if tool == "go" { &["version"] } else { &["--version"] };
+    let version = Command::new(program).args(args).output().ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| {
+            let text = if output.stdout.is_empty() { &output.stderr } else { &output.stdout };
+            String::from_utf8_lossy(text).lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .map(str::to_string)
+        });
+    TOOL_VERSIONS.lock().unwrap().insert(program.to_string(), version.clone());
+    version
+}
+
+/// The versions of the tools behind a file's verdict: its validator and, for
+/// Python, ruff. Cached results are only trusted while this stays the same.
+pub fn validator_fingerprint(file_type: &FileType, config: Option<&FileValidationConfig>) -> Option<String> {
+    let linter = match file_type {
+        FileType::Python => Some("ruff"),
+        _ => None,
+    };
+    let versions: Vec<String> = validator_tool(file_type).into_iter()
+        .chain(linter)
+        .filter_map(|tool| tool_version(tool, config))
+        .collect();
+    if versions.is_empty() { None } else { Some(versions.join("; ")) }
+}
+
+/// A hash of the settings that decide the verdict on `file_path`: how strict
+/// to be, the timeout, its language's section or custom validator, and the
+/// extra passes. Cached results are only trusted while this stays the same.
+pub fn config_fingerprint(file_path: &Path, options: &ValidationOptions) -> String {
+    let file_type = validator_type(file_path, options).ok();
+    type_config_fingerprint(file_type.as_deref(), options)
+}
+
+// Stands in for validators registered in code, whose behavior can't be hashed:
+// their verdicts are only reused by the process that gave them
+static REGISTRY_TOKEN: Lazy<String> = Lazy::new(|| uuid::Uuid::new_v4().to_string());
+
+/// `config_fingerprint` for every file that `validator_type` maps to
+/// `file_type`; it depends on nothing else about the file
+pub(crate) fn type_config_fingerprint(file_type: Option<&str>, options: &ValidationOptions) -> String {
+    let config = options.config.as_ref().map(|config| {
+        serde_json::json!({
+            "validator": file_type.and_then(language_section)
+                .map(|section| config.validators.settings_for(section)),
+            "custom": file_type.and_then(|file_type| config.validators.custom.get(file_type)),
+            "adoption": config.adoption,
+            "tools": config.tools,
+            "encoding": config.encoding,
+            "complexity": config.complexity,
+            "hygiene": config.hygiene,
+            "secrets": config.secrets,
+            "check_code_blocks": config.check_code_blocks,
+            "empty_files": config.empty_files,
+            "max_issues_per_file": config.max_issues_per_file,
+        })
+    });
+    let registered = file_type
+        .filter(|file_type| options.registry.get(file_type).is_some())
+        .map(|_| REGISTRY_TOKEN.as_str());
+    // Object keys serialize sorted, so equal settings always hash the same
+    let settings = serde_json::json!({
+        "file_type": file_type,
+        "strict": options.strict,
+        "timeout": options.timeout,
+        "allow_binary": options.allow_binary,
+        "offline": options.offline,
+        "registered": registered,
+        "config": config,
+    });
+    blake3::hash(settings.to_string().as_bytes()).to_hex().to_string()
+}
+
+/// The version of every validator tool; `None` for tools that aren't installed
+pub fn tool_versions(config: Option<&FileValidationConfig>) -> Vec<(&'static str, Option<String>)> {
+    VALIDATOR_TOOLS.iter()
+        .map(|&tool| (tool, tool_version(tool, config)))
+        .collect()
+}
+
+fn validate_rust(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
     if options.verbose {
         eprintln!("Validating Rust file: {}", file_path.display());
     }
@@ -130,12 +1045,12 @@ fn find_cargo_project_root(file_path: &Path) -> Option<PathBuf> {
 }
 
 /// Validate Rust file using Cargo (for project files)
-fn validate_rust_with_cargo(file_path: &Path, cargo_dir: &Path, options: &ValidationOptions) -> Result<bool> {
+fn validate_rust_with_cargo(file_path: &Path, cargo_dir: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
     if options.verbose {
         eprintln!("Using Cargo validation for {} in project {}", file_path.display(), cargo_dir.display());
     }
     
-    let mut cmd = Command::new("cargo");
+    let mut cmd = tool_command("cargo", file_path, options);
     cmd.current_dir(cargo_dir)
        .arg("check")
        .arg("--message-format=short");
@@ -145,7 +1060,7 @@ fn validate_rust_with_cargo(file_path: &Path, cargo_dir: &Path, options: &Valida
     
     if options.strict {
         // In strict mode, also run clippy if available
-        let clippy_available = Command::new("cargo")
+        let clippy_available = tool_command("cargo", file_path, options)
             .arg("clippy")
             .arg("--version")
             .output()
@@ -153,7 +1068,7 @@ fn validate_rust_with_cargo(file_path: &Path, cargo_dir: &Path, options: &Valida
             .unwrap_or(false);
             
         if clippy_available {
-            cmd = Command::new("cargo");
+            cmd = tool_command("cargo", file_path, options);
             cmd.current_dir(cargo_dir)
                .arg("clippy")
                .arg("--message-format=short")
@@ -165,7 +1080,7 @@ fn validate_rust_with_cargo(file_path: &Path, cargo_dir: &Path, options: &Valida
         }
     }
     
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("rust", options), output_limit(options))?;
     let success = output.status.success();
     
     if !success && options.verbose {
@@ -178,13 +1093,17 @@ fn validate_rust_with_cargo(file_path: &Path, cargo_dir: &Path, options: &Valida
         }
     }
     
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "rust", success, &output))
 }
 
 /// Validate standalone Rust file using rustc (for files outside projects)
-fn validate_rust_standalone(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("rustc");
+fn validate_rust_standalone(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    // Only the diagnostics matter; keep the compiled metadata out of the project
+    let out_dir = tempfile::Builder::new().prefix("synx-rust-").tempdir()?;
+    let mut cmd = tool_command("rustc", file_path, options);
     cmd.arg("--crate-type=lib")
+       .arg("--emit=metadata")
+       .arg("--out-dir").arg(out_dir.path())
        .arg("--error-format=short")
        .arg("-A").arg("dead_code")
        .arg("-A").arg("unused_variables")
@@ -195,7 +1114,7 @@ fn validate_rust_standalone(file_path: &Path, options: &ValidationOptions) -> Re
         cmd.arg("-D").arg("warnings");
     }
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("rust", options), output_limit(options))?;
     let success = output.status.success();
     
     if !success && options.verbose {
@@ -205,23 +1124,54 @@ fn validate_rust_standalone(file_path: &Path, options: &ValidationOptions) -> Re
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "rust", success, &output))
 }
 
 // Add other validator functions...
 
-fn validate_unknown(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
+fn validate_unknown(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
     if options.verbose {
         eprintln!("No validator available for file: {}", file_path.display());
     }
-    Ok(!options.strict)
+    Ok(ValidationResult { success: !options.strict, errors: Vec::new(), suppressed: 0, hidden_issues: 0 })
+}
+
+fn is_header(file_path: &Path) -> bool {
+    let extension = file_path.extension().map(|ext| ext.to_string_lossy().to_lowercase());
+    matches!(extension.as_deref(), Some("h" | "hpp" | "hxx" | "hh"))
+}
+
+/// Compiler flags for the configured language standard, include paths and defines
+fn c_family_flags(
+    standard: &Option<String>,
+    include_paths: &Option<Vec<String>>,
+    defines: &Option<Vec<String>>,
+) -> Vec<String> {
+    let mut flags = Vec::new();
+    
+    if let Some(standard) = standard {
+        flags.push(format!("-std={}", standard));
+    }
+    for path in include_paths.iter().flatten() {
+        flags.push(format!("-I{}", path));
+    }
+    for define in defines.iter().flatten() {
+        flags.push(format!("-D{}", define));
+    }
+    
+    flags
 }
 
-fn validate_cpp(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("g++");
+fn validate_cpp(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("g++", file_path, options);
     cmd.arg("-fsyntax-only")
        .arg("-Wall")
        .arg("-pedantic");
+    
+    if let Some(config) = &options.config {
+        let cpp = &config.validators.cpp;
+        cmd.args(c_family_flags(&cpp.standard, &cpp.include_paths, &cpp.defines));
+    }
 
     if options.strict {
         cmd.arg("-Werror")
@@ -229,8 +1179,12 @@ fn validate_cpp(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
            .arg("-Wconversion");
     }
 
+    // As a header, the file has to compile on its own, without whatever its users include first
+    if is_header(file_path) {
+        cmd.arg("-x").arg("c++-header");
+    }
     cmd.arg(file_path);
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("cpp", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -240,14 +1194,19 @@ fn validate_cpp(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "cpp", success, &output))
 }
 
-fn validate_c(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("gcc");
+fn validate_c(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("gcc", file_path, options);
     cmd.arg("-fsyntax-only")
        .arg("-Wall")
        .arg("-pedantic");
+    
+    if let Some(config) = &options.config {
+        let c = &config.validators.c;
+        cmd.args(c_family_flags(&c.standard, &c.include_paths, &c.defines));
+    }
 
     if options.strict {
         cmd.arg("-Werror")
@@ -255,8 +1214,12 @@ fn validate_c(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
            .arg("-Wconversion");
     }
 
+    // As a header, the file has to compile on its own, without whatever its users include first
+    if is_header(file_path) {
+        cmd.arg("-x").arg("c-header");
+    }
     cmd.arg(file_path);
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("c", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -266,19 +1229,78 @@ fn validate_c(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "c", success, &output))
 }
 
-fn validate_csharp(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("dotnet");
-    cmd.arg("build")
-       .arg(file_path);
+fn validate_csharp(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let config = options.config.as_ref().map(|c| &c.validators.csharp);
+    let use_dotnet = config.and_then(|c| c.use_dotnet).unwrap_or(true);
+    
+    // `dotnet build` needs a project; a lone file only gets a compiler syntax check
+    match find_csharp_project(file_path) {
+        Some(project) if use_dotnet => {
+            let framework = config.and_then(|c| c.framework.as_deref());
+            validate_csharp_with_dotnet(file_path, &project, framework, options)
+        }
+        _ => {
+            if options.verbose {
+                eprintln!("No .csproj/.sln used for {}, using standalone validation", file_path.display());
+            }
+            validate_csharp_standalone(file_path, options)
+        }
+    }
+}
 
+/// Find the closest `.csproj` (or, failing that, `.sln`) above a file
+fn find_csharp_project(file_path: &Path) -> Option<PathBuf> {
+    let abs_path = std::fs::canonicalize(file_path).ok()?;
+    let mut current = abs_path.parent()?;
+    
+    loop {
+        let mut entries: Vec<PathBuf> = fs::read_dir(current).ok()?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect();
+        entries.sort();
+        for extension in ["csproj", "sln"] {
+            if let Some(project) = entries.iter().find(|path| path.extension().and_then(|ext| ext.to_str()) == Some(extension)) {
+                return Some(project.clone());
+            }
+        }
+        
+        current = current.parent()?;
+    }
+}
+
+/// Validate a C# file by building the project it belongs to
+fn validate_csharp_with_dotnet(
+    file_path: &Path,
+    project: &Path,
+    framework: Option<&str>,
+    options: &ValidationOptions,
+) -> Result<ValidationResult> {
+    if options.verbose {
+        eprintln!("Using dotnet build for {} in project {}", file_path.display(), project.display());
+    }
+    
+    let mut cmd = tool_command("dotnet", file_path, options);
+    cmd.arg("build")
+       .arg(project)
+       .arg("-nologo");
+    
+    if let Some(framework) = framework {
+        cmd.arg("--framework").arg(framework);
+    }
+    // Restoring is what downloads packages; a project that was never restored
+    // then fails with NETSDK1004 ("assets file not found")
+    if options.offline {
+        cmd.arg("--no-restore");
+    }
     if options.strict {
         cmd.arg("/warnaserror");
     }
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("csharp", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -288,45 +1310,214 @@ fn validate_csharp(file_path: &Path, options: &ValidationOptions) -> Result<bool
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "csharp", success, &output))
 }
 
-fn validate_python(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("python3");
-    cmd.arg("-m").arg("py_compile").arg(file_path);
+/// Syntax-check a C# file on its own with Mono's `mcs`, or `csc` if that's what is installed
+fn validate_csharp_standalone(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mcs_configured = matches!(&options.config, Some(c) if c.tools.contains_key("mcs"));
+    let compiler = if mcs_configured || which::which("mcs").is_ok() {
+        "mcs"
+    } else {
+        "csc"
+    };
+    
+    // Compiling as a library means no Main is required; the output is thrown away
+    let out_dir = tempfile::Builder::new().prefix("synx-csharp-").tempdir()?;
+    let mut cmd = tool_command(compiler, file_path, options);
+    cmd.arg("-target:library")
+       .arg("-nologo")
+       .arg(format!("-out:{}", out_dir.path().join("check.dll").display()));
+    
+    if options.strict {
+        cmd.arg("-warnaserror+");
+    }
+    cmd.arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("csharp", options), output_limit(options))?;
     let success = output.status.success();
 
-    // Enhanced error reporting with colorized output
-    if !success {
-        let error_output = if !output.stderr.is_empty() {
-            String::from_utf8_lossy(&output.stderr).to_string()
-        } else {
-            String::from_utf8_lossy(&output.stdout).to_string()
-        };
-        
+    if !success && options.verbose {
+        eprintln!("C# validation errors:");
+        let text = if output.stderr.is_empty() { &output.stdout } else { &output.stderr };
+        eprintln!("{}", String::from_utf8_lossy(text));
+    }
+
+    Ok(ValidationResult::from_output(file_path, "csharp", success, &output))
+}
+
+/// The interpreters `[validators.python] interpreters` lists, or just python3
+fn python_interpreters(options: &ValidationOptions) -> Vec<String> {
+    options.config.as_ref()
+        .and_then(|config| config.validators.python.interpreters.clone())
+        .filter(|interpreters| !interpreters.is_empty())
+        .unwrap_or_else(|| vec!["python3".to_string()])
+}
+
+/// `python -m py_compile`, but writing the bytecode to the path given as the
+/// second argument; errors are printed the same way
+const PY_COMPILE_SCRIPT: &str = "\
+import py_compile, sys
+try:
+    py_compile.compile(sys.argv[1], cfile=sys.argv[2], doraise=True)
+except py_compile.PyCompileError as error:
+    sys.stderr.write(error.msg)
+    sys.exit(1)
+";
+
+fn validate_python(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    // The compiler's own message for this doesn't say where the mix is or what to do
+    if let Some(line) = indentation::inconsistent_python_indentation(&String::from_utf8_lossy(&fs::read(file_path)?)) {
+        let errors = vec![ValidationError {
+            file_path: file_path.to_string_lossy().to_string(),
+            error_type: ErrorType::SyntaxError,
+            message: "Indentation mixes tabs and spaces, so its meaning depends on the tab width".to_string(),
+            line: Some(line),
+            column: Some(1),
+            code: Some("TabError".to_string()),
+            suggestion: Some("Indent this block with spaces only (4 per level, as PEP 8 recommends)".to_string()),
+            severity: ErrorType::SyntaxError.default_severity(),
+        }];
         if options.verbose {
-            // Parse and display structured errors
-            let errors = parse_validation_output(file_path, &error_output, "python");
-            if !errors.is_empty() {
-                let _ = display_validation_errors(&errors);
+            let _ = display_validation_errors(&errors);
+        }
+        return Ok(ValidationResult { success: false, errors, suppressed: 0, hidden_issues: 0 });
+    }
+
+    // The file has to compile under every supported Python
+    let interpreters = python_interpreters(options);
+    // Only the verdict matters; keep the bytecode out of the __pycache__
+    // that `-m py_compile` would write next to the file
+    let out_dir = tempfile::Builder::new().prefix("synx-python-").tempdir()?;
+    let mut errors = Vec::new();
+    let mut success = true;
+    for interpreter in &interpreters {
+        let mut cmd = tool_command(interpreter, file_path, options);
+        cmd.arg("-c").arg(PY_COMPILE_SCRIPT)
+           .arg(file_path)
+           .arg(out_dir.path().join("compiled.pyc"));
+
+        let output = run_with_timeout(&mut cmd, validator_timeout("python", options), output_limit(options))?;
+        if output.status.success() {
+            continue;
+        }
+        success = false;
+
+        let mut result = ValidationResult::from_output(file_path, "python", false, &output);
+        // Say which version rejected the file when there's more than one
+        if interpreters.len() > 1 {
+            for error in &mut result.errors {
+                error.message = format!("{}: {}", interpreter, error.message);
+            }
+        }
+
+        // Enhanced error reporting with colorized output
+        if options.verbose {
+            if !result.errors.is_empty() {
+                let _ = display_validation_errors(&result.errors);
             } else {
                 // Fallback to simple error display
-                eprintln!("Python validation errors:");
+                let error_output = if !output.stderr.is_empty() {
+                    String::from_utf8_lossy(&output.stderr)
+                } else {
+                    String::from_utf8_lossy(&output.stdout)
+                };
+                eprintln!("Python validation errors ({}):", interpreter);
                 eprintln!("{}", error_output);
             }
         }
+        errors.extend(result.errors);
+    }
+
+    // A syntax error is all ruff would report too, so only lint files that compile
+    let ruff_configured = matches!(&options.config, Some(c) if c.tools.contains_key("ruff"));
+    if success && (ruff_configured || which::which("ruff").is_ok()) {
+        return validate_python_with_ruff(file_path, options);
+    }
+
+    Ok(ValidationResult { success, errors, suppressed: 0, hidden_issues: 0 })
+}
+
+/// Lint a Python file with ruff, turning its JSON diagnostics into issues
+fn validate_python_with_ruff(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("ruff", file_path, options);
+    cmd.arg("check")
+       .arg("--output-format=json")
+       .arg("--no-cache");
+
+    let ignore_rules = options.config.as_ref()
+        .and_then(|config| config.validators.python.ignore_rules.as_ref())
+        .filter(|rules| !rules.is_empty());
+    if let Some(rules) = ignore_rules {
+        cmd.arg("--ignore").arg(rules.join(","));
+    }
+    cmd.arg(file_path);
+
+    let output = run_with_timeout(&mut cmd, validator_timeout("python", options), output_limit(options))?;
+
+    // ruff exits with 1 when it finds something; anything else means it didn't run properly
+    let diagnostics: Vec<RuffDiagnostic> = match output.status.code() {
+        Some(0) | Some(1) => serde_json::from_slice(&output.stdout)
+            .map_err(|e| anyhow!("Failed to parse ruff output: {}", e))?,
+        _ => return Ok(ValidationResult::from_output(file_path, "python", false, &output)),
+    };
+
+    // The file compiles, so lint findings are warnings unless in strict mode
+    let errors: Vec<ValidationError> = diagnostics.into_iter()
+        .map(|diagnostic| {
+            let mut error = diagnostic.into_error(file_path);
+            if !options.strict && error.error_type == ErrorType::Lint {
+                error.error_type = ErrorType::Warning;
+                error.severity = ErrorType::Warning.default_severity();
+            }
+            error
+        })
+        .collect();
+
+    if !errors.is_empty() && options.verbose {
+        let _ = display_validation_errors(&errors);
     }
 
-    Ok(success)
+    let success = !errors.iter().any(|e| options.strict || e.error_type != ErrorType::Warning);
+    Ok(ValidationResult { success, errors, suppressed: 0, hidden_issues: 0 })
 }
 
-fn validate_javascript(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("node");
+/// One entry of `ruff check --output-format=json`
+#[derive(Debug, Deserialize)]
+struct RuffDiagnostic {
+    // Missing for syntax errors
+    code: Option<String>,
+    message: String,
+    location: RuffLocation,
+}
+
+#[derive(Debug, Deserialize)]
+struct RuffLocation {
+    row: usize,
+    column: usize,
+}
+
+impl RuffDiagnostic {
+    fn into_error(self, file_path: &Path) -> ValidationError {
+        let error_type = if self.code.is_none() { ErrorType::SyntaxError } else { ErrorType::Lint };
+        ValidationError {
+            file_path: file_path.to_string_lossy().to_string(),
+            severity: error_type.default_severity(),
+            error_type,
+            message: self.message,
+            line: Some(self.location.row),
+            column: Some(self.location.column),
+            code: self.code,
+            suggestion: None,
+        }
+    }
+}
+
+fn validate_javascript(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("node", file_path, options);
     cmd.arg("--check").arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("javascript", options), output_limit(options))?;
     let success = output.status.success();
 
     // Enhanced error reporting with colorized output
@@ -350,14 +1541,14 @@ fn validate_javascript(file_path: &Path, options: &ValidationOptions) -> Result<
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "javascript", success, &output))
 }
 
-fn validate_java(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("javac");
+fn validate_java(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("javac", file_path, options);
     cmd.arg("-Werror").arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("java", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -367,14 +1558,14 @@ fn validate_java(file_path: &Path, options: &ValidationOptions) -> Result<bool>
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "java", success, &output))
 }
 
-fn validate_go(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("go");
+fn validate_go(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("go", file_path, options);
     cmd.arg("vet").arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("go", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -384,14 +1575,14 @@ fn validate_go(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "go", success, &output))
 }
 
-fn validate_typescript(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("tsc");
+fn validate_typescript(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("tsc", file_path, options);
     cmd.arg("--noEmit").arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("typescript", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -401,14 +1592,22 @@ fn validate_typescript(file_path: &Path, options: &ValidationOptions) -> Result<
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "typescript", success, &output))
 }
 
-fn validate_json(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("jq");
+fn validate_json(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    // jq only accepts RFC 8259 JSON, so commented files need a JSON5 parser
+    let allow_comments = options.config.as_ref()
+        .and_then(|config| config.validators.json.allow_comments)
+        .unwrap_or(false);
+    if allow_comments {
+        return validate_json5(file_path, options);
+    }
+
+    let mut cmd = tool_command("jq", file_path, options);
     cmd.arg(".").arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("json", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -418,31 +1617,40 @@ fn validate_json(file_path: &Path, options: &ValidationOptions) -> Result<bool>
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "json", success, &output))
 }
 
-fn validate_yaml(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("yamllint");
-    cmd.arg(file_path);
-
-    let output = cmd.output()?;
-    let success = output.status.success();
+/// Parse JSON that may contain comments and trailing commas (JSONC/JSON5)
+fn validate_json5(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let content = fs::read_to_string(file_path)?;
+    let error = match json5::from_str::<serde_json::Value>(&content) {
+        Ok(_) => return Ok(ValidationResult::passed()),
+        Err(error) => error,
+    };
 
-    if !success && options.verbose {
-        eprintln!("YAML validation errors:");
-        if !output.stderr.is_empty() {
-            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
-        }
+    let json5::Error::Message { msg, location } = error;
+    let error = ValidationError {
+        file_path: file_path.to_string_lossy().to_string(),
+        error_type: ErrorType::SyntaxError,
+        message: msg,
+        line: location.as_ref().map(|location| location.line),
+        column: location.as_ref().map(|location| location.column),
+        code: None,
+        suggestion: None,
+        severity: IssueSeverity::High,
+    };
+    if options.verbose {
+        let _ = display_validation_errors(std::slice::from_ref(&error));
     }
 
-    Ok(success)
+    Ok(ValidationResult { success: false, errors: vec![error], suppressed: 0, hidden_issues: 0 })
 }
 
-fn validate_html(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("tidy");
+fn validate_html(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("tidy", file_path, options);
     cmd.arg("-q").arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("html", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -452,14 +1660,14 @@ fn validate_html(file_path: &Path, options: &ValidationOptions) -> Result<bool>
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "html", success, &output))
 }
 
-fn validate_css(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("stylelint");
+fn validate_css(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("stylelint", file_path, options);
     cmd.arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("css", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -469,14 +1677,14 @@ fn validate_css(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "css", success, &output))
 }
 
-fn validate_shell(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("shellcheck");
+fn validate_shell(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("shellcheck", file_path, options);
     cmd.arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("shell", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -486,14 +1694,14 @@ fn validate_shell(file_path: &Path, options: &ValidationOptions) -> Result<bool>
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "shell", success, &output))
 }
 
-fn validate_dockerfile(file_path: &Path, options: &ValidationOptions) -> Result<bool> {
-    let mut cmd = Command::new("hadolint");
+fn validate_dockerfile(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let mut cmd = tool_command("hadolint", file_path, options);
     cmd.arg(file_path);
 
-    let output = cmd.output()?;
+    let output = run_with_timeout(&mut cmd, validator_timeout("dockerfile", options), output_limit(options))?;
     let success = output.status.success();
 
     if !success && options.verbose {
@@ -503,5 +1711,5 @@ fn validate_dockerfile(file_path: &Path, options: &ValidationOptions) -> Result<
         }
     }
 
-    Ok(success)
+    Ok(ValidationResult::from_output(file_path, "dockerfile", success, &output))
 }
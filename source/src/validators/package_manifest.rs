@@ -0,0 +1,254 @@
+//! Semantic checks for package manifests
+//!
+//! `Cargo.toml` and `package.json` are recognized by file name and checked
+//! beyond their syntax: the package needs a name and a semver version, and
+//! `package.json` dependency tables must map names to version strings. A
+//! workspace-only `Cargo.toml` has no `[package]`, and a `package.json`
+//! marked `"private": true` is never published, so neither needs a name or
+//! version.
+
+use std::fs;
+use std::path::Path;
+use anyhow::{Result, Context};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::{ValidationError, ErrorType};
+
+/// What npm accepts as a package name, optionally scoped
+static NPM_NAME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:@[a-z0-9*~-][a-z0-9*._~-]*/)?[a-z0-9~-][a-z0-9._~-]*$").unwrap()
+});
+
+/// npm's limit on the length of a package name
+const NPM_NAME_MAX_LEN: usize = 214;
+
+const NPM_DEPENDENCY_FIELDS: &[&str] = &["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"];
+
+/// Whether `file_path` is a manifest with semantic checks
+pub fn is_manifest(file_path: &Path) -> bool {
+    matches!(file_path.file_name().and_then(|name| name.to_str()), Some("Cargo.toml" | "package.json"))
+}
+
+/// Check a manifest, returning every problem found. Files that aren't
+/// manifests have none.
+pub fn check_manifest(file_path: &Path) -> Result<Vec<ValidationError>> {
+    let content = fs::read_to_string(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+    let issues = match file_path.file_name().and_then(|name| name.to_str()) {
+        Some("Cargo.toml") => check_cargo_toml(&content),
+        Some("package.json") => check_package_json(&content),
+        _ => Vec::new(),
+    };
+    let path = file_path.to_string_lossy();
+    Ok(issues.into_iter().map(|issue| issue.into_error(&path)).collect())
+}
+
+// One problem, before it's tied to a file
+#[derive(Debug)]
+struct Issue {
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+    suggestion: Option<String>,
+}
+
+impl Issue {
+    fn new(line: Option<usize>, message: String) -> Self {
+        Self { line, column: None, message, suggestion: None }
+    }
+
+    fn suggest(mut self, suggestion: &str) -> Self {
+        self.suggestion = Some(suggestion.to_string());
+        self
+    }
+
+    fn into_error(self, file_path: &str) -> ValidationError {
+        ValidationError {
+            file_path: file_path.to_string(),
+            error_type: ErrorType::SyntaxError,
+            severity: ErrorType::SyntaxError.default_severity(),
+            message: self.message,
+            line: self.line,
+            column: self.column,
+            code: None,
+            suggestion: self.suggestion,
+        }
+    }
+}
+
+fn check_cargo_toml(content: &str) -> Vec<Issue> {
+    let manifest: toml::Table = match toml::from_str(content) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            let (line, column) = e.span().map_or((None, None), |span| {
+                let (line, column) = position(content, span.start);
+                (Some(line), Some(column))
+            });
+            return vec![Issue { line, column, message: e.message().to_string(), suggestion: None }];
+        }
+    };
+
+    let Some(package) = manifest.get("package") else {
+        if manifest.contains_key("workspace") {
+            return Vec::new();
+        }
+        return vec![Issue::new(None, "Missing [package] section".to_string())
+            .suggest("Add a [package] section with name and version, or a [workspace] section")];
+    };
+    let Some(package) = package.as_table() else {
+        return vec![Issue::new(line_of(content, "[package]"), "[package] must be a table".to_string())];
+    };
+    let package_line = line_of(content, "[package]");
+    let field_line = |field: &str| find_key_line(content, "[package]", field).or(package_line);
+
+    let mut issues = Vec::new();
+    match package.get("name") {
+        None => issues.push(Issue::new(package_line, "[package] is missing name".to_string())),
+        Some(toml::Value::String(name)) if !name.trim().is_empty() => {}
+        Some(_) => issues.push(Issue::new(field_line("name"), "Package name must be a non-empty string".to_string())),
+    }
+    match package.get("version") {
+        None => issues.push(Issue::new(package_line, "[package] is missing version".to_string())
+            .suggest("Add version = \"0.1.0\", or version.workspace = true in a workspace")),
+        Some(toml::Value::String(version)) => {
+            if let Err(e) = semver::Version::parse(version) {
+                issues.push(Issue::new(field_line("version"), format!("Invalid version '{}': {}", version, e))
+                    .suggest("Use a semantic version such as 1.2.3"));
+            }
+        }
+        // Inherited from the workspace
+        Some(toml::Value::Table(table)) if table.get("workspace").and_then(|value| value.as_bool()) == Some(true) => {}
+        Some(_) => issues.push(Issue::new(field_line("version"), "Package version must be a string".to_string())),
+    }
+    issues
+}
+
+fn check_package_json(content: &str) -> Vec<Issue> {
+    // Broken JSON is reported by the syntax check
+    let Ok(serde_json::Value::Object(manifest)) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+    let private = manifest.get("private").and_then(|value| value.as_bool()) == Some(true);
+    let field_line = |field: &str| line_of(content, &format!("\"{}\"", field));
+
+    let mut issues = Vec::new();
+    match manifest.get("name") {
+        None if private => {}
+        None => issues.push(Issue::new(None, "package.json is missing name".to_string())
+            .suggest("Add a name, or \"private\": true if the package is never published")),
+        Some(serde_json::Value::String(name)) => {
+            if name.len() > NPM_NAME_MAX_LEN || !NPM_NAME.is_match(name) {
+                issues.push(Issue::new(field_line("name"), format!("Invalid package name '{}'", name))
+                    .suggest("Names are lowercase, URL-safe and at most 214 characters, optionally scoped as @scope/name"));
+            }
+        }
+        Some(_) => issues.push(Issue::new(field_line("name"), "Package name must be a string".to_string())),
+    }
+    match manifest.get("version") {
+        None if private => {}
+        None => issues.push(Issue::new(None, "package.json is missing version".to_string())
+            .suggest("Add a version such as \"1.0.0\", or \"private\": true if the package is never published")),
+        Some(serde_json::Value::String(version)) => {
+            if let Err(e) = semver::Version::parse(version) {
+                issues.push(Issue::new(field_line("version"), format!("Invalid version '{}': {}", version, e))
+                    .suggest("Use a semantic version such as 1.2.3"));
+            }
+        }
+        Some(_) => issues.push(Issue::new(field_line("version"), "Package version must be a string".to_string())),
+    }
+
+    for field in NPM_DEPENDENCY_FIELDS {
+        match manifest.get(*field) {
+            None => {}
+            Some(serde_json::Value::Object(dependencies)) => {
+                for (name, range) in dependencies {
+                    if !range.is_string() {
+                        issues.push(Issue::new(line_of(content, &format!("\"{}\"", name)),
+                            format!("{} entry '{}' must be a version string", field, name)));
+                    }
+                }
+            }
+            Some(_) => issues.push(Issue::new(field_line(field),
+                format!("{} must be an object mapping package names to versions", field))),
+        }
+    }
+    issues
+}
+
+/// The 1-based line and column of a byte offset
+fn position(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    (line, column)
+}
+
+/// The first line containing `needle`
+fn line_of(content: &str, needle: &str) -> Option<usize> {
+    content.lines().position(|line| line.contains(needle)).map(|index| index + 1)
+}
+
+/// The line setting `key` inside the TOML `section`, before the next section starts
+fn find_key_line(content: &str, section: &str, key: &str) -> Option<usize> {
+    let start = line_of(content, section)?;
+    content.lines().enumerate()
+        .skip(start)
+        .take_while(|(_, line)| !line.trim_start().starts_with('['))
+        .find(|(_, line)| {
+            let line = line.trim_start();
+            line.strip_prefix(key).is_some_and(|rest| rest.trim_start().starts_with(['=', '.']))
+        })
+        .map(|(index, _)| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(issues: &[Issue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_cargo_toml() {
+        assert!(check_cargo_toml("[package]\nname = \"demo\"\nversion = \"0.1.0\"\n").is_empty());
+        assert!(check_cargo_toml("[package]\nname = \"demo\"\nversion.workspace = true\n").is_empty());
+        assert!(check_cargo_toml("[workspace]\nmembers = [\"a\"]\n").is_empty());
+
+        let issues = check_cargo_toml("[package]\nname = \"demo\"\nversion = \"1.0\"\n");
+        assert_eq!(messages(&issues).len(), 1);
+        assert_eq!(issues[0].line, Some(3));
+        assert!(issues[0].message.starts_with("Invalid version '1.0'"), "{}", issues[0].message);
+
+        let issues = check_cargo_toml("[dependencies]\nserde = \"1\"\n");
+        assert_eq!(messages(&issues), vec!["Missing [package] section"]);
+
+        let issues = check_cargo_toml("[package]\nname = \"demo\"\nversion = \n");
+        assert_eq!(issues[0].line, Some(3));
+    }
+
+    #[test]
+    fn test_package_json() {
+        assert!(check_package_json(r#"{"name": "@acme/demo", "version": "1.2.3-beta.1", "dependencies": {"left-pad": "^1.0.0"}}"#).is_empty());
+        assert!(check_package_json(r#"{"private": true}"#).is_empty());
+        // Broken JSON is left to the syntax check
+        assert!(check_package_json("{").is_empty());
+
+        let issues = check_package_json("{\n  \"name\": \"Demo App\",\n  \"version\": \"1\",\n  \"dependencies\": {\"a\": 1}\n}\n");
+        let lines: Vec<_> = issues.iter().map(|issue| issue.line).collect();
+        assert_eq!(lines, vec![Some(2), Some(3), Some(4)], "{:?}", issues);
+
+        let issues = check_package_json(r#"{"name": "demo", "devDependencies": ["a"]}"#);
+        assert_eq!(messages(&issues), vec![
+            "package.json is missing version",
+            "devDependencies must be an object mapping package names to versions",
+        ]);
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(position("ab\ncd", 0), (1, 1));
+        assert_eq!(position("ab\ncd", 4), (2, 2));
+    }
+}
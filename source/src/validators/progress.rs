@@ -0,0 +1,109 @@
+//! Progress reporting for library consumers
+//!
+//! `validate_files_with_progress` validates a list of files and tells a
+//! callback about each one as it goes, so embedders can draw their own
+//! progress instead of synx's progress bar. `synx::run` draws its bar on top
+//! of it.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use anyhow::{Error, Result};
+
+use super::{validate_file_detailed, BinaryFileError, DisabledValidatorError, EmptyFileError, ValidationOptions, ValidationResult};
+
+/// Something that happened while validating a list of files
+#[derive(Debug)]
+pub enum ProgressEvent {
+    /// Validation of `path` is about to start
+    Started { path: PathBuf },
+    /// `path` is done; `result` is what `validate_file_detailed` returned
+    Finished { path: PathBuf, result: Result<ValidationResult> },
+    /// Every file is done
+    Done { summary: ProgressSummary },
+}
+
+/// Totals for a finished run, sent with `ProgressEvent::Done`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgressSummary {
+    pub files: usize,
+    pub passed: usize,
+    pub failed: usize,
+    /// Binary, empty or disabled files, which say nothing about validity
+    pub skipped: usize,
+    /// Files whose validation couldn't run
+    pub errored: usize,
+    pub elapsed: Duration,
+}
+
+impl ProgressSummary {
+    /// Whether no file failed or errored
+    pub fn success(&self) -> bool {
+        self.failed == 0 && self.errored == 0
+    }
+}
+
+/// Whether an error from `validate_file_detailed` means the file was skipped
+/// rather than that validating it went wrong
+pub fn is_skip(error: &Error) -> bool {
+    error.downcast_ref::<BinaryFileError>().is_some()
+        || error.downcast_ref::<EmptyFileError>().is_some()
+        || error.downcast_ref::<DisabledValidatorError>().is_some()
+}
+
+/// Validate `files` one after another, reporting each to `callback`. Stops
+/// early if `files` does, so a lazy iterator can end the run.
+pub fn validate_files_with_progress<I, P>(
+    files: I,
+    options: &ValidationOptions,
+    mut callback: impl FnMut(ProgressEvent),
+) -> ProgressSummary
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let start = Instant::now();
+    let mut summary = ProgressSummary::default();
+
+    for file in files {
+        let path = file.as_ref();
+        callback(ProgressEvent::Started { path: path.to_path_buf() });
+
+        let result = validate_file_detailed(path, options);
+        summary.files += 1;
+        match &result {
+            Ok(result) if result.success => summary.passed += 1,
+            Ok(_) => summary.failed += 1,
+            Err(e) if is_skip(e) => summary.skipped += 1,
+            Err(_) => summary.errored += 1,
+        }
+        callback(ProgressEvent::Finished { path: path.to_path_buf(), result });
+    }
+
+    summary.elapsed = start.elapsed();
+    callback(ProgressEvent::Done { summary: summary.clone() });
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_counts_by_outcome() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let empty = dir.path().join("empty.json");
+        let binary = dir.path().join("blob.json");
+        fs::write(&empty, "")?;
+        fs::write(&binary, [0u8, 159, 146, 150, 0, 1])?;
+        let missing = dir.path().join("missing.json");
+
+        let summary = validate_files_with_progress([&empty, &binary, &missing], &ValidationOptions::default(), |_| {});
+        assert_eq!(summary.files, 3);
+        assert_eq!(summary.passed, 1, "empty files pass by default");
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.errored, 1);
+        assert!(!summary.success());
+        Ok(())
+    }
+}
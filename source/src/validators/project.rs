@@ -0,0 +1,71 @@
+//! Project roots
+//!
+//! Tools like eslint, tsc and cargo find their config relative to the
+//! directory they run in, so validators run from the root of the project a
+//! file belongs to: the nearest directory above it holding a Cargo.toml,
+//! package.json or tsconfig.json.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Files that mark the root of a project
+pub const ROOT_MARKERS: &[&str] = &["Cargo.toml", "package.json", "tsconfig.json"];
+
+/// The nearest directory containing `file_path` that holds a root marker
+pub fn project_root(file_path: &Path) -> Option<PathBuf> {
+    let absolute = absolute(file_path)?;
+    absolute.parent()?
+        .ancestors()
+        .find(|dir| ROOT_MARKERS.iter().any(|marker| dir.join(marker).is_file()))
+        .map(Path::to_path_buf)
+}
+
+/// Run `cmd` from the project root of `file_path`. Files outside any project
+/// leave the working directory alone.
+pub fn run_from_project_root(cmd: &mut Command, file_path: &Path) {
+    if let Some(root) = project_root(file_path) {
+        cmd.current_dir(root);
+    }
+}
+
+/// `path` made absolute against the current directory
+pub fn absolute(path: &Path) -> Option<PathBuf> {
+    if path.is_absolute() {
+        Some(path.to_path_buf())
+    } else {
+        env::current_dir().ok().map(|dir| dir.join(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_nearest_marker_wins() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let web = dir.path().join("web");
+        fs::create_dir_all(web.join("src"))?;
+        fs::write(dir.path().join("Cargo.toml"), "[package]\n")?;
+        fs::write(web.join("package.json"), "{}\n")?;
+        fs::write(web.join("src/app.js"), "")?;
+        fs::write(dir.path().join("build.rs"), "")?;
+
+        assert_eq!(project_root(&web.join("src/app.js")), Some(web.clone()));
+        assert_eq!(project_root(&dir.path().join("build.rs")), Some(dir.path().to_path_buf()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_marker_directories_dont_count() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir(dir.path().join("package.json"))?;
+        fs::write(dir.path().join("app.js"), "")?;
+
+        // Only a real marker file makes a root; the temp dir itself has none above it
+        assert_ne!(project_root(&dir.path().join("app.js")), Some(dir.path().to_path_buf()));
+        Ok(())
+    }
+}
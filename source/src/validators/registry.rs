@@ -0,0 +1,68 @@
+//! Validators registered in code by programs embedding synx.
+//!
+//! A registered validator is looked up by the same key as the built-in ones:
+//! the file's extension (e.g. "proto"), or the type a config file mapping
+//! gives it. It takes precedence over both config and built-in validators.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+use anyhow::Result;
+
+use super::{ValidationOptions, ValidationResult};
+
+/// A validator supplied by the embedding program
+pub type ValidatorFn = dyn Fn(&Path, &ValidationOptions) -> Result<ValidationResult> + Send + Sync;
+
+/// Validators keyed by file type; cheap to clone, so it can ride along in options
+#[derive(Clone, Default)]
+pub struct ValidatorRegistry {
+    validators: HashMap<String, Arc<ValidatorFn>>,
+}
+
+impl ValidatorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate files of `file_type` (an extension or mapped type name,
+    /// without the dot) with `validator`, replacing any earlier registration
+    pub fn register(&mut self, file_type: &str, validator: Box<ValidatorFn>) {
+        let key = file_type.trim_start_matches('.').to_lowercase();
+        self.validators.insert(key, Arc::from(validator));
+    }
+
+    /// The validator registered for `file_type`, if any
+    pub fn get(&self, file_type: &str) -> Option<&ValidatorFn> {
+        self.validators.get(&file_type.to_lowercase()).map(|validator| validator.as_ref())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+}
+
+impl fmt::Debug for ValidatorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut types: Vec<_> = self.validators.keys().collect();
+        types.sort();
+        f.debug_struct("ValidatorRegistry").field("types", &types).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_ignores_case_and_leading_dot() {
+        let mut registry = ValidatorRegistry::new();
+        registry.register(".Proto", Box::new(|_, _| Ok(ValidationResult::passed())));
+
+        assert!(registry.get("proto").is_some());
+        assert!(registry.get("PROTO").is_some());
+        assert!(registry.get("json").is_none());
+        assert_eq!(format!("{:?}", registry), "ValidatorRegistry { types: [\"proto\"] }");
+    }
+}
@@ -0,0 +1,464 @@
+//! Scan reporters
+//!
+//! Every output format is a `Reporter`, looked up by name with `reporter`, so
+//! the console and `--report` files render a scan the same way. Adding a
+//! format means implementing the trait and listing it in the registry.
+//! `write_report_dir` renders each file's share of a scan on its own.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use anyhow::{Result, Context};
+
+use crate::analysis::IssueSeverity;
+use super::{ScanResult, ValidationError, to_checkstyle};
+use super::scan::TypeResult;
+use super::display::write_scan_results;
+
+/// Formats `reporter` knows, for help text and error messages
+pub const REPORT_FORMATS: &[&str] = &["text", "json", "checkstyle", "sarif", "gcc", "compact"];
+
+/// Renders a scan result in one output format
+pub trait Reporter {
+    fn write(&self, result: &ScanResult, w: &mut dyn Write) -> Result<()>;
+}
+
+/// What reporters may include besides the result itself
+#[derive(Debug, Clone, Default)]
+pub struct ReportOptions {
+    /// Heading of the text summary, usually the scanned directories
+    pub title: String,
+    /// Keep terminal colors in text output; off for files
+    pub color: bool,
+    /// Validator tool versions behind the results, for json and sarif
+    pub tool_versions: BTreeMap<String, String>,
+}
+
+/// The reporter for a format name, or `None` for an unknown format
+pub fn reporter(format: &str, options: &ReportOptions) -> Option<Box<dyn Reporter>> {
+    let reporter: Box<dyn Reporter> = match format {
+        "text" => Box::new(TextReporter { title: options.title.clone(), color: options.color }),
+        "json" => Box::new(JsonReporter { tool_versions: options.tool_versions.clone() }),
+        "checkstyle" => Box::new(CheckstyleReporter),
+        "sarif" => Box::new(SarifReporter { tool_versions: options.tool_versions.clone() }),
+        "gcc" | "compact" => Box::new(GccReporter),
+        _ => return None,
+    };
+    Some(reporter)
+}
+
+/// File extension for reports in a format
+pub fn report_extension(format: &str) -> &'static str {
+    match format {
+        "json" => "json",
+        "checkstyle" => "xml",
+        "sarif" => "sarif",
+        _ => "txt",
+    }
+}
+
+/// The format a report file's extension asks for, if it's one of ours
+pub fn format_for_path(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "json" => Some("json"),
+        "sarif" => Some("sarif"),
+        "xml" => Some("checkstyle"),
+        "txt" => Some("text"),
+        _ => None,
+    }
+}
+
+/// Write one report per scanned file into `dir`, mirroring the files' paths
+/// (`src/app.py` becomes `src/app.py.json`), plus an `index.json` listing
+/// them all. `files` are the scanned files, as `result` names them.
+/// Returns the path of the index.
+pub fn write_report_dir(
+    dir: &Path,
+    result: &ScanResult,
+    files: &[PathBuf],
+    format: &str,
+    options: &ReportOptions,
+) -> Result<PathBuf> {
+    let options = ReportOptions { color: false, ..options.clone() };
+    let reporter = reporter(format, &options)
+        .or_else(|| reporter("text", &options))
+        .unwrap();
+    let extension = report_extension(format);
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create report directory {}", dir.display()))?;
+
+    let mut entries = Vec::new();
+    for file in files {
+        let file_result = file_result(result, file);
+        let name = report_name(file, extension);
+        let report_path = dir.join(&name);
+        if let Some(parent) = report_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut out = fs::File::create(&report_path)
+            .with_context(|| format!("Failed to create {}", report_path.display()))?;
+        reporter.write(&file_result, &mut out)?;
+
+        entries.push(serde_json::json!({
+            "file": file.to_string_lossy(),
+            "report": name.to_string_lossy(),
+            "valid": file_result.invalid_files.is_empty(),
+            "issues": file_result.issues.len(),
+        }));
+    }
+
+    let index = serde_json::json!({
+        "format": format,
+        "total_files": files.len(),
+        "invalid_files": result.invalid_files.len(),
+        "files": entries,
+    });
+    let index_path = dir.join("index.json");
+    fs::write(&index_path, serde_json::to_string_pretty(&index)? + "\n")?;
+    Ok(index_path)
+}
+
+/// One file's share of a scan: its verdict and the issues found in it
+fn file_result(result: &ScanResult, file: &Path) -> ScanResult {
+    let invalid = result.invalid_files.iter().any(|path| path == file);
+    let path = file.to_string_lossy();
+    let extension = file.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+    let type_result = TypeResult {
+        total: 1,
+        valid: usize::from(!invalid),
+        invalid: if invalid { vec![file.to_path_buf()] } else { Vec::new() },
+    };
+    ScanResult {
+        total_files: 1,
+        valid_files: usize::from(!invalid),
+        valid_file_paths: if invalid { Vec::new() } else { vec![file.to_path_buf()] },
+        invalid_files: type_result.invalid.clone(),
+        skipped_files: Vec::new(),
+        results_by_type: BTreeMap::from([(extension, type_result)]),
+        issues: result.issues.iter().filter(|issue| issue.file_path == path).cloned().collect(),
+        cached_files: 0,
+        cache_misses: 0,
+        suppressed_issues: 0,
+        hidden_issues: 0,
+    }
+}
+
+// Paths outside the scan root are absolute or climb with `..`; only the
+// plain components are kept so every report lands inside the directory
+fn report_name(file: &Path, extension: &str) -> PathBuf {
+    let mut name: PathBuf = file.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect();
+    let mut file_name = name.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(extension);
+    name.set_file_name(file_name);
+    name
+}
+
+/// The human-readable summary
+pub struct TextReporter {
+    pub title: String,
+    pub color: bool,
+}
+
+impl Reporter for TextReporter {
+    fn write(&self, result: &ScanResult, w: &mut dyn Write) -> Result<()> {
+        if self.color {
+            write_scan_results(result, &self.title, w)?;
+        } else {
+            let mut summary = Vec::new();
+            write_scan_results(result, &self.title, &mut summary)?;
+            w.write_all(console::strip_ansi_codes(&String::from_utf8_lossy(&summary)).as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// The result as one JSON document
+pub struct JsonReporter {
+    pub tool_versions: BTreeMap<String, String>,
+}
+
+impl Reporter for JsonReporter {
+    fn write(&self, result: &ScanResult, w: &mut dyn Write) -> Result<()> {
+        let mut report = result.to_report(true);
+        report.tool_versions = self.tool_versions.clone();
+        serde_json::to_writer_pretty(&mut *w, &report)?;
+        writeln!(w)?;
+        Ok(())
+    }
+}
+
+/// Checkstyle XML, for IDE and CI plugins
+pub struct CheckstyleReporter;
+
+impl Reporter for CheckstyleReporter {
+    fn write(&self, result: &ScanResult, w: &mut dyn Write) -> Result<()> {
+        w.write_all(to_checkstyle(result).as_bytes())?;
+        Ok(())
+    }
+}
+
+/// One `path:line:col: severity: message` line per issue, the compiler format
+/// editors' quickfix lists and grep understand. There's no header or summary.
+pub struct GccReporter;
+
+impl Reporter for GccReporter {
+    fn write(&self, result: &ScanResult, w: &mut dyn Write) -> Result<()> {
+        for issue in &result.issues {
+            writeln!(w, "{}", gcc_line(issue))?;
+        }
+        // A failed file the tool gave no details for still gets a line
+        for path in &result.invalid_files {
+            let path = path.to_string_lossy();
+            if !result.issues.iter().any(|issue| issue.file_path == path) {
+                writeln!(w, "{}:1:1: error: validation failed", path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An issue as one compiler-style line. Issues without a position point at the
+/// start of the file.
+fn gcc_line(issue: &ValidationError) -> String {
+    let severity = match issue.severity {
+        IssueSeverity::Critical | IssueSeverity::High => "error",
+        IssueSeverity::Medium => "warning",
+        IssueSeverity::Low => "note",
+    };
+    let message = issue.message.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut line = format!("{}:{}:{}: {}: {}",
+        issue.file_path, issue.line.unwrap_or(1), issue.column.unwrap_or(1), severity, message);
+    if let Some(code) = &issue.code {
+        line.push_str(&format!(" [{}]", code));
+    }
+    line
+}
+
+/// SARIF 2.1.0, for code scanning dashboards
+pub struct SarifReporter {
+    pub tool_versions: BTreeMap<String, String>,
+}
+
+impl Reporter for SarifReporter {
+    fn write(&self, result: &ScanResult, w: &mut dyn Write) -> Result<()> {
+        let mut results: Vec<serde_json::Value> = result.issues.iter().map(sarif_result).collect();
+        // Files that failed without detailed errors still need to show up
+        for path in &result.invalid_files {
+            let path = path.to_string_lossy();
+            if !result.issues.iter().any(|issue| issue.file_path == path) {
+                results.push(serde_json::json!({
+                    "ruleId": "synx.ValidationFailed",
+                    "level": "error",
+                    "message": { "text": "File failed validation" },
+                    "locations": [{ "physicalLocation": { "artifactLocation": { "uri": path } } }],
+                }));
+            }
+        }
+
+        let mut run = serde_json::json!({
+            "tool": {
+                "driver": {
+                    "name": "synx",
+                    "version": crate::VERSION,
+                    "informationUri": "https://github.com/A5873/synx",
+                }
+            },
+            "results": results,
+        });
+        if !self.tool_versions.is_empty() {
+            run["properties"] = serde_json::json!({ "toolVersions": self.tool_versions });
+        }
+
+        let report = serde_json::json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [run],
+        });
+        serde_json::to_writer_pretty(&mut *w, &report)?;
+        writeln!(w)?;
+        Ok(())
+    }
+}
+
+fn sarif_result(issue: &ValidationError) -> serde_json::Value {
+    let mut location = serde_json::json!({
+        "physicalLocation": { "artifactLocation": { "uri": issue.file_path } }
+    });
+    // SARIF lines and columns start at 1, so unknown positions are left out
+    if let Some(line) = issue.line.filter(|&line| line > 0) {
+        let mut region = serde_json::json!({ "startLine": line });
+        if let Some(column) = issue.column.filter(|&column| column > 0) {
+            region["startColumn"] = serde_json::json!(column);
+        }
+        location["physicalLocation"]["region"] = region;
+    }
+
+    let rule = match &issue.code {
+        Some(code) => format!("synx.{}", code),
+        None => format!("synx.{:?}", issue.error_type),
+    };
+    let level = match issue.severity {
+        IssueSeverity::Critical | IssueSeverity::High => "error",
+        IssueSeverity::Medium => "warning",
+        IssueSeverity::Low => "note",
+    };
+    serde_json::json!({
+        "ruleId": rule,
+        "level": level,
+        "message": { "text": issue.message },
+        "locations": [location],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use crate::validators::{ErrorType, ScanReportV1, SkipReason, SkippedFileReport, SCAN_REPORT_SCHEMA_VERSION};
+
+    fn sample_result() -> ScanResult {
+        ScanResult {
+            total_files: 4,
+            valid_files: 1,
+            valid_file_paths: Vec::new(),
+            invalid_files: vec![PathBuf::from("src/a.py"), PathBuf::from("broken.json")],
+            skipped_files: vec![(PathBuf::from("logo.png"), SkipReason::BinaryFile)],
+            results_by_type: BTreeMap::new(),
+            issues: vec![ValidationError {
+                file_path: "src/a.py".to_string(),
+                error_type: ErrorType::SyntaxError,
+                message: "invalid syntax".to_string(),
+                line: Some(3),
+                column: Some(5),
+                code: None,
+                suggestion: None,
+                severity: ErrorType::SyntaxError.default_severity(),
+            }],
+            cached_files: 0,
+            cache_misses: 0,
+            suppressed_issues: 0,
+            hidden_issues: 0,
+        }
+    }
+
+    fn render(format: &str) -> String {
+        let options = ReportOptions {
+            title: "project".to_string(),
+            tool_versions: BTreeMap::from([("python3".to_string(), "Python 3.12.1".to_string())]),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        reporter(format, &options).unwrap().write(&sample_result(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_every_format_renders() {
+        for format in REPORT_FORMATS {
+            assert!(!render(format).trim().is_empty(), "{} report is empty", format);
+        }
+        assert!(reporter("yaml", &ReportOptions::default()).is_none());
+    }
+
+    #[test]
+    fn test_format_for_path() {
+        assert_eq!(format_for_path(Path::new("out.sarif")), Some("sarif"));
+        assert_eq!(format_for_path(Path::new("out.JSON")), Some("json"));
+        assert_eq!(format_for_path(Path::new("checkstyle.xml")), Some("checkstyle"));
+        assert_eq!(format_for_path(Path::new("log.txt")), Some("text"));
+        assert_eq!(format_for_path(Path::new("report")), None);
+        for format in ["json", "sarif", "checkstyle", "text"] {
+            assert_eq!(format_for_path(Path::new(&format!("r.{}", report_extension(format)))), Some(format));
+        }
+    }
+
+    #[test]
+    fn test_text_report_is_plain() {
+        let text = render("text");
+        assert!(text.contains("project"));
+        assert!(text.contains("src/a.py"));
+        assert!(!text.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn test_json_report_parses() {
+        let report: serde_json::Value = serde_json::from_str(&render("json")).unwrap();
+        assert_eq!(report["invalid_files"], 2);
+        assert_eq!(report["tool_versions"]["python3"], "Python 3.12.1");
+    }
+
+    #[test]
+    fn test_json_report_is_versioned_and_round_trips() {
+        let json = render("json");
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], SCAN_REPORT_SCHEMA_VERSION);
+
+        let report: ScanReportV1 = serde_json::from_str(&json).unwrap();
+        assert_eq!(report.schema_version, SCAN_REPORT_SCHEMA_VERSION);
+        assert_eq!(report.invalid_files, 2);
+        assert_eq!(report.invalid_file_paths, Some(vec!["src/a.py".to_string(), "broken.json".to_string()]));
+        assert_eq!(report.skipped_files, vec![SkippedFileReport { path: "logo.png".to_string(), reason: SkipReason::BinaryFile }]);
+        assert_eq!(report.issues[0].error_type, ErrorType::SyntaxError);
+        assert_eq!(report.tool_versions["python3"], "Python 3.12.1");
+
+        // Serializing the parsed report gives the same document back
+        assert_eq!(serde_json::to_string_pretty(&report).unwrap(), json.trim_end());
+    }
+
+    #[test]
+    fn test_gcc_report_has_one_line_per_issue() {
+        let report = render("gcc");
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines, vec![
+            "src/a.py:3:5: error: invalid syntax",
+            "broken.json:1:1: error: validation failed",
+        ]);
+        assert_eq!(render("compact"), report);
+    }
+
+    #[test]
+    fn test_checkstyle_report_parses() {
+        let xml = render("checkstyle");
+        assert!(xml.starts_with("<?xml"));
+        assert_eq!(xml.matches("<file ").count(), xml.matches("</file>").count());
+    }
+
+    #[test]
+    fn test_report_dir_has_one_report_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = vec![PathBuf::from("src/a.py"), PathBuf::from("broken.json"), PathBuf::from("ok.py")];
+        let index = write_report_dir(dir.path(), &sample_result(), &files, "json", &ReportOptions::default()).unwrap();
+
+        let a: serde_json::Value = serde_json::from_str(&fs::read_to_string(dir.path().join("src/a.py.json")).unwrap()).unwrap();
+        assert_eq!(a["invalid_files"], 1);
+        assert_eq!(a["issues"].as_array().map_or(0, Vec::len), 1);
+        assert!(dir.path().join("broken.json.json").exists());
+
+        let index: serde_json::Value = serde_json::from_str(&fs::read_to_string(index).unwrap()).unwrap();
+        assert_eq!(index["files"].as_array().unwrap().len(), 3);
+        assert_eq!(index["files"][2]["valid"], true);
+        assert_eq!(report_name(Path::new("../lib/b.rs"), "xml"), PathBuf::from("lib/b.rs.xml"));
+    }
+
+    #[test]
+    fn test_sarif_report_parses() {
+        let report: serde_json::Value = serde_json::from_str(&render("sarif")).unwrap();
+        assert_eq!(report["version"], "2.1.0");
+        let results = report["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "synx.SyntaxError");
+        assert_eq!(results[0]["locations"][0]["physicalLocation"]["region"]["startLine"], 3);
+        assert_eq!(results[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "broken.json");
+    }
+}
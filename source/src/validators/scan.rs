@@ -1,30 +1,387 @@
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
-use anyhow::Result;
-use std::collections::HashMap;
+use anyhow::{Result, anyhow};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use indicatif::{ProgressBar, ProgressStyle};
 use colored::*;
-use console::Emoji;
+use crate::glyphs::Glyph;
 use rayon::prelude::*;
 use std::sync::{Arc, Mutex};
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use blake3::Hasher;
 use std::fs;
-use std::io::Read;
+use std::io::{Read, Write};
+use std::sync::mpsc::Sender;
+use serde::{Serialize, Deserialize};
 
-use super::{ValidationOptions, validate_file};
+use super::display::relative_path;
+use super::{ValidationOptions, ValidationError, ErrorType, BinaryFileError, DisabledValidatorError, EmptyFileError, MissingToolError, VanishedFileError, validate_file_detailed, has_validator, validator_tool, validator_fingerprint, validator_type, type_config_fingerprint, memory, generated};
+use super::generated::GeneratedAction;
+use crate::config::GeneratedConfig;
+use crate::detectors::{detect_file_type, FileType};
+use crate::performance::cache::{with_cache_lock, read_cache_file, write_cache_file};
+use crate::performance::{Phase, ToolLimits};
 
-static SCAN_MARK: Emoji<'_, '_> = Emoji("🔍", ">");
-static FILE_MARK: Emoji<'_, '_> = Emoji("📄", "-");
-static ERROR_MARK: Emoji<'_, '_> = Emoji("❌", "x");
+static SCAN_MARK: Glyph = Glyph::new("🔍", ">");
+static FILE_MARK: Glyph = Glyph::new("📄", "-");
+static ERROR_MARK: Glyph = Glyph::new("❌", "x");
 
 #[derive(Default)]
 pub struct ScanResult {
     pub total_files: usize,
     pub valid_files: usize,
+    /// The files that passed, so merged scans can tell which files they share
+    pub valid_file_paths: Vec<PathBuf>,
     pub invalid_files: Vec<PathBuf>,
-    pub skipped_files: Vec<PathBuf>,
-    pub results_by_type: HashMap<String, TypeResult>,
+    pub skipped_files: Vec<(PathBuf, SkipReason)>,
+    /// Keyed by extension; a `BTreeMap` so reports list types in a stable order
+    pub results_by_type: BTreeMap<String, TypeResult>,
+    /// Individual issues: what the validators reported for files that failed
+    /// fresh validation, and findings of analysis passes (e.g. memory leaks)
+    pub issues: Vec<ValidationError>,
+    /// Files whose result came from the cache instead of a fresh validation
+    pub cached_files: usize,
+    /// Files an incremental scan looked up in the cache without finding a usable result
+    pub cache_misses: usize,
+    /// Issues hidden by `synx-disable` comments in freshly validated files
+    pub suppressed_issues: usize,
+    /// Issues dropped past `max_issues_per_file` in freshly validated files
+    pub hidden_issues: usize,
+}
+
+impl ScanResult {
+    /// Rewrite every reported path relative to `base`; paths outside it become absolute
+    pub fn relative_to(mut self, base: &Path) -> Self {
+        let relative = |path: &PathBuf| relative_path(path, base);
+        self.valid_file_paths = self.valid_file_paths.iter().map(relative).collect();
+        self.invalid_files = self.invalid_files.iter().map(relative).collect();
+        for (path, _) in &mut self.skipped_files {
+            *path = relative(&*path);
+        }
+        for type_result in self.results_by_type.values_mut() {
+            type_result.invalid = type_result.invalid.iter().map(relative).collect();
+        }
+        for issue in &mut self.issues {
+            issue.file_path = relative_path(Path::new(&issue.file_path), base).to_string_lossy().to_string();
+        }
+        self.sort();
+        self
+    }
+
+    /// Count one scanned file in. Lists are left in the order files come;
+    /// `sort` puts them in path order.
+    pub fn add(&mut self, file: ScannedFile) {
+        self.total_files += 1;
+        self.cached_files += usize::from(file.cached);
+        self.cache_misses += usize::from(file.cache_miss);
+        self.suppressed_issues += file.suppressed_issues;
+        self.hidden_issues += file.hidden_issues;
+        self.issues.extend(file.issues);
+
+        match file.outcome {
+            FileOutcome::Valid => {
+                self.valid_files += 1;
+                self.valid_file_paths.push(file.path);
+                let type_result = self.results_by_type.entry(file.file_type).or_default();
+                type_result.total += 1;
+                type_result.valid += 1;
+            }
+            FileOutcome::Invalid => {
+                self.invalid_files.push(file.path.clone());
+                let type_result = self.results_by_type.entry(file.file_type).or_default();
+                type_result.total += 1;
+                type_result.invalid.push(file.path);
+            }
+            // Neither valid nor invalid for its type, but it didn't pass either
+            FileOutcome::Failed(_) => self.invalid_files.push(file.path),
+            FileOutcome::Skipped(reason) => self.skipped_files.push((file.path, reason)),
+        }
+    }
+
+    /// Fold another scan's result into this one. A file both scans saw (say
+    /// `src` and `src/lib` were scanned) counts once, as invalid if either scan
+    /// failed it; the file counts are recomputed from the combined lists.
+    /// Cache and suppression figures are added.
+    pub fn merge(&mut self, other: ScanResult) {
+        let seen: HashSet<PathBuf> = self.file_paths().cloned().collect();
+
+        self.cached_files += other.cached_files;
+        self.cache_misses += other.cache_misses;
+        self.suppressed_issues += other.suppressed_issues;
+        self.hidden_issues += other.hidden_issues;
+        self.issues.extend(other.issues.into_iter().filter(|issue| !seen.contains(Path::new(&issue.file_path))));
+
+        extend_unique(&mut self.invalid_files, other.invalid_files);
+        extend_unique(&mut self.valid_file_paths, other.valid_file_paths);
+        let invalid: HashSet<&PathBuf> = self.invalid_files.iter().collect();
+        self.valid_file_paths.retain(|path| !invalid.contains(path));
+        for (path, reason) in other.skipped_files {
+            if !self.skipped_files.iter().any(|(seen, _)| *seen == path) {
+                self.skipped_files.push((path, reason));
+            }
+        }
+
+        for (ext, theirs) in other.results_by_type {
+            extend_unique(&mut self.results_by_type.entry(ext).or_default().invalid, theirs.invalid);
+        }
+        for type_result in self.results_by_type.values_mut() {
+            type_result.valid = 0;
+        }
+        for path in &self.valid_file_paths {
+            self.results_by_type.entry(file_type_key(path)).or_default().valid += 1;
+        }
+        for type_result in self.results_by_type.values_mut() {
+            type_result.total = type_result.valid + type_result.invalid.len();
+        }
+        self.results_by_type.retain(|_, type_result| type_result.total > 0);
+
+        self.valid_files = self.valid_file_paths.len();
+        self.total_files = self.file_paths().collect::<HashSet<_>>().len();
+        self.sort();
+    }
+
+    // Every file the scan looked at, whatever became of it
+    fn file_paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.valid_file_paths.iter()
+            .chain(&self.invalid_files)
+            .chain(self.skipped_files.iter().map(|(path, _)| path))
+    }
+
+    /// Put every list in path order. Files finish validating in whatever order
+    /// the thread pool gets to them, and reports shouldn't depend on that.
+    pub fn sort(&mut self) {
+        self.valid_file_paths.sort();
+        self.invalid_files.sort();
+        self.skipped_files.sort_by(|a, b| a.0.cmp(&b.0));
+        for type_result in self.results_by_type.values_mut() {
+            type_result.invalid.sort();
+        }
+        self.issues.sort_by(|a, b| {
+            (&a.file_path, a.line, a.column, &a.message).cmp(&(&b.file_path, b.line, b.column, &b.message))
+        });
+    }
+
+    /// The scan as a versioned JSON report. `include_paths` adds the list of
+    /// invalid files next to their count.
+    pub fn to_report(&self, include_paths: bool) -> ScanReportV1 {
+        // JSON strings must be UTF-8, which Unix paths needn't be
+        let skipped_files = self.skipped_files.iter()
+            .map(|(path, reason)| SkippedFileReport { path: path.to_string_lossy().to_string(), reason: *reason })
+            .collect();
+        let results_by_type = self.results_by_type.iter()
+            .map(|(ext, type_result)| (ext.clone(), TypeReport {
+                total: type_result.total,
+                valid: type_result.valid,
+                invalid: lossy_paths(&type_result.invalid),
+            }))
+            .collect();
+
+        ScanReportV1 {
+            schema_version: SCAN_REPORT_SCHEMA_VERSION,
+            total_files: self.total_files,
+            valid_files: self.valid_files,
+            invalid_files: self.invalid_files.len(),
+            invalid_file_paths: include_paths.then(|| lossy_paths(&self.invalid_files)),
+            skipped_files,
+            results_by_type,
+            issues: self.issues.clone(),
+            suppressed_issues: self.suppressed_issues,
+            hidden_issues: self.hidden_issues,
+            tool_versions: BTreeMap::new(),
+        }
+    }
+}
+
+impl FromIterator<ScannedFile> for ScanResult {
+    fn from_iter<I: IntoIterator<Item = ScannedFile>>(files: I) -> Self {
+        let mut result = ScanResult::default();
+        for file in files {
+            result.add(file);
+        }
+        result.sort();
+        result
+    }
+}
+
+/// Version of the JSON scan report layout. It goes up whenever a field is
+/// renamed, removed or changes meaning; new optional fields don't bump it.
+pub const SCAN_REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// The JSON scan report, as written by `--format json` and `--report`.
+/// docs/REPORT_SCHEMA.md describes every field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanReportV1 {
+    pub schema_version: u32,
+    pub total_files: usize,
+    pub valid_files: usize,
+    /// How many files failed
+    pub invalid_files: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invalid_file_paths: Option<Vec<String>>,
+    pub skipped_files: Vec<SkippedFileReport>,
+    pub results_by_type: BTreeMap<String, TypeReport>,
+    pub issues: Vec<ValidationError>,
+    pub suppressed_issues: usize,
+    pub hidden_issues: usize,
+    /// Versions of the validator tools behind the results
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tool_versions: BTreeMap<String, String>,
+}
+
+/// A file the scan didn't validate, in a `ScanReportV1`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedFileReport {
+    pub path: String,
+    pub reason: SkipReason,
+}
+
+/// Results for one file type, in a `ScanReportV1`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeReport {
+    pub total: usize,
+    pub valid: usize,
+    /// Files of this type that failed
+    pub invalid: Vec<String>,
+}
+
+// What `results_by_type` files a path under: its extension
+fn file_type_key(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+fn lossy_paths(paths: &[PathBuf]) -> Vec<String> {
+    paths.iter().map(|path| path.to_string_lossy().to_string()).collect()
+}
+
+fn extend_unique(paths: &mut Vec<PathBuf>, more: Vec<PathBuf>) {
+    for path in more {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+}
+
+/// Cache and timing figures for a finished scan, as `scan --stats` prints them
+#[derive(Debug, Clone)]
+pub struct ScanStats {
+    pub cache_hits: usize,
+    pub cache_misses: usize,
+    pub total_files: usize,
+    /// Files validated fresh rather than answered from the cache
+    pub validated_files: usize,
+    pub cached_files: usize,
+    pub elapsed: Duration,
+}
+
+impl ScanStats {
+    pub fn new(result: &ScanResult, elapsed: Duration) -> Self {
+        let checked = result.valid_files + result.invalid_files.len();
+        Self {
+            cache_hits: result.cached_files,
+            cache_misses: result.cache_misses,
+            total_files: result.total_files,
+            validated_files: checked.saturating_sub(result.cached_files),
+            cached_files: result.cached_files,
+            elapsed,
+        }
+    }
+
+    /// Share of cache lookups that found a result; 0 when nothing was looked up
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.cache_hits + self.cache_misses;
+        if lookups == 0 { 0.0 } else { self.cache_hits as f64 / lookups as f64 }
+    }
+}
+
+impl std::fmt::Display for ScanStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} Scan stats:", crate::glyphs::STATS)?;
+        if self.cache_hits + self.cache_misses == 0 {
+            writeln!(f, "  Cache: not used (--incremental reuses results)")?;
+        } else {
+            writeln!(f, "  Cache: {} hits, {} misses, {:.1}% hit ratio",
+                self.cache_hits, self.cache_misses, self.hit_ratio() * 100.0)?;
+        }
+        writeln!(f, "  Files: {} total, {} validated, {} cached", self.total_files, self.validated_files, self.cached_files)?;
+        write!(f, "  Elapsed: {:.2}s", self.elapsed.as_secs_f64())
+    }
+}
+
+/// Why a scan didn't validate a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// No validator handles this kind of file
+    UnknownType,
+    /// Over the `--max-file-size` limit
+    TooLarge,
+    /// Left out by `--only`/`--skip` or the configured languages
+    Ignored,
+    /// The file doesn't look like text
+    BinaryFile,
+    /// The validator's tool isn't installed
+    NoValidatorTool,
+    /// Written by a tool, and `[generated]` says to leave such files alone
+    Generated,
+    /// A symlink, and the scan wasn't asked to follow them
+    Symlink,
+    /// Empty or whitespace-only, and `[scan] empty_files = "skip"`
+    EmptyFile,
+    /// Its language's validator has `enabled = false` (or --disable)
+    Disabled,
+    /// Deleted between being found and being validated
+    Vanished,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            SkipReason::UnknownType => "unknown file type",
+            SkipReason::TooLarge => "too large",
+            SkipReason::Ignored => "ignored",
+            SkipReason::BinaryFile => "binary file",
+            SkipReason::NoValidatorTool => "validator tool not installed",
+            SkipReason::Generated => "generated file",
+            SkipReason::Symlink => "symlink not followed",
+            SkipReason::EmptyFile => "empty file",
+            SkipReason::Disabled => "validator disabled",
+            SkipReason::Vanished => "removed during the scan",
+        };
+        f.write_str(reason)
+    }
+}
+
+/// Outcome of validating a single file, streamed while a scan is running
+#[derive(Debug, Clone, Serialize)]
+pub struct FileScanRecord {
+    pub path: PathBuf,
+    pub file_type: String,
+    pub success: bool,
+    pub issue_count: usize,
+}
+
+/// Identifies a directory however it was reached: device and inode on Unix,
+/// the resolved path elsewhere
+#[cfg(unix)]
+fn dir_identity(path: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    fs::metadata(path).ok().map(|metadata| (metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn dir_identity(path: &Path) -> Option<PathBuf> {
+    fs::canonicalize(path).ok()
+}
+
+/// Write a record as one line of newline-delimited JSON and flush immediately
+pub fn write_ndjson<W: Write>(out: &mut W, record: &FileScanRecord) -> Result<()> {
+    serde_json::to_writer(&mut *out, record)?;
+    out.write_all(b"\n")?;
+    out.flush()?;
+    Ok(())
 }
 
 #[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -34,37 +391,145 @@ pub struct TypeResult {
     pub invalid: Vec<PathBuf>,
 }
 
+/// Restricts a scan to, or away from, particular languages
+#[derive(Debug, Clone, Default)]
+pub struct LanguageFilter {
+    /// When non-empty, only files of these types are validated
+    pub only: Vec<FileType>,
+    /// Files of these types are never validated
+    pub skip: Vec<FileType>,
+}
+
+impl LanguageFilter {
+    /// Build a filter from language names, failing on any name that isn't recognized
+    pub fn from_names(only: &[String], skip: &[String]) -> Result<Self> {
+        let parse = |names: &[String]| -> Result<Vec<FileType>> {
+            names.iter()
+                .filter(|name| !name.trim().is_empty())
+                .map(|name| FileType::from_name(name)
+                    .ok_or_else(|| anyhow!("Unknown language '{}'", name)))
+                .collect()
+        };
+
+        Ok(Self {
+            only: parse(only)?,
+            skip: parse(skip)?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.only.is_empty() && self.skip.is_empty()
+    }
+
+    /// Whether a file of the given type should be validated
+    pub fn allows(&self, file_type: &FileType) -> bool {
+        (self.only.is_empty() || self.only.contains(file_type)) && !self.skip.contains(file_type)
+    }
+}
+
+/// Parse a human-readable size such as "5MB", "512k" or "1.5GiB" into bytes.
+/// Units are binary (1KB = 1024 bytes); a bare number is taken as bytes.
+pub fn parse_size(text: &str) -> Result<u64> {
+    let text = text.trim();
+    let split = text.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+
+    let value: f64 = number.parse()
+        .map_err(|_| anyhow!("Invalid size '{}'", text))?;
+    let multiplier: u64 = match unit.trim().to_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1024,
+        "m" | "mb" | "mib" => 1024 * 1024,
+        "g" | "gb" | "gib" => 1024 * 1024 * 1024,
+        other => return Err(anyhow!("Unknown size unit '{}' in '{}'", other, text)),
+    };
+
+    Ok((value * multiplier as f64) as u64)
+}
+
+/// Turn a window such as "30m", "2h" or "1d" into the time it started
+pub fn parse_since(text: &str) -> Result<SystemTime> {
+    let window = humantime::parse_duration(text.trim())
+        .map_err(|e| anyhow!("Invalid duration '{}': {}", text, e))?;
+    SystemTime::now().checked_sub(window)
+        .ok_or_else(|| anyhow!("Duration '{}' reaches back too far", text))
+}
+
+/// Whether a file was last modified at or after `since`. Files whose
+/// modification time can't be read are kept.
+pub fn modified_since(path: &Path, since: SystemTime) -> bool {
+    match fs::metadata(path).and_then(|metadata| metadata.modified()) {
+        Ok(modified) => modified >= since,
+        Err(_) => true,
+    }
+}
+
+fn file_too_large(path: &Path, size: u64, limit: u64) -> ValidationError {
+    ValidationError {
+        file_path: path.to_string_lossy().to_string(),
+        error_type: ErrorType::FileTooLarge,
+        message: format!("File is {} bytes, larger than the {} byte limit; skipped", size, limit),
+        line: None,
+        column: None,
+        code: None,
+        suggestion: Some("Raise the limit with --max-file-size or exclude the file".to_string()),
+        severity: ErrorType::FileTooLarge.default_severity(),
+    }
+}
+
+fn generated_file_failed(path: &Path) -> ValidationError {
+    ValidationError {
+        file_path: path.to_string_lossy().to_string(),
+        error_type: ErrorType::Warning,
+        message: "Generated file failed validation".to_string(),
+        line: None,
+        column: None,
+        code: None,
+        suggestion: Some("Regenerate the file, or set [generated] action = \"validate\" to treat this as an error".to_string()),
+        severity: ErrorType::Warning.default_severity(),
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct CacheEntry {
     hash: String,
     is_valid: bool,
     timestamp: u64,
+    /// Versions of the tools that gave the verdict; an upgrade may judge differently
+    #[serde(default)]
+    tool_version: Option<String>,
+    /// `config_fingerprint` of the settings the verdict was given under
+    #[serde(default)]
+    config_hash: Option<String>,
+    /// What was wrong with a failing file, reported again on a cache hit
+    #[serde(default)]
+    errors: Vec<ValidationError>,
 }
 
-struct ValidationCache {
+/// Content-hash cache of verdicts, shared by scans and the daemon
+pub(crate) struct ValidationCache {
     entries: Arc<Mutex<HashMap<PathBuf, CacheEntry>>>,
+    /// Entries for files that no longer exist, dropped from the file on save
+    forgotten: Mutex<HashSet<PathBuf>>,
     cache_file: PathBuf,
 }
 
 impl ValidationCache {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         let cache_dir = dirs::cache_dir()
             .unwrap_or_else(|| PathBuf::from(".cache"))
             .join("synx");
         fs::create_dir_all(&cache_dir).ok();
-        let cache_file = cache_dir.join("validation_cache.json");
-        
-        let entries = if cache_file.exists() {
-            fs::read_to_string(&cache_file)
-                .ok()
-                .and_then(|s| serde_json::from_str(&s).ok())
-                .unwrap_or_default()
-        } else {
-            HashMap::new()
-        };
+        Self::with_file(cache_dir.join("validation_cache.json"))
+    }
+    
+    pub(crate) fn with_file(cache_file: PathBuf) -> Self {
+        let entries = with_cache_lock(&cache_file, || Ok(read_cache_file(&cache_file)))
+            .unwrap_or_default();
         
         Self {
             entries: Arc::new(Mutex::new(entries)),
+            forgotten: Mutex::new(HashSet::new()),
             cache_file,
         }
     }
@@ -78,19 +543,30 @@ impl ValidationCache {
         Some(hasher.finalize().to_hex().to_string())
     }
     
-    fn is_valid_cached(&self, path: &Path) -> Option<bool> {
+    pub(crate) fn is_valid_cached(&self, path: &Path, tool_version: Option<&str>, config_hash: &str) -> Option<bool> {
+        self.cached_verdict(path, tool_version, config_hash).map(|(is_valid, _)| is_valid)
+    }
+
+    /// The cached verdict on `path` along with the issues behind it
+    pub(crate) fn cached_verdict(&self, path: &Path, tool_version: Option<&str>, config_hash: &str) -> Option<(bool, Vec<ValidationError>)> {
         let hash = Self::get_file_hash(path)?;
         let entries = self.entries.lock().ok()?;
         
         if let Some(entry) = entries.get(path) {
-            if entry.hash == hash {
-                return Some(entry.is_valid);
+            if entry.hash == hash && entry.tool_version.as_deref() == tool_version
+                && entry.config_hash.as_deref() == Some(config_hash)
+            {
+                return Some((entry.is_valid, entry.errors.clone()));
             }
         }
         None
     }
     
-    fn cache_result(&self, path: &Path, is_valid: bool) {
+    pub(crate) fn cache_result(&self, path: &Path, is_valid: bool, errors: &[ValidationError], tool_version: Option<&str>, config_hash: &str) {
+        // The cache file is JSON, and one path it can't hold would stop it being saved at all
+        if path.to_str().is_none() {
+            return;
+        }
         if let Some(hash) = Self::get_file_hash(path) {
             if let Ok(mut entries) = self.entries.lock() {
                 let timestamp = SystemTime::now()
@@ -102,107 +578,440 @@ impl ValidationCache {
                     hash,
                     is_valid,
                     timestamp,
+                    tool_version: tool_version.map(str::to_string),
+                    config_hash: Some(config_hash.to_string()),
+                    errors: errors.to_vec(),
                 });
             }
         }
     }
     
-    fn save(&self) {
-        if let Ok(entries) = self.entries.lock() {
-            if let Ok(json) = serde_json::to_string_pretty(&*entries) {
-                fs::write(&self.cache_file, json).ok();
-            }
+    /// Drop the entries for files under `root` that have been deleted
+    fn forget_missing(&self, root: &Path) {
+        if let (Ok(mut entries), Ok(mut forgotten)) = (self.entries.lock(), self.forgotten.lock()) {
+            entries.retain(|path, _| {
+                let missing = path.starts_with(root) && !path.exists();
+                if missing {
+                    forgotten.insert(path.clone());
+                }
+                !missing
+            });
+        }
+    }
+    
+    /// Write the cache, merged with whatever other processes saved since we loaded it
+    pub(crate) fn save(&self) {
+        if let (Ok(entries), Ok(forgotten)) = (self.entries.lock(), self.forgotten.lock()) {
+            let _ = with_cache_lock(&self.cache_file, || {
+                let mut merged: HashMap<PathBuf, CacheEntry> = read_cache_file(&self.cache_file);
+                merged.retain(|path, _| !forgotten.contains(path));
+                merged.extend(entries.iter().map(|(path, entry)| (path.clone(), entry.clone())));
+                write_cache_file(&self.cache_file, &merged)
+            });
         }
     }
 }
 
-pub fn scan_directory(
-    dir_path: &Path,
-    options: &ValidationOptions,
-    exclude_patterns: &[String],
-) -> Result<ScanResult> {
-    let start_time = Instant::now();
-    
-    println!("\n{} {} {}", 
-        SCAN_MARK,
-        "Starting parallel scan of".bright_blue(),
-        dir_path.display().to_string().bright_white().underline()
-    );
+/// Name of the per-project config file looked up above each scanned file
+const PROJECT_CONFIG_FILE: &str = ".synx.toml";
 
-    let cache = ValidationCache::new();
-    
-    // Collect all file paths first
-    let files: Vec<PathBuf> = WalkDir::new(dir_path)
-        .into_iter()
+/// `config_fingerprint` per project config and file type, hashed once per
+/// scan rather than once per file
+#[derive(Default)]
+struct ConfigFingerprints {
+    hashes: Mutex<HashMap<FingerprintKey, String>>,
+}
+
+/// The project config file that applies, if any, and the validator type
+type FingerprintKey = (Option<PathBuf>, Option<String>);
+
+impl ConfigFingerprints {
+    fn get(&self, path: &Path, project_config: Option<PathBuf>, options: &ValidationOptions) -> String {
+        let key = (project_config, validator_type(path, options).ok());
+        if let Some(hash) = self.hashes.lock().unwrap().get(&key) {
+            return hash.clone();
+        }
+        let hash = type_config_fingerprint(key.1.as_deref(), options);
+        self.hashes.lock().unwrap().insert(key, hash.clone());
+        hash
+    }
+}
+
+/// Scan options for files below a subproject's own `.synx.toml`, so each
+/// package in a monorepo is checked against its local config. Directories
+/// are looked up, and config files loaded, once per scan.
+#[derive(Default)]
+struct ProjectConfigs {
+    // Nearest config file above each directory, if it isn't already applied
+    nearest: Mutex<HashMap<PathBuf, Option<PathBuf>>>,
+    // Options with each config file layered on; None if it failed to load
+    options: Mutex<HashMap<PathBuf, Option<Arc<ValidationOptions>>>>,
+}
+
+impl ProjectConfigs {
+    /// Options for `file`, or None when the scan's own options apply
+    fn options_for(&self, file: &Path, base: &ValidationOptions) -> Option<Arc<ValidationOptions>> {
+        let config_path = self.config_path_for(file, base)?;
+        let mut options = self.options.lock().unwrap();
+        options.entry(config_path.clone())
+            .or_insert_with(|| match base.with_config_file(&config_path) {
+                Ok(options) => Some(Arc::new(options)),
+                Err(e) => {
+                    eprintln!("{} Ignoring {}: {:#}", crate::glyphs::WARN, config_path.display(), e);
+                    None
+                }
+            })
+            .clone()
+    }
+
+    /// The config file behind `options_for(file)`, if it isn't the scan's own
+    fn config_path_for(&self, file: &Path, base: &ValidationOptions) -> Option<PathBuf> {
+        self.nearest_config(file.parent()?, base)
+    }
+
+    fn nearest_config(&self, dir: &Path, base: &ValidationOptions) -> Option<PathBuf> {
+        let dir = fs::canonicalize(dir).ok()?;
+        if let Some(found) = self.nearest.lock().unwrap().get(&dir) {
+            return found.clone();
+        }
+        let loaded = base.config.as_ref()
+            .map(|config| config.loaded_config_paths.as_slice())
+            .unwrap_or_default();
+        let found = dir.ancestors()
+            .map(|ancestor| ancestor.join(PROJECT_CONFIG_FILE))
+            .find(|path| path.is_file())
+            .filter(|path| !loaded.iter().any(|loaded| fs::canonicalize(loaded).ok().as_ref() == Some(path)));
+        self.nearest.lock().unwrap().insert(dir, found.clone());
+        found
+    }
+}
+
+/// The entries below `dir_path` that aren't excluded. Symlinks are only
+/// followed when asked, and then each directory is entered once however many
+/// links lead to it, so a cycle of links can't keep the walk going forever.
+fn walk<'a>(
+    dir_path: &Path,
+    options: &'a ValidationOptions,
+    exclude_patterns: &'a [String],
+) -> impl Iterator<Item = walkdir::DirEntry> + Send + 'a {
+    let mut walker = WalkDir::new(dir_path).follow_links(options.follow_symlinks);
+    if let Some(depth) = options.max_depth {
+        walker = walker.max_depth(depth);
+    }
+    let mut visited = HashSet::new();
+    walker.into_iter()
+        .filter_entry(move |e| {
+            !options.follow_symlinks
+                || !e.file_type().is_dir()
+                || dir_identity(e.path()).is_none_or(|id| visited.insert(id))
+        })
         .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file())
-        .filter(|e| !exclude_patterns.iter().any(|pattern| {
+        .filter(move |entry| !exclude_patterns.iter().any(|pattern| {
             glob::Pattern::new(pattern)
-                .map(|p| p.matches(e.path().to_str().unwrap_or("")))
+                .map(|p| p.matches(&entry.path().to_string_lossy()))
                 .unwrap_or(false)
         }))
-        .map(|e| e.path().to_path_buf())
-        .collect();
+}
 
-    let total_files = files.len();
-    println!("  Found {} files to validate", total_files.to_string().bright_white());
-    
-    if total_files == 0 {
-        return Ok(ScanResult::default());
+// The scanned directory itself may be a link; that one is always followed
+fn is_unfollowed_symlink(entry: &walkdir::DirEntry, options: &ValidationOptions) -> bool {
+    entry.path_is_symlink() && !options.follow_symlinks && entry.depth() > 0
+}
+
+fn is_scanned_file(entry: &walkdir::DirEntry, options: &ValidationOptions) -> bool {
+    entry.file_type().is_file()
+        && options.modified_since.is_none_or(|since| modified_since(entry.path(), since))
+}
+
+/// What became of one file in a scan
+#[derive(Debug)]
+pub enum FileOutcome {
+    Valid,
+    Invalid,
+    /// The validator couldn't be run to a verdict
+    Failed(anyhow::Error),
+    Skipped(SkipReason),
+}
+
+/// One file's part in a scan, as `scan_directory_streaming` yields it
+#[derive(Debug)]
+pub struct ScannedFile {
+    pub path: PathBuf,
+    /// Extension the file is counted under in `ScanResult::results_by_type`
+    pub file_type: String,
+    pub outcome: FileOutcome,
+    /// What the validator and analysis passes reported for the file
+    pub issues: Vec<ValidationError>,
+    /// The verdict came from the cache instead of a fresh validation
+    pub cached: bool,
+    /// An incremental scan looked the file up in the cache without finding a usable result
+    pub cache_miss: bool,
+    /// Issues hidden by `synx-disable` comments
+    pub suppressed_issues: usize,
+    /// Issues dropped past `max_issues_per_file`
+    pub hidden_issues: usize,
+}
+
+impl ScannedFile {
+    fn new(path: &Path) -> Self {
+        ScannedFile {
+            path: path.to_path_buf(),
+            file_type: file_type_key(path),
+            outcome: FileOutcome::Valid,
+            issues: Vec::new(),
+            cached: false,
+            cache_miss: false,
+            suppressed_issues: 0,
+            hidden_issues: 0,
+        }
     }
 
-    let progress = Arc::new(Mutex::new(ProgressBar::new(total_files as u64)));
-    {
-        let p = progress.lock().unwrap();
-        p.set_style(
-            ProgressStyle::default_bar()
-                .template(&format!("{} {}",
-                    "[{elapsed_precise}]".bright_black(),
-                    " {bar:40.cyan/blue} {pos:>7}/{len:7} {msg}"))?
-                .progress_chars("█▇▆▅▄▃▂▁  ")
-        );
+    fn skipped(mut self, reason: SkipReason) -> Self {
+        self.outcome = FileOutcome::Skipped(reason);
+        self
     }
 
-    // Thread-safe collections for results
-    let valid_files = Arc::new(Mutex::new(Vec::new()));
-    let invalid_files = Arc::new(Mutex::new(Vec::new()));
-    let skipped_files = Arc::new(Mutex::new(Vec::new()));
-    let results_by_type = Arc::new(Mutex::new(HashMap::<String, TypeResult>::new()));
-    let cache_hits = Arc::new(Mutex::new(0usize));
-    
-    // Process files in parallel
-    files.par_iter().for_each(|path| {
-        let mut cached = false;
+    /// The file as a streamed `FileScanRecord`; skipped files have none
+    pub fn record(&self) -> Option<FileScanRecord> {
+        if matches!(self.outcome, FileOutcome::Skipped(_)) {
+            return None;
+        }
+        Some(FileScanRecord {
+            path: self.path.clone(),
+            file_type: self.file_type.clone(),
+            success: matches!(self.outcome, FileOutcome::Valid),
+            issue_count: self.issues.len(),
+        })
+    }
+}
+
+/// The per-file steps of a scan: language filter, size limit, generated
+/// files, the cache, validation and memory analysis
+struct FileScanner<'a> {
+    options: &'a ValidationOptions,
+    filter: &'a LanguageFilter,
+    // Print nothing per file; the output belongs to whoever consumes the scan
+    quiet: bool,
+    cache: ValidationCache,
+    tool_limits: ToolLimits,
+    generated_config: GeneratedConfig,
+    generated_action: GeneratedAction,
+    project_configs: ProjectConfigs,
+    config_fingerprints: ConfigFingerprints,
+}
+
+impl<'a> FileScanner<'a> {
+    fn new(dir_path: &Path, options: &'a ValidationOptions, filter: &'a LanguageFilter, quiet: bool) -> Result<Self> {
+        // Every scan records its results, but only incremental scans trust them
+        let cache = ValidationCache::new();
+        if options.incremental {
+            cache.forget_missing(dir_path);
+        }
+
+        let tool_limits = options.config.as_ref()
+            .map(|config| ToolLimits::new(&config.concurrency))
+            .unwrap_or_default();
+        let generated_config = options.config.as_ref()
+            .map(|config| config.generated.clone())
+            .unwrap_or_default();
+        let generated_action = generated::action(&generated_config)?;
+
+        Ok(FileScanner {
+            options,
+            filter,
+            quiet,
+            cache,
+            tool_limits,
+            generated_config,
+            generated_action,
+            project_configs: ProjectConfigs::default(),
+            config_fingerprints: ConfigFingerprints::default(),
+        })
+    }
+
+    fn scan(&self, entry: walkdir::DirEntry) -> ScannedFile {
+        if is_unfollowed_symlink(&entry, self.options) {
+            return ScannedFile::new(entry.path()).skipped(SkipReason::Symlink);
+        }
+        self.scan_file(entry.path())
+    }
+
+    fn scan_file(&self, path: &Path) -> ScannedFile {
+        let base_options = self.options;
+        let profiler = base_options.profiler.as_deref();
+        // A subproject's own .synx.toml decides how its files are checked
+        let project_options = self.project_configs.options_for(path, base_options);
+        let options = project_options.as_deref().unwrap_or(base_options);
+        let verbose = options.verbose && !self.quiet;
+        let mut file = ScannedFile::new(path);
+
+        let detection_started = Instant::now();
+        let record_detection = || {
+            if let Some(profiler) = profiler {
+                profiler.record(Phase::TypeDetection, detection_started.elapsed());
+            }
+        };
+
+        // Detection is only needed (and only paid for) when filtering by language
+        if !self.filter.is_empty() {
+            let allowed = detect_file_type(path)
+                .map(|file_type| self.filter.allows(&file_type))
+                .unwrap_or(false);
+            if !allowed {
+                record_detection();
+                return file.skipped(SkipReason::Ignored);
+            }
+        }
+
+        // Oversized files (e.g. minified bundles) can hang a validator, so don't hand them over
+        if let Some(limit) = options.max_file_size {
+            // Stat now rather than trusting the walk: a build may have rewritten or removed it since
+            let size = match fs::metadata(path) {
+                Ok(metadata) => metadata.len(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    return file.skipped(SkipReason::Vanished);
+                }
+                Err(_) => 0,
+            };
+            if size > limit {
+                file.issues.push(file_too_large(path, size, limit));
+                return file.skipped(SkipReason::TooLarge);
+            }
+        }
+
+        let known = has_validator(path, options);
+        record_detection();
+        if !known {
+            return file.skipped(SkipReason::UnknownType);
+        }
+
+        let is_generated = self.generated_action != GeneratedAction::Validate
+            && generated::is_generated(path, &self.generated_config);
+        if is_generated && self.generated_action == GeneratedAction::Skip {
+            return file.skipped(SkipReason::Generated);
+        }
+
+        // A crashed tool's verdict would stick in the cache, so it isn't kept
+        let mut crashed = false;
+        // What the validator found wrong with a failing file, kept for the cache
+        let mut file_errors = Vec::new();
         
-        // Check cache first
-        let validation_result = if let Some(is_valid) = cache.is_valid_cached(path) {
-            cached = true;
-            *cache_hits.lock().unwrap() += 1;
+        // Check cache first; a result from another version of the tool, or
+        // under other settings, doesn't count
+        let file_type = FileType::from_path(path).ok();
+        let fingerprint = file_type.as_ref()
+            .and_then(|file_type| validator_fingerprint(file_type, options.config.as_ref()));
+        let project_config = self.project_configs.config_path_for(path, base_options);
+        let config_hash = self.config_fingerprints.get(path, project_config, options);
+        let cached_result = if options.incremental {
+            let cached_result = self.cache.cached_verdict(path, fingerprint.as_deref(), &config_hash);
+            file.cache_miss = cached_result.is_none();
+            cached_result
+        } else {
+            None
+        };
+        let validation_result = if let Some((is_valid, errors)) = cached_result {
+            file.cached = true;
+            // A cached failure still says what to fix
+            if !is_valid && !is_generated {
+                file.issues.extend(errors);
+            }
             Ok(is_valid)
         } else {
-            validate_file(path, options)
+            // Heavy tools wait their turn here rather than all starting at once
+            let tool = file_type.as_ref().and_then(validator_tool);
+            let _permit = tool.and_then(|tool| self.tool_limits.acquire(tool));
+            let started = Instant::now();
+            let result = validate_file_detailed(path, options).map(|result| {
+                crashed = result.errors.iter().any(|e| e.error_type == ErrorType::ToolCrashed);
+                file.suppressed_issues = result.suppressed;
+                file.hidden_issues = result.hidden_issues;
+                // A generated file's failures are reported as one warning instead
+                if !result.success {
+                    file_errors = result.errors;
+                    if !is_generated {
+                        file.issues.extend(file_errors.iter().cloned());
+                    }
+                }
+                result.success
+            });
+            if let Some(profiler) = profiler {
+                let elapsed = started.elapsed();
+                profiler.record(Phase::ToolExecution, elapsed);
+                profiler.record_file(path, matches!(result, Ok(true)), elapsed);
+            }
+            result
+        };
+
+        // Binary, empty and vanished files and missing tools say nothing about whether the file is valid
+        if let Err(e) = &validation_result {
+            let reason = if e.downcast_ref::<BinaryFileError>().is_some() {
+                Some(SkipReason::BinaryFile)
+            } else if e.downcast_ref::<DisabledValidatorError>().is_some() {
+                Some(SkipReason::Disabled)
+            } else if e.downcast_ref::<EmptyFileError>().is_some() {
+                Some(SkipReason::EmptyFile)
+            } else if e.downcast_ref::<MissingToolError>().is_some() {
+                Some(SkipReason::NoValidatorTool)
+            } else if e.downcast_ref::<VanishedFileError>().is_some() {
+                Some(SkipReason::Vanished)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                if verbose {
+                    println!("  {} {} {} - {}",
+                        ERROR_MARK,
+                        "Skipped".yellow(),
+                        path.display().to_string().bright_white(),
+                        e.to_string().bright_black()
+                    );
+                }
+                return file.skipped(reason);
+            }
+        }
+
+        // Under `action = "warn"` a failing generated file passes with a warning.
+        // The cache keeps the validator's own verdict.
+        let downgraded = is_generated && matches!(validation_result, Ok(false));
+        let validation_result = if downgraded {
+            file.issues.push(generated_file_failed(path));
+            Ok(true)
+        } else {
+            validation_result
         };
 
-        let ext = path.extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("unknown")
-            .to_string();
+        // Memory analysis is opt-in and only runs on files that passed validation
+        let leaks = match validation_result {
+            Ok(true) if memory::should_analyze(path, options) => {
+                let started = Instant::now();
+                let leaks = memory::analyze_memory(path, options);
+                if let Some(profiler) = profiler {
+                    profiler.record(Phase::MemoryAnalysis, started.elapsed());
+                }
+                leaks.unwrap_or_else(|e| {
+                    if verbose {
+                        println!("  {} {} {} - {}",
+                            ERROR_MARK,
+                            "Memory analysis skipped".yellow(),
+                            path.display().to_string().bright_white(),
+                            e.to_string().bright_black()
+                        );
+                    }
+                    Vec::new()
+                })
+            }
+            _ => Vec::new(),
+        };
 
+        let cache_indicator = if file.cached { " (cached)".bright_black() } else { "".normal() };
         match validation_result {
-            Ok(true) => {
-                valid_files.lock().unwrap().push(path.clone());
-                
-                let mut type_results = results_by_type.lock().unwrap();
-                let type_result = type_results.entry(ext).or_default();
-                type_result.total += 1;
-                type_result.valid += 1;
-                
-                if !cached {
-                    cache.cache_result(path, true);
+            Ok(true) if leaks.is_empty() => {
+                if !file.cached {
+                    self.cache.cache_result(path, !downgraded, &file_errors, fingerprint.as_deref(), &config_hash);
                 }
 
-                if options.verbose {
-                    let cache_indicator = if cached { " (cached)".bright_black() } else { "".normal() };
+                if verbose {
                     println!("  {} {} {}{}", 
                         FILE_MARK,
                         "Valid".green(),
@@ -211,20 +1020,16 @@ pub fn scan_directory(
                     );
                 }
             }
-            Ok(false) => {
-                invalid_files.lock().unwrap().push(path.clone());
-                
-                let mut type_results = results_by_type.lock().unwrap();
-                let type_result = type_results.entry(ext).or_default();
-                type_result.total += 1;
-                type_result.invalid.push(path.clone());
-                
-                if !cached {
-                    cache.cache_result(path, false);
+            Ok(is_valid) => {
+                file.outcome = FileOutcome::Invalid;
+
+                // Cache the validator's verdict only; leaks are re-checked on each analysis run
+                if !file.cached && !crashed {
+                    self.cache.cache_result(path, is_valid, &file_errors, fingerprint.as_deref(), &config_hash);
                 }
+                file.issues.extend(leaks);
 
-                if options.verbose {
-                    let cache_indicator = if cached { " (cached)".bright_black() } else { "".normal() };
+                if verbose {
                     println!("  {} {} {}{}", 
                         ERROR_MARK,
                         "Invalid".red(),
@@ -234,9 +1039,7 @@ pub fn scan_directory(
                 }
             }
             Err(e) => {
-                invalid_files.lock().unwrap().push(path.clone());
-                
-                if options.verbose {
+                if verbose {
                     println!("  {} {} {} - {}", 
                         ERROR_MARK,
                         "Error".red().bold(),
@@ -244,55 +1047,178 @@ pub fn scan_directory(
                         e.to_string().bright_black()
                     );
                 }
+                file.outcome = FileOutcome::Failed(e);
             }
         }
 
-        // Update progress
-        {
-            let p = progress.lock().unwrap();
-            p.inc(1);
-            let invalid_count = invalid_files.lock().unwrap().len();
-            if invalid_count > 0 {
-                p.set_message(format!("({} {})", 
-                    invalid_count.to_string().red(),
-                    if invalid_count == 1 { "issue found" } else { "issues found" }
-                ));
-            }
-        }
-    });
+        file
+    }
+}
 
-    progress.lock().unwrap().finish();
-    
-    // Save cache to disk
-    cache.save();
-    
-    let elapsed = start_time.elapsed();
-    let cache_hit_count = *cache_hits.lock().unwrap();
-    
-    // Construct final result
-    let valid_files_vec = Arc::try_unwrap(valid_files).unwrap().into_inner().unwrap();
-    let invalid_files_vec = Arc::try_unwrap(invalid_files).unwrap().into_inner().unwrap();
-    let skipped_files_vec = Arc::try_unwrap(skipped_files).unwrap().into_inner().unwrap();
-    let results_by_type_map = Arc::try_unwrap(results_by_type).unwrap().into_inner().unwrap();
+/// A scan that validates one file each time it is advanced, see
+/// `scan_directory_streaming`. Verdicts go into the cache as they come and
+/// the cache is saved when the stream is dropped.
+pub struct ScanStream<'a> {
+    scanner: FileScanner<'a>,
+    entries: Box<dyn Iterator<Item = walkdir::DirEntry> + Send + 'a>,
+}
+
+impl ScanStream<'_> {
+    /// Scan the files the stream hasn't reached yet on the thread pool,
+    /// handing each to `f` as it finishes
+    pub fn for_each_parallel<F>(mut self, f: F)
+    where
+        F: Fn(ScannedFile) + Sync + Send,
+    {
+        let scanner = &self.scanner;
+        (&mut self.entries).par_bridge().for_each(|entry| f(scanner.scan(entry)));
+    }
+}
+
+impl Iterator for ScanStream<'_> {
+    type Item = ScannedFile;
+
+    fn next(&mut self) -> Option<ScannedFile> {
+        let entry = self.entries.next()?;
+        Some(self.scanner.scan(entry))
+    }
+}
+
+impl Drop for ScanStream<'_> {
+    fn drop(&mut self) {
+        self.scanner.cache.save();
+    }
+}
+
+/// The entries a scan looks at: files, and the symlinks it won't follow
+fn scan_entries<'a>(
+    dir_path: &Path,
+    options: &'a ValidationOptions,
+    exclude_patterns: &'a [String],
+) -> impl Iterator<Item = walkdir::DirEntry> + Send + 'a {
+    walk(dir_path, options, exclude_patterns)
+        .filter(move |entry| is_unfollowed_symlink(entry, options) || is_scanned_file(entry, options))
+}
+
+/// Scan the files below `dir_path` lazily. Each file goes through the same
+/// steps as in `scan_directory` (language filter, size limit, generated
+/// files, the cache, validation), but only once the stream reaches it:
+/// nothing is validated ahead of the consumer and no `ScanResult` is built,
+/// so a huge tree can be processed without holding its results in memory.
+/// Collecting the stream gives the `ScanResult` a scan would.
+pub fn scan_directory_streaming<'a>(
+    dir_path: &Path,
+    options: &'a ValidationOptions,
+    exclude_patterns: &'a [String],
+    filter: &'a LanguageFilter,
+) -> Result<ScanStream<'a>> {
+    Ok(ScanStream {
+        scanner: FileScanner::new(dir_path, options, filter, true)?,
+        entries: Box::new(scan_entries(dir_path, options, exclude_patterns)),
+    })
+}
+
+pub fn scan_directory(
+    dir_path: &Path,
+    options: &ValidationOptions,
+    exclude_patterns: &[String],
+    filter: &LanguageFilter,
+) -> Result<ScanResult> {
+    scan_directory_with_events(dir_path, options, exclude_patterns, filter, None)
+}
+
+/// Scan a directory, sending a `FileScanRecord` through `events` as each file
+/// finishes. The files go through the stream `scan_directory_streaming`
+/// yields, validated in parallel. When streaming, progress output is
+/// suppressed so stdout stays free for the consumer.
+pub fn scan_directory_with_events(
+    dir_path: &Path,
+    options: &ValidationOptions,
+    exclude_patterns: &[String],
+    filter: &LanguageFilter,
+    events: Option<Sender<FileScanRecord>>,
+) -> Result<ScanResult> {
+    let start_time = Instant::now();
+    let quiet = events.is_some();
     
-    println!("\n{} Scan completed in {:.2}s ({} cache hits)", 
-        "✓".green(),
-        elapsed.as_secs_f64(),
-        cache_hit_count.to_string().bright_blue()
+    if !quiet {
+        println!("\n{} {} {}", 
+            SCAN_MARK,
+            "Starting parallel scan of".bright_blue(),
+            dir_path.display().to_string().bright_white().underline()
+        );
+    }
+
+    let scanner = FileScanner::new(dir_path, options, filter, quiet)?;
+
+    // Walk the whole tree first, so progress can be shown against the total
+    let walk_started = Instant::now();
+    let entries: Vec<_> = scan_entries(dir_path, options, exclude_patterns).collect();
+    if let Some(profiler) = options.profiler.as_deref() {
+        profiler.record(Phase::FileWalk, walk_started.elapsed());
+    }
+
+    let file_count = entries.iter().filter(|entry| !is_unfollowed_symlink(entry, options)).count();
+    if !quiet {
+        println!("  Found {} files to validate", file_count.to_string().bright_white());
+    }
+
+    let progress = if quiet || file_count == 0 {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new(entries.len() as u64)
+    };
+    progress.set_style(
+        ProgressStyle::default_bar()
+            .template(&format!("{} {}",
+                "[{elapsed_precise}]".bright_black(),
+                " {bar:40.cyan/blue} {pos:>7}/{len:7} {msg}"))?
+            .progress_chars(crate::glyphs::progress_chars())
     );
+
+    let stream = ScanStream { scanner, entries: Box::new(entries.into_iter()) };
+    let result = Mutex::new(ScanResult::default());
+    stream.for_each_parallel(|file| {
+        if let (Some(sender), Some(record)) = (&events, file.record()) {
+            // The receiver going away just means nobody is listening any more
+            let _ = sender.send(record);
+        }
+
+        let mut result = result.lock().unwrap();
+        result.add(file);
+        progress.inc(1);
+        let invalid_count = result.invalid_files.len();
+        if invalid_count > 0 {
+            progress.set_message(format!("({} {})", 
+                invalid_count.to_string().red(),
+                if invalid_count == 1 { "issue found" } else { "issues found" }
+            ));
+        }
+    });
+    progress.finish();
+
+    let mut result = result.into_inner().unwrap();
+    result.sort();
+
+    if !quiet && file_count > 0 {
+        let elapsed = start_time.elapsed();
+        println!("\n{} Scan completed in {:.2}s", crate::glyphs::CHECK.as_str().green(), elapsed.as_secs_f64());
+        if options.incremental {
+            let revalidated = result.valid_files + result.invalid_files.len() - result.cached_files;
+            println!("  {} cached, {} re-validated",
+                result.cached_files.to_string().bright_blue(),
+                revalidated.to_string().bright_white()
+            );
+        }
+    }
     
-    Ok(ScanResult {
-        total_files,
-        valid_files: valid_files_vec.len(),
-        invalid_files: invalid_files_vec,
-        skipped_files: skipped_files_vec,
-        results_by_type: results_by_type_map,
-    })
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::validators::{config_fingerprint, ValidationResult};
     use std::fs::{self, File};
     use std::io::Write;
     use tempfile::TempDir;
@@ -323,12 +1249,605 @@ mod tests {
             verbose: false,
             timeout: 30,
             config: None,
+            analyze_memory: false,
+            max_file_size: None,
+            allow_binary: false,
+            modified_since: None,
+            max_depth: None,
+            profiler: None,
+            incremental: false,
+            follow_symlinks: false,
+            offline: false,
+            registry: Default::default(),
         };
         
-        let result = scan_directory(temp_dir.path(), &options, &[]).unwrap();
+        let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
         
         assert_eq!(result.total_files, 4);
         assert!(result.valid_files > 0);
         assert!(!result.invalid_files.is_empty());
     }
+
+    fn skipped_for(result: &ScanResult, reason: SkipReason) -> Vec<PathBuf> {
+        result.skipped_files.iter()
+            .filter(|(_, r)| *r == reason)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    fn create_mixed_dir() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("app.py"), "print('Hello')\n").unwrap();
+        fs::write(temp_dir.path().join("lib.rs"), "pub fn hello() {}\n").unwrap();
+        fs::write(temp_dir.path().join("ci.yaml"), "steps: []\n").unwrap();
+        fs::write(temp_dir.path().join("data.json"), "{}\n").unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_scan_only_languages() {
+        let temp_dir = create_mixed_dir();
+        let filter = LanguageFilter::from_names(&["rust".to_string(), "python".to_string()], &[]).unwrap();
+
+        let result = scan_directory(temp_dir.path(), &ValidationOptions::default(), &[], &filter).unwrap();
+
+        assert_eq!(result.total_files, 4);
+        let ignored: Vec<&PathBuf> = result.skipped_files.iter()
+            .filter(|(_, reason)| *reason == SkipReason::Ignored)
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(ignored.len(), 2);
+        assert!(ignored.iter().all(|p| {
+            matches!(p.extension().and_then(|e| e.to_str()), Some("yaml") | Some("json"))
+        }));
+    }
+
+    #[test]
+    fn test_scan_skip_languages() {
+        let temp_dir = create_mixed_dir();
+        let filter = LanguageFilter::from_names(&[], &["yaml".to_string()]).unwrap();
+
+        let result = scan_directory(temp_dir.path(), &ValidationOptions::default(), &[], &filter).unwrap();
+
+        assert!(result.skipped_files.contains(&(temp_dir.path().join("ci.yaml"), SkipReason::Ignored)));
+        assert_eq!(skipped_for(&result, SkipReason::Ignored).len(), 1);
+        assert!(!result.results_by_type.contains_key("yaml"));
+    }
+
+    #[test]
+    fn test_ndjson_lines_parse_independently() {
+        let temp_dir = create_mixed_dir();
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let result = scan_directory_with_events(
+            temp_dir.path(),
+            &ValidationOptions::default(),
+            &[],
+            &LanguageFilter::default(),
+            Some(sender),
+        ).unwrap();
+
+        let mut output = Vec::new();
+        for record in receiver {
+            write_ndjson(&mut output, &record).unwrap();
+        }
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), result.total_files - result.skipped_files.len());
+        for line in lines {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(value["path"].is_string());
+            assert!(value["file_type"].is_string());
+            assert!(value["success"].is_boolean());
+            assert!(value["issue_count"].is_u64());
+        }
+    }
+
+    #[test]
+    fn test_ndjson_issue_count_includes_validation_errors() {
+        let temp_dir = create_mixed_dir();
+        fs::write(temp_dir.path().join("broken.py"), "def broken(:\n    pass\n").unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        scan_directory_with_events(
+            temp_dir.path(),
+            &ValidationOptions::default(),
+            &[],
+            &LanguageFilter::default(),
+            Some(sender),
+        ).unwrap();
+
+        let records: Vec<FileScanRecord> = receiver.iter().collect();
+        let broken = records.iter().find(|record| record.path.ends_with("broken.py")).unwrap();
+        assert!(!broken.success);
+        assert!(broken.issue_count > 0);
+        let clean = records.iter().find(|record| record.path.ends_with("app.py")).unwrap();
+        assert_eq!(clean.issue_count, 0);
+    }
+
+    #[test]
+    fn test_oversized_file_is_skipped() {
+        let temp_dir = create_mixed_dir();
+        let big = temp_dir.path().join("bundle.min.js");
+        fs::write(&big, "var a=1;".repeat(512)).unwrap();
+
+        let options = ValidationOptions {
+            max_file_size: Some(parse_size("1KB").unwrap()),
+            ..Default::default()
+        };
+        let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
+
+        assert_eq!(result.total_files, 5);
+        assert_eq!(skipped_for(&result, SkipReason::TooLarge), vec![big.clone()]);
+        assert!(!result.results_by_type.contains_key("js"));
+        assert!(!result.invalid_files.contains(&big));
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].error_type, ErrorType::FileTooLarge);
+    }
+
+    #[test]
+    fn test_generated_file_is_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let generated = temp_dir.path().join("schema.py");
+        fs::write(&generated, "# @generated by protoc\nSCHEMA = {\n").unwrap();
+
+        let result = scan_directory(temp_dir.path(), &ValidationOptions::default(), &[], &LanguageFilter::default()).unwrap();
+
+        assert_eq!(result.skipped_files, vec![(generated.clone(), SkipReason::Generated)]);
+        assert!(!result.invalid_files.contains(&generated));
+        assert!(result.issues.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_cycle_terminates() {
+        let temp_dir = TempDir::new().unwrap();
+        let inner = temp_dir.path().join("a/b");
+        fs::create_dir_all(&inner).unwrap();
+        fs::write(inner.join("notes.xyz"), "just some notes\n").unwrap();
+        // a/b/loop leads back to a, and alias is a second way into it
+        let cycle = inner.join("loop");
+        std::os::unix::fs::symlink(temp_dir.path().join("a"), &cycle).unwrap();
+        let alias = temp_dir.path().join("alias");
+        std::os::unix::fs::symlink(temp_dir.path().join("a"), &alias).unwrap();
+
+        let result = scan_directory(temp_dir.path(), &ValidationOptions::default(), &[], &LanguageFilter::default()).unwrap();
+        assert_eq!(result.total_files, 3);
+        assert!(result.skipped_files.contains(&(cycle, SkipReason::Symlink)));
+        assert!(result.skipped_files.contains(&(alias, SkipReason::Symlink)));
+
+        // Followed, the directory behind both links is still scanned only once
+        let options = ValidationOptions { follow_symlinks: true, ..Default::default() };
+        let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
+        assert_eq!(result.total_files, 1);
+        assert_eq!(result.skipped_files.len(), 1);
+        assert_eq!(result.skipped_files[0].1, SkipReason::UnknownType);
+    }
+
+    #[test]
+    fn test_skip_reasons_are_recorded() {
+        let temp_dir = TempDir::new().unwrap();
+        let ignored = temp_dir.path().join("app.py");
+        fs::write(&ignored, "print('Hello')\n").unwrap();
+        let too_large = temp_dir.path().join("bundle.min.js");
+        fs::write(&too_large, "var a=1;".repeat(512)).unwrap();
+        let unknown = temp_dir.path().join("notes.xyz");
+        fs::write(&unknown, "just some notes\n").unwrap();
+        let no_tool = temp_dir.path().join("data.json");
+        fs::write(&no_tool, "{}\n").unwrap();
+
+        let mut config = crate::validators::FileValidationConfig::default();
+        config.tools.insert("jq".to_string(), "/nonexistent/synx-test/jq".to_string());
+        let options = ValidationOptions {
+            config: Some(config),
+            max_file_size: Some(parse_size("1KB").unwrap()),
+            ..Default::default()
+        };
+        let filter = LanguageFilter::from_names(&[], &["python".to_string()]).unwrap();
+        let result = scan_directory(temp_dir.path(), &options, &[], &filter).unwrap();
+
+        assert_eq!(result.total_files, 4);
+        assert_eq!(skipped_for(&result, SkipReason::Ignored), vec![ignored]);
+        assert_eq!(skipped_for(&result, SkipReason::TooLarge), vec![too_large]);
+        assert_eq!(skipped_for(&result, SkipReason::UnknownType), vec![unknown]);
+        assert_eq!(skipped_for(&result, SkipReason::NoValidatorTool), vec![no_tool]);
+        assert!(result.invalid_files.is_empty());
+    }
+
+    #[test]
+    fn test_binary_file_is_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let binary = temp_dir.path().join("logo.py");
+        fs::write(&binary, b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00").unwrap();
+
+        let result = scan_directory(temp_dir.path(), &ValidationOptions::default(), &[], &LanguageFilter::default()).unwrap();
+        assert_eq!(result.skipped_files, vec![(binary.clone(), SkipReason::BinaryFile)]);
+        assert!(result.invalid_files.is_empty());
+
+        // With the override the file goes to the validator like any other
+        let options = ValidationOptions {
+            allow_binary: true,
+            modified_since: None,
+            max_depth: None,
+            profiler: None,
+            incremental: false,
+            ..Default::default()
+        };
+        let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
+        assert!(skipped_for(&result, SkipReason::BinaryFile).is_empty());
+    }
+
+    #[test]
+    fn test_paths_relative_to_scan_root() {
+        let temp_dir = create_mixed_dir();
+        fs::create_dir_all(temp_dir.path().join("web")).unwrap();
+        let big = temp_dir.path().join("web/bundle.min.js");
+        fs::write(&big, "var a=1;".repeat(512)).unwrap();
+
+        let options = ValidationOptions {
+            max_file_size: Some(parse_size("1KB").unwrap()),
+            ..Default::default()
+        };
+        let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default())
+            .unwrap()
+            .relative_to(temp_dir.path());
+
+        assert!(result.skipped_files.contains(&(PathBuf::from("web/bundle.min.js"), SkipReason::TooLarge)));
+        assert_eq!(result.issues[0].file_path, PathBuf::from("web/bundle.min.js").to_string_lossy());
+        let root = temp_dir.path().to_string_lossy().to_string();
+        assert!(result.skipped_files.iter().all(|(path, _)| path.is_relative()));
+        assert!(result.invalid_files.iter().all(|path| path.is_relative()));
+        assert!(result.issues.iter().all(|issue| !issue.file_path.contains(&root)));
+    }
+
+    #[test]
+    fn test_relative_path_outside_base_is_absolute() {
+        let base = TempDir::new().unwrap();
+        let other = TempDir::new().unwrap();
+        let file = other.path().join("a.py");
+        fs::write(&file, "print('hi')\n").unwrap();
+
+        let shown = relative_path(&file, base.path());
+        assert!(shown.is_absolute());
+        assert!(shown.ends_with("a.py"));
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1024").unwrap(), 1024);
+        assert_eq!(parse_size("5MB").unwrap(), 5 * 1024 * 1024);
+        assert_eq!(parse_size("512k").unwrap(), 512 * 1024);
+        assert_eq!(parse_size("1.5 GiB").unwrap(), 3 * 512 * 1024 * 1024);
+        assert!(parse_size("MB").is_err());
+        assert!(parse_size("5 parsecs").is_err());
+    }
+
+    #[test]
+    fn test_language_filter_rejects_unknown_names() {
+        assert!(LanguageFilter::from_names(&["pyhton".to_string()], &[]).is_err());
+        assert!(LanguageFilter::from_names(&[], &["ymal".to_string()]).is_err());
+        assert!(LanguageFilter::from_names(&["py".to_string()], &["yml".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn test_cache_entry_needs_matching_config_hash() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("app.txt");
+        fs::write(&file, "text\n").unwrap();
+
+        let lenient = config_fingerprint(&file, &ValidationOptions::default());
+        let strict = config_fingerprint(&file, &ValidationOptions { strict: true, ..Default::default() });
+        assert_ne!(lenient, strict);
+        assert_eq!(lenient, config_fingerprint(&file, &ValidationOptions::default()));
+
+        // A longer timeout or a validator registered in code may give another verdict
+        let patient = config_fingerprint(&file, &ValidationOptions { timeout: 600, ..Default::default() });
+        assert_ne!(lenient, patient);
+        let mut registry = crate::validators::ValidatorRegistry::new();
+        registry.register("txt", Box::new(|_: &Path, _: &ValidationOptions| Ok(ValidationResult::default())));
+        let registered = config_fingerprint(&file, &ValidationOptions { registry, ..Default::default() });
+        assert_ne!(lenient, registered);
+
+        let cache = ValidationCache::with_file(temp_dir.path().join("validation_cache.json"));
+        cache.cache_result(&file, true, &[], None, &lenient);
+        assert_eq!(cache.is_valid_cached(&file, None, &lenient), Some(true));
+        assert_eq!(cache.is_valid_cached(&file, None, &strict), None);
+    }
+
+    #[test]
+    fn test_concurrent_cache_writes_stay_valid() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_file = temp_dir.path().join("validation_cache.json");
+
+        let handles: Vec<_> = (0..2)
+            .map(|worker| {
+                let dir = temp_dir.path().to_path_buf();
+                let cache_file = cache_file.clone();
+                std::thread::spawn(move || {
+                    let cache = ValidationCache::with_file(cache_file);
+                    for i in 0..20 {
+                        let path = dir.join(format!("worker{}_{}.txt", worker, i));
+                        fs::write(&path, format!("{} {}", worker, i)).unwrap();
+                        cache.cache_result(&path, true, &[], None, "");
+                        cache.save();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let content = fs::read_to_string(&cache_file).unwrap();
+        let entries: HashMap<PathBuf, CacheEntry> = serde_json::from_str(&content).unwrap();
+        assert_eq!(entries.len(), 40);
+    }
+
+    #[test]
+    fn test_corrupt_cache_is_discarded() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_file = temp_dir.path().join("validation_cache.json");
+        fs::write(&cache_file, "{ not json").unwrap();
+
+        let cache = ValidationCache::with_file(cache_file.clone());
+        assert!(cache.entries.lock().unwrap().is_empty());
+        assert!(!cache_file.exists());
+    }
+
+    #[test]
+    fn test_json_report_is_deterministic() {
+        let temp_dir = create_mixed_dir();
+        for name in ["b.unknownext", "a.unknownext", "c/d.unknownext", "z.py", "m.rs"] {
+            let path = temp_dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "not really code (\n").unwrap();
+        }
+
+        let render = || {
+            let result = scan_directory(temp_dir.path(), &ValidationOptions::default(), &[], &LanguageFilter::default())
+                .unwrap()
+                .relative_to(temp_dir.path());
+            serde_json::to_string_pretty(&result.to_report(true)).unwrap()
+        };
+
+        let first = render();
+        assert_eq!(first, render());
+
+        let report: serde_json::Value = serde_json::from_str(&first).unwrap();
+        let skipped: Vec<&str> = report["skipped_files"].as_array().unwrap().iter()
+            .map(|entry| entry["path"].as_str().unwrap())
+            .collect();
+        let mut sorted = skipped.clone();
+        sorted.sort();
+        assert_eq!(skipped, sorted);
+    }
+
+    #[test]
+    fn test_since_limits_scan_to_recent_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let recent = temp_dir.path().join("recent.unknownext");
+        let stale = temp_dir.path().join("stale.unknownext");
+        fs::write(&recent, "new\n").unwrap();
+        fs::write(&stale, "old\n").unwrap();
+
+        let three_hours_ago = SystemTime::now() - std::time::Duration::from_secs(3 * 60 * 60);
+        File::options().write(true).open(&stale).unwrap().set_modified(three_hours_ago).unwrap();
+
+        let options = ValidationOptions {
+            modified_since: Some(parse_since("1h").unwrap()),
+            ..Default::default()
+        };
+        let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
+
+        assert_eq!(result.total_files, 1);
+        let seen: Vec<&PathBuf> = result.skipped_files.iter().map(|(path, _)| path).collect();
+        assert_eq!(seen, vec![&recent]);
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let an_hour_ago = parse_since("1h").unwrap();
+        let elapsed = SystemTime::now().duration_since(an_hour_ago).unwrap();
+        assert!(elapsed >= std::time::Duration::from_secs(3600));
+        assert!(elapsed < std::time::Duration::from_secs(3660));
+
+        assert!(parse_since("30m").is_ok());
+        assert!(parse_since("1d").is_ok());
+        assert!(parse_since("soon").is_err());
+    }
+
+    #[test]
+    fn test_max_depth_limits_recursion() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["top.unknownext", "a/one.unknownext", "a/b/two.unknownext", "a/b/c/three.unknownext"] {
+            let path = temp_dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "x\n").unwrap();
+        }
+
+        let scan = |max_depth| {
+            let options = ValidationOptions { max_depth, ..Default::default() };
+            scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap()
+        };
+
+        let top_level = scan(Some(1));
+        assert_eq!(top_level.total_files, 1);
+        assert_eq!(top_level.skipped_files[0].0, temp_dir.path().join("top.unknownext"));
+
+        assert_eq!(scan(Some(2)).total_files, 2);
+        assert_eq!(scan(None).total_files, 4);
+
+        // Excludes still apply within the depth limit
+        let options = ValidationOptions { max_depth: Some(2), ..Default::default() };
+        let excluded = scan_directory(temp_dir.path(), &options, &["*/one.unknownext".to_string()], &LanguageFilter::default()).unwrap();
+        assert_eq!(excluded.total_files, 1);
+    }
+
+    #[test]
+    fn test_merge_combines_scans() {
+        let first = TempDir::new().unwrap();
+        let second = TempDir::new().unwrap();
+        fs::write(first.path().join("one.unknownext"), "1\n").unwrap();
+        fs::write(second.path().join("two.unknownext"), "2\n").unwrap();
+        fs::write(second.path().join("three.unknownext"), "3\n").unwrap();
+
+        let options = ValidationOptions::default();
+        let mut combined = ScanResult::default();
+        for dir in [first.path(), second.path()] {
+            combined.merge(scan_directory(dir, &options, &[], &LanguageFilter::default()).unwrap());
+        }
+
+        assert_eq!(combined.total_files, 3);
+        let skipped: Vec<&PathBuf> = combined.skipped_files.iter().map(|(path, _)| path).collect();
+        assert!(skipped.contains(&&first.path().join("one.unknownext")));
+        assert!(skipped.contains(&&second.path().join("two.unknownext")));
+        assert!(skipped.contains(&&second.path().join("three.unknownext")));
+
+        // Merging the same scan again doesn't repeat its files
+        combined.merge(scan_directory(first.path(), &options, &[], &LanguageFilter::default()).unwrap());
+        assert_eq!(combined.skipped_files.len(), 3);
+        assert_eq!(combined.total_files, 3);
+    }
+
+    #[test]
+    fn test_merge_counts_overlapping_scans_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let src = temp_dir.path().join("src");
+        fs::create_dir_all(src.join("lib")).unwrap();
+        fs::write(src.join("main.txt"), "ok\n").unwrap();
+        fs::write(src.join("lib/one.txt"), "ok\n").unwrap();
+        fs::write(src.join("lib/two.txt"), "bad\n").unwrap();
+        fs::write(src.join("lib/notes.unknownext"), "?\n").unwrap();
+
+        let mut registry = crate::validators::ValidatorRegistry::new();
+        registry.register("txt", Box::new(|path: &Path, _: &ValidationOptions| {
+            let mut result = ValidationResult::passed();
+            result.success = fs::read_to_string(path)? == "ok\n";
+            Ok(result)
+        }));
+        let options = ValidationOptions { registry, ..Default::default() };
+
+        let mut combined = ScanResult::default();
+        for dir in [src.clone(), src.join("lib")] {
+            combined.merge(scan_directory(&dir, &options, &[], &LanguageFilter::default()).unwrap());
+        }
+
+        assert_eq!(combined.total_files, 4);
+        assert_eq!(combined.valid_files, 2);
+        assert_eq!(combined.invalid_files, [src.join("lib/two.txt")]);
+        assert_eq!(combined.skipped_files.len(), 1);
+        let txt = &combined.results_by_type["txt"];
+        assert_eq!((txt.total, txt.valid, txt.invalid.len()), (3, 2, 1));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_tool_upgrade_invalidates_cached_results() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        fs::create_dir(&project).unwrap();
+        fs::write(project.join("data.json"), "{}\n").unwrap();
+
+        // Two installs of the same tool, told apart only by their version
+        let stub = |version: &str| {
+            let path = temp_dir.path().join(format!("jq-{}", version));
+            fs::write(&path, format!(
+                "#!/bin/sh\nif [ \"$1\" = --version ]; then echo 'jq-{}'; fi\nexit 0\n",
+                version,
+            )).unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+            path
+        };
+        let (old_jq, new_jq) = (stub("1.6"), stub("1.7"));
+
+        let scan = |jq: &Path| {
+            let mut config = crate::validators::FileValidationConfig::default();
+            config.tools.insert("jq".to_string(), jq.to_string_lossy().to_string());
+            let options = ValidationOptions {
+                config: Some(config),
+                incremental: true,
+                ..Default::default()
+            };
+            scan_directory(&project, &options, &[], &LanguageFilter::default()).unwrap()
+        };
+
+        assert_eq!(scan(&old_jq).cached_files, 0);
+        assert_eq!(scan(&old_jq).cached_files, 1);
+
+        // The upgrade re-validates the unchanged file once, then caches again
+        let upgraded = scan(&new_jq);
+        assert_eq!(upgraded.cached_files, 0);
+        assert_eq!(upgraded.valid_files, 1);
+        assert_eq!(scan(&new_jq).cached_files, 1);
+    }
+
+    #[test]
+    fn test_incremental_scan_revalidates_changed_files() {
+        if which::which("python3").is_err() {
+            eprintln!("Skipping test_incremental_scan_revalidates_changed_files: python3 not found");
+            return;
+        }
+        let temp_dir = TempDir::new().unwrap();
+        let unchanged = temp_dir.path().join("unchanged.py");
+        let changed = temp_dir.path().join("changed.py");
+        fs::write(&unchanged, "print('same')\n").unwrap();
+        fs::write(&changed, "print('before')\n").unwrap();
+
+        let scan = || {
+            let profiler = Arc::new(crate::performance::Profiler::new());
+            let options = ValidationOptions {
+                incremental: true,
+                profiler: Some(profiler.clone()),
+                ..Default::default()
+            };
+            let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
+            let validated: Vec<PathBuf> = profiler.report(usize::MAX).slowest_files.into_iter()
+                .map(|(path, _)| path)
+                .collect();
+            (result, validated)
+        };
+
+        let (first, validated) = scan();
+        assert_eq!(first.cached_files, 0);
+        assert_eq!(validated.len(), 2);
+
+        fs::write(&changed, "print('after')\n").unwrap();
+        let (second, validated) = scan();
+        assert_eq!(second.valid_files, 2);
+        assert_eq!(second.cached_files, 1);
+        assert_eq!(validated, vec![changed.clone()]);
+
+        // Deleted files don't linger in the cache
+        fs::remove_file(&unchanged).unwrap();
+        let (third, _) = scan();
+        assert_eq!(third.total_files, 1);
+        let entries = ValidationCache::new().entries.lock().unwrap().clone();
+        assert!(!entries.contains_key(&unchanged));
+        assert!(entries.contains_key(&changed));
+    }
+
+    #[test]
+    fn test_cached_failure_reports_its_issues() {
+        if which::which("python3").is_err() {
+            eprintln!("Skipping test_cached_failure_reports_its_issues: python3 not found");
+            return;
+        }
+        let temp_dir = TempDir::new().unwrap();
+        let broken = temp_dir.path().join("broken.py");
+        fs::write(&broken, "def broken(:\n    pass\n").unwrap();
+
+        let options = ValidationOptions { incremental: true, ..Default::default() };
+        let first = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
+        assert_eq!(first.invalid_files, vec![broken.clone()]);
+        assert!(!first.issues.is_empty());
+
+        let second = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default()).unwrap();
+        assert_eq!(second.cached_files, 1);
+        assert_eq!(second.invalid_files, vec![broken.clone()]);
+        assert_eq!(second.issues.len(), first.issues.len());
+        assert_eq!(second.issues[0].message, first.issues[0].message);
+    }
 }
@@ -0,0 +1,408 @@
+//! Validation for single-file components (Vue and Svelte)
+//!
+//! When `vue-tsc` or `svelte-check` is installed the whole component is
+//! handed to it. Otherwise the component is split into blocks: `<script>` is
+//! checked as JavaScript/TypeScript, `<style>` as CSS and the markup for tag
+//! balance. Errors from each block are mapped back to lines in the component.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use anyhow::{Result, Context};
+use regex::Regex;
+
+use super::{
+    ValidationOptions, ValidationResult, ValidationError, ErrorType, display_validation_errors,
+    tool_command, run_with_timeout, validator_timeout, output_limit,
+};
+
+/// Which kind of component is being validated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentKind {
+    Vue,
+    Svelte,
+}
+
+/// A `<script>`, `<style>` or `<template>` block extracted from a component
+#[derive(Debug, Clone)]
+struct Block {
+    lang: Option<String>,
+    content: String,
+    /// Lines to add to a line number within `content` to get the component line
+    line_offset: usize,
+}
+
+/// Elements that never have a closing tag
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input",
+    "link", "meta", "param", "source", "track", "wbr",
+];
+
+pub fn validate_vue(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    validate_component(file_path, ComponentKind::Vue, options)
+}
+
+pub fn validate_svelte(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    validate_component(file_path, ComponentKind::Svelte, options)
+}
+
+fn validate_component(file_path: &Path, kind: ComponentKind, options: &ValidationOptions) -> Result<ValidationResult> {
+    if let Some(result) = run_component_tool(file_path, kind, options)? {
+        return Ok(result);
+    }
+
+    let errors = check_component(file_path, kind)?;
+    if !errors.is_empty() && options.verbose {
+        let _ = display_validation_errors(&errors);
+    }
+
+    Ok(ValidationResult { success: errors.is_empty(), errors, suppressed: 0, hidden_issues: 0 })
+}
+
+/// Run `vue-tsc`/`svelte-check` if installed. Returns `None` when the tool is missing.
+fn run_component_tool(file_path: &Path, kind: ComponentKind, options: &ValidationOptions) -> Result<Option<ValidationResult>> {
+    let (tool, language) = match kind {
+        ComponentKind::Vue => ("vue-tsc", "vue"),
+        ComponentKind::Svelte => ("svelte-check", "svelte"),
+    };
+    if which::which(tool).is_err() {
+        return Ok(None);
+    }
+
+    let mut cmd = tool_command(tool, file_path, options);
+    match kind {
+        ComponentKind::Vue => {
+            cmd.arg("--noEmit").arg(file_path);
+        }
+        ComponentKind::Svelte => {
+            let workspace = file_path.parent().unwrap_or(Path::new("."));
+            cmd.arg("--workspace").arg(workspace).arg("--output").arg("machine");
+        }
+    }
+
+    let output = run_with_timeout(&mut cmd, validator_timeout(language, options), output_limit(options))?;
+    let success = output.status.success();
+
+    if !success && options.verbose {
+        eprintln!("{} validation errors:", tool);
+        if !output.stdout.is_empty() {
+            eprintln!("{}", String::from_utf8_lossy(&output.stdout));
+        }
+        if !output.stderr.is_empty() {
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+    }
+
+    Ok(Some(ValidationResult::from_output(file_path, language, success, &output)))
+}
+
+/// Check a component block by block, returning errors with component line numbers
+pub fn check_component(file_path: &Path, kind: ComponentKind) -> Result<Vec<ValidationError>> {
+    let content = fs::read_to_string(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+    let mut errors = Vec::new();
+
+    for block in extract_blocks(&content, "script") {
+        errors.extend(check_script(file_path, &block)?);
+    }
+
+    for block in extract_blocks(&content, "style") {
+        errors.extend(check_style(file_path, &block));
+    }
+
+    let markup = match kind {
+        // Vue markup lives in the outermost <template>
+        ComponentKind::Vue => extract_outer_template(&content),
+        // Svelte markup is everything outside <script> and <style>
+        ComponentKind::Svelte => Some(Block {
+            lang: None,
+            content: blank_blocks(&content, &["script", "style"]),
+            line_offset: 0,
+        }),
+    };
+    if let Some(block) = markup {
+        errors.extend(check_markup(file_path, &block));
+    }
+
+    Ok(errors)
+}
+
+fn extract_blocks(content: &str, tag: &str) -> Vec<Block> {
+    let pattern = format!(r"(?s)<{tag}(\s[^>]*)?>(.*?)</{tag}>", tag = tag);
+    let re = Regex::new(&pattern).expect("valid block regex");
+    let lang_re = Regex::new(r#"lang\s*=\s*["']([^"']+)["']"#).expect("valid lang regex");
+
+    re.captures_iter(content)
+        .filter_map(|cap| {
+            let body = cap.get(2)?;
+            let lang = cap.get(1)
+                .and_then(|attrs| lang_re.captures(attrs.as_str()))
+                .map(|l| l[1].to_lowercase());
+            Some(Block {
+                lang,
+                content: body.as_str().to_string(),
+                line_offset: line_at(content, body.start()) - 1,
+            })
+        })
+        .collect()
+}
+
+fn extract_outer_template(content: &str) -> Option<Block> {
+    let open = Regex::new(r"<template(\s[^>]*)?>").expect("valid template regex");
+    let start = open.find(content)?.end();
+    let end = content.rfind("</template>")?;
+    if end < start {
+        return None;
+    }
+
+    Some(Block {
+        lang: None,
+        content: content[start..end].to_string(),
+        line_offset: line_at(content, start) - 1,
+    })
+}
+
+// Replace the given blocks with whitespace, keeping newlines so line numbers hold
+fn blank_blocks(content: &str, tags: &[&str]) -> String {
+    let mut result = content.to_string();
+    for tag in tags {
+        let re = Regex::new(&format!(r"(?s)<{tag}(\s[^>]*)?>.*?</{tag}>", tag = tag))
+            .expect("valid block regex");
+        result = re.replace_all(&result, |caps: &regex::Captures| blank(&caps[0])).to_string();
+    }
+    result
+}
+
+fn blank(text: &str) -> String {
+    text.chars().map(|c| if c == '\n' { '\n' } else { ' ' }).collect()
+}
+
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+fn block_error(file_path: &Path, error_type: ErrorType, message: String, line: Option<usize>, column: Option<usize>) -> ValidationError {
+    let severity = error_type.default_severity();
+    ValidationError {
+        file_path: file_path.to_string_lossy().to_string(),
+        error_type,
+        message,
+        line,
+        column,
+        code: None,
+        suggestion: None,
+        severity,
+    }
+}
+
+/// Write the script block to a temporary file and check it with node or tsc
+fn check_script(file_path: &Path, block: &Block) -> Result<Vec<ValidationError>> {
+    let typescript = matches!(block.lang.as_deref(), Some("ts") | Some("typescript"));
+    let (tool, extension) = if typescript { ("tsc", "ts") } else { ("node", "mjs") };
+    if which::which(tool).is_err() {
+        return Ok(Vec::new());
+    }
+
+    let script = crate::tools::SecureTempFile::new(&format!("component.{}", extension), block.content.as_bytes())?;
+    let script_path = script.path();
+
+    let output = if typescript {
+        Command::new(tool).arg("--noEmit").arg(script_path).output()?
+    } else {
+        Command::new(tool).arg("--check").arg(script_path).output()?
+    };
+    if output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    let text = format!("{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let (line, column, message) = if typescript {
+        parse_tsc_output(&text)
+    } else {
+        parse_node_output(&text)
+    };
+
+    Ok(vec![block_error(
+        file_path,
+        ErrorType::SyntaxError,
+        format!("<script>: {}", message),
+        line.map(|l| l + block.line_offset),
+        column,
+    )])
+}
+
+// node --check prints "path:LINE", the source line, a caret line, then "SyntaxError: ..."
+fn parse_node_output(output: &str) -> (Option<usize>, Option<usize>, String) {
+    let line = output.lines()
+        .next()
+        .and_then(|first| first.rsplit(':').next())
+        .and_then(|n| n.trim().parse().ok());
+    let column = output.lines()
+        .find(|l| l.trim_start().starts_with('^'))
+        .and_then(|caret| caret.find('^'))
+        .map(|c| c + 1);
+    let message = output.lines()
+        .find(|l| l.contains("Error:"))
+        .unwrap_or("Invalid script")
+        .trim()
+        .to_string();
+    (line, column, message)
+}
+
+// tsc prints "path(LINE,COL): error TSxxxx: message"
+fn parse_tsc_output(output: &str) -> (Option<usize>, Option<usize>, String) {
+    let re = Regex::new(r"\((\d+),(\d+)\): (error .*)").expect("valid tsc regex");
+    match re.captures(output) {
+        Some(cap) => (cap[1].parse().ok(), cap[2].parse().ok(), cap[3].to_string()),
+        None => (None, None, output.trim().to_string()),
+    }
+}
+
+/// Check that the braces in a style block are balanced
+fn check_style(file_path: &Path, block: &Block) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    let mut open_braces: Vec<usize> = Vec::new();
+
+    for (index, line) in block.content.lines().enumerate() {
+        let line_number = index + 1 + block.line_offset;
+        for c in line.chars() {
+            match c {
+                '{' => open_braces.push(line_number),
+                '}' if open_braces.pop().is_none() => {
+                    errors.push(block_error(
+                        file_path,
+                        ErrorType::SyntaxError,
+                        "<style>: Unexpected '}'".to_string(),
+                        Some(line_number),
+                        None,
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for line in open_braces {
+        errors.push(block_error(
+            file_path,
+            ErrorType::SyntaxError,
+            "<style>: Unclosed '{'".to_string(),
+            Some(line),
+            None,
+        ));
+    }
+
+    errors
+}
+
+/// Check that every element in the markup is closed in the right order
+fn check_markup(file_path: &Path, block: &Block) -> Vec<ValidationError> {
+    let comments = Regex::new(r"(?s)<!--.*?-->").expect("valid comment regex");
+    let markup = comments.replace_all(&block.content, |caps: &regex::Captures| blank(&caps[0]));
+    let tags = Regex::new(r#"<(/?)([A-Za-z][\w\-.:]*)((?:"[^"]*"|'[^']*'|[^'">])*?)(/?)>"#)
+        .expect("valid tag regex");
+
+    let mut errors = Vec::new();
+    let mut stack: Vec<(String, usize)> = Vec::new();
+
+    for cap in tags.captures_iter(&markup) {
+        let name = cap[2].to_string();
+        let line = line_at(&markup, cap.get(0).map_or(0, |m| m.start())) + block.line_offset;
+        let closing = !cap[1].is_empty();
+        let self_closing = !cap[4].is_empty();
+
+        if self_closing || VOID_ELEMENTS.contains(&name.to_lowercase().as_str()) {
+            continue;
+        }
+
+        if !closing {
+            stack.push((name, line));
+            continue;
+        }
+
+        match stack.iter().rposition(|(open, _)| *open == name) {
+            Some(index) => {
+                // Anything opened after the matching tag was never closed
+                for (unclosed, open_line) in stack.drain(index..).skip(1) {
+                    errors.push(block_error(
+                        file_path,
+                        ErrorType::SyntaxError,
+                        format!("<{}> is not closed before </{}>", unclosed, name),
+                        Some(open_line),
+                        None,
+                    ));
+                }
+            }
+            None => {
+                errors.push(block_error(
+                    file_path,
+                    ErrorType::SyntaxError,
+                    format!("Unexpected closing tag </{}>", name),
+                    Some(line),
+                    None,
+                ));
+            }
+        }
+    }
+
+    for (unclosed, line) in stack {
+        errors.push(block_error(
+            file_path,
+            ErrorType::SyntaxError,
+            format!("<{}> is never closed", unclosed),
+            Some(line),
+            None,
+        ));
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture(path: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files").join(path)
+    }
+
+    #[test]
+    fn test_valid_components() {
+        assert!(check_component(&fixture("vue/valid/Counter.vue"), ComponentKind::Vue).unwrap().is_empty());
+        assert!(check_component(&fixture("svelte/valid/Counter.svelte"), ComponentKind::Svelte).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_broken_vue_markup_and_style_lines() {
+        let errors = check_component(&fixture("vue/invalid/Broken.vue"), ComponentKind::Vue).unwrap();
+
+        let markup = errors.iter().find(|e| e.message.contains("<span>")).unwrap();
+        assert_eq!(markup.line, Some(4));
+
+        let style = errors.iter().find(|e| e.message.starts_with("<style>")).unwrap();
+        assert_eq!(style.line, Some(20));
+    }
+
+    #[test]
+    fn test_broken_script_line_is_adjusted() {
+        if which::which("node").is_err() {
+            eprintln!("node not available, skipping");
+            return;
+        }
+
+        let errors = check_component(&fixture("svelte/invalid/Broken.svelte"), ComponentKind::Svelte).unwrap();
+        let script = errors.iter().find(|e| e.message.starts_with("<script>")).unwrap();
+        assert_eq!(script.line, Some(4));
+    }
+
+    #[test]
+    fn test_svelte_unclosed_element() {
+        let errors = check_component(&fixture("svelte/invalid/Broken.svelte"), ComponentKind::Svelte).unwrap();
+        let markup = errors.iter().find(|e| e.message.contains("<p>")).unwrap();
+        assert_eq!(markup.line, Some(9));
+    }
+}
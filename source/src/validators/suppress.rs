@@ -0,0 +1,184 @@
+//! Inline suppression comments
+//!
+//! A false positive can be silenced where it happens, in whatever comment
+//! syntax the language uses:
+//!
+//! - `synx-disable-line [rules]` on the offending line
+//! - `synx-disable-next-line [rules]` on the line above it
+//! - `synx-disable-file [rules]` anywhere in the file
+//!
+//! Rules are issue codes (`E0425`, `F401`) or issue types (`SyntaxError`,
+//! `syntax_error`), separated by commas or spaces. Without rules every issue
+//! on the line (or in the file) is suppressed.
+
+use std::collections::HashMap;
+
+use super::ValidationError;
+
+const DISABLE_LINE: &str = "synx-disable-line";
+const DISABLE_NEXT_LINE: &str = "synx-disable-next-line";
+const DISABLE_FILE: &str = "synx-disable-file";
+
+// A directive only counts inside a comment
+const COMMENT_MARKERS: &[&str] = &["//", "#", "/*", "--", "<!--", ";", "%"];
+
+/// The suppression comments found in one file
+#[derive(Debug, Default)]
+pub struct Suppressions {
+    // Rules per 1-based line; an empty list covers every rule
+    lines: HashMap<usize, Vec<String>>,
+    file: Option<Vec<String>>,
+}
+
+impl Suppressions {
+    /// Collect the directives in a file's content
+    pub fn parse(content: &str) -> Self {
+        let mut suppressions = Suppressions::default();
+        for (index, text) in content.lines().enumerate() {
+            let (directive, rules) = match directive(text) {
+                Some(found) => found,
+                None => continue,
+            };
+            let line = index + 1;
+            match directive {
+                DISABLE_LINE => suppressions.add_line(line, rules),
+                DISABLE_NEXT_LINE => suppressions.add_line(line + 1, rules),
+                _ => match &mut suppressions.file {
+                    Some(existing) => merge_rules(existing, rules),
+                    None => suppressions.file = Some(all_if_empty(rules)),
+                },
+            }
+        }
+        suppressions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.file.is_none()
+    }
+
+    /// Whether a comment covers this issue
+    pub fn suppresses(&self, error: &ValidationError) -> bool {
+        if self.file.as_ref().is_some_and(|rules| matches_rules(rules, error)) {
+            return true;
+        }
+        error.line
+            .and_then(|line| self.lines.get(&line))
+            .map_or(false, |rules| matches_rules(rules, error))
+    }
+
+    fn add_line(&mut self, line: usize, rules: Vec<String>) {
+        match self.lines.get_mut(&line) {
+            Some(existing) => merge_rules(existing, rules),
+            None => {
+                self.lines.insert(line, all_if_empty(rules));
+            }
+        }
+    }
+}
+
+/// Remove the issues a suppression comment covers, returning how many went
+pub fn apply(errors: &mut Vec<ValidationError>, suppressions: &Suppressions) -> usize {
+    let before = errors.len();
+    errors.retain(|error| !suppressions.suppresses(error));
+    before - errors.len()
+}
+
+// An empty rule stands for "everything"
+fn all_if_empty(rules: Vec<String>) -> Vec<String> {
+    if rules.is_empty() { vec![String::new()] } else { rules }
+}
+
+// "Everything" absorbs any specific rules
+fn merge_rules(existing: &mut Vec<String>, rules: Vec<String>) {
+    if rules.is_empty() {
+        existing.clear();
+        existing.push(String::new());
+    } else if !existing.iter().any(String::is_empty) {
+        existing.extend(rules);
+    }
+}
+
+fn matches_rules(rules: &[String], error: &ValidationError) -> bool {
+    let error_type = format!("{:?}", error.error_type);
+    rules.iter().any(|rule| {
+        rule.is_empty()
+            || error.code.as_deref().is_some_and(|code| code.eq_ignore_ascii_case(rule))
+            || error_type.eq_ignore_ascii_case(&rule.replace('_', ""))
+    })
+}
+
+/// The directive on a line and its rules, if the line has one inside a comment
+fn directive(text: &str) -> Option<(&'static str, Vec<String>)> {
+    let (position, directive) = [DISABLE_NEXT_LINE, DISABLE_LINE, DISABLE_FILE].iter()
+        .find_map(|directive| text.find(directive).map(|position| (position, *directive)))?;
+    if !COMMENT_MARKERS.iter().any(|marker| text[..position].contains(marker)) {
+        return None;
+    }
+
+    let rest = &text[position + directive.len()..];
+    // "synx-disable-lines" or similar is not ours
+    if rest.chars().next().is_some_and(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+        return None;
+    }
+    let rest = rest.trim().trim_end_matches("-->").trim_end_matches("*/");
+    let rules = rest.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|rule| !rule.is_empty())
+        .map(str::to_string)
+        .collect();
+    Some((directive, rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators::ErrorType;
+
+    fn error(line: usize, error_type: ErrorType, code: Option<&str>) -> ValidationError {
+        ValidationError {
+            file_path: "sample".to_string(),
+            severity: error_type.default_severity(),
+            error_type,
+            message: "problem".to_string(),
+            line: Some(line),
+            column: None,
+            code: code.map(str::to_string),
+            suggestion: None,
+        }
+    }
+
+    #[test]
+    fn test_line_directives() {
+        let suppressions = Suppressions::parse(concat!(
+            "import os  # synx-disable-line F401\n",
+            "// synx-disable-next-line\n",
+            "let x = y;\n",
+            "let z = w; /* synx-disable-line syntax_error, E0425 */\n",
+        ));
+
+        assert!(suppressions.suppresses(&error(1, ErrorType::Lint, Some("F401"))));
+        assert!(!suppressions.suppresses(&error(1, ErrorType::Lint, Some("E501"))));
+        assert!(suppressions.suppresses(&error(3, ErrorType::CompileError, Some("E0308"))));
+        assert!(suppressions.suppresses(&error(4, ErrorType::SyntaxError, None)));
+        assert!(suppressions.suppresses(&error(4, ErrorType::CompileError, Some("E0425"))));
+        assert!(!suppressions.suppresses(&error(4, ErrorType::TypeError, None)));
+        assert!(!suppressions.suppresses(&error(2, ErrorType::SyntaxError, None)));
+    }
+
+    #[test]
+    fn test_file_directive() {
+        let suppressions = Suppressions::parse("# synx-disable-file lint\nimport os\n");
+        let mut errors = vec![
+            error(2, ErrorType::Lint, Some("F401")),
+            error(2, ErrorType::SyntaxError, None),
+        ];
+        assert_eq!(apply(&mut errors, &suppressions), 1);
+        assert_eq!(errors[0].error_type, ErrorType::SyntaxError);
+    }
+
+    #[test]
+    fn test_directive_outside_comment_is_ignored() {
+        let suppressions = Suppressions::parse("message = \"synx-disable-line\"\n");
+        assert!(suppressions.is_empty());
+        assert!(Suppressions::parse("# synx-disable-lines\n").is_empty());
+    }
+}
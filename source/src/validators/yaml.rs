@@ -0,0 +1,120 @@
+//! Validation for YAML files
+//!
+//! `yamllint` is used when it's installed. Otherwise the file is parsed with
+//! a built-in YAML parser, whose error positions are turned into 1-based
+//! lines and columns so editors can point at the problem.
+
+use std::fs;
+use std::path::Path;
+use anyhow::{Result, Context};
+use yaml_rust::YamlLoader;
+
+use super::{
+    ValidationOptions, ValidationResult, ValidationError, ErrorType, display_validation_errors,
+    tool_command, run_with_timeout, validator_timeout, output_limit,
+};
+
+pub fn validate_yaml(file_path: &Path, options: &ValidationOptions) -> Result<ValidationResult> {
+    let yamllint_configured = matches!(&options.config, Some(c) if c.tools.contains_key("yamllint"));
+    if !yamllint_configured && which::which("yamllint").is_err() {
+        let errors = check_yaml(file_path)?;
+        if !errors.is_empty() && options.verbose {
+            let _ = display_validation_errors(&errors);
+        }
+        return Ok(ValidationResult { success: errors.is_empty(), errors, suppressed: 0, hidden_issues: 0 });
+    }
+
+    let mut cmd = tool_command("yamllint", file_path, options);
+    cmd.arg(file_path);
+
+    let output = run_with_timeout(&mut cmd, validator_timeout("yaml", options), output_limit(options))?;
+    let success = output.status.success();
+
+    if !success && options.verbose {
+        eprintln!("YAML validation errors:");
+        if !output.stderr.is_empty() {
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+    }
+
+    Ok(ValidationResult::from_output(file_path, "yaml", success, &output))
+}
+
+/// Parse a YAML file with the built-in parser, returning its syntax error if any
+pub fn check_yaml(file_path: &Path) -> Result<Vec<ValidationError>> {
+    let content = fs::read_to_string(file_path)
+        .context(format!("Failed to read {}", file_path.display()))?;
+    Ok(parse_errors(&content)
+        .map(|(line, column, message)| vec![syntax_error(file_path, line, column, message)])
+        .unwrap_or_default())
+}
+
+// The parser's error as (line, column, message), both counted from 1
+fn parse_errors(content: &str) -> Option<(usize, usize, String)> {
+    let error = YamlLoader::load_from_str(content).err()?;
+    let marker = error.marker();
+    // The parser counts lines from 1 but columns from 0
+    let line = marker.line().max(1);
+    let column = marker.col() + 1;
+
+    // Tabs trip the parser in many ways; name the real problem
+    let indentation = content.lines().nth(line - 1)
+        .map(|text| &text[..text.len() - text.trim_start().len()])
+        .unwrap_or("");
+    if let Some(tab) = indentation.find('\t') {
+        return Some((line, tab + 1, "Tab character used for indentation; YAML only allows spaces".to_string()));
+    }
+
+    // Display adds " at line N column M", which the issue carries separately
+    let message = error.to_string();
+    let message = message.rsplit_once(" at line ").map_or(message.as_str(), |(info, _)| info);
+    Some((line, column, message.to_string()))
+}
+
+fn syntax_error(file_path: &Path, line: usize, column: usize, message: String) -> ValidationError {
+    ValidationError {
+        file_path: file_path.to_string_lossy().to_string(),
+        error_type: ErrorType::SyntaxError,
+        message,
+        line: Some(line),
+        column: Some(column),
+        code: None,
+        suggestion: None,
+        severity: ErrorType::SyntaxError.default_severity(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture(path: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files/yaml").join(path)
+    }
+
+    #[test]
+    fn test_valid_yaml() {
+        assert!(check_yaml(&fixture("valid/service.yaml")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tab_indentation_is_reported_where_it_is() {
+        let errors = check_yaml(&fixture("invalid/tab_indent.yaml")).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, Some(4));
+        assert_eq!(errors[0].column, Some(1));
+        assert!(errors[0].message.contains("Tab character"), "{}", errors[0].message);
+    }
+
+    #[test]
+    fn test_mapping_error_position() {
+        // The unterminated flow sequence is only noticed at the end of the input
+        let (line, column, message) = parse_errors("name: app\nports: [80, 443\n").unwrap();
+        assert_eq!((line, column), (3, 1));
+        assert!(!message.contains(" at line "), "{}", message);
+
+        let (line, column, _) = parse_errors("name: app\n  version: 2\n").unwrap();
+        assert_eq!((line, column), (2, 10));
+    }
+}
@@ -0,0 +1,193 @@
+//! Watch mode for the files given on the command line
+//!
+//! Files are re-validated whenever they change, optionally running a user
+//! command afterwards (e.g. to kick off a build once the file is valid).
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use anyhow::{Context, Result};
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::config::Config;
+use crate::validators::{self, FileValidationConfig, ValidationOptions};
+
+/// Shell commands to run after a file is re-validated. `{file}` in a command
+/// is replaced with the path of the file that changed.
+#[derive(Debug, Clone, Default)]
+pub struct WatchHooks {
+    pub on_success: Option<String>,
+    pub on_failure: Option<String>,
+}
+
+impl WatchHooks {
+    /// Run the hook for a validation outcome, returning its exit status if one is configured
+    pub fn run(&self, file: &Path, success: bool) -> Result<Option<ExitStatus>> {
+        let command = if success { &self.on_success } else { &self.on_failure };
+        match command {
+            Some(command) => run_hook(command, file).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Run a hook command through the shell with `{file}` substituted
+pub fn run_hook(command: &str, file: &Path) -> Result<ExitStatus> {
+    let command = command.replace("{file}", &file.to_string_lossy());
+
+    #[cfg(unix)]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(&command);
+        cmd
+    };
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(&command);
+        cmd
+    };
+
+    cmd.status().context(format!("Failed to run `{}`", command))
+}
+
+/// Validate the files, then re-validate each one as it changes until `stop` is set.
+/// With `config.skip_initial_scan` nothing is validated until a file changes.
+///
+/// Changes within `config.watch_interval` seconds of the last run for a file
+/// are ignored, as are changes made while a hook is running or shortly after,
+/// so a hook that touches the watched files doesn't trigger itself forever.
+pub fn watch(files: &[String], config: &Config, hooks: &WatchHooks, stop: &AtomicBool) -> Result<()> {
+    if !config.skip_initial_scan {
+        let started = Instant::now();
+        crate::run(files, config)?;
+        if config.verbose {
+            println!("{} Initial scan took {:.2}s", crate::glyphs::STATS, started.elapsed().as_secs_f64());
+        }
+    }
+
+    let options = ValidationOptions {
+        strict: config.strict,
+        verbose: config.verbose,
+        timeout: config.timeout,
+        config: Some(FileValidationConfig::from_config(config)),
+        analyze_memory: false,
+        max_file_size: None,
+        allow_binary: config.allow_binary,
+        modified_since: None,
+        max_depth: None,
+        profiler: None,
+        incremental: false,
+        follow_symlinks: false,
+        offline: config.offline,
+        registry: Default::default(),
+    };
+    let debounce = Duration::from_secs(config.watch_interval);
+
+    // Watch the parent directories, since editors often save by replacing the file
+    let watched: HashSet<PathBuf> = files.iter()
+        .filter_map(|file| Path::new(file).canonicalize().ok())
+        .collect();
+    let directories: HashSet<PathBuf> = watched.iter()
+        .filter_map(|file| file.parent().map(Path::to_path_buf))
+        .collect();
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let _ = sender.send(event);
+    })?;
+    for directory in &directories {
+        watcher.watch(directory, RecursiveMode::NonRecursive)?;
+    }
+
+    println!("{} Watching {} file(s) for changes...", crate::glyphs::WATCH, watched.len());
+
+    let mut last_run: HashMap<PathBuf, Instant> = HashMap::new();
+    let mut quiet_until = Instant::now();
+
+    while !stop.load(Ordering::SeqCst) {
+        let event = match receiver.recv_timeout(Duration::from_millis(100)) {
+            Ok(Ok(event)) => event,
+            Ok(Err(e)) => {
+                eprintln!("{} Watch error: {}", crate::glyphs::WARN, e);
+                continue;
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => continue,
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        };
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+            continue;
+        }
+
+        for path in event.paths {
+            let now = Instant::now();
+            if !watched.contains(&path) || now < quiet_until {
+                continue;
+            }
+            if matches!(last_run.get(&path), Some(last) if now.duration_since(*last) < debounce) {
+                continue;
+            }
+            last_run.insert(path.clone(), now);
+
+            let success = match validators::validate_file(&path, &options) {
+                Ok(true) => {
+                    println!("{} {}: Validation passed", crate::glyphs::OK, path.display());
+                    true
+                }
+                Ok(false) => {
+                    println!("{} {}: Validation failed", crate::glyphs::FAIL, path.display());
+                    false
+                }
+                Err(e) => {
+                    eprintln!("{} {}: Error during validation: {}", crate::glyphs::FAIL, path.display(), e);
+                    false
+                }
+            };
+
+            match hooks.run(&path, success) {
+                Ok(Some(status)) if status.success() => println!("  {} Command finished ({})", crate::glyphs::RESULT, status),
+                Ok(Some(status)) => eprintln!("  {} Command failed ({})", crate::glyphs::RESULT, status),
+                Ok(None) => {}
+                Err(e) => eprintln!("  {} {}", crate::glyphs::RESULT, e),
+            }
+            quiet_until = Instant::now() + debounce;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    #[cfg(unix)]
+    fn test_hook_substitutes_file() {
+        let dir = tempdir().unwrap();
+        let marker = dir.path().join("marker");
+        let file = dir.path().join("data.txt");
+
+        let status = run_hook(&format!("echo {{file}} > {}", marker.display()), &file).unwrap();
+        assert!(status.success());
+        assert_eq!(std::fs::read_to_string(&marker).unwrap().trim(), file.to_string_lossy());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_only_matching_hook_runs() {
+        let hooks = WatchHooks {
+            on_success: Some("exit 0".to_string()),
+            on_failure: Some("exit 3".to_string()),
+        };
+        let file = Path::new("file.txt");
+
+        assert_eq!(hooks.run(file, true).unwrap().unwrap().code(), Some(0));
+        assert_eq!(hooks.run(file, false).unwrap().unwrap().code(), Some(3));
+        assert!(WatchHooks::default().run(file, true).unwrap().is_none());
+    }
+}
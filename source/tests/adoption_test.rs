@@ -0,0 +1,49 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::time::Duration;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::config::{AdoptionConfig, CustomValidatorConfig};
+use synx::validators::{validate_file, FileValidationConfig, ValidationOptions};
+use synx::validators::adoption::parse_cutoff;
+
+#[test]
+fn test_new_files_are_validated_strictly() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let old = temp_dir.path().join("legacy.json");
+    let new = temp_dir.path().join("fresh.json");
+    fs::write(&old, "{}\n")?;
+    fs::write(&new, "{}\n")?;
+    let cutoff = parse_cutoff("2024-01-01")?;
+    fs::File::options().write(true).open(&old)?.set_modified(cutoff - Duration::from_secs(86400))?;
+
+    // The stub validator passes in lenient mode and fails when handed the strict flag
+    let mut config = FileValidationConfig::default();
+    config.validators.custom.insert("json".to_string(), CustomValidatorConfig {
+        command: "/bin/sh".to_string(),
+        args: Some(vec![
+            "-c".to_string(),
+            "for arg; do [ \"$arg\" = --strict ] && exit 1; done; exit 0".to_string(),
+            "sh".to_string(),
+            "{file}".to_string(),
+        ]),
+        strict_args: Some(vec!["--strict".to_string()]),
+        success_pattern: None,
+        working_dir: None,
+    });
+    config.adoption = AdoptionConfig {
+        strict_after: Some("2024-01-01".to_string()),
+        source: Some("mtime".to_string()),
+    };
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    assert!(validate_file(&old, &options)?, "files from before the cutoff stay lenient");
+    assert!(!validate_file(&new, &options)?, "files from after the cutoff are strict");
+
+    Ok(())
+}
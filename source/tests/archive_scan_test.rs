@@ -0,0 +1,56 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+// JSON with allow_comments is parsed in-process, so no tool needs to be installed
+const CONFIG: &str = "[validators.json]\nallow_comments = true\n";
+
+#[test]
+fn test_broken_file_in_zip_is_reported() -> Result<()> {
+    let dir = tempdir()?;
+    let config = dir.path().join("synx.toml");
+    fs::write(&config, CONFIG)?;
+    let archive = dir.path().join("artifact.zip");
+    let mut writer = zip::ZipWriter::new(File::create(&archive)?);
+    writer.add_directory("config/", zip::write::FileOptions::default())?;
+    writer.start_file("config/valid.json", zip::write::FileOptions::default())?;
+    writer.write_all(b"{\"name\": \"synx\"}\n")?;
+    writer.start_file("config/broken.json", zip::write::FileOptions::default())?;
+    writer.write_all(b"{\"name\": \n")?;
+    writer.finish()?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .args(["scan", "--format", "ndjson", "--from-archive"])
+        .arg(&archive)
+        .output()?;
+    assert_eq!(output.status.code(), Some(1), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    // Paths are relative to the archive root, not the temporary directory
+    let mut records: Vec<(String, bool)> = String::from_utf8(output.stdout)?.lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .map(|record| (record["path"].as_str().unwrap().to_string(), record["success"].as_bool().unwrap()))
+        .collect();
+    records.sort();
+    assert_eq!(records, [("config/broken.json".to_string(), false), ("config/valid.json".to_string(), true)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_unsupported_archive_is_rejected() -> Result<()> {
+    let dir = tempdir()?;
+    let archive = dir.path().join("artifact.rar");
+    fs::write(&archive, b"not an archive")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["scan", "--from-archive"])
+        .arg(&archive)
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)?.contains("Unsupported archive"));
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::process::Command;
+use anyhow::Result;
+
+use synx::validators::{validate_file, FileValidationConfig};
+
+mod common;
+
+use common::{fixture, options_with};
+
+fn gcc_available() -> bool {
+    Command::new("gcc").arg("--version").output().is_ok()
+}
+
+#[test]
+fn test_local_header_requires_include_path() -> Result<()> {
+    if !gcc_available() {
+        eprintln!("gcc not available, skipping");
+        return Ok(());
+    }
+    let file = fixture("c/include/uses_header.c");
+
+    // Without the include path the header can't be found
+    let options = options_with(FileValidationConfig::default());
+    assert!(!validate_file(&file, &options)?);
+
+    // With it configured, the file validates
+    let mut config = FileValidationConfig::default();
+    config.validators.c.include_paths = Some(vec![
+        fixture("c/include/headers").to_string_lossy().to_string(),
+    ]);
+    assert!(validate_file(&file, &options_with(config))?);
+
+    Ok(())
+}
+
+#[test]
+fn test_defines_are_passed_to_compiler() -> Result<()> {
+    if !gcc_available() {
+        eprintln!("gcc not available, skipping");
+        return Ok(());
+    }
+    let file = fixture("c/include/uses_header.c");
+
+    let mut config = FileValidationConfig::default();
+    config.validators.c.include_paths = Some(vec![
+        fixture("c/include/headers").to_string_lossy().to_string(),
+    ]);
+    config.validators.c.defines = Some(vec!["PROJECT_DEBUG=1".to_string()]);
+    assert!(validate_file(&file, &options_with(config.clone()))?);
+
+    // An unknown standard is rejected by the compiler, proving -std= is passed through
+    config.validators.c.standard = Some("c1999".to_string());
+    assert!(!validate_file(&file, &options_with(config))?);
+
+    Ok(())
+}
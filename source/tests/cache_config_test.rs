@@ -0,0 +1,56 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use anyhow::{Result, anyhow};
+use tempfile::tempdir;
+
+// Run `synx scan --incremental --stats` with `config`, any top-level `flags`
+// and the cache kept under `home`, returning the cache hits and misses
+fn scan_cache_counts(dir: &Path, home: &Path, config: &Path, flags: &[&str]) -> Result<(usize, usize)> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(config)
+        .args(flags)
+        .args(["scan", "--incremental", "--stats"])
+        .arg(dir)
+        .env("HOME", home)
+        .env("XDG_CACHE_HOME", home.join(".cache"))
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+
+    let line = stderr.lines()
+        .find(|line| line.contains("Cache: "))
+        .ok_or_else(|| anyhow!("no cache stats in:\n{}", stderr))?;
+    let counts: Vec<usize> = line.split_whitespace()
+        .filter_map(|word| word.trim_end_matches(',').parse().ok())
+        .collect();
+    match counts[..] {
+        [hits, misses] => Ok((hits, misses)),
+        _ => Err(anyhow!("unexpected cache stats '{}'", line)),
+    }
+}
+
+#[test]
+fn test_changed_strictness_bypasses_the_cache() -> Result<()> {
+    let home = tempdir()?;
+    let project = tempdir()?;
+    fs::write(project.path().join(".env"), "PORT=8080\n")?;
+    fs::write(project.path().join("settings.json"), "{\n  // Commented JSON needs no external tool\n  \"port\": 8080\n}\n")?;
+
+    let lenient = home.path().join("lenient.toml");
+    let strict_json = home.path().join("strict_json.toml");
+    fs::write(&lenient, "[validators.json]\nallow_comments = true\n")?;
+    fs::write(&strict_json, "[validators.json]\nallow_comments = true\nstrict = true\n")?;
+
+    assert_eq!(scan_cache_counts(project.path(), home.path(), &lenient, &[])?, (0, 2));
+    assert_eq!(scan_cache_counts(project.path(), home.path(), &lenient, &[])?, (2, 0));
+
+    // Only the JSON file's settings changed, so only it is validated again
+    assert_eq!(scan_cache_counts(project.path(), home.path(), &strict_json, &[])?, (1, 1));
+
+    // --strict applies to every file
+    assert_eq!(scan_cache_counts(project.path(), home.path(), &lenient, &["--strict"])?, (0, 2));
+    assert_eq!(scan_cache_counts(project.path(), home.path(), &lenient, &["--strict"])?, (2, 0));
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::process::Command;
+use anyhow::Result;
+
+use synx::validators::{validate_file_detailed, FileValidationConfig, ValidationOptions};
+
+mod common;
+
+use common::{fixture, options_with};
+
+fn options(check_code_blocks: bool) -> ValidationOptions {
+    options_with(FileValidationConfig {
+        check_code_blocks,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_broken_block_reported_at_markdown_line() -> Result<()> {
+    if Command::new("python3").arg("--version").output().is_err() {
+        eprintln!("python3 not available, skipping");
+        return Ok(());
+    }
+    let path = fixture("markdown/broken_python.md");
+
+    let result = validate_file_detailed(&path, &options(true))?;
+    assert!(!result.success);
+    // The typo is on the first line of the block that opens on line 12
+    assert!(result.errors.iter().any(|error| error.line == Some(13)), "{:?}", result.errors);
+    assert!(result.errors.iter().all(|error| error.file_path == path.to_string_lossy()), "{:?}", result.errors);
+
+    Ok(())
+}
+
+#[test]
+fn test_code_blocks_unchecked_by_default() -> Result<()> {
+    let result = validate_file_detailed(&fixture("markdown/broken_python.md"), &options(false))?;
+    assert!(result.success);
+    assert!(result.errors.is_empty());
+    Ok(())
+}
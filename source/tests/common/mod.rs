@@ -0,0 +1,27 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use synx::validators::{FileValidationConfig, ValidationOptions};
+
+/// A file or directory under `tests/files`.
+pub fn fixture(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/files").join(path)
+}
+
+/// Options with a timeout, so a hung tool fails the test instead of stalling it.
+pub fn options() -> ValidationOptions {
+    ValidationOptions {
+        timeout: 30,
+        ..Default::default()
+    }
+}
+
+/// Like [`options`], validating with the given config.
+pub fn options_with(config: FileValidationConfig) -> ValidationOptions {
+    ValidationOptions {
+        config: Some(config),
+        ..options()
+    }
+}
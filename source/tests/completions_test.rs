@@ -0,0 +1,26 @@
+use std::process::Command;
+use anyhow::Result;
+
+#[test]
+fn test_bash_completions_cover_subcommands() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["completions", "bash"])
+        .output()?;
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let script = String::from_utf8(output.stdout)?;
+    assert!(script.contains("_synx()"));
+    assert!(script.contains("scan"));
+    assert!(script.contains("daemon"));
+    assert!(script.contains("--from-archive"));
+    Ok(())
+}
+
+#[test]
+fn test_unknown_shell_is_rejected() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["completions", "tcsh"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use std::process::Command;
+use anyhow::Result;
+
+use synx::validators::{validate_file_detailed, ErrorType, FileValidationConfig, ValidationOptions};
+
+mod common;
+
+use common::{fixture, options_with};
+
+fn options(enabled: bool, max_cyclomatic: usize) -> ValidationOptions {
+    let mut config = FileValidationConfig::default();
+    config.complexity.enabled = Some(enabled);
+    config.complexity.max_cyclomatic = Some(max_cyclomatic);
+    options_with(config)
+}
+
+#[test]
+fn test_function_over_budget_fails_validation() -> Result<()> {
+    if Command::new("python3").arg("--version").output().is_err() {
+        eprintln!("python3 not available, skipping");
+        return Ok(());
+    }
+
+    // The file is valid Python, so without the pass it passes
+    assert!(validate_file_detailed(&fixture("python/metrics/functions.py"), &options(false, 5))?.success);
+
+    let result = validate_file_detailed(&fixture("python/metrics/functions.py"), &options(true, 5))?;
+    assert!(!result.success);
+    let error = result.errors.iter()
+        .find(|e| e.error_type == ErrorType::Complexity)
+        .expect("complexity error");
+    assert!(error.message.contains("classify"));
+
+    Ok(())
+}
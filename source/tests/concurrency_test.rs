@@ -0,0 +1,53 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{scan_directory, FileValidationConfig, LanguageFilter, ValidationOptions};
+
+#[test]
+fn test_tool_concurrency_is_capped() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let running = temp_dir.path().join("running");
+    let log = temp_dir.path().join("concurrency.log");
+    fs::create_dir(&running)?;
+
+    // A heavy tool stand-in: notes how many copies are running, lingers, then
+    // passes. The once-per-process version probe for the cache key isn't a
+    // validation run and answers straight away without being counted.
+    let tool = temp_dir.path().join("heavy-jq");
+    fs::write(&tool, format!(
+        "#!/bin/sh\nif [ \"$1\" = --version ]; then echo jq-1.7; exit 0; fi\ntouch '{running}/'$$\nls '{running}' | wc -l >> '{log}'\nsleep 0.2\nrm '{running}/'$$\nexit 0\n",
+        running = running.display(),
+        log = log.display(),
+    ))?;
+    fs::set_permissions(&tool, fs::Permissions::from_mode(0o755))?;
+
+    let project = temp_dir.path().join("project");
+    fs::create_dir(&project)?;
+    for i in 0..6 {
+        fs::write(project.join(format!("data{}.json", i)), "{}\n")?;
+    }
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("jq".to_string(), tool.to_string_lossy().to_string());
+    config.concurrency.insert("jq".to_string(), 2);
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    let result = scan_directory(&project, &options, &[], &LanguageFilter::default())?;
+    assert_eq!(result.valid_files, 6);
+
+    let counts: Vec<usize> = fs::read_to_string(&log)?
+        .lines()
+        .map(|line| line.trim().parse().unwrap())
+        .collect();
+    assert_eq!(counts.len(), 6);
+    assert!(counts.iter().all(|&count| count <= 2), "too many at once: {:?}", counts);
+
+    Ok(())
+}
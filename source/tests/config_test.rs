@@ -4,7 +4,7 @@ use anyhow::Result;
 use tempfile::tempdir;
 
 // Import the config module from the main crate
-use synx::config::Config;
+use synx::config::{Config, ConfigIssueLevel, ConfigTemplate, validate_config_str};
 
 #[test]
 fn test_default_config() -> Result<()> {
@@ -210,3 +210,182 @@ fn test_config_file_save() -> Result<()> {
     Ok(())
 }
 
+
+#[test]
+fn test_validate_reports_misspelled_key() -> Result<()> {
+    let issues = validate_config_str(r#"
+[general]
+stirct = true
+verbose = true
+
+[validators.rust]
+edtion = "2021"
+"#);
+
+    let errors: Vec<_> = issues.iter()
+        .filter(|i| i.level == ConfigIssueLevel::Error)
+        .collect();
+    assert_eq!(errors.len(), 2);
+
+    assert_eq!(errors[0].key.as_deref(), Some("general.stirct"));
+    assert_eq!(errors[0].line, Some(3));
+    assert!(errors[0].message.contains("did you mean `strict`"));
+
+    assert_eq!(errors[1].key.as_deref(), Some("validators.rust.edtion"));
+    assert_eq!(errors[1].line, Some(7));
+    
+    Ok(())
+}
+
+#[test]
+fn test_validate_type_mismatch_and_deprecated_key() -> Result<()> {
+    let issues = validate_config_str(r#"
+[general]
+strict = "yes"
+interval = 5
+"#);
+
+    assert!(issues.iter().any(|i| i.level == ConfigIssueLevel::Error && i.line == Some(3)));
+    assert!(issues.iter().any(|i| {
+        i.level == ConfigIssueLevel::Warning && i.key.as_deref() == Some("general.interval")
+    }));
+
+    // A well-formed config produces no issues at all
+    assert!(validate_config_str("[general]\nstrict = true\n").is_empty());
+    
+    Ok(())
+}
+
+#[test]
+fn test_unknown_keys_do_not_stop_config_loading() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let config_path = temp_dir.path().join("synx.toml");
+    std::fs::write(&config_path, r#"
+[general]
+strict = true
+from_a_newer_version = 1
+
+[validators.rust]
+edition = "2018"
+
+[future_section]
+enabled = true
+"#)?;
+
+    let config = Config::new(None, None, None, None, None, Some(config_path.to_str().unwrap()))?;
+    assert_eq!(config.strict, true);
+    assert_eq!(config.validators.rust.edition, Some("2018".to_string()));
+
+    // `config validate` still rejects them
+    let content = std::fs::read_to_string(&config_path)?;
+    let errors = validate_config_str(&content).into_iter()
+        .filter(|i| i.level == ConfigIssueLevel::Error)
+        .count();
+    assert_eq!(errors, 2);
+    
+    Ok(())
+}
+
+#[test]
+fn test_unknown_multi_line_values_and_tables_are_dropped() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let config_path = temp_dir.path().join("synx.toml");
+    std::fs::write(&config_path, r#"
+[general]
+future_list = [
+    "one",
+    "two",
+]
+strict = true
+future_text = """
+[validators.rust]
+not a header
+"""
+timeout = 45
+
+[general.future_table]
+enabled = true
+
+[validators.rust]
+edition = "2018"
+"#)?;
+
+    let config = Config::new(None, None, None, None, None, Some(config_path.to_str().unwrap()))?;
+    assert!(config.strict);
+    assert_eq!(config.timeout, 45);
+    assert_eq!(config.validators.rust.edition, Some("2018".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn test_per_type_timeout_setting() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let config_path = temp_dir.path().join("synx.toml");
+
+    let mut file = File::create(&config_path)?;
+    writeln!(file, r#"
+[general]
+timeout = 20
+
+[validators.rust]
+timeout_secs = 120
+"#)?;
+
+    let config = Config::new(None, None, None, None, None, Some(config_path.to_str().unwrap()))?;
+    assert_eq!(config.timeout, 20);
+    assert_eq!(config.validators.timeout_for("rust"), Some(120));
+    assert_eq!(config.validators.timeout_for("json"), None);
+
+    // --timeout overrides the general setting but not the per-type one
+    let config = Config::new(None, None, None, None, Some(5), Some(config_path.to_str().unwrap()))?;
+    assert_eq!(config.timeout, 5);
+    assert_eq!(config.validators.timeout_for("rust"), Some(120));
+
+    Ok(())
+}
+
+#[test]
+fn test_templates_produce_distinct_valid_configs() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let mut contents = Vec::new();
+
+    for name in ConfigTemplate::NAMES {
+        let path = temp_dir.path().join(format!("{}.toml", name));
+        Config::init_config_file(&path, ConfigTemplate::from_name(name)?, false)?;
+
+        let content = std::fs::read_to_string(&path)?;
+        assert!(validate_config_str(&content).iter().all(|issue| issue.level != ConfigIssueLevel::Error));
+        contents.push(content);
+    }
+    assert_ne!(contents[0], contents[1]);
+    assert_ne!(contents[0], contents[2]);
+    assert_ne!(contents[1], contents[2]);
+
+    let strict = Config::new(None, None, None, None, None, Some(temp_dir.path().join("strict.toml").to_str().unwrap()))?;
+    assert!(strict.strict);
+    assert_eq!(strict.validators.rust.clippy, Some(true));
+    assert_eq!(strict.validators.python.mypy_strict, Some(true));
+
+    let ci = Config::new(None, None, None, None, None, Some(temp_dir.path().join("ci.toml").to_str().unwrap()))?;
+    assert!(ci.summary_only);
+    assert_eq!(ci.timeout, 120);
+
+    assert!(ConfigTemplate::from_name("fancy").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_init_config_needs_force_to_overwrite() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join("config.toml");
+    std::fs::write(&path, "[general]\nstrict = true\n")?;
+
+    let refused = Config::init_config_file(&path, ConfigTemplate::Minimal, false).unwrap_err();
+    assert!(refused.to_string().contains(path.to_str().unwrap()));
+    assert_eq!(std::fs::read_to_string(&path)?, "[general]\nstrict = true\n");
+
+    Config::init_config_file(&path, ConfigTemplate::Ci, true)?;
+    assert!(std::fs::read_to_string(&path)?.contains("summary_only = true"));
+    Ok(())
+}
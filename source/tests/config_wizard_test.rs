@@ -0,0 +1,58 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::config::Config;
+
+#[test]
+fn test_non_interactive_wizard_writes_chosen_options() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let output_path = temp_dir.path().join("wizard.toml");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["config", "wizard", "--non-interactive", "--strict"])
+        .args(["--languages", "python,rust", "--timeout", "45"])
+        .args(["--tool", "python3=/opt/python/bin/python3"])
+        .arg("--output").arg(&output_path)
+        .current_dir(temp_dir.path())
+        .output()?;
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let written: toml::Table = toml::from_str(&fs::read_to_string(&output_path)?)?;
+    let general = written["general"].as_table().unwrap();
+    assert_eq!(general["strict"].as_bool(), Some(true));
+    assert_eq!(general["timeout"].as_integer(), Some(45));
+    let languages: Vec<&str> = general["languages"].as_array().unwrap()
+        .iter()
+        .filter_map(|l| l.as_str())
+        .collect();
+    assert_eq!(languages, vec!["python", "rust"]);
+    assert_eq!(written["tools"]["python3"].as_str(), Some("/opt/python/bin/python3"));
+
+    // The generated file loads back into the same settings
+    let config = Config::new(None, None, None, None, None, Some(output_path.to_str().unwrap()))?;
+    assert!(config.strict);
+    assert_eq!(config.timeout, 45);
+    assert_eq!(config.languages, vec!["python", "rust"]);
+    assert_eq!(config.tools.get("python3").map(String::as_str), Some("/opt/python/bin/python3"));
+
+    Ok(())
+}
+
+#[test]
+fn test_wizard_rejects_unknown_language() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let output_path = temp_dir.path().join("wizard.toml");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["config", "wizard", "--non-interactive", "--languages", "pythn"])
+        .arg("--output").arg(&output_path)
+        .current_dir(temp_dir.path())
+        .output()?;
+
+    assert!(!output.status.success());
+    assert!(!output_path.exists());
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{validate_file, FileValidationConfig};
+
+mod common;
+
+use common::{fixture, options_with};
+
+// A stand-in compiler that records its arguments and succeeds
+fn recording_stub(dir: &Path, name: &str) -> Result<(String, PathBuf)> {
+    let log = dir.join(format!("{}.args", name));
+    let path = dir.join(name);
+    fs::write(&path, format!("#!/bin/sh\necho \"$@\" > '{}'\nexit 0\n", log.display()))?;
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    Ok((path.to_string_lossy().to_string(), log))
+}
+
+fn stubbed_config(dir: &Path) -> Result<(FileValidationConfig, PathBuf, PathBuf)> {
+    let (dotnet, dotnet_log) = recording_stub(dir, "dotnet")?;
+    let (mcs, mcs_log) = recording_stub(dir, "mcs")?;
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("dotnet".to_string(), dotnet);
+    config.tools.insert("mcs".to_string(), mcs);
+    Ok((config, dotnet_log, mcs_log))
+}
+
+#[test]
+fn test_standalone_file_uses_compiler() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let (config, dotnet_log, mcs_log) = stubbed_config(temp_dir.path())?;
+
+    assert!(validate_file(&fixture("csharp/standalone/Greeting.cs"), &options_with(config))?);
+
+    assert!(!dotnet_log.exists());
+    let args = fs::read_to_string(&mcs_log)?;
+    assert!(args.contains("-target:library"));
+    assert!(args.contains("Greeting.cs"));
+    Ok(())
+}
+
+#[test]
+fn test_project_file_builds_project() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let (config, dotnet_log, mcs_log) = stubbed_config(temp_dir.path())?;
+
+    assert!(validate_file(&fixture("csharp/project/src/Counter.cs"), &options_with(config.clone()))?);
+
+    assert!(!mcs_log.exists());
+    let args = fs::read_to_string(&dotnet_log)?;
+    assert!(args.starts_with("build "));
+    assert!(args.contains("Project.csproj"));
+    fs::remove_file(&dotnet_log)?;
+
+    // With use_dotnet off even project files are checked on their own
+    let mut config = config;
+    config.validators.csharp.use_dotnet = Some(false);
+    assert!(validate_file(&fixture("csharp/project/src/Counter.cs"), &options_with(config))?);
+    assert!(!dotnet_log.exists());
+    assert!(mcs_log.exists());
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::daemon::DaemonConfig;
+
+#[test]
+fn test_missing_watch_path_is_reported() -> Result<()> {
+    let dir = tempdir()?;
+    let missing = dir.path().join("no-such-project");
+    let mut config = DaemonConfig::with_watch_paths(vec![missing.clone()]);
+    config.pid_file = None;
+    config.log_file = None;
+    let config_path = dir.path().join("daemon.toml");
+    config.save_to_file(&config_path)?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["daemon", "validate-config"])
+        .arg(&config_path)
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains(&format!("watch_paths: {} does not exist", missing.display())), "{}", stderr);
+
+    // Once the path is there the same file passes
+    fs::create_dir(&missing)?;
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["daemon", "validate-config"])
+        .arg(&config_path)
+        .output()?;
+    assert_eq!(output.status.code(), Some(0), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    Ok(())
+}
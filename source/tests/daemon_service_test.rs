@@ -0,0 +1,33 @@
+#![cfg(target_os = "linux")]
+
+use std::path::Path;
+use std::process::Command;
+use anyhow::Result;
+
+const SERVICE_NAME: &str = "synx-dry-run-test";
+
+#[test]
+fn test_install_dry_run_prints_unit_without_writing() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["daemon", "install", "--dry-run", "--service-name", SERVICE_NAME, "--binary-path", "/usr/bin/synx"])
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let unit_file = format!("/etc/systemd/system/{}.service", SERVICE_NAME);
+    assert!(stdout.contains(&format!("Would write {}", unit_file)), "{}", stdout);
+    assert!(stdout.contains("[Unit]") && stdout.contains("ExecStart=/usr/bin/synx daemon"), "{}", stdout);
+    assert!(!Path::new(&unit_file).exists());
+    Ok(())
+}
+
+#[test]
+fn test_uninstall_dry_run_of_missing_service() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["daemon", "uninstall", "--dry-run", "--service-name", SERVICE_NAME])
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("is not installed"), "{}", stdout);
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use std::path::Path;
+use anyhow::Result;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use synx::daemon::{FailureNotification, NotificationConfig, WebhookNotifier};
+use synx::validators::{ErrorType, ValidationError, ValidationResult};
+
+/// Accept one request, answer 200 and hand back the raw request text
+async fn mock_server() -> Result<(String, tokio::task::JoinHandle<Result<String>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}/hook", listener.local_addr()?);
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await?;
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        loop {
+            let read = socket.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+            if request_complete(&request) {
+                break;
+            }
+        }
+        socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").await?;
+        Ok(String::from_utf8_lossy(&request).to_string())
+    });
+    Ok((url, server))
+}
+
+// Headers are in and the body is as long as Content-Length says
+fn request_complete(request: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(request);
+    let Some(end) = text.find("\r\n\r\n") else {
+        return false;
+    };
+    let length = text[..end].lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse::<usize>().ok())?
+        })
+        .unwrap_or(0);
+    request.len() >= end + 4 + length
+}
+
+fn failed_result() -> ValidationResult {
+    ValidationResult {
+        success: false,
+        errors: vec![ValidationError {
+            file_path: "src/app.py".to_string(),
+            error_type: ErrorType::SyntaxError,
+            message: "invalid syntax".to_string(),
+            line: Some(3),
+            column: None,
+            code: None,
+            suggestion: None,
+            severity: ErrorType::SyntaxError.default_severity(),
+        }],
+        suppressed: 0,
+        hidden_issues: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_failure_posts_to_webhook() -> Result<()> {
+    let (url, server) = mock_server().await?;
+    let config = NotificationConfig {
+        webhook_url: Some(url),
+        ..Default::default()
+    };
+    let mut notifier = WebhookNotifier::from_config(&config)?.expect("webhook configured");
+
+    let failure = FailureNotification::from_result(Path::new("src/app.py"), &failed_result());
+    let sent = notifier.notify(&failure).expect("notification should be sent");
+    sent.await??;
+
+    let request = server.await??;
+    assert!(request.starts_with("POST /hook"));
+    let body = &request[request.find("\r\n\r\n").unwrap() + 4..];
+    let payload: serde_json::Value = serde_json::from_str(body)?;
+    assert_eq!(payload["file"], "src/app.py");
+    assert_eq!(payload["summary"], "invalid syntax");
+    assert!(payload["timestamp"].is_string());
+
+    // A second failure of the same file right away is debounced
+    assert!(notifier.notify(&failure).is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_min_severity_holds_back_minor_failures() -> Result<()> {
+    let config = NotificationConfig {
+        webhook_url: Some("http://127.0.0.1:9/hook".to_string()),
+        min_severity: "critical".to_string(),
+        ..Default::default()
+    };
+    let mut notifier = WebhookNotifier::from_config(&config)?.expect("webhook configured");
+
+    let failure = FailureNotification::from_result(Path::new("src/app.py"), &failed_result());
+    assert!(notifier.notify(&failure).is_none());
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{
+    scan_directory, validate_file_detailed, DisabledValidatorError, FileValidationConfig, LanguageFilter, SkipReason,
+    ValidationOptions,
+};
+
+mod common;
+
+use common::fixture;
+
+fn yaml_disabled() -> ValidationOptions {
+    let mut config = FileValidationConfig::default();
+    config.validators.yaml.enabled = Some(false);
+    ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_disabled_yaml_files_are_skipped() -> Result<()> {
+    let error = validate_file_detailed(&fixture("yaml/invalid/tab_indent.yaml"), &yaml_disabled()).unwrap_err();
+    let disabled = error.downcast_ref::<DisabledValidatorError>().expect("skipped as disabled");
+    assert_eq!(disabled.language, "yaml");
+
+    let result = scan_directory(&fixture("yaml/"), &yaml_disabled(), &[], &LanguageFilter::default())?;
+    assert_eq!(result.valid_files, 0);
+    assert!(result.invalid_files.is_empty());
+    assert!(result.skipped_files.contains(&(fixture("yaml/invalid/tab_indent.yaml"), SkipReason::Disabled)));
+    assert!(result.skipped_files.contains(&(fixture("yaml/valid/service.yaml"), SkipReason::Disabled)));
+
+    Ok(())
+}
+
+#[test]
+fn test_enabled_false_in_config() -> Result<()> {
+    let dir = tempdir()?;
+    let config = dir.path().join("synx.toml");
+    fs::write(&config, "[validators.yaml]\nenabled = false\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .arg(fixture("yaml/invalid/tab_indent.yaml"))
+        .output()?;
+    assert_eq!(output.status.code(), Some(0), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8(output.stderr)?.contains("the yaml validator is disabled"));
+
+    Ok(())
+}
+
+#[test]
+fn test_disable_flag() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["scan", "--disable", "yml"])
+        .arg(fixture("yaml/"))
+        .output()?;
+    assert_eq!(output.status.code(), Some(0), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["--disable", "cobol"])
+        .arg(fixture("yaml/valid/service.yaml"))
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)?.contains("'cobol'"));
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{
+    scan_directory, validate_file_detailed, EmptyFileError, FileValidationConfig, LanguageFilter, SkipReason,
+    ValidationOptions,
+};
+
+mod common;
+
+use common::{fixture, options_with};
+
+fn options(empty_files: Option<&str>) -> ValidationOptions {
+    options_with(FileValidationConfig {
+        empty_files: empty_files.map(str::to_string),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_empty_files_pass_by_default() -> Result<()> {
+    for name in ["empty.rs", "blank.py"] {
+        let result = validate_file_detailed(&fixture("empty").join(name), &options(None))?;
+        assert!(result.success, "{}", name);
+        assert!(result.errors.is_empty(), "{}", name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_empty_files_fail() -> Result<()> {
+    for name in ["empty.rs", "blank.py"] {
+        let result = validate_file_detailed(&fixture("empty").join(name), &options(Some("fail")))?;
+        assert!(!result.success, "{}", name);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].code.as_deref(), Some("empty-file"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_empty_files_skip() -> Result<()> {
+    let error = validate_file_detailed(&fixture("empty/empty.rs"), &options(Some("skip"))).unwrap_err();
+    assert!(error.downcast_ref::<EmptyFileError>().is_some());
+
+    // Scans list them as skipped rather than passed or failed
+    let result = scan_directory(&fixture("empty"), &options(Some("skip")), &[], &LanguageFilter::default())?;
+    assert_eq!(result.valid_files, 0);
+    assert!(result.invalid_files.is_empty());
+    assert!(result.skipped_files.contains(&(fixture("empty/blank.py"), SkipReason::EmptyFile)));
+    assert!(result.skipped_files.contains(&(fixture("empty/empty.rs"), SkipReason::EmptyFile)));
+    Ok(())
+}
+
+#[test]
+fn test_unknown_policy_is_rejected() -> Result<()> {
+    let config_dir = tempdir()?;
+    let config = config_dir.path().join("synx.toml");
+    fs::write(&config, "[scan]\nempty_files = \"ignore\"\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .arg(fixture("empty/empty.rs"))
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)?.contains("empty_files 'ignore'"));
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use anyhow::Result;
+use tempfile::tempdir;
+
+/// Write a set where b.json can't be validated (jq points nowhere) and c.txt passes
+fn write_files(dir: &Path) -> Result<()> {
+    fs::write(dir.join("a.txt"), "plain text\n")?;
+    fs::write(dir.join("b.json"), "{\"name\": \"synx\"}\n")?;
+    fs::write(dir.join("c.txt"), "plain text\n")?;
+    fs::write(dir.join("synx.toml"), "[tools]\njq = \"/nonexistent/bin/jq\"\n")?;
+    Ok(())
+}
+
+fn run_synx(dir: &Path, extra: &[&str]) -> Result<Output> {
+    let mut args = vec!["--summary", "--config", "synx.toml"];
+    args.extend_from_slice(extra);
+    args.extend_from_slice(&["a.txt", "b.json", "c.txt"]);
+    Ok(Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(&args)
+        .current_dir(dir)
+        .output()?)
+}
+
+#[test]
+fn test_default_mode_checks_every_file_and_lists_errors() -> Result<()> {
+    let temp_dir = tempdir()?;
+    write_files(temp_dir.path())?;
+
+    let output = run_synx(temp_dir.path(), &[])?;
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stdout.starts_with("2 passed, 1 failed in "), "unexpected stdout: {:?}", stdout);
+    assert!(stderr.contains("1 file(s) could not be validated:"), "unexpected stderr: {}", stderr);
+    assert!(stderr.contains("  b.json: "), "unexpected stderr: {}", stderr);
+    assert!(!stderr.contains("--fail-fast"), "unexpected stderr: {}", stderr);
+    assert!(!output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn test_fail_fast_stops_at_the_first_error() -> Result<()> {
+    let temp_dir = tempdir()?;
+    write_files(temp_dir.path())?;
+
+    let output = run_synx(temp_dir.path(), &["--fail-fast"])?;
+
+    // c.txt is never reached
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stdout.starts_with("1 passed, 1 failed in "), "unexpected stdout: {:?}", stdout);
+    assert!(stderr.contains("(--fail-fast); 1 file(s) not checked"), "unexpected stderr: {}", stderr);
+    assert!(stderr.contains("  b.json: "), "unexpected stderr: {}", stderr);
+    assert!(!output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn test_validation_failures_are_not_listed_as_errors() -> Result<()> {
+    let temp_dir = tempdir()?;
+    fs::write(temp_dir.path().join("broken.yaml"), "key: [unclosed\n")?;
+    fs::write(temp_dir.path().join("ok.txt"), "plain text\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["--summary", "broken.yaml", "ok.txt"])
+        .current_dir(temp_dir.path())
+        .output()?;
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stdout.starts_with("1 passed, 1 failed in "), "unexpected stdout: {:?}", stdout);
+    assert!(!stderr.contains("could not be validated"), "unexpected stderr: {}", stderr);
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+#ifndef POINT_H
+#define POINT_H
+
+/* A point on the plane, shared by the geometry code */
+struct point {
+    int x;
+    int y;
+};
+
+int point_distance_squared(const struct point *a, const struct point *b);
+
+#endif
@@ -0,0 +1,8 @@
+#ifndef PROJECT_CONFIG_H
+#define PROJECT_CONFIG_H
+
+#define PROJECT_NAME "synx"
+
+int project_version(void);
+
+#endif
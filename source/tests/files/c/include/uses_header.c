@@ -0,0 +1,11 @@
+#include <stdio.h>
+#include "project_config.h"
+
+int main(void) {
+#ifdef PROJECT_DEBUG
+    printf("debug build of %s v%d\n", PROJECT_NAME, project_version());
+#else
+    printf("%s\n", PROJECT_NAME);
+#endif
+    return 0;
+}
@@ -0,0 +1,10 @@
+#include <stdlib.h>
+#include <string.h>
+
+/* Deliberately leaks a heap buffer so memory analysis has something to find. */
+int main(void) {
+    char *buffer = malloc(64);
+    strcpy(buffer, "never freed");
+    buffer = NULL;
+    return 0;
+}
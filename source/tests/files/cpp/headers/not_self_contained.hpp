@@ -0,0 +1,6 @@
+#pragma once
+
+// Relies on whoever includes it to have included <string> first
+struct Label {
+    std::string text;
+};
@@ -0,0 +1,17 @@
+#ifndef SHAPE_H
+#define SHAPE_H
+
+#include <string>
+
+namespace geometry {
+
+class Shape {
+public:
+    virtual ~Shape() = default;
+    virtual double area() const = 0;
+    virtual std::string name() const = 0;
+};
+
+}
+
+#endif
@@ -0,0 +1,13 @@
+#pragma once
+
+#include <vector>
+
+template <typename T>
+class Widget {
+public:
+    void add(const T &item) { items_.push_back(item); }
+    std::size_t size() const { return items_.size(); }
+
+private:
+    std::vector<T> items_;
+};
@@ -0,0 +1,12 @@
+namespace Project
+{
+    public class Counter
+    {
+        public int Value { get; private set; }
+
+        public void Increment()
+        {
+            Value++;
+        }
+    }
+}
@@ -0,0 +1,10 @@
+namespace Standalone
+{
+    public static class Greeting
+    {
+        public static string For(string name)
+        {
+            return "Hello, " + name + "!";
+        }
+    }
+}
@@ -0,0 +1,13 @@
+"""Small, focused helpers for plane geometry."""
+
+
+def area(width, height):
+    return width * height
+
+
+def perimeter(width, height):
+    return 2 * (width + height)
+
+
+def is_square(width, height):
+    return width == height
@@ -0,0 +1,125 @@
+"""Pricing rules, grown one special case at a time."""
+
+
+def price(kind, size, region, member, coupon, season):
+    if kind == 0:
+        if size > 0 and region == "r0":
+            if member or coupon == "c0":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("0 * size")
+            elif season > 0:
+                return 0 * size
+        else:
+            return 0
+    if kind == 1:
+        if size > 1 and region == "r1":
+            if member or coupon == "c1":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("1 * size")
+            elif season > 1:
+                return 1 * size
+        else:
+            return 1
+    if kind == 2:
+        if size > 2 and region == "r2":
+            if member or coupon == "c2":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("2 * size")
+            elif season > 2:
+                return 2 * size
+        else:
+            return 2
+    if kind == 3:
+        if size > 3 and region == "r3":
+            if member or coupon == "c3":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("3 * size")
+            elif season > 3:
+                return 3 * size
+        else:
+            return 3
+    if kind == 4:
+        if size > 4 and region == "r4":
+            if member or coupon == "c4":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("4 * size")
+            elif season > 4:
+                return 4 * size
+        else:
+            return 4
+    if kind == 5:
+        if size > 5 and region == "r5":
+            if member or coupon == "c5":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("5 * size")
+            elif season > 5:
+                return 5 * size
+        else:
+            return 5
+    if kind == 6:
+        if size > 6 and region == "r6":
+            if member or coupon == "c6":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("6 * size")
+            elif season > 6:
+                return 6 * size
+        else:
+            return 6
+    if kind == 7:
+        if size > 7 and region == "r7":
+            if member or coupon == "c7":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("7 * size")
+            elif season > 7:
+                return 7 * size
+        else:
+            return 7
+    if kind == 8:
+        if size > 8 and region == "r8":
+            if member or coupon == "c8":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("8 * size")
+            elif season > 8:
+                return 8 * size
+        else:
+            return 8
+    if kind == 9:
+        if size > 9 and region == "r9":
+            if member or coupon == "c9":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("9 * size")
+            elif season > 9:
+                return 9 * size
+        else:
+            return 9
+    if kind == 10:
+        if size > 10 and region == "r10":
+            if member or coupon == "c10":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("10 * size")
+            elif season > 10:
+                return 10 * size
+        else:
+            return 10
+    if kind == 11:
+        if size > 11 and region == "r11":
+            if member or coupon == "c11":
+                for step in range(season):
+                    if step % 2 == 0 and coupon:
+                        return eval("11 * size")
+            elif season > 11:
+                return 11 * size
+        else:
+            return 11
+    return -1
@@ -0,0 +1,5 @@
+#!/bin/sh
+# Build everything
+
+set -e
+cargo build --release
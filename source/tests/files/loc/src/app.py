@@ -0,0 +1,12 @@
+# Greeting service
+
+import sys
+
+
+def greet(name):
+    # Say hello
+    return f"Hello, {name}!"
+
+
+if __name__ == "__main__":
+    print(greet(sys.argv[1]))
@@ -0,0 +1,13 @@
+//! Arithmetic helpers
+
+/// Add two numbers
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+/* Subtraction
+ * wraps on overflow
+ */
+pub fn sub(a: i32, b: i32) -> i32 {
+    a.wrapping_sub(b)
+}
@@ -0,0 +1,5 @@
+def total(prices):
+        subtotal = sum(prices)
+	if subtotal > 100:
+		subtotal *= 0.9
+        return subtotal
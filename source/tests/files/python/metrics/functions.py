@@ -0,0 +1,25 @@
+def classify(values, threshold):
+    results = []
+    for value in values:
+        if value is None:
+            continue
+        elif value < 0 and threshold > 0:
+            results.append("negative")
+        elif value > threshold:
+            if value > threshold * 2:
+                results.append("huge")
+            else:
+                results.append("large")
+        else:
+            results.append("small")
+    return results
+
+
+def describe(value):
+    if value:
+        return "truthy"
+    return "falsy"
+
+
+def identity(value):
+    return value
@@ -0,0 +1,2 @@
+# A deliberate syntax error, silenced inline
+total = 1 +  # synx-disable-line SyntaxError
@@ -0,0 +1,2 @@
+# The same syntax error without a suppression comment
+total = 1 +
@@ -0,0 +1,11 @@
+# Structural pattern matching needs Python 3.10 or later
+
+
+def describe(command):
+    match command.split():
+        case ["go", direction]:
+            return f"going {direction}"
+        case ["quit"]:
+            return "bye"
+        case _:
+            return "unknown"
@@ -0,0 +1,50 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use anyhow::Result;
+use tempfile::tempdir;
+
+#[test]
+fn test_files_from_list_is_merged_with_positional_files() -> Result<()> {
+    let temp_dir = tempdir()?;
+    for name in ["one.txt", "two.txt", "three.txt", "unlisted.txt"] {
+        fs::write(temp_dir.path().join(name), "plain text\n")?;
+    }
+    fs::write(
+        temp_dir.path().join("files.lst"),
+        "# generated by CI\none.txt\n\n  two.txt  \none.txt\n",
+    )?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["--summary", "--files-from", "files.lst", "two.txt", "three.txt"])
+        .current_dir(temp_dir.path())
+        .output()?;
+
+    // one, two and three once each; duplicates, comments and unlisted files are ignored
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.starts_with("3 passed, 0 failed in "), "unexpected stdout: {:?}", stdout);
+    assert!(output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn test_files_from_stdin() -> Result<()> {
+    let temp_dir = tempdir()?;
+    fs::write(temp_dir.path().join("one.txt"), "plain text\n")?;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["--summary", "--files-from", "-"])
+        .current_dir(temp_dir.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(b"one.txt\nmissing.txt\n")?;
+    let output = child.wait_with_output()?;
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.starts_with("1 passed, 1 failed in "), "unexpected stdout: {:?}", stdout);
+    assert!(!output.status.success());
+
+    Ok(())
+}
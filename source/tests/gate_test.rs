@@ -0,0 +1,39 @@
+use std::fs;
+use std::process::{Command, Output};
+use anyhow::Result;
+use tempfile::tempdir;
+
+mod common;
+
+use common::fixture;
+
+fn run_gate(project: &str) -> Result<Output> {
+    let config_dir = tempdir()?;
+    let config = config_dir.path().join("synx.toml");
+    fs::write(&config, "[ui]\nglyphs = \"unicode\"\n\n[gate]\nmin_quality = 60\nmax_critical_complexity_files = 0\n")?;
+    Ok(Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .arg("gate")
+        .arg(fixture("gate").join(project))
+        .output()?)
+}
+
+#[test]
+fn test_gate_passes_good_project() -> Result<()> {
+    let output = run_gate("good")?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert_eq!(output.status.code(), Some(0), "stdout: {}", stdout);
+    assert!(stdout.contains("Quality gate passed"));
+    Ok(())
+}
+
+#[test]
+fn test_gate_fails_poor_project() -> Result<()> {
+    let output = run_gate("poor")?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert_eq!(output.status.code(), Some(1), "stdout: {}", stdout);
+    // The breached threshold is named
+    assert!(stdout.contains("❌ critical-complexity files"));
+    assert!(stdout.contains("Quality gate failed"));
+    Ok(())
+}
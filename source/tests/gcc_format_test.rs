@@ -0,0 +1,40 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use regex::Regex;
+use tempfile::tempdir;
+
+#[test]
+fn test_gcc_format_prints_one_line_per_issue() -> Result<()> {
+    let dir = tempdir()?;
+    // JSON with allow_comments is parsed in-process, so no tool needs to be installed
+    let config = dir.path().join("synx.toml");
+    fs::write(&config, "[validators.json]\nallow_comments = true\n")?;
+    let project = dir.path().join("project");
+    fs::create_dir(&project)?;
+    fs::write(project.join("valid.json"), "{\"name\": \"synx\"}\n")?;
+    fs::write(project.join("broken.json"), "{\n  \"name\": \"synx\",\n  \"tags\": [1, 2\n}\n")?;
+    fs::write(project.join("unclosed.json"), "{\"name\": \n")?;
+
+    for format in ["gcc", "compact"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+            .arg("--config").arg(&config)
+            .args(["scan", "--format", format])
+            .arg(&project)
+            .output()?;
+        assert_eq!(output.status.code(), Some(1), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+        let stdout = String::from_utf8(output.stdout)?;
+        let line = Regex::new(r"^[^:]+\.json:\d+:\d+: (error|warning|note): .+$")?;
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(lines.len(), 2, "{}", stdout);
+        for issue in &lines {
+            assert!(line.is_match(issue), "not a gcc-style line: {}", issue);
+        }
+        assert!(lines.iter().any(|issue| issue.starts_with("broken.json:")));
+
+        // The summary goes to stderr so stdout stays parseable
+        assert!(String::from_utf8(output.stderr)?.contains("2 of 3 files invalid"));
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use anyhow::Result;
+use tempfile::tempdir;
+
+// JSON with allow_comments is parsed in-process, so no tool needs to be installed
+fn write_config(dir: &Path, glyphs: &str) -> Result<std::path::PathBuf> {
+    let config = dir.join("synx.toml");
+    fs::write(&config, format!("[ui]\nglyphs = \"{}\"\n\n[validators.json]\nallow_comments = true\n", glyphs))?;
+    Ok(config)
+}
+
+fn synx(config: &Path, args: &[&Path]) -> Result<Output> {
+    Ok(Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(config)
+        .args(args)
+        .output()?)
+}
+
+fn combined(output: &Output) -> Vec<u8> {
+    [output.stdout.as_slice(), output.stderr.as_slice()].concat()
+}
+
+#[test]
+fn test_ascii_glyphs_produce_ascii_output() -> Result<()> {
+    let dir = tempdir()?;
+    let config = write_config(dir.path(), "ascii")?;
+    let project = dir.path().join("project");
+    fs::create_dir(&project)?;
+    let valid = project.join("valid.json");
+    let broken = project.join("broken.json");
+    fs::write(&valid, "{\"name\": \"synx\"}\n")?;
+    fs::write(&broken, "{\"name\": \n")?;
+
+    let passed = synx(&config, &[&valid])?;
+    assert_eq!(passed.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&passed.stdout).contains("[OK]"));
+
+    let failed = synx(&config, &[&broken])?;
+    assert_eq!(failed.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&failed.stdout).contains("[FAIL]"));
+
+    let scan = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .arg("scan").arg(&project)
+        .output()?;
+    assert_eq!(scan.status.code(), Some(1));
+
+    for output in [&passed, &failed, &scan] {
+        let bytes = combined(output);
+        assert!(bytes.is_ascii(), "non-ASCII output: {}", String::from_utf8_lossy(&bytes));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_ascii_glyphs_cover_plugin_and_doctor_output() -> Result<()> {
+    let dir = tempdir()?;
+    let config = write_config(dir.path(), "ascii")?;
+    let run = |args: &[&str]| Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .args(args)
+        .output();
+
+    let plugins = run(&["plugin", "list"])?;
+    assert!(String::from_utf8_lossy(&plugins.stdout).contains("== Available Plugins"));
+    let doctor = run(&["doctor"])?;
+    assert!(String::from_utf8_lossy(&doctor.stdout).contains("Validator tools:"));
+
+    for output in [&plugins, &doctor] {
+        let bytes = combined(output);
+        assert!(bytes.is_ascii(), "non-ASCII output: {}", String::from_utf8_lossy(&bytes));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_unicode_glyphs_keep_emoji() -> Result<()> {
+    let dir = tempdir()?;
+    let config = write_config(dir.path(), "unicode")?;
+    let valid = dir.path().join("valid.json");
+    fs::write(&valid, "{}\n")?;
+
+    let output = synx(&config, &[&valid])?;
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8(output.stdout)?.contains("✅"));
+    Ok(())
+}
+
+#[test]
+fn test_unknown_glyph_set_is_rejected() -> Result<()> {
+    let dir = tempdir()?;
+    let config = write_config(dir.path(), "emoji")?;
+    let valid = dir.path().join("valid.json");
+    fs::write(&valid, "{}\n")?;
+
+    let output = synx(&config, &[&valid])?;
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)?.contains("[ui] glyphs 'emoji'"));
+    Ok(())
+}
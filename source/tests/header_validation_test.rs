@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use anyhow::Result;
+
+use synx::detectors::{detect_file_type_with_mappings, FileType};
+use synx::validators::{validate_file};
+
+mod common;
+
+use common::{fixture, options};
+
+fn available(compiler: &str) -> bool {
+    Command::new(compiler).arg("--version").output().is_ok()
+}
+
+fn detect(path: &Path) -> Result<FileType> {
+    detect_file_type_with_mappings(path, &HashMap::new())
+}
+
+#[test]
+fn test_headers_are_classified() -> Result<()> {
+    assert_eq!(detect(&fixture("c/headers/point.h"))?, FileType::C);
+    assert_eq!(detect(&fixture("cpp/headers/shape.h"))?, FileType::Cpp);
+    assert_eq!(detect(&fixture("cpp/headers/widget.hpp"))?, FileType::Cpp);
+    Ok(())
+}
+
+#[test]
+fn test_c_header_validates() -> Result<()> {
+    if !available("gcc") {
+        eprintln!("gcc not available, skipping");
+        return Ok(());
+    }
+    assert!(validate_file(&fixture("c/headers/point.h"), &options())?);
+    Ok(())
+}
+
+#[test]
+fn test_cpp_headers_validate() -> Result<()> {
+    if !available("g++") {
+        eprintln!("g++ not available, skipping");
+        return Ok(());
+    }
+    // A C++ header named .h is compiled as C++, not C
+    assert!(validate_file(&fixture("cpp/headers/shape.h"), &options())?);
+    assert!(validate_file(&fixture("cpp/headers/widget.hpp"), &options())?);
+
+    // Headers have to compile on their own
+    assert!(!validate_file(&fixture("cpp/headers/not_self_contained.hpp"), &options())?);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+
+use synx::validators::{validate_file_detailed, ErrorType, FileValidationConfig};
+
+mod common;
+
+use common::{fixture, options_with};
+
+#[test]
+fn test_editorconfig_line_length_is_enforced() -> Result<()> {
+    // Without the pass the text file has nothing to fail on
+    assert!(validate_file_detailed(&fixture("hygiene/long_line.txt"), &options_with(FileValidationConfig::default()))?.success);
+
+    let mut config = FileValidationConfig::default();
+    config.hygiene.enabled = Some(true);
+    let result = validate_file_detailed(&fixture("hygiene/long_line.txt"), &options_with(config.clone()))?;
+
+    assert!(!result.success);
+    let errors: Vec<_> = result.errors.iter().filter(|e| e.error_type == ErrorType::Lint).collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, Some(2));
+    assert!(errors[0].message.contains("max 40"));
+
+    // The synx config wins over .editorconfig
+    config.hygiene.max_line_length = Some(100);
+    assert!(validate_file_detailed(&fixture("hygiene/long_line.txt"), &options_with(config))?.success);
+
+    Ok(())
+}
+
+#[test]
+fn test_issues_past_the_cap_are_counted_not_listed() -> Result<()> {
+    let path = fixture("hygiene/trailing_whitespace.txt");
+    let mut config = FileValidationConfig::default();
+    config.hygiene.enabled = Some(true);
+    config.hygiene.trim_trailing_whitespace = Some(true);
+    config.max_issues_per_file = 25;
+
+    let result = validate_file_detailed(&path, &options_with(config))?;
+
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 26);
+    assert_eq!(result.errors[24].line, Some(25));
+    assert_eq!(result.hidden_issues, 35);
+    let overflow = &result.errors[25];
+    assert_eq!(overflow.message, "Showing 25 of 60 issues");
+    assert_eq!(overflow.code.as_deref(), Some(synx::validators::TRUNCATED_ISSUES_CODE));
+
+    Ok(())
+}
+
+#[test]
+fn test_capped_file_still_fails_the_run() -> Result<()> {
+    let path = fixture("hygiene/trailing_whitespace.txt");
+    let config_dir = tempfile::tempdir()?;
+    let config = config_dir.path().join("synx.toml");
+    std::fs::write(&config, "[hygiene]\nenabled = true\ntrim_trailing_whitespace = true\n")?;
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .args(["--max-issues-per-file", "3"])
+        .arg(&path)
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use std::process::Command;
+use anyhow::Result;
+
+use synx::validators::{validate_file, validate_file_detailed, FileValidationConfig, ValidationOptions};
+
+mod common;
+
+use common::{fixture, options_with};
+
+fn options(allow_comments: bool) -> ValidationOptions {
+    let mut config = FileValidationConfig::default();
+    config.validators.json.allow_comments = Some(allow_comments);
+    options_with(config)
+}
+
+#[test]
+fn test_commented_json_passes_with_allow_comments() -> Result<()> {
+    assert!(validate_file(&fixture("json/commented.jsonc"), &options(true))?);
+    Ok(())
+}
+
+#[test]
+fn test_json5_errors_report_position() -> Result<()> {
+    let result = validate_file_detailed(&fixture("json/broken.jsonc"), &options(true))?;
+
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].line, Some(3));
+    assert!(result.errors[0].column.is_some());
+    Ok(())
+}
+
+#[test]
+fn test_commented_json_fails_without_allow_comments() -> Result<()> {
+    if Command::new("jq").arg("--version").output().is_err() {
+        eprintln!("jq not available, skipping");
+        return Ok(());
+    }
+    assert!(!validate_file(&fixture("json/commented.jsonc"), &options(false))?);
+    Ok(())
+}
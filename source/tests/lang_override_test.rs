@@ -0,0 +1,69 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use anyhow::Result;
+use tempfile::tempdir;
+
+fn python_available() -> bool {
+    if which::which("python3").is_ok() {
+        return true;
+    }
+    eprintln!("Skipping test: python3 not installed");
+    false
+}
+
+fn synx_with_stdin(args: &[&str], input: &str) -> Result<Output> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(input.as_bytes())?;
+    Ok(child.wait_with_output()?)
+}
+
+#[test]
+fn test_stdin_is_validated_as_the_given_language() -> Result<()> {
+    if !python_available() {
+        return Ok(());
+    }
+
+    let output = synx_with_stdin(&["--lang", "python", "-"], "def greet(name):\n    return name\n")?;
+    assert_eq!(output.status.code(), Some(0), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    // Only the Python validator rejects this
+    let output = synx_with_stdin(&["--lang", "python", "-"], "def greet(name:\n    return name\n")?;
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stdout)?.contains("<stdin>: Validation failed"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lang_overrides_detection_for_files() -> Result<()> {
+    if !python_available() {
+        return Ok(());
+    }
+    let temp_dir = tempdir()?;
+    let script = temp_dir.path().join("snippet");
+    fs::write(&script, "def greet(name:\n    return name\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx")).args(["--lang", "py"]).arg(&script).output()?;
+    assert_eq!(output.status.code(), Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn test_unknown_language_and_missing_lang_are_rejected() -> Result<()> {
+    let output = synx_with_stdin(&["--lang", "cobol", "-"], "")?;
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)?.contains("unknown language 'cobol'"));
+
+    let output = synx_with_stdin(&["-"], "x = 1\n")?;
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)?.contains("needs --lang"));
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+#![cfg(unix)]
+
+use std::fs;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::config::CustomValidatorConfig;
+use synx::validators::{validate_file, FileValidationConfig, ValidationOptions};
+
+mod common;
+
+use common::options_with;
+
+// A stand-in linter whose files only have warnings: it passes in lenient mode
+// and fails when handed the strict flag
+fn warns_only() -> CustomValidatorConfig {
+    CustomValidatorConfig {
+        command: "/bin/sh".to_string(),
+        args: Some(vec![
+            "-c".to_string(),
+            "for arg; do [ \"$arg\" = --strict ] && exit 1; done; exit 0".to_string(),
+            "sh".to_string(),
+            "{file}".to_string(),
+        ]),
+        strict_args: Some(vec!["--strict".to_string()]),
+        success_pattern: None,
+        working_dir: None,
+    }
+}
+
+fn options(global_strict: bool) -> ValidationOptions {
+    let mut config = FileValidationConfig::default();
+    config.validators.custom.insert("rs".to_string(), warns_only());
+    config.validators.custom.insert("js".to_string(), warns_only());
+    config.validators.rust.strict = Some(true);
+    config.validators.javascript.strict = Some(false);
+    ValidationOptions {
+        strict: global_strict,
+        ..options_with(config)
+    }
+}
+
+#[test]
+fn test_language_strict_overrides_global_flag() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let rust = temp_dir.path().join("lib.rs");
+    let js = temp_dir.path().join("app.js");
+    fs::write(&rust, "fn main() {}\n")?;
+    fs::write(&js, "let x = 1;\n")?;
+
+    let lenient = options(false);
+    assert!(!validate_file(&rust, &lenient)?, "rust is strict even without --strict");
+    assert!(validate_file(&js, &lenient)?, "javascript warnings pass");
+
+    let strict = options(true);
+    assert!(!validate_file(&rust, &strict)?);
+    assert!(validate_file(&js, &strict)?, "javascript stays lenient under --strict");
+
+    Ok(())
+}
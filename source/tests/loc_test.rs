@@ -0,0 +1,45 @@
+use std::process::Command;
+use anyhow::Result;
+
+mod common;
+
+use common::fixture;
+
+#[test]
+fn test_loc_counts_per_language() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["loc", "--format", "json"])
+        .arg(fixture("loc"))
+        .output()?;
+    assert_eq!(output.status.code(), Some(0), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    // (files, code, comments, blank); the minified bundle is generated and left out
+    let expected = [("Python", [1, 5, 2, 5]), ("Rust", [1, 6, 5, 2]), ("Shell", [1, 2, 2, 1])];
+    for (language, [files, code, comments, blank]) in expected {
+        let counts = &report["languages"][language];
+        assert_eq!(counts["files"], files, "{}", language);
+        assert_eq!(counts["code"], code, "{}", language);
+        assert_eq!(counts["comments"], comments, "{}", language);
+        assert_eq!(counts["blank"], blank, "{}", language);
+    }
+    assert_eq!(report["languages"].as_object().unwrap().len(), 3);
+    assert_eq!(report["total"]["files"], 3);
+    assert_eq!(report["total"]["code"], 13);
+
+    Ok(())
+}
+
+#[test]
+fn test_loc_exclude() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["loc", "--exclude", "*/scripts/*"])
+        .arg(fixture("loc"))
+        .output()?;
+    assert_eq!(output.status.code(), Some(0));
+    let table = String::from_utf8(output.stdout)?;
+    assert!(table.contains("Python"));
+    assert!(!table.contains("Shell"));
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+#[test]
+fn test_json_log_lines_are_parseable() -> Result<()> {
+    let dir = tempdir()?;
+    let file = dir.path().join(".env");
+    fs::write(&file, "PORT=8080\n")?;
+    // A config path that doesn't exist is logged as a warning
+    let missing = dir.path().join("missing.toml");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .env("RUST_LOG", "debug")
+        .arg("--log-format").arg("json")
+        .arg("-c").arg(&missing)
+        .arg(&file)
+        .output()?;
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let logs: Vec<serde_json::Value> = stderr.lines()
+        .filter(|line| line.starts_with('{'))
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    assert!(!logs.is_empty(), "no JSON log lines in: {}", stderr);
+    for log in &logs {
+        for field in ["timestamp", "level", "module", "message"] {
+            assert!(log[field].is_string(), "{} missing from {}", field, log);
+        }
+    }
+    assert!(logs.iter().any(|log| log["level"] == "WARN"
+        && log["message"].as_str().is_some_and(|message| message.contains("does not exist"))), "{}", stderr);
+
+    // Results stay on stdout, logs stay off it
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("Validation passed"), "{}", stdout);
+    assert!(!stdout.lines().any(|line| line.starts_with('{')), "{}", stdout);
+
+    Ok(())
+}
+
+#[test]
+fn test_unknown_log_format_is_rejected() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["--log-format", "xml", "version"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+    Ok(())
+}
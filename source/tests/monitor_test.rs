@@ -0,0 +1,28 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+#[test]
+fn test_monitor_on_clean_directory_succeeds() -> Result<()> {
+    let temp_dir = tempdir()?;
+    fs::write(temp_dir.path().join("notes.txt"), "nothing to fix here\n")?;
+
+    // No issues means no TUI, so this runs without a terminal
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("monitor")
+        .arg(temp_dir.path())
+        .output()?;
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8(output.stdout)?.contains("No issues found"));
+    assert!(!String::from_utf8(output.stderr)?.contains("❌"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["monitor", "--summary"])
+        .arg(temp_dir.path())
+        .output()?;
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout)?.trim(), "0 issues in 0 files");
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+#[test]
+fn test_one_scan_writes_every_report() -> Result<()> {
+    let project = tempdir()?;
+    let out = tempdir()?;
+    fs::write(project.path().join(".env"), "PORT=8080\n")?;
+    let (sarif, json, text) = (out.path().join("out.sarif"), out.path().join("out.json"), out.path().join("out.txt"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("scan")
+        .arg(project.path())
+        .arg("--report").arg(&sarif)
+        .arg("--report").arg(&json)
+        .arg("--report").arg(&text)
+        .output()?;
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let report: serde_json::Value = serde_json::from_str(&fs::read_to_string(&json)?)?;
+    assert_eq!(report["total_files"], 1);
+
+    let report: serde_json::Value = serde_json::from_str(&fs::read_to_string(&sarif)?)?;
+    assert!(report["runs"].is_array(), "{}", report);
+
+    let report = fs::read_to_string(&text)?;
+    assert!(!report.is_empty());
+    assert!(serde_json::from_str::<serde_json::Value>(&report).is_err(), "{}", report);
+
+    Ok(())
+}
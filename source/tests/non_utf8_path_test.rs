@@ -0,0 +1,42 @@
+#![cfg(unix)]
+
+use std::ffi::OsStr;
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::config::CustomValidatorConfig;
+use synx::validators::{scan_directory, validate_file_detailed, FileValidationConfig, LanguageFilter, ValidationOptions};
+
+#[test]
+fn test_non_utf8_path_is_validated() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join(OsStr::from_bytes(b"data-\xff\xfe.json"));
+    if fs::write(&path, "{}\n").is_err() {
+        eprintln!("filesystem rejects non-UTF-8 names, skipping");
+        return Ok(());
+    }
+
+    // The check only passes if the tool receives the exact bytes of the path
+    let mut config = FileValidationConfig::default();
+    config.validators.custom.insert("json".to_string(), CustomValidatorConfig {
+        command: "/bin/sh".to_string(),
+        args: Some(vec!["-c".to_string(), "test -f \"$1\"".to_string(), "sh".to_string(), "{file}".to_string()]),
+        strict_args: None,
+        success_pattern: None,
+        working_dir: None,
+    });
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    assert!(validate_file_detailed(&path, &options)?.success);
+
+    let result = scan_directory(temp_dir.path(), &options, &["*.skip".to_string()], &LanguageFilter::default())?;
+    assert_eq!(result.valid_files, 1);
+    assert!(result.invalid_files.is_empty());
+
+    Ok(())
+}
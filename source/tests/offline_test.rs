@@ -0,0 +1,66 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{validate_file, FileValidationConfig, ValidationOptions, OFFLINE_ENV};
+
+mod common;
+
+use common::options_with;
+
+// A stand-in Python interpreter that records its environment and accepts the file
+fn env_recorder(dir: &Path) -> Result<String> {
+    let script = dir.join("fake-python");
+    fs::write(&script, format!("#!/bin/sh\nenv > '{}'\n", dir.join("env.txt").display()))?;
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755))?;
+    Ok(script.to_string_lossy().to_string())
+}
+
+fn options(interpreter: &str, offline: bool) -> ValidationOptions {
+    let mut config = FileValidationConfig::default();
+    config.validators.python.interpreters = Some(vec![interpreter.to_string()]);
+    ValidationOptions {
+        offline,
+        ..options_with(config)
+    }
+}
+
+#[test]
+fn test_offline_env_is_set_on_validator_commands() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let interpreter = env_recorder(temp_dir.path())?;
+    let file = temp_dir.path().join("app.py");
+    fs::write(&file, "print('hi')\n")?;
+
+    assert!(validate_file(&file, &options(&interpreter, true))?);
+    let env = fs::read_to_string(temp_dir.path().join("env.txt"))?;
+    for (name, value) in OFFLINE_ENV {
+        assert!(env.lines().any(|line| line == format!("{}={}", name, value)), "{} not set:\n{}", name, env);
+    }
+    assert!(env.contains("CARGO_NET_OFFLINE=true"));
+    assert!(env.contains("npm_config_offline=true"));
+
+    Ok(())
+}
+
+#[test]
+fn test_online_by_default() -> Result<()> {
+    if std::env::var_os("CARGO_NET_OFFLINE").is_some() {
+        eprintln!("CARGO_NET_OFFLINE already set, skipping");
+        return Ok(());
+    }
+    let temp_dir = tempdir()?;
+    let interpreter = env_recorder(temp_dir.path())?;
+    let file = temp_dir.path().join("app.py");
+    fs::write(&file, "print('hi')\n")?;
+
+    assert!(validate_file(&file, &options(&interpreter, false))?);
+    let env = fs::read_to_string(temp_dir.path().join("env.txt"))?;
+    assert!(!env.contains("CARGO_NET_OFFLINE="), "{}", env);
+
+    Ok(())
+}
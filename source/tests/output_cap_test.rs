@@ -0,0 +1,75 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{validate_file_detailed, FileValidationConfig, ValidationOptions};
+
+// A stand-in validator tool running `script`
+fn stub(dir: &Path, name: &str, script: &str) -> Result<String> {
+    let path: PathBuf = dir.join(name);
+    fs::write(&path, format!("#!/bin/sh\n{}\n", script))?;
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[test]
+fn test_huge_tool_output_is_truncated() -> Result<()> {
+    let temp_dir = tempdir()?;
+    // About 8MB of stderr, far beyond the limit and any pipe buffer
+    let noisy = stub(temp_dir.path(), "noisy-jq", "yes 'parse error: this line repeats forever' | head -c 8000000 >&2\nexit 1")?;
+
+    let json_file = temp_dir.path().join("data.json");
+    fs::write(&json_file, "{}\n")?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("jq".to_string(), noisy);
+    config.max_output_bytes = 64 * 1024;
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    let result = validate_file_detailed(&json_file, &options)?;
+
+    assert!(!result.success);
+    let captured: usize = result.errors.iter().map(|error| error.message.len()).sum();
+    assert!(captured <= 64 * 1024 + 64, "captured {} bytes", captured);
+    assert!(result.errors.iter().any(|error| error.message.ends_with("... (truncated)")));
+
+    Ok(())
+}
+
+#[test]
+fn test_issues_per_file_are_capped() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let noisy = stub(
+        temp_dir.path(),
+        "noisy-gcc",
+        "i=1\nwhile [ $i -le 500 ]; do echo \"main.c:$i:1: error: expected ';'\" >&2; i=$((i+1)); done\nexit 1",
+    )?;
+
+    let c_file = temp_dir.path().join("main.c");
+    fs::write(&c_file, "int main(void) { return 0 }\n")?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("gcc".to_string(), noisy);
+    config.max_issues_per_file = 20;
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    let result = validate_file_detailed(&c_file, &options)?;
+
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 21);
+    assert_eq!(result.errors[19].line, Some(20));
+    assert_eq!(result.errors[20].message, "Showing 20 of 500 issues");
+    assert_eq!(result.hidden_issues, 480);
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+use synx::validators::package_manifest::check_manifest;
+use synx::validators::{validate_file_detailed, ValidationOptions};
+
+mod common;
+
+use common::fixture;
+
+#[test]
+fn test_cargo_toml_missing_version_fails() -> Result<()> {
+    let result = validate_file_detailed(&fixture("manifests/cargo/Cargo.toml"), &ValidationOptions::default())?;
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+    assert_eq!(result.errors[0].message, "[package] is missing version");
+    assert_eq!(result.errors[0].line, Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_package_json_missing_version_flagged() -> Result<()> {
+    let errors = check_manifest(&fixture("manifests/npm/package.json"))?;
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(errors[0].message, "package.json is missing version");
+    Ok(())
+}
@@ -0,0 +1,48 @@
+#![cfg(unix)]
+
+use std::env;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{validate_file_detailed, ErrorType, ValidationOptions};
+
+// The stub checks it got the request on stdin, then reports a failure
+const STUB_PLUGIN: &str = r#"#!/bin/sh
+request=$(cat)
+case "$request" in
+  *'"file_type":"zz"'*) ;;
+  *) echo "bad request: $request" >&2; exit 1 ;;
+esac
+cat <<JSON
+{"success": false, "errors": [{"file_path": "$1", "error_type": "SyntaxError", "message": "unexpected token", "line": 2, "severity": "High"}]}
+JSON
+"#;
+
+#[test]
+fn test_path_plugin_failure_is_surfaced() -> Result<()> {
+    let plugin_dir = tempdir()?;
+    let plugin = plugin_dir.path().join("synx-validator-zz");
+    fs::write(&plugin, STUB_PLUGIN)?;
+    fs::set_permissions(&plugin, fs::Permissions::from_mode(0o755))?;
+
+    // This is the only test in this binary, so changing PATH can't disturb others
+    let path = env::var("PATH").unwrap_or_default();
+    env::set_var("PATH", format!("{}:{}", plugin_dir.path().display(), path));
+
+    let work_dir = tempdir()?;
+    let file = work_dir.path().join("sample.zz");
+    fs::write(&file, "let x = \n")?;
+
+    let result = validate_file_detailed(&file, &ValidationOptions::default())?;
+
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].error_type, ErrorType::SyntaxError);
+    assert_eq!(result.errors[0].message, "unexpected token");
+    assert_eq!(result.errors[0].line, Some(2));
+    assert_eq!(result.errors[0].file_path, file.to_string_lossy());
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::sync::Arc;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::performance::{Phase, Profiler};
+use synx::validators::{scan_directory, FileValidationConfig, LanguageFilter, ValidationOptions};
+
+#[test]
+fn test_profile_reports_tool_execution() -> Result<()> {
+    let temp_dir = tempdir()?;
+    // A validator tool that takes a noticeable moment and then passes
+    let tool = temp_dir.path().join("slow-jq");
+    fs::write(&tool, "#!/bin/sh\nsleep 0.2\nexit 0\n")?;
+    fs::set_permissions(&tool, fs::Permissions::from_mode(0o755))?;
+
+    let project = temp_dir.path().join("project");
+    fs::create_dir(&project)?;
+    fs::write(project.join("data.json"), "{}\n")?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("jq".to_string(), tool.to_string_lossy().to_string());
+    let profiler = Arc::new(Profiler::new());
+    let options = ValidationOptions {
+        config: Some(config),
+        profiler: Some(profiler.clone()),
+        ..Default::default()
+    };
+
+    let result = scan_directory(&project, &options, &[], &LanguageFilter::default())?;
+    assert_eq!(result.valid_files, 1);
+
+    let report = profiler.report(5);
+    assert!(report.phase(Phase::ToolExecution).as_millis() >= 200);
+    assert_eq!(report.slowest_files.len(), 1);
+    assert!(report.to_string().contains("tool execution"));
+
+    Ok(())
+}
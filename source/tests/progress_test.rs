@@ -0,0 +1,33 @@
+use std::fs;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::{validate_files_with_progress, ProgressEvent, ValidationOptions};
+
+#[test]
+fn test_progress_events_per_file() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let files: Vec<_> = ["a.json", "b.json", "c.json"].iter()
+        .map(|name| temp_dir.path().join(name))
+        .collect();
+    for file in &files {
+        fs::write(file, "")?;
+    }
+
+    let mut events = Vec::new();
+    let summary = validate_files_with_progress(&files, &ValidationOptions::default(), |event| events.push(event));
+
+    assert_eq!(events.len(), files.len() * 2 + 1);
+    for (file, pair) in files.iter().zip(events.chunks(2)) {
+        assert!(matches!(&pair[0], ProgressEvent::Started { path } if path == file), "{:?}", pair[0]);
+        assert!(matches!(&pair[1], ProgressEvent::Finished { path, result: Ok(_) } if path == file), "{:?}", pair[1]);
+    }
+    match events.last() {
+        Some(ProgressEvent::Done { summary: done }) => assert_eq!(done, &summary),
+        other => panic!("expected Done last, got {:?}", other),
+    }
+    assert_eq!(summary.files, 3);
+    assert_eq!(summary.passed, 3);
+
+    Ok(())
+}
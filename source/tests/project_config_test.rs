@@ -0,0 +1,60 @@
+#![cfg(unix)]
+
+use std::fs;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::config::CustomValidatorConfig;
+use synx::validators::{scan_directory, FileValidationConfig, LanguageFilter, ValidationOptions};
+
+#[test]
+fn test_each_subproject_uses_its_own_config() -> Result<()> {
+    let root = tempdir()?;
+    for (package, strict) in [("strict", true), ("lenient", false)] {
+        let dir = root.path().join("packages").join(package);
+        fs::create_dir_all(dir.join("src"))?;
+        fs::write(dir.join(".synx.toml"), format!("[general]\nstrict = {}\n", strict))?;
+        fs::write(dir.join("src/data.json"), "{}\n")?;
+    }
+
+    // The stub validator passes in lenient mode and fails when handed the strict flag
+    let mut config = FileValidationConfig::default();
+    config.validators.custom.insert("json".to_string(), CustomValidatorConfig {
+        command: "/bin/sh".to_string(),
+        args: Some(vec![
+            "-c".to_string(),
+            "for arg; do [ \"$arg\" = --strict ] && exit 1; done; exit 0".to_string(),
+            "sh".to_string(),
+            "{file}".to_string(),
+        ]),
+        strict_args: Some(vec!["--strict".to_string()]),
+        success_pattern: None,
+        working_dir: None,
+    });
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    let result = scan_directory(root.path(), &options, &[], &LanguageFilter::default())?;
+
+    // The config files themselves are scanned too, so count only the JSON files
+    let invalid: Vec<_> = result.invalid_files.iter()
+        .map(|path| path.strip_prefix(root.path()).unwrap().to_path_buf())
+        .collect();
+    assert_eq!(invalid, vec![std::path::PathBuf::from("packages/strict/src/data.json")]);
+
+    Ok(())
+}
+
+#[test]
+fn test_broken_subproject_config_falls_back_to_the_scan_options() -> Result<()> {
+    let root = tempdir()?;
+    fs::write(root.path().join(".synx.toml"), "[general]\nstrict = \"yes\"\n")?;
+    fs::write(root.path().join("notes.unknownext"), "kept\n")?;
+
+    let result = scan_directory(root.path(), &ValidationOptions::default(), &[], &LanguageFilter::default())?;
+    assert!(result.invalid_files.is_empty());
+
+    Ok(())
+}
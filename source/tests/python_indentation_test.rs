@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use synx::validators::{validate_file_detailed, ValidationOptions};
+
+mod common;
+
+use common::fixture;
+
+#[test]
+fn test_mixed_tabs_and_spaces_are_explained() -> Result<()> {
+    // Reported before the compiler runs, so python3 needn't be installed
+    let result = validate_file_detailed(&fixture("python/invalid/mixed_indentation.py"), &ValidationOptions::default())?;
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 1);
+
+    let error = &result.errors[0];
+    assert_eq!(error.line, Some(3));
+    assert!(error.message.contains("mixes tabs and spaces"), "{}", error.message);
+    assert!(error.suggestion.as_deref().unwrap_or("").contains("spaces only"));
+
+    Ok(())
+}
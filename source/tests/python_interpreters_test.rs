@@ -0,0 +1,56 @@
+use std::process::Command;
+use anyhow::Result;
+
+use synx::validators::{validate_file_detailed, FileValidationConfig, ValidationOptions};
+
+mod common;
+
+use common::fixture;
+
+// The (major, minor) version of an interpreter on PATH, if it runs
+fn interpreter_version(interpreter: &str) -> Option<(u32, u32)> {
+    let output = Command::new(interpreter)
+        .args(["-c", "import sys; print(sys.version_info[0], sys.version_info[1])"])
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let mut parts = stdout.split_whitespace().map(|part| part.parse().ok());
+    Some((parts.next()??, parts.next()??))
+}
+
+fn options_for(interpreters: &[&str]) -> ValidationOptions {
+    let mut config = FileValidationConfig::default();
+    config.validators.python.interpreters = Some(interpreters.iter().map(|name| name.to_string()).collect());
+    ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_every_interpreter_must_accept_the_file() -> Result<()> {
+    // `match` is a syntax error before 3.10, so one older and one newer Python are needed
+    let candidates = (6..=14).map(|minor| format!("python3.{}", minor));
+    let available: Vec<(String, (u32, u32))> = candidates
+        .filter_map(|name| interpreter_version(&name).map(|version| (name, version)))
+        .collect();
+    let old = available.iter().find(|(_, version)| *version < (3, 10));
+    let new = available.iter().find(|(_, version)| *version >= (3, 10));
+    let (Some((old, _)), Some((new, _))) = (old, new) else {
+        eprintln!("Skipping test: needs Python interpreters both older and newer than 3.10");
+        return Ok(());
+    };
+
+    let file = fixture("python/versions/match_statement.py");
+
+    let result = validate_file_detailed(&file, &options_for(&[new.as_str()]))?;
+    assert!(result.success, "{} should accept the match statement", new);
+
+    let result = validate_file_detailed(&file, &options_for(&[old.as_str(), new.as_str()]))?;
+    assert!(!result.success, "{} should reject the match statement", old);
+    assert!(!result.errors.is_empty());
+    assert!(result.errors.iter().all(|error| error.message.starts_with(&format!("{}: ", old))),
+        "issues should name the interpreter that failed: {:?}", result.errors);
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use std::process::Command;
+use anyhow::Result;
+
+use synx::validators::{validate_file_detailed, ErrorType, FileValidationConfig, ValidationOptions};
+
+mod common;
+
+use common::{fixture, options_with};
+
+fn ruff_available() -> bool {
+    Command::new("ruff").arg("--version").output().is_ok()
+        && Command::new("python3").arg("--version").output().is_ok()
+}
+
+#[test]
+fn test_ruff_reports_rule_code() -> Result<()> {
+    if !ruff_available() {
+        eprintln!("ruff not available, skipping");
+        return Ok(());
+    }
+
+    let strict = ValidationOptions { strict: true, ..options_with(FileValidationConfig::default()) };
+    let result = validate_file_detailed(&fixture("python/lint/unused_import.py"), &strict)?;
+
+    assert!(!result.success);
+    let unused = result.errors.iter()
+        .find(|error| error.code.as_deref() == Some("F401"))
+        .expect("unused import should be reported");
+    assert_eq!(unused.error_type, ErrorType::Lint);
+    assert_eq!(unused.line, Some(1));
+    assert_eq!(unused.column, Some(8));
+    assert!(unused.message.contains("os"));
+    Ok(())
+}
+
+#[test]
+fn test_lint_findings_only_warn_outside_strict_mode() -> Result<()> {
+    if !ruff_available() {
+        eprintln!("ruff not available, skipping");
+        return Ok(());
+    }
+
+    let result = validate_file_detailed(&fixture("python/lint/unused_import.py"), &options_with(FileValidationConfig::default()))?;
+
+    assert!(result.success);
+    let unused = result.errors.iter()
+        .find(|error| error.code.as_deref() == Some("F401"))
+        .expect("unused import should still be reported");
+    assert_eq!(unused.error_type, ErrorType::Warning);
+    Ok(())
+}
+
+#[test]
+fn test_ignored_rules_are_passed_to_ruff() -> Result<()> {
+    if !ruff_available() {
+        eprintln!("ruff not available, skipping");
+        return Ok(());
+    }
+
+    let mut config = FileValidationConfig::default();
+    config.validators.python.ignore_rules = Some(vec!["F401".to_string()]);
+
+    let result = validate_file_detailed(&fixture("python/lint/unused_import.py"), &options_with(config))?;
+
+    assert!(result.success);
+    assert!(result.errors.is_empty());
+    Ok(())
+}
+
+// A stand-in ruff that records its arguments and reports one diagnostic
+#[cfg(unix)]
+#[test]
+fn test_ruff_json_is_parsed() -> Result<()> {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    if Command::new("python3").arg("--version").output().is_err() {
+        eprintln!("python3 not available, skipping");
+        return Ok(());
+    }
+
+    let temp_dir = tempfile::tempdir()?;
+    let log = temp_dir.path().join("ruff.args");
+    let ruff = temp_dir.path().join("ruff");
+    fs::write(&ruff, format!(
+        "#!/bin/sh\necho \"$@\" > '{}'\n\
+         echo '[{{\"code\":\"E501\",\"message\":\"Line too long (120 > 88)\",\"location\":{{\"row\":5,\"column\":89}},\"url\":null}}]'\n\
+         exit 1\n",
+        log.display(),
+    ))?;
+    fs::set_permissions(&ruff, fs::Permissions::from_mode(0o755))?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("ruff".to_string(), ruff.to_string_lossy().to_string());
+    config.validators.python.ignore_rules = Some(vec!["F401".to_string(), "W291".to_string()]);
+
+    let options = ValidationOptions { strict: true, ..options_with(config) };
+    let result = validate_file_detailed(&fixture("python/lint/unused_import.py"), &options)?;
+
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].code.as_deref(), Some("E501"));
+    assert_eq!((result.errors[0].line, result.errors[0].column), (Some(5), Some(89)));
+
+    let args = fs::read_to_string(&log)?;
+    assert!(args.contains("--output-format=json"));
+    assert!(args.contains("--ignore F401,W291"));
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+#[test]
+fn test_report_dir_has_a_report_per_invalid_file() -> Result<()> {
+    if Command::new("python3").arg("--version").output().is_err() {
+        eprintln!("python3 not available, skipping");
+        return Ok(());
+    }
+    let project = tempdir()?;
+    fs::create_dir(project.path().join("pkg"))?;
+    fs::write(project.path().join("broken.py"), "def broken(:\n    pass\n")?;
+    fs::write(project.path().join("pkg/also_broken.py"), "print('unclosed\n")?;
+    fs::write(project.path().join("fine.py"), "x = 1\n")?;
+    let reports = tempdir()?;
+    let report_dir = reports.path().join("per-file");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["scan", "--format", "json", "--report-dir"])
+        .arg(&report_dir)
+        .arg(project.path())
+        .output()?;
+    assert_eq!(output.status.code(), Some(1), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    // The directory is created, with a report in the chosen format for each file
+    for (name, invalid) in [("broken.py.json", 1), ("pkg/also_broken.py.json", 1), ("fine.py.json", 0)] {
+        let report: serde_json::Value = serde_json::from_str(&fs::read_to_string(report_dir.join(name))?)?;
+        assert_eq!(report["invalid_files"], invalid, "{}", name);
+    }
+
+    let index: serde_json::Value = serde_json::from_str(&fs::read_to_string(report_dir.join("index.json"))?)?;
+    let entries = index["files"].as_array().expect("index lists files");
+    assert_eq!(entries.len(), 3);
+    let invalid: Vec<&str> = entries.iter()
+        .filter(|entry| entry["valid"] == false)
+        .filter_map(|entry| entry["report"].as_str())
+        .collect();
+    assert_eq!(invalid, ["broken.py.json", "pkg/also_broken.py.json"]);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+// Scan `project` with `config`, returning the JSON report
+fn scan(config: &Path, project: &Path, args: &[&str]) -> Result<serde_json::Value> {
+    let reports = tempdir()?;
+    let report = reports.path().join("report.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(config)
+        .arg("scan")
+        .args(args)
+        .arg("--report").arg(&report)
+        .arg(project)
+        .output()?;
+    assert!(output.status.code().is_some(), "synx was killed");
+    // Without --format the config's default_format picks JSON
+    Ok(serde_json::from_str(&fs::read_to_string(&report)?)?)
+}
+
+#[test]
+fn test_config_excludes_apply_without_flags() -> Result<()> {
+    let project = tempdir()?;
+    fs::create_dir(project.path().join("vendor"))?;
+    fs::write(project.path().join("notes.unknownext"), "kept\n")?;
+    fs::write(project.path().join("vendor/lib.unknownext"), "excluded\n")?;
+
+    let config_dir = tempdir()?;
+    let config = config_dir.path().join("synx.toml");
+    fs::write(&config, "[scan]\nexclude = [\"*/vendor/*\"]\ndefault_format = \"json\"\ndefault_parallel = 2\n")?;
+
+    let report = scan(&config, project.path(), &[])?;
+    assert_eq!(report["total_files"], 1);
+
+    // --exclude adds to the configured patterns rather than replacing them
+    let report = scan(&config, project.path(), &["--exclude", "*/notes.*"])?;
+    assert_eq!(report["total_files"], 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_scan_defaults_are_rejected() -> Result<()> {
+    let config_dir = tempdir()?;
+    let config = config_dir.path().join("synx.toml");
+    fs::write(&config, "[scan]\ndefault_format = \"yaml\"\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--config").arg(&config)
+        .args(["scan", "."])
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)?.contains("default_format 'yaml'"));
+
+    Ok(())
+}
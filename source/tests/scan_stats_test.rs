@@ -0,0 +1,45 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use anyhow::{Result, anyhow};
+use tempfile::tempdir;
+
+// Run `synx scan --incremental --stats` with its cache kept under `home`,
+// returning the reported hit ratio
+fn scan_hit_ratio(dir: &Path, home: &Path) -> Result<f64> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["scan", "--incremental", "--stats"])
+        .arg(dir)
+        .env("HOME", home)
+        .env("XDG_CACHE_HOME", home.join(".cache"))
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stderr.contains("Scan stats:"), "{}", stderr);
+    assert!(stderr.contains("Files: 2 total"), "{}", stderr);
+    assert!(stderr.contains("Elapsed: "), "{}", stderr);
+
+    let line = stderr.lines()
+        .find(|line| line.contains("hit ratio"))
+        .ok_or_else(|| anyhow!("no hit ratio in:\n{}", stderr))?;
+    let ratio = line.split_whitespace()
+        .find_map(|word| word.strip_suffix('%'))
+        .ok_or_else(|| anyhow!("no percentage in '{}'", line))?;
+    Ok(ratio.parse()?)
+}
+
+#[test]
+fn test_second_scan_hits_the_cache() -> Result<()> {
+    let home = tempdir()?;
+    let project = tempdir()?;
+    fs::create_dir(project.path().join("app"))?;
+    fs::write(project.path().join(".env"), "PORT=8080\n")?;
+    fs::write(project.path().join("app/.env"), "DEBUG=true\n")?;
+
+    let first = scan_hit_ratio(project.path(), home.path())?;
+    let second = scan_hit_ratio(project.path(), home.path())?;
+    assert_eq!(first, 0.0);
+    assert!(second > first, "second run should reuse the cache: {} vs {}", second, first);
+
+    Ok(())
+}
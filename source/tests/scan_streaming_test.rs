@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{
+    scan_directory, scan_directory_streaming, FileOutcome, LanguageFilter, ScanResult, SkipReason, ValidationOptions,
+    ValidationResult, ValidatorRegistry,
+};
+
+// Passes every file, counting the calls
+fn counting_options(calls: Arc<AtomicUsize>) -> ValidationOptions {
+    let mut registry = ValidatorRegistry::new();
+    registry.register("widget", Box::new(move |_path, _options| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        Ok(ValidationResult::passed())
+    }));
+    ValidationOptions { registry, ..Default::default() }
+}
+
+#[test]
+fn test_stream_yields_one_item_per_file() -> Result<()> {
+    let temp_dir = tempdir()?;
+    fs::create_dir(temp_dir.path().join("nested"))?;
+    for name in ["a.widget", "b.widget", "nested/c.widget", "skipped.widget", "notes.gadget"] {
+        fs::write(temp_dir.path().join(name), "knob\n")?;
+    }
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let options = counting_options(calls.clone());
+    let exclude = vec!["*skipped*".to_string()];
+    let mut valid = Vec::new();
+    let mut skipped = Vec::new();
+    for file in scan_directory_streaming(temp_dir.path(), &options, &exclude, &LanguageFilter::default())? {
+        let path = file.path.strip_prefix(temp_dir.path())?.to_path_buf();
+        match file.outcome {
+            FileOutcome::Valid => valid.push(path),
+            FileOutcome::Skipped(reason) => skipped.push((path, reason)),
+            outcome => panic!("{}: {:?}", path.display(), outcome),
+        }
+    }
+    valid.sort();
+
+    assert_eq!(valid, ["a.widget", "b.widget", "nested/c.widget"].map(PathBuf::from));
+    assert_eq!(skipped, [(PathBuf::from("notes.gadget"), SkipReason::UnknownType)]);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    Ok(())
+}
+
+#[test]
+fn test_stream_is_lazy() -> Result<()> {
+    let temp_dir = tempdir()?;
+    for i in 0..10 {
+        fs::write(temp_dir.path().join(format!("{}.widget", i)), "knob\n")?;
+    }
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let options = counting_options(calls.clone());
+    let filter = LanguageFilter::default();
+    let mut files = scan_directory_streaming(temp_dir.path(), &options, &[], &filter)?;
+    assert_eq!(calls.load(Ordering::SeqCst), 0, "nothing is validated before the first item is asked for");
+
+    let first: Vec<_> = files.by_ref().take(1).collect();
+    assert_eq!(first.len(), 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "only the file that was taken is validated");
+    Ok(())
+}
+
+#[test]
+fn test_collected_stream_matches_scan() -> Result<()> {
+    let temp_dir = tempdir()?;
+    for name in ["a.widget", "b.widget", "notes.gadget"] {
+        fs::write(temp_dir.path().join(name), "knob\n")?;
+    }
+
+    let options = counting_options(Arc::new(AtomicUsize::new(0)));
+    let filter = LanguageFilter::default();
+    let collected: ScanResult = scan_directory_streaming(temp_dir.path(), &options, &[], &filter)?.collect();
+    let scanned = scan_directory(temp_dir.path(), &options, &[], &filter)?;
+
+    assert_eq!(collected.total_files, 3);
+    assert_eq!(collected.total_files, scanned.total_files);
+    assert_eq!(collected.valid_files, scanned.valid_files);
+    assert_eq!(collected.skipped_files, scanned.skipped_files);
+    assert_eq!(collected.results_by_type["widget"].valid, 2);
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+#[test]
+fn test_summary_mode_prints_single_line() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let mut files = Vec::new();
+    for name in ["one.txt", "two.txt", "three.txt"] {
+        let path = temp_dir.path().join(name);
+        fs::write(&path, "plain text\n")?;
+        files.push(path);
+    }
+    let missing = temp_dir.path().join("missing.txt");
+    files.push(missing.clone());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .arg("--summary")
+        .args(&files)
+        .current_dir(temp_dir.path())
+        .output()?;
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1, "unexpected stdout: {:?}", stdout);
+    assert!(lines[0].starts_with("3 passed, 1 failed in "));
+
+    // The failing file is still reported, on stderr
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains(&missing.display().to_string()));
+    assert!(!output.status.success());
+
+    Ok(())
+}
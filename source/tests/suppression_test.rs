@@ -0,0 +1,58 @@
+use std::fs;
+use std::process::Command;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{validate_file_detailed};
+
+mod common;
+
+use common::{fixture, options};
+
+fn tool_available(tool: &str) -> bool {
+    Command::new(tool).arg("--version").output().is_ok()
+}
+
+#[test]
+fn test_python_disable_line_removes_issue() -> Result<()> {
+    if !tool_available("python3") {
+        eprintln!("python3 not available, skipping");
+        return Ok(());
+    }
+
+    let failing = validate_file_detailed(&fixture("python/suppressed/syntax_error.py"), &options())?;
+    assert!(!failing.success);
+    assert!(failing.errors.iter().any(|e| e.line == Some(2)));
+
+    let silenced = validate_file_detailed(&fixture("python/suppressed/disabled_syntax_error.py"), &options())?;
+    assert!(silenced.success, "errors left: {:?}", silenced.errors);
+    assert_eq!(silenced.suppressed, 1);
+    assert!(silenced.errors.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_rust_disable_next_line_removes_issue() -> Result<()> {
+    if !tool_available("rustc") {
+        eprintln!("rustc not available, skipping");
+        return Ok(());
+    }
+
+    // Written outside the repository, where no Cargo.toml turns it into a cargo check
+    let temp_dir = tempdir()?;
+    let failing = temp_dir.path().join("failing.rs");
+    fs::write(&failing, "pub fn answer() -> i32 {\n    missing_value\n}\n")?;
+    let silenced = temp_dir.path().join("silenced.rs");
+    fs::write(&silenced, "pub fn answer() -> i32 {\n    // synx-disable-next-line E0425\n    missing_value\n}\n")?;
+
+    let result = validate_file_detailed(&failing, &options())?;
+    assert!(!result.success);
+    assert!(result.errors.iter().any(|e| e.code.as_deref() == Some("E0425")));
+
+    let result = validate_file_detailed(&silenced, &options())?;
+    assert!(result.success, "errors left: {:?}", result.errors);
+    assert_eq!(result.suppressed, 1);
+
+    Ok(())
+}
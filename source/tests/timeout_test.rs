@@ -0,0 +1,77 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{validate_file, FileValidationConfig, ValidationOptions};
+
+// A stand-in validator tool that takes a while and then succeeds
+fn slow_stub(dir: &Path) -> Result<PathBuf> {
+    let path = dir.join("slow-tool");
+    fs::write(&path, "#!/bin/sh\nsleep 2\nexit 0\n")?;
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    Ok(path)
+}
+
+#[test]
+fn test_per_type_timeout_overrides_default() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let stub = slow_stub(temp_dir.path())?.to_string_lossy().to_string();
+
+    let python_file = temp_dir.path().join("script.py");
+    fs::write(&python_file, "print('hi')\n")?;
+    let json_file = temp_dir.path().join("data.json");
+    fs::write(&json_file, "{}\n")?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("python3".to_string(), stub.clone());
+    config.tools.insert("jq".to_string(), stub);
+    config.validators.python.timeout_secs = Some(1);
+
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    // Python has a 1s limit, so the 2s stub is killed
+    let error = validate_file(&python_file, &options).unwrap_err();
+    assert!(error.to_string().contains("timed out after 1 seconds"), "{}", error);
+
+    // JSON has no override and falls back to the default, so the stub finishes
+    assert!(validate_file(&json_file, &options)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_global_timeout_applies_without_override() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let stub = slow_stub(temp_dir.path())?.to_string_lossy().to_string();
+
+    let json_file = temp_dir.path().join("data.json");
+    fs::write(&json_file, "{}\n")?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("jq".to_string(), stub);
+
+    let options = ValidationOptions {
+        timeout: 1,
+        config: Some(config.clone()),
+        ..Default::default()
+    };
+    assert!(validate_file(&json_file, &options).is_err());
+
+    // A per-type setting beats the global one
+    config.validators.json.timeout_secs = Some(10);
+    let options = ValidationOptions {
+        timeout: 1,
+        config: Some(config),
+        ..Default::default()
+    };
+    assert!(validate_file(&json_file, &options)?);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{validate_file_detailed, ErrorType, FileValidationConfig, ValidationOptions};
+
+#[test]
+fn test_signal_killed_tool_is_reported_as_crash() -> Result<()> {
+    let temp_dir = tempdir()?;
+    // A stand-in for jq that dies the way an OOM-killed process does
+    let stub = temp_dir.path().join("crashing-tool");
+    fs::write(&stub, "#!/bin/sh\nkill -9 $$\n")?;
+    fs::set_permissions(&stub, fs::Permissions::from_mode(0o755))?;
+
+    let json_file = temp_dir.path().join("data.json");
+    fs::write(&json_file, "{}\n")?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("jq".to_string(), stub.to_string_lossy().to_string());
+    let options = ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    };
+
+    let result = validate_file_detailed(&json_file, &options)?;
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].error_type, ErrorType::ToolCrashed);
+    assert!(result.errors[0].message.contains("killed by signal 9"), "{}", result.errors[0].message);
+
+    Ok(())
+}
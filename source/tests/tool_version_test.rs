@@ -0,0 +1,41 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{tool_version, tool_versions, FileValidationConfig};
+
+#[test]
+fn test_tool_version_is_captured_once() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let calls = temp_dir.path().join("calls.log");
+
+    // A stand-in node that logs each run and reports a made-up version
+    let tool = temp_dir.path().join("fake-node");
+    fs::write(&tool, format!(
+        "#!/bin/sh\necho run >> '{}'\necho 'v99.1.0'\n",
+        calls.display(),
+    ))?;
+    fs::set_permissions(&tool, fs::Permissions::from_mode(0o755))?;
+
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("node".to_string(), tool.to_string_lossy().to_string());
+
+    assert_eq!(tool_version("node", Some(&config)).as_deref(), Some("v99.1.0"));
+    let versions = tool_versions(Some(&config));
+    assert!(versions.contains(&("node", Some("v99.1.0".to_string()))));
+
+    // Later lookups are answered from the session cache
+    assert_eq!(fs::read_to_string(&calls)?.lines().count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_missing_tool_has_no_version() {
+    let mut config = FileValidationConfig::default();
+    config.tools.insert("jq".to_string(), "/nonexistent/synx-test/jq".to_string());
+    assert_eq!(tool_version("jq", Some(&config)), None);
+}
@@ -0,0 +1,45 @@
+use std::process::Command;
+use anyhow::Result;
+
+use synx::{validate_source, FileType, ValidationOptions};
+
+fn python_available() -> bool {
+    Command::new("python3").arg("--version").output().is_ok()
+}
+
+#[test]
+fn test_broken_python_snippet() -> Result<()> {
+    if !python_available() {
+        eprintln!("python3 not available, skipping");
+        return Ok(());
+    }
+
+    let source = "def greet(name):\n    print(\"hi\" name)\n";
+    let result = validate_source(source, FileType::Python, &ValidationOptions::default())?;
+
+    assert!(!result.success);
+    assert!(!result.errors.is_empty());
+    let error = &result.errors[0];
+    assert_eq!(error.file_path, "source.py");
+    assert_eq!(error.line, Some(2));
+    assert!(error.message.contains("SyntaxError"), "{}", error.message);
+    // Nothing about the temporary location leaks into the report
+    assert!(!error.message.contains("synx-source-"));
+
+    Ok(())
+}
+
+#[test]
+fn test_valid_python_snippet() -> Result<()> {
+    if !python_available() {
+        eprintln!("python3 not available, skipping");
+        return Ok(());
+    }
+
+    let result = validate_source("print('hello')\n", FileType::Python, &ValidationOptions::default())?;
+    assert!(result.success);
+    assert!(result.errors.is_empty());
+
+    Ok(())
+}
+
@@ -0,0 +1,83 @@
+use std::fs;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{
+    scan_directory, validate_file, ErrorType, LanguageFilter, ValidationError, ValidationOptions, ValidationResult,
+    ValidatorRegistry,
+};
+use synx::{ValidationConfig, Validator};
+
+// Fails files containing "TODO", counting every call
+fn widget_registry(calls: Arc<AtomicUsize>) -> ValidatorRegistry {
+    let mut registry = ValidatorRegistry::new();
+    registry.register("widget", Box::new(move |path, _options| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        if !fs::read_to_string(path)?.contains("TODO") {
+            return Ok(ValidationResult::passed());
+        }
+        Ok(ValidationResult {
+            success: false,
+            errors: vec![ValidationError {
+                file_path: path.to_string_lossy().to_string(),
+                error_type: ErrorType::SyntaxError,
+                message: "Unfinished widget".to_string(),
+                line: Some(1),
+                column: None,
+                code: None,
+                suggestion: None,
+                severity: synx::analysis::IssueSeverity::High,
+            }],
+            suppressed: 0,
+            hidden_issues: 0,
+        })
+    }));
+    registry
+}
+
+#[test]
+fn test_registered_validator_is_used_for_its_extension() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let done = temp_dir.path().join("done.widget");
+    let unfinished = temp_dir.path().join("unfinished.widget");
+    fs::write(&done, "knob\n")?;
+    fs::write(&unfinished, "TODO: knob\n")?;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let options = ValidationOptions {
+        registry: widget_registry(calls.clone()),
+        ..Default::default()
+    };
+
+    assert!(validate_file(&done, &options)?);
+    assert!(!validate_file(&unfinished, &options)?);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // Scans treat the extension as known instead of skipping it
+    let result = scan_directory(temp_dir.path(), &options, &[], &LanguageFilter::default())?;
+    assert_eq!(result.valid_files, 1);
+    assert_eq!(result.invalid_files, vec![unfinished]);
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_validator_register() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let file = temp_dir.path().join("panel.widget");
+    fs::write(&file, "TODO: panel\n")?;
+
+    let mut config = ValidationConfig::default();
+    config.security.allowed_dirs.push(temp_dir.path().to_path_buf());
+    let calls = Arc::new(AtomicUsize::new(0));
+    config.validators = widget_registry(calls.clone());
+
+    let mut validator = Validator::new(config)?;
+    assert!(!validator.validate_file(&file)?);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::fs;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::validators::{
+    scan_directory, validate_file_detailed, LanguageFilter, SkipReason, ValidationOptions, ValidatorRegistry,
+    VanishedFileError,
+};
+
+#[test]
+fn test_file_removed_mid_scan_is_skipped() -> Result<()> {
+    let dir = tempdir()?;
+    let doomed = dir.path().join("doomed.widget");
+    let kept = dir.path().join("kept.widget");
+    fs::write(&doomed, "widget\n")?;
+    fs::write(&kept, "widget\n")?;
+
+    // Stands in for a build deleting the file after the scan found it: it is
+    // gone by the time the validator reads it
+    let mut registry = ValidatorRegistry::new();
+    registry.register("widget", Box::new(|path, _options| {
+        if path.ends_with("doomed.widget") {
+            fs::remove_file(path)?;
+        }
+        fs::read_to_string(path)?;
+        Ok(synx::validators::ValidationResult::passed())
+    }));
+    let options = ValidationOptions { registry, ..Default::default() };
+
+    let result = scan_directory(dir.path(), &options, &[], &LanguageFilter::default())?;
+    assert_eq!(result.skipped_files, vec![(doomed.clone(), SkipReason::Vanished)]);
+    assert!(result.invalid_files.is_empty());
+    assert_eq!(result.valid_files, 1);
+
+    // Once it's gone, validating it directly says so rather than failing on a read
+    let error = validate_file_detailed(&doomed, &options).unwrap_err();
+    assert!(error.downcast_ref::<VanishedFileError>().is_some(), "{}", error);
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use std::process::Command;
+use anyhow::Result;
+
+#[test]
+fn test_version_json() -> Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["version", "--json"])
+        .output()?;
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(info["version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(info["authors"], synx::AUTHORS);
+    assert!(info["git_sha"].is_string() || info["git_sha"].is_null());
+    assert_eq!(info["build_date"].as_str().map(str::len), Some(10));
+    Ok(())
+}
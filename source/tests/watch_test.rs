@@ -0,0 +1,100 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::config::Config;
+use synx::watch::{watch, WatchHooks};
+
+#[test]
+fn test_success_command_runs_after_change() -> Result<()> {
+    let temp_dir = tempdir()?;
+    let file = temp_dir.path().join("notes.txt");
+    fs::write(&file, "first\n")?;
+    let marker = temp_dir.path().join("ran");
+
+    let mut config = Config::default();
+    config.watch_interval = 0;
+    let hooks = WatchHooks {
+        on_success: Some(format!("echo {{file}} >> {}", marker.display())),
+        on_failure: None,
+    };
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let watcher = {
+        let files = vec![file.to_string_lossy().to_string()];
+        let stop = stop.clone();
+        thread::spawn(move || watch(&files, &config, &hooks, &stop))
+    };
+
+    // Give the watcher time to start, then change the file until the hook fires
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !marker.exists() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(300));
+        fs::write(&file, "second\n")?;
+    }
+    stop.store(true, Ordering::SeqCst);
+    watcher.join().unwrap()?;
+
+    let ran = fs::read_to_string(&marker)?;
+    assert!(ran.contains("notes.txt"), "{}", ran);
+
+    Ok(())
+}
+
+#[test]
+fn test_no_initial_scan_waits_for_a_change() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::{Command, Stdio};
+    use std::sync::mpsc;
+
+    let temp_dir = tempdir()?;
+    let file = temp_dir.path().join(".env");
+    fs::write(&file, "PORT=8080\n")?;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_synx"))
+        .args(["--watch", "--no-initial-scan", "--interval", "0"])
+        .arg(&file)
+        .stdout(Stdio::piped())
+        .spawn()?;
+    let stdout = child.stdout.take().unwrap();
+    let (sender, lines) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let _ = sender.send(line);
+        }
+    });
+
+    // Nothing is validated before watching starts
+    let mut startup = Vec::new();
+    loop {
+        let line = lines.recv_timeout(Duration::from_secs(10))?;
+        let watching = line.contains("Watching 1 file(s)");
+        startup.push(line);
+        if watching {
+            break;
+        }
+    }
+    thread::sleep(Duration::from_millis(500));
+    startup.extend(lines.try_iter());
+    assert!(!startup.iter().any(|line| line.contains("Validat")), "{:?}", startup);
+
+    // A change is validated as usual
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut validated = false;
+    while !validated && Instant::now() < deadline {
+        fs::write(&file, "PORT=9090\n")?;
+        validated = lines.recv_timeout(Duration::from_millis(300))
+            .map_or(false, |line| line.contains("Validation passed"));
+    }
+    child.kill()?;
+    child.wait()?;
+    assert!(validated, "no validation after the file changed");
+
+    Ok(())
+}
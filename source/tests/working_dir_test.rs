@@ -0,0 +1,69 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::path::Path;
+use anyhow::Result;
+use tempfile::tempdir;
+
+use synx::config::CustomValidatorConfig;
+use synx::validators::{validate_file, FileValidationConfig, ValidationOptions};
+
+// A stand-in for eslint that only passes when its config is in the directory it runs in
+fn eslint_stub(working_dir: Option<&str>) -> ValidationOptions {
+    let mut config = FileValidationConfig::default();
+    config.validators.custom.insert("json".to_string(), CustomValidatorConfig {
+        command: "/bin/sh".to_string(),
+        args: Some(vec!["-c".to_string(), "test -f .eslintrc.json".to_string(), "sh".to_string(), "{file}".to_string()]),
+        strict_args: None,
+        success_pattern: None,
+        working_dir: working_dir.map(str::to_string),
+    });
+    ValidationOptions {
+        config: Some(config),
+        ..Default::default()
+    }
+}
+
+fn web_project(dir: &Path) -> Result<std::path::PathBuf> {
+    let web = dir.join("web");
+    fs::create_dir_all(web.join("src"))?;
+    fs::write(web.join("package.json"), "{\"name\": \"web\"}\n")?;
+    fs::write(web.join(".eslintrc.json"), "{\"root\": true}\n")?;
+    let file = web.join("src/data.json");
+    fs::write(&file, "{}\n")?;
+    Ok(file)
+}
+
+#[test]
+fn test_validator_runs_from_project_root() -> Result<()> {
+    let dir = tempdir()?;
+    let file = web_project(dir.path())?;
+
+    // package.json marks web/ as the root, so the tool finds .eslintrc.json there
+    assert!(validate_file(&file, &eslint_stub(None))?);
+
+    // Outside any project the working directory is left alone, and there's no config
+    let loose = dir.path().join("loose.json");
+    fs::write(&loose, "{}\n")?;
+    assert!(!validate_file(&loose, &eslint_stub(None))?);
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_working_dir_overrides_project_root() -> Result<()> {
+    let dir = tempdir()?;
+    let file = web_project(dir.path())?;
+
+    // {dir} is web/src, which has no config
+    assert!(!validate_file(&file, &eslint_stub(Some("{dir}")))?);
+
+    let web = dir.path().join("web").to_string_lossy().to_string();
+    assert!(validate_file(&file, &eslint_stub(Some(&web)))?);
+
+    let missing = dir.path().join("missing").to_string_lossy().to_string();
+    let error = validate_file(&file, &eslint_stub(Some(&missing))).unwrap_err();
+    assert!(error.to_string().contains("is not a directory"), "{}", error);
+
+    Ok(())
+}